@@ -271,6 +271,12 @@ pub struct CardTestingGuardConfig {
     pub customer_id_blocking_threshold: i32,
     /// Determines Redis Expiry for Card Testing Guard for profile
     pub card_testing_guard_expiry: i32,
+    /// IP addresses or CIDR ranges whose payments are exempt from the Card Testing Guard checks
+    #[schema(value_type = Option<Vec<String>>, example = json!(["203.0.113.7", "198.51.100.0/24"]))]
+    pub allowlisted_ips: Option<Vec<String>>,
+    /// Customer ids whose payments are exempt from the Card Testing Guard checks
+    #[schema(value_type = Option<Vec<String>>)]
+    pub allowlisted_customer_ids: Option<Vec<id_type::CustomerId>>,
 }
 
 /// Configuration for payment method blocking based on card attributes
@@ -2108,6 +2114,72 @@ pub struct ToggleAllKVResponse {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct KvMigrationRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Status of KV for the merchant once the migration completes
+    #[schema(example = true)]
+    pub kv_enabled: bool,
+    /// Duration after which the migration fails if the drainer has not caught up with the
+    /// merchant, defaults to an hour
+    #[schema(example = 3600)]
+    pub timeout_in_seconds: Option<u32>,
+    /// Maximum number of KV partitions compared with Postgres before KV is disabled, all the
+    /// partitions of the merchant are compared if not set
+    #[schema(example = 1000)]
+    pub verification_sample_size: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum KvMigrationPhase {
+    /// Waiting for the drainer to apply the entries of the merchant pushed before the migration
+    /// started, the storage scheme has not been switched yet
+    DrainingBeforeSwitch,
+    /// The storage scheme has been switched, waiting for the drainer to apply the entries pushed
+    /// by the requests which were still using KV
+    DrainingAfterSwitch,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct KvMigrationResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Process tracker task which performs the migration
+    pub process_tracker_id: String,
+    /// Status of KV for the merchant once the migration completes
+    #[schema(example = true)]
+    pub kv_enabled: bool,
+    pub phase: KvMigrationPhase,
+    /// Drainer stream entries of the merchant the migration is waiting for, as of the last check
+    pub pending_entries: usize,
+    /// Entries of the merchant in the dead-letter streams, these have to be replayed or
+    /// discarded for the migration to proceed
+    pub dead_lettered_entries: usize,
+    /// Entities found missing or outdated in Postgres while verifying it before disabling KV
+    pub divergences: usize,
+    /// KV partitions left behind by an earlier period of KV, evicted before enabling KV
+    pub partitions_evicted: Option<usize>,
+    pub failure_reason: Option<String>,
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub started_at: time::PrimitiveDateTime,
+    /// Time at which the storage scheme of the merchant was switched
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub switched_at: Option<time::PrimitiveDateTime>,
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_checked_at: Option<time::PrimitiveDateTime>,
+}
+
 /// Merchant connector details used to make payments.
 #[derive(
     Debug,
@@ -2322,6 +2394,14 @@ pub struct ProfileCreate {
     #[schema(value_type = Option<i32>, example = 2)]
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
 
+    /// IANA name of the timezone in which the `time_of_day`, `day_of_week` and `date` routing keys are evaluated, daylight saving time is accounted for. Defaults to UTC
+    #[schema(value_type = Option<String>, example = "Asia/Kolkata")]
+    pub routing_timezone: Option<primitive_wrappers::RoutingTimezone>,
+
+    /// Rules of the built-in fraud engine, evaluated over the payment, customer, device and velocity of the card, email and IP address. Takes precedence over the FRM connector configured on the merchant account
+    #[schema(value_type = Option<NativeFrmConfig>)]
+    pub native_frm_config: Option<common_types::fraud_rule_engine::NativeFrmConfig>,
+
     /// Indicates if manual retry for payment is enabled or not
     pub is_manual_retry_enabled: Option<bool>,
 
@@ -2702,6 +2782,14 @@ pub struct ProfileResponse {
     #[schema(value_type = Option<u32>, example = 2)]
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
 
+    /// IANA name of the timezone in which the `time_of_day`, `day_of_week` and `date` routing keys are evaluated, daylight saving time is accounted for. Defaults to UTC
+    #[schema(value_type = Option<String>, example = "Asia/Kolkata")]
+    pub routing_timezone: Option<primitive_wrappers::RoutingTimezone>,
+
+    /// Rules of the built-in fraud engine, evaluated over the payment, customer, device and velocity of the card, email and IP address. Takes precedence over the FRM connector configured on the merchant account
+    #[schema(value_type = Option<NativeFrmConfig>)]
+    pub native_frm_config: Option<common_types::fraud_rule_engine::NativeFrmConfig>,
+
     /// Indicates if manual retry for payment is enabled or not
     pub is_manual_retry_enabled: Option<bool>,
 
@@ -3085,6 +3173,14 @@ pub struct ProfileUpdate {
     #[schema(value_type = Option<u32>, example = 2)]
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
 
+    /// IANA name of the timezone in which the `time_of_day`, `day_of_week` and `date` routing keys are evaluated, daylight saving time is accounted for. Defaults to UTC
+    #[schema(value_type = Option<String>, example = "Asia/Kolkata")]
+    pub routing_timezone: Option<primitive_wrappers::RoutingTimezone>,
+
+    /// Rules of the built-in fraud engine, evaluated over the payment, customer, device and velocity of the card, email and IP address. Takes precedence over the FRM connector configured on the merchant account
+    #[schema(value_type = Option<NativeFrmConfig>)]
+    pub native_frm_config: Option<common_types::fraud_rule_engine::NativeFrmConfig>,
+
     /// Indicates if manual retry for payment is enabled or not
     pub is_manual_retry_enabled: Option<bool>,
 
@@ -3619,6 +3715,38 @@ pub struct ConnectorAgnosticMitChoice {
 
 impl common_utils::events::ApiEventMetric for ConnectorAgnosticMitChoice {}
 
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookSecretRotationRequest {
+    /// Duration (in seconds) for which outgoing webhooks are signed with both the current and the
+    /// new secret. Defaults to 24 hours.
+    pub grace_period_in_seconds: Option<u32>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookSecretRotationRequest {}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct WebhookSecretRotationResponse {
+    /// The identifier of the profile whose webhook signing secret is being rotated
+    pub profile_id: id_type::ProfileId,
+
+    /// The new secret. Until the grace period ends, its signature is sent in the
+    /// `X-Webhook-Signature-512-Next` header, after which it replaces the current secret.
+    pub staged_payment_response_hash_key: Secret<String>,
+
+    /// Time at which the current secret is retired
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub grace_period_ends_at: time::PrimitiveDateTime,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookSecretRotationResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
 impl common_utils::events::ApiEventMetric for payment_methods::PaymentMethodMigrate {}
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
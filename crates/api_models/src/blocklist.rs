@@ -9,6 +9,20 @@ pub enum BlocklistRequest {
     CardBin(String),
     Fingerprint(String),
     ExtendedCardBin(String),
+    /// A customer email, matched case insensitively and without its `+` sub-address. It is
+    /// stored as its HMAC keyed by the fingerprint secret of the merchant, which is returned in
+    /// place of the email and is accepted as well.
+    Email(String),
+    /// An IPv4 or IPv6 address, or a CIDR range such as `203.0.113.0/24`
+    IpAddress(String),
+    /// The hex encoded SHA-256 digest of the `|` separated user agent, accept language,
+    /// language, color depth, screen width, screen height, time zone, java enabled,
+    /// javascript enabled, os type, os version and device model of the browser information of
+    /// a payment, absent values being empty
+    DeviceFingerprint(String),
+    /// An inclusive range of card BINs of the same length, such as `400000-400999`. It is
+    /// stored over the first 8 digits of the card number, as `40000000-40099999`.
+    CardBinRange(String),
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -20,7 +34,16 @@ pub struct GenerateFingerprintRequest {
 pub struct Card {
     pub card_number: StrongSecret<String>,
 }
-pub type AddToBlocklistRequest = BlocklistRequest;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct AddToBlocklistRequest {
+    #[serde(flatten)]
+    pub data: BlocklistRequest,
+    /// The number of seconds after which the entry stops blocking payments, the entry never
+    /// expires when not provided
+    pub ttl_in_seconds: Option<u32>,
+}
+
 pub type DeleteFromBlocklistRequest = BlocklistRequest;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -30,6 +53,8 @@ pub struct BlocklistResponse {
     pub data_kind: enums::BlocklistDataKind,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: time::PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -76,6 +101,49 @@ pub struct ToggleBlocklistQuery {
     pub status: bool,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ExportBlocklistQuery {
+    /// Export only the entries of this kind, all the entries are exported when not provided
+    #[schema(value_type = Option<BlocklistDataKind>)]
+    pub data_kind: Option<enums::BlocklistDataKind>,
+}
+
+/// A row of the CSV file of a blocklist import
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct BlocklistImportRecord {
+    pub data_kind: enums::BlocklistDataKind,
+    pub data: String,
+    pub ttl_in_seconds: Option<u32>,
+}
+
+/// A row of the CSV file of a blocklist export
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BlocklistExportRecord {
+    pub data_kind: enums::BlocklistDataKind,
+    pub data: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct BlocklistImportFailure {
+    /// The line of the CSV file, the header being the first line
+    pub line_number: usize,
+    pub data: Option<String>,
+    pub error_message: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct BlocklistImportResponse {
+    /// The number of entries added to the blocklist
+    pub imported_count: usize,
+    /// The rows which could not be added to the blocklist
+    pub failed: Vec<BlocklistImportFailure>,
+}
+
+impl ApiEventMetric for AddToBlocklistRequest {}
 impl ApiEventMetric for BlocklistRequest {}
 impl ApiEventMetric for BlocklistResponse {}
 impl ApiEventMetric for ListBlocklistResponse {}
@@ -85,3 +153,5 @@ impl ApiEventMetric for GenerateFingerprintRequest {}
 impl ApiEventMetric for ToggleBlocklistQuery {}
 impl ApiEventMetric for GenerateFingerprintResponsePayload {}
 impl ApiEventMetric for Card {}
+impl ApiEventMetric for ExportBlocklistQuery {}
+impl ApiEventMetric for BlocklistImportResponse {}
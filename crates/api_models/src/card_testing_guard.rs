@@ -0,0 +1,68 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The check of the Card Testing Guard which blocks the payments
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CardTestingGuardBlockType {
+    /// Unsuccessful payments of a card from an IP address
+    CardIp,
+    /// Unsuccessful payments of a card without a customer
+    GuestUserCard,
+    /// Unsuccessful payments of a customer
+    CustomerId,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CardTestingGuardBlock {
+    /// The identifier of the block
+    pub block_id: String,
+
+    /// The check which blocks the payments
+    pub block_type: CardTestingGuardBlockType,
+
+    /// The IP address the payments are blocked from, for `card_ip` blocks
+    #[schema(value_type = Option<String>)]
+    pub ip_address: Option<std::net::IpAddr>,
+
+    /// The customer whose payments are blocked, for `customer_id` blocks
+    #[schema(value_type = Option<String>)]
+    pub customer_id: Option<common_utils::id_type::CustomerId>,
+
+    /// The number of unsuccessful payments counted
+    pub unsuccessful_payment_count: i32,
+
+    /// The number of unsuccessful payments from which payments are blocked
+    pub threshold: i32,
+
+    /// The time at which the counter, and hence the block, expires
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CardTestingGuardBlockListResponse {
+    /// The number of active blocks
+    pub count: usize,
+
+    /// The active blocks of the Business Profile
+    pub data: Vec<CardTestingGuardBlock>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CardTestingGuardBlockRequestInternal {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub block_id: String,
+}
+
+impl ApiEventMetric for CardTestingGuardBlockRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for CardTestingGuardBlock {}
+impl ApiEventMetric for CardTestingGuardBlockListResponse {}
@@ -98,6 +98,8 @@ pub enum ApiErrorResponse {
     InternalServerError(ApiError),
     NotImplemented(ApiError),
     ConnectorError(ApiError, #[serde(skip_serializing)] StatusCode),
+    /// Carries the number of seconds after which the request may be retried
+    TooManyRequests(ApiError, #[serde(skip_serializing)] u64),
     NotFound(ApiError),
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
@@ -131,7 +133,8 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _) => i,
         }
     }
 
@@ -149,7 +152,8 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _) => i,
         }
     }
 
@@ -164,7 +168,8 @@ impl ApiErrorResponse {
             | Self::NotImplemented(_)
             | Self::MethodNotAllowed(_)
             | Self::NotFound(_)
-            | Self::BadRequest(_) => "invalid_request",
+            | Self::BadRequest(_)
+            | Self::TooManyRequests(_, _) => "invalid_request",
             Self::InternalServerError(_) => "api",
             Self::DomainError(_) => "blocked",
             Self::ConnectorError(_, _) => "connector",
@@ -0,0 +1,32 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::process_tracker::admin::{
+    ProcessTrackerActionWrapper, ProcessTrackerId, ProcessTrackerListRequest,
+    ProcessTrackerListResponse, ProcessTrackerResponse,
+};
+
+impl ApiEventMetric for ProcessTrackerListRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for ProcessTrackerListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for ProcessTrackerId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for ProcessTrackerResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl<T> ApiEventMetric for ProcessTrackerActionWrapper<T> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
@@ -0,0 +1,163 @@
+use common_enums::enums;
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmReviewListConstraints {
+    /// The review status to filter on, defaults to `pending`
+    #[schema(value_type = Option<FrmReviewStatus>)]
+    pub status: Option<enums::FrmReviewStatus>,
+    /// Only list reviews assigned to this user
+    pub assigned_to: Option<String>,
+    /// The maximum number of reviews to return
+    pub limit: Option<u32>,
+    /// The number of reviews to skip
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct FrmReviewNote {
+    /// The user who wrote the note, empty for notes added with an API key or by the review SLA
+    pub author: Option<String>,
+    /// The note text
+    pub note: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct FrmReviewResponse {
+    /// The payment under review
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The attempt that was checked by the fraud engine
+    pub attempt_id: String,
+    /// The fraud engine that raised the review
+    pub frm_name: String,
+    /// The decision returned by the fraud engine
+    #[schema(value_type = FraudCheckStatus)]
+    pub frm_status: enums::FraudCheckStatus,
+    /// The reasons returned by the fraud engine
+    #[schema(value_type = Option<Object>)]
+    pub frm_reason: Option<serde_json::Value>,
+    /// The score returned by the fraud engine
+    pub frm_score: Option<i32>,
+    /// The payment details sent to the fraud engine
+    #[schema(value_type = Option<Object>)]
+    pub payment_details: Option<serde_json::Value>,
+    #[schema(value_type = FrmReviewStatus)]
+    pub review_status: enums::FrmReviewStatus,
+    /// The user the review is assigned to
+    pub assigned_to: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub assigned_at: Option<time::PrimitiveDateTime>,
+    /// The time after which the review is decided automatically
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub review_deadline: Option<time::PrimitiveDateTime>,
+    /// The user who decided the review, empty if it was decided automatically
+    pub reviewed_by: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub reviewed_at: Option<time::PrimitiveDateTime>,
+    pub notes: Vec<FrmReviewNote>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct FrmReviewListResponse {
+    /// The number of reviews in the current response
+    pub count: usize,
+    /// The total number of reviews matching the constraints, across all pages
+    pub total_count: usize,
+    pub data: Vec<FrmReviewResponse>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct FrmReviewAssignRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The user to assign the review to
+    pub reviewer_id: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct FrmReviewNoteRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    pub note: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct FrmReviewDecisionRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// An optional note recorded along with the decision
+    pub note: Option<String>,
+}
+
+/// Merchant level configuration for reviews that are not decided in time
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct FrmReviewSlaConfig {
+    /// The number of seconds a review may stay pending
+    pub sla_in_seconds: u32,
+    /// The decision taken once the deadline passes
+    pub action: FrmReviewSlaAction,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FrmReviewSlaAction {
+    Approve,
+    Reject,
+}
+
+impl ApiEventMetric for FrmReviewListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for FrmReviewListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for FrmReviewResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for FrmReviewAssignRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for FrmReviewNoteRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for FrmReviewDecisionRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
@@ -5,6 +5,7 @@ pub mod apple_pay_certificates_migration;
 pub mod authentication;
 pub mod blocklist;
 pub mod card_issuer;
+pub mod card_testing_guard;
 pub mod cards_info;
 pub mod chat;
 pub mod conditional_configs;
@@ -22,6 +23,8 @@ pub mod events;
 pub mod external_service_auth;
 pub mod feature_matrix;
 pub mod files;
+#[cfg(feature = "v1")]
+pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
 pub mod locker_migration;
@@ -58,6 +61,7 @@ pub mod user;
 pub mod user_role;
 pub mod verifications;
 pub mod verify_connector;
+pub mod webhook_endpoints;
 pub mod webhook_events;
 pub mod webhooks;
 
@@ -1,2 +1,3 @@
+pub mod admin;
 #[cfg(feature = "v2")]
 pub mod revenue_recovery;
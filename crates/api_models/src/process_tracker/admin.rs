@@ -0,0 +1,110 @@
+use common_utils::id_type;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::enums;
+
+/// Default number of tasks returned by a single list call
+pub const DEFAULT_PROCESS_TRACKER_LIST_LIMIT: u32 = 20;
+
+/// Maximum number of tasks returned by a single list call
+pub const MAX_PROCESS_TRACKER_LIST_LIMIT: u32 = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerListRequest {
+    /// The runner (workflow) which executes the tasks
+    pub runner: Option<enums::ProcessTrackerRunner>,
+    /// Status of the tasks
+    pub status: Option<enums::ProcessTrackerStatus>,
+    /// Only tasks whose tracking data belongs to this merchant
+    pub merchant_id: Option<id_type::MerchantId>,
+    /// Only tasks scheduled at or after this time
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time_start: Option<PrimitiveDateTime>,
+    /// Only tasks scheduled at or before this time
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time_end: Option<PrimitiveDateTime>,
+    /// Number of tasks to return, defaults to 20 and can be at most 100
+    pub limit: Option<u32>,
+    /// Number of tasks to skip
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessTrackerId {
+    pub process_tracker_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessTrackerResponse {
+    pub id: String,
+    /// Name of the task
+    pub name: Option<String>,
+    /// The runner (workflow) which executes the task
+    pub runner: Option<String>,
+    pub tag: Vec<String>,
+    pub status: enums::ProcessTrackerStatus,
+    pub business_status: String,
+    /// Time at which the task will be picked up next
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+    /// Data the runner needs to execute the task
+    pub tracking_data: serde_json::Value,
+    pub retry_history: ProcessTrackerRetryHistory,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessTrackerRetryHistory {
+    /// Number of times the task has been retried by its runner
+    pub retry_count: i32,
+    /// Events recorded against the task, including the operator actions performed on it
+    pub events: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessTrackerListResponse {
+    pub count: usize,
+    pub data: Vec<ProcessTrackerResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerRunRequest {
+    /// Requeue the task even if it is currently being processed by a consumer
+    #[serde(default)]
+    pub force: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerRescheduleRequest {
+    /// Time at which the task should be picked up
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub schedule_time: PrimitiveDateTime,
+    /// Reschedule the task even if it is currently being processed by a consumer
+    #[serde(default)]
+    pub force: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerFinishRequest {
+    /// Business status to record against the finished task
+    pub business_status: String,
+    /// Reason for finishing the task, recorded in the events of the task
+    pub reason: Option<String>,
+    /// Finish the task even if it is currently being processed by a consumer
+    #[serde(default)]
+    pub force: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessTrackerActionWrapper<T> {
+    pub process_tracker_id: String,
+    pub request: T,
+}
@@ -45,6 +45,67 @@ impl ConnectorSelection {
         }
     }
 }
+
+impl From<ast::RoutableConnectorChoice> for RoutableConnectorChoice {
+    fn from(value: ast::RoutableConnectorChoice) -> Self {
+        Self {
+            choice_kind: RoutableChoiceKind::FullStruct,
+            connector: value.connector,
+            merchant_connector_id: value.merchant_connector_id,
+        }
+    }
+}
+
+impl From<ast::ConnectorSelection> for ConnectorSelection {
+    fn from(value: ast::ConnectorSelection) -> Self {
+        match value {
+            ast::ConnectorSelection::Priority(choices) => {
+                Self::Priority(choices.into_iter().map(Into::into).collect())
+            }
+            ast::ConnectorSelection::VolumeSplit(splits) => Self::VolumeSplit(
+                splits
+                    .into_iter()
+                    .map(|split| ConnectorVolumeSplit {
+                        connector: split.connector.into(),
+                        split: split.split,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "routing_text")]
+impl ast::parser::EuclidParsable for ConnectorSelection {
+    fn parse_output(input: &str) -> ast::parser::ParseResult<&str, Self> {
+        ast::parser::connector_selection(input)
+            .map(|(remaining, selection)| (remaining, selection.into()))
+    }
+}
+
+impl ast::formatter::EuclidFormattable for ConnectorSelection {
+    fn format_output(&self) -> String {
+        match self {
+            Self::Priority(choices) => {
+                ast::formatter::format_output_list(choices.iter().map(|choice| {
+                    ast::formatter::format_connector_choice(
+                        &choice.connector,
+                        choice.merchant_connector_id.as_ref(),
+                    )
+                }))
+            }
+            Self::VolumeSplit(splits) => {
+                ast::formatter::format_output_list(splits.iter().map(|split| {
+                    ast::formatter::format_volume_split(
+                        &split.connector.connector,
+                        split.connector.merchant_connector_id.as_ref(),
+                        split.split,
+                    )
+                }))
+            }
+        }
+    }
+}
 #[cfg(feature = "v2")]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct RoutingConfigRequest {
@@ -429,6 +490,9 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::RealTimePaymentType,
         DirKeyKind::TransactionInitiator,
         DirKeyKind::NetworkTokenType,
+        DirKeyKind::TimeOfDay,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::CalendarDate,
     ];
 }
 
@@ -898,6 +962,79 @@ pub struct RoutingDictionaryRecord {
     /// ```
     #[schema(example = "de_route_456")]
     pub decision_engine_routing_id: Option<String>,
+
+    /// Warnings found while analysing the routing program, such as rules that can never be
+    /// selected. Only returned when the configuration is created, activated or scheduled for
+    /// activation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<RoutingAnalysisWarning>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingAnalysisWarningType {
+    /// The rule can never be selected, as earlier rules match all of its payments
+    ShadowedRule,
+    /// The rule matches some of the payments of an earlier rule which selects a different output
+    OverlappingRules,
+    /// The connector can never accept the payments matched by the rule
+    UnreachableConnector,
+}
+
+/// A likely mistake in a routing program which does not make the program invalid
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingAnalysisWarning {
+    pub warning_type: RoutingAnalysisWarningType,
+    /// Name of the rule the warning is about
+    pub rule_name: String,
+    /// Names of the earlier rules which shadow or overlap with the rule
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_rules: Vec<String>,
+    /// The connector which can never be selected by the rule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector: Option<RoutableConnectors>,
+    /// Description of the warning
+    pub message: String,
+}
+
+impl From<euclid::dssa::types::AnalysisWarning> for RoutingAnalysisWarning {
+    fn from(value: euclid::dssa::types::AnalysisWarning) -> Self {
+        use euclid::dssa::types::AnalysisWarning;
+
+        let message = value.to_string();
+        match value {
+            AnalysisWarning::ShadowedRule {
+                rule_name,
+                shadowed_by,
+            } => Self {
+                warning_type: RoutingAnalysisWarningType::ShadowedRule,
+                rule_name,
+                related_rules: shadowed_by,
+                connector: None,
+                message,
+            },
+            AnalysisWarning::OverlappingRules {
+                rule_name,
+                overlapping_rule_name,
+            } => Self {
+                warning_type: RoutingAnalysisWarningType::OverlappingRules,
+                rule_name,
+                related_rules: vec![overlapping_rule_name],
+                connector: None,
+                message,
+            },
+            AnalysisWarning::UnreachableConnector {
+                rule_name,
+                connector,
+            } => Self {
+                warning_type: RoutingAnalysisWarningType::UnreachableConnector,
+                rule_name,
+                related_rules: Vec::new(),
+                connector: Some(connector),
+                message,
+            },
+        }
+    }
 }
 
 /// Routing dictionary for a merchant.
@@ -1887,6 +2024,440 @@ impl RuleMigrationResponse {
     }
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationRequest {
+    /// The profile whose historical payments are replayed
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The candidate algorithm to replay the payments through
+    pub algorithm: StaticRoutingAlgorithm,
+    /// The time range in which the replayed payments were created
+    pub time_range: common_utils::types::TimeRange,
+    /// The maximum number of payments to replay, defaults to 100 and is capped at 1000
+    pub limit: Option<u32>,
+}
+
+impl RoutingSimulationRequest {
+    pub fn validated_limit(&self) -> u32 {
+        self.limit.unwrap_or(100).min(1000)
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationResponse {
+    /// Number of payments fetched in the time range
+    pub total_count: usize,
+    /// Number of payments which were routed by the candidate algorithm
+    pub simulated_count: usize,
+    /// Number of payments which were not routed to any connector by the candidate algorithm
+    pub unrouted_count: usize,
+    /// Number of payments which were routed by the candidate algorithm, but never reached a connector and hence cannot be compared
+    pub no_connector_count: usize,
+    /// Number of payments which could not be replayed due to an error
+    pub error_count: usize,
+    /// The first few payments which could not be replayed, along with the reason
+    pub errors: Vec<RoutingSimulationError>,
+    /// Number of simulated payments for which the candidate algorithm picked the connector that was actually used
+    pub matched_count: usize,
+    /// Connector distribution across all the simulated payments
+    pub distribution: Vec<RoutingSimulationConnectorCount>,
+    /// Connector distribution per payment method
+    pub by_payment_method: Vec<RoutingSimulationBreakdown>,
+    /// Connector distribution per currency
+    pub by_currency: Vec<RoutingSimulationBreakdown>,
+    /// Connector distribution per card network
+    pub by_card_network: Vec<RoutingSimulationBreakdown>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationError {
+    /// The payment which could not be replayed
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// The reason the payment could not be replayed
+    pub message: String,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationBreakdown {
+    /// The value of the dimension, such as `card` or `USD`
+    pub value: String,
+    /// Number of simulated payments with this value
+    pub simulated_count: usize,
+    /// Number of those payments for which the candidate algorithm picked the connector that was actually used
+    pub matched_count: usize,
+    /// Connector distribution for this value
+    pub distribution: Vec<RoutingSimulationConnectorCount>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationConnectorCount {
+    /// The connector name
+    pub connector: String,
+    /// Number of payments the candidate algorithm would have routed to this connector
+    pub simulated_count: usize,
+    /// Number of payments which were actually routed to this connector
+    pub actual_count: usize,
+}
+
+/// Request to create an advanced routing algorithm from its text form
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingTextConfigRequest {
+    /// Unique name of the routing configuration
+    #[schema(value_type = Option<String>, example = "default_card_routing")]
+    pub name: Option<RoutingConfigName>,
+    /// Optional human-readable description of the routing configuration
+    pub description: Option<String>,
+    /// The routing program written in the routing DSL
+    #[schema(
+        example = "default: [stripe]\n\ncard_rule: [adyen] {\n    payment_method = card\n}\n"
+    )]
+    pub program: String,
+    /// Profile ID associated with this routing configuration
+    #[schema(value_type = Option<String>, example = "profile_123")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    /// Transaction type for which this routing config applies, defaults to `payment`
+    #[schema(example = "payment")]
+    pub transaction_type: Option<TransactionType>,
+}
+
+#[cfg(all(feature = "v1", feature = "routing_text"))]
+impl RoutingTextConfigRequest {
+    /// Parses the program and converts the request into its JSON counterpart
+    pub fn try_into_config_request(
+        self,
+    ) -> Result<RoutingConfigRequest, ast::parser::ProgramParseError> {
+        let program = ast::parser::parse_program::<ConnectorSelection>(&self.program)?;
+
+        Ok(RoutingConfigRequest {
+            name: self.name,
+            description: self.description,
+            algorithm: Some(StaticRoutingAlgorithm::Advanced(program)),
+            profile_id: self.profile_id,
+            transaction_type: self.transaction_type,
+        })
+    }
+}
+
+/// An advanced routing algorithm rendered in its text form
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingTextConfigResponse {
+    #[schema(value_type = String)]
+    pub id: common_utils::id_type::RoutingId,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: String,
+    pub description: String,
+    /// The routing program in its canonical text form
+    pub program: String,
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+/// The kind of decision which was made by evaluating a rule program
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionTraceType {
+    /// Connector selection by the routing algorithm of the profile
+    Routing,
+    /// Surcharge calculation by the surcharge decision manager
+    Surcharge,
+    /// Authentication type override by the conditional configs of the merchant
+    ThreeDs,
+    /// 3DS decision by a three_ds_decision_rule algorithm
+    ThreeDsDecisionRule,
+    /// Fraud decision by the rules of the built-in fraud engine of the profile
+    FraudRule,
+}
+
+/// A single evaluation of a rule program
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RuleEvaluationTrace {
+    pub decision_type: DecisionTraceType,
+    /// The algorithm which was evaluated
+    pub algorithm_id: Option<String>,
+    /// The input the program was evaluated against, with the metadata values and the card BIN
+    /// redacted
+    #[schema(value_type = Object)]
+    pub dsl_input: serde_json::Value,
+    /// Name of the rule which matched, absent when the default selection of the program was used
+    pub matched_rule: Option<String>,
+    /// The output of the matched rule or of the default selection
+    #[schema(value_type = Object)]
+    pub output: serde_json::Value,
+}
+
+impl RuleEvaluationTrace {
+    pub fn new<O: Serialize>(
+        decision_type: DecisionTraceType,
+        algorithm_id: Option<String>,
+        backend_input: &euclid::backend::BackendInput,
+        output: &euclid::backend::BackendOutput<O>,
+    ) -> Self {
+        Self {
+            decision_type,
+            algorithm_id,
+            dsl_input: serde_json::to_value(Self::redact_backend_input(backend_input))
+                .unwrap_or_default(),
+            matched_rule: output.rule_name.clone(),
+            output: serde_json::to_value(output.get_output()).unwrap_or_default(),
+        }
+    }
+
+    /// Traces are retained and emitted as events, so the values which could identify the
+    /// customer or the card are redacted. The metadata keys are kept to show which of them were
+    /// present.
+    fn redact_backend_input(
+        backend_input: &euclid::backend::BackendInput,
+    ) -> euclid::backend::BackendInput {
+        let mut redacted_input = backend_input.clone();
+        if let Some(metadata) = redacted_input.metadata.as_mut() {
+            metadata
+                .values_mut()
+                .for_each(|value| *value = pii::REDACTED.to_string());
+        }
+        redacted_input.payment.card_bin = redacted_input
+            .payment
+            .card_bin
+            .map(|_| pii::REDACTED.to_string());
+        redacted_input.payment.extended_card_bin = redacted_input
+            .payment
+            .extended_card_bin
+            .map(|_| pii::REDACTED.to_string());
+        redacted_input
+    }
+}
+
+/// Reason for which a connector was removed by the eligibility analysis
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EligibilityFilterReason {
+    /// The constraint graph built from the connector configuration rejected the payment
+    ConstraintGraph,
+    /// The connector is not one of the eligible connectors of the request
+    NotInEligibleConnectors,
+    /// The merchant connector account is disabled or does not belong to the merchant
+    InactiveMerchantConnectorAccount,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct EligibilityFilterRemoval {
+    pub connector: RoutableConnectorChoice,
+    pub reasons: Vec<EligibilityFilterReason>,
+    /// Requirements of the connector configuration which the payment did not satisfy, present
+    /// when the connector was rejected by the constraint graph
+    pub constraint_graph_explanation: Option<String>,
+}
+
+/// Outcome of the eligibility analysis performed on the connectors chosen by routing
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct EligibilityTrace {
+    /// Connectors which were removed, along with the reasons for their removal
+    pub removed: Vec<EligibilityFilterRemoval>,
+    /// Eligible connectors from the default fallback which were appended to the selection
+    pub fallback_connectors: Vec<RoutableConnectorChoice>,
+}
+
+/// Everything that went into the connector decision of a payment attempt
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingDecisionTrace {
+    pub attempt_id: String,
+    /// Rule programs evaluated for the attempt, in the order of evaluation
+    pub rule_evaluations: Vec<RuleEvaluationTrace>,
+    /// Connectors chosen by routing, before the eligibility analysis
+    pub routed_connectors: Vec<RoutableConnectorChoice>,
+    pub routing_approach: Option<common_enums::RoutingApproach>,
+    pub eligibility: Option<EligibilityTrace>,
+    /// The connector the attempt was sent to first
+    pub final_connector: Option<RoutableConnectorChoice>,
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+impl RoutingDecisionTrace {
+    pub fn new(attempt_id: String) -> Self {
+        Self {
+            attempt_id,
+            rule_evaluations: Vec::new(),
+            routed_connectors: Vec::new(),
+            routing_approach: None,
+            eligibility: None,
+            final_connector: None,
+            created_at: common_utils::date_time::now(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingDecisionTraceResponse {
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// Traces of the attempts of the payment, ordered by creation time
+    pub traces: Vec<RoutingDecisionTrace>,
+}
+
+/// Guard-rail monitored after a scheduled activation, which reverts to the previously active
+/// algorithm when the authorization rate drops beyond the tolerated threshold
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingRollbackGuardrail {
+    /// Maximum drop in the authorization rate, in percentage points, tolerated after activation
+    #[schema(example = 5.0)]
+    pub max_auth_rate_drop: f64,
+    /// Minimum number of payment attempts routed by the algorithm which must reach a final status
+    /// after activation before the authorization rate is compared with the baseline
+    #[schema(example = 200)]
+    pub min_attempts: u32,
+    /// Duration after activation for which the guard-rail is monitored, defaults to a day and is
+    /// capped at a week. The baseline authorization rate is computed over the same duration
+    /// preceding the activation.
+    #[schema(example = 1440)]
+    pub monitoring_window_in_minutes: Option<u32>,
+}
+
+impl RoutingRollbackGuardrail {
+    pub const DEFAULT_MONITORING_WINDOW_IN_MINUTES: u32 = 24 * 60;
+    pub const MAX_MONITORING_WINDOW_IN_MINUTES: u32 = 7 * 24 * 60;
+
+    pub fn get_monitoring_window(&self) -> time::Duration {
+        time::Duration::minutes(i64::from(
+            self.monitoring_window_in_minutes
+                .unwrap_or(Self::DEFAULT_MONITORING_WINDOW_IN_MINUTES),
+        ))
+    }
+
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        fp_utils::when(
+            !(self.max_auth_rate_drop > 0.0 && self.max_auth_rate_drop <= 100.0),
+            || {
+                Err(ValidationError::InvalidValue {
+                    message: "`max_auth_rate_drop` must be greater than 0 and at most 100"
+                        .to_string(),
+                })
+            },
+        )?;
+        fp_utils::when(self.min_attempts == 0, || {
+            Err(ValidationError::InvalidValue {
+                message: "`min_attempts` must be greater than 0".to_string(),
+            })
+        })?;
+        fp_utils::when(
+            self.monitoring_window_in_minutes.is_some_and(|window| {
+                window == 0 || window > Self::MAX_MONITORING_WINDOW_IN_MINUTES
+            }),
+            || {
+                Err(ValidationError::InvalidValue {
+                    message: format!(
+                        "`monitoring_window_in_minutes` must be between 1 and {}",
+                        Self::MAX_MONITORING_WINDOW_IN_MINUTES
+                    ),
+                })
+            },
+        )
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingActivationScheduleRequest {
+    /// The time at which the algorithm is activated, must be in the future
+    #[schema(value_type = PrimitiveDateTime, example = "2026-10-17T02:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub activate_at: time::PrimitiveDateTime,
+    /// Guard-rail monitored after activation, the algorithm is left active without any
+    /// monitoring when absent
+    pub guardrail: Option<RoutingRollbackGuardrail>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingActivationScheduleWrapper {
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub request: RoutingActivationScheduleRequest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingActivationScheduleStatus {
+    /// The algorithm is yet to be activated
+    Scheduled,
+    /// The algorithm was activated and its guard-rail is being monitored
+    Monitoring,
+    /// The algorithm was activated, and the guard-rail if any was not breached
+    Completed,
+    /// The guard-rail was breached and the previously active algorithm was restored
+    RolledBack,
+    /// Another algorithm was activated for the profile while the guard-rail was being monitored
+    Superseded,
+    /// The schedule was cancelled before it completed
+    Cancelled,
+    /// The algorithm could not be activated or monitored
+    Failed,
+}
+
+/// State of a scheduled activation once the algorithm has been activated
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingActivationDetails {
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub activated_at: time::PrimitiveDateTime,
+    /// The algorithm which was active before the activation, restored on rollback
+    #[schema(value_type = Option<String>)]
+    pub previous_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// Authorization rate, as a percentage, preceding the activation
+    pub baseline_auth_rate: Option<f64>,
+    /// Number of payments which reached a final status preceding the activation
+    pub baseline_attempts: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingActivationScheduleResponse {
+    /// Identifier of the scheduled activation
+    pub schedule_id: String,
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub transaction_type: TransactionType,
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub activate_at: time::PrimitiveDateTime,
+    pub guardrail: Option<RoutingRollbackGuardrail>,
+    pub status: RoutingActivationScheduleStatus,
+    pub activation: Option<RoutingActivationDetails>,
+    /// Warnings found while analysing the routing program when the activation is scheduled
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<RoutingAnalysisWarning>,
+}
+
+/// Content of the outgoing webhook sent when a guard-rail reverts a scheduled activation
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingRollbackDetails {
+    /// Identifier of the scheduled activation which was rolled back
+    pub schedule_id: String,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub transaction_type: TransactionType,
+    /// The algorithm which was deactivated
+    #[schema(value_type = String)]
+    pub rolled_back_algorithm_id: common_utils::id_type::RoutingId,
+    /// The algorithm which was restored, absent when no algorithm was active before activation
+    #[schema(value_type = Option<String>)]
+    pub restored_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    pub guardrail: RoutingRollbackGuardrail,
+    /// Authorization rate, as a percentage, preceding the activation
+    pub baseline_auth_rate: f64,
+    /// Authorization rate, as a percentage, observed after the activation
+    pub observed_auth_rate: f64,
+    /// Number of payments which reached a final status after the activation
+    pub observed_attempts: u64,
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub activated_at: time::PrimitiveDateTime,
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub rolled_back_at: time::PrimitiveDateTime,
+}
+
 /// Source from which the routing result was generated.
 ///
 /// Possible values:
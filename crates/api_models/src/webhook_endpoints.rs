@@ -0,0 +1,176 @@
+use common_enums::EventType;
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use hyperswitch_masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The request body for creating a webhook endpoint under a business profile.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointCreateRequest {
+    /// The URL to which outgoing webhooks are delivered.
+    #[schema(value_type = String, example = "https://example.com/webhooks/refunds")]
+    pub url: Secret<String>,
+
+    /// The secret used to sign webhooks sent to this endpoint. A random secret is generated if
+    /// not provided.
+    #[schema(value_type = Option<String>)]
+    pub signing_secret: Option<Secret<String>>,
+
+    /// The event types delivered to this endpoint. An empty list subscribes the endpoint to all
+    /// event types.
+    #[serde(default)]
+    pub enabled_events: Vec<EventType>,
+
+    /// Whether webhooks are delivered to this endpoint. Defaults to `true`.
+    #[schema(default = true, example = true)]
+    pub is_enabled: Option<bool>,
+
+    /// An optional description of the endpoint.
+    #[schema(max_length = 255, example = "Refunds and disputes for the finance team")]
+    pub description: Option<String>,
+}
+
+/// The request body for updating a webhook endpoint.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointUpdateRequest {
+    /// The URL to which outgoing webhooks are delivered.
+    #[schema(value_type = Option<String>, example = "https://example.com/webhooks/refunds")]
+    pub url: Option<Secret<String>>,
+
+    /// The event types delivered to this endpoint. An empty list subscribes the endpoint to all
+    /// event types.
+    pub enabled_events: Option<Vec<EventType>>,
+
+    /// Whether webhooks are delivered to this endpoint.
+    #[schema(example = true)]
+    pub is_enabled: Option<bool>,
+
+    /// An optional description of the endpoint. An empty string clears the description.
+    #[schema(max_length = 255, example = "Refunds and disputes for the finance team")]
+    pub description: Option<String>,
+}
+
+/// The response body for a webhook endpoint.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whep_018e31720d1b7a2b82677d3032cab959")]
+    pub id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The URL to which outgoing webhooks are delivered.
+    #[schema(value_type = String, example = "https://example.com/webhooks/refunds")]
+    pub url: Secret<String>,
+
+    /// The event types delivered to this endpoint. An empty list indicates that the endpoint is
+    /// subscribed to all event types.
+    pub enabled_events: Vec<EventType>,
+
+    /// Whether webhooks are delivered to this endpoint.
+    pub is_enabled: bool,
+
+    /// An optional description of the endpoint.
+    pub description: Option<String>,
+
+    /// Time at which the endpoint was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time at which the endpoint was last modified.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// The response body when a webhook endpoint is created. The signing secret is only returned once.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointWithSecretResponse {
+    #[serde(flatten)]
+    pub webhook_endpoint: WebhookEndpointResponse,
+
+    /// The secret used to sign webhooks sent to this endpoint.
+    #[schema(value_type = String)]
+    pub signing_secret: Secret<String>,
+}
+
+/// The response body for listing webhook endpoints of a business profile.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointListResponse {
+    /// The number of webhook endpoints in the response.
+    pub count: usize,
+
+    /// The list of webhook endpoints.
+    pub data: Vec<WebhookEndpointResponse>,
+}
+
+/// The response body for deleting a webhook endpoint.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointDeleteResponse {
+    /// The identifier for the webhook endpoint.
+    pub id: String,
+
+    /// The identifier for the Business Profile.
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// Whether the webhook endpoint was deleted.
+    pub deleted: bool,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct WebhookEndpointRequestInternal {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub endpoint_id: String,
+}
+
+impl ApiEventMetric for WebhookEndpointRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointCreateRequest {}
+impl ApiEventMetric for WebhookEndpointUpdateRequest {}
+
+impl ApiEventMetric for WebhookEndpointResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointWithSecretResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        self.webhook_endpoint.get_api_event_type()
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.data.first().map(|endpoint| endpoint.profile_id.clone())?,
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointDeleteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
@@ -5,7 +5,7 @@ use utoipa::ToSchema;
 
 #[cfg(feature = "payouts")]
 use crate::payouts;
-use crate::{disputes, enums as api_enums, mandates, payments, refunds, subscription};
+use crate::{disputes, enums as api_enums, mandates, payments, refunds, routing, subscription};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -470,6 +470,8 @@ pub enum OutgoingWebhookContent {
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
     #[schema(value_type = ConfirmSubscriptionResponse, title = "ConfirmSubscriptionResponse")]
     SubscriptionDetails(Box<subscription::ConfirmSubscriptionResponse>),
+    #[schema(value_type = RoutingRollbackDetails, title = "RoutingRollbackDetails")]
+    RoutingRollbackDetails(Box<routing::RoutingRollbackDetails>),
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
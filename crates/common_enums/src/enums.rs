@@ -569,6 +569,31 @@ pub enum FraudCheckStatus {
     TransactionFailure,
 }
 
+/// The state of a manual review raised by a fraud check
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    strum::Display,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum FrmReviewStatus {
+    /// The payment is waiting for a reviewer's decision
+    Pending,
+    /// The payment was approved after review
+    Approved,
+    /// The payment was rejected after review
+    Rejected,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -673,6 +698,14 @@ pub enum BlocklistDataKind {
     PaymentMethod,
     CardBin,
     ExtendedCardBin,
+    /// A normalized customer email
+    Email,
+    /// An IP address or a CIDR range of addresses
+    IpAddress,
+    /// A fingerprint of the device derived from the browser information of the payment
+    DeviceFingerprint,
+    /// An inclusive range of card BINs
+    CardBinRange,
 }
 
 /// Specifies how the payment is captured.
@@ -1703,6 +1736,7 @@ pub enum EventObjectType {
     MandateDetails,
     PayoutDetails,
     SubscriptionDetails,
+    RoutingRollbackDetails,
 }
 
 #[derive(
@@ -1729,6 +1763,7 @@ pub enum EventClass {
     #[cfg(feature = "payouts")]
     Payouts,
     Subscriptions,
+    Routing,
 }
 
 impl EventClass {
@@ -1768,6 +1803,7 @@ impl EventClass {
                 EventType::PayoutReversed,
             ]),
             Self::Subscriptions => HashSet::from([EventType::InvoicePaid]),
+            Self::Routing => HashSet::from([EventType::RoutingAlgorithmRolledBack]),
         }
     }
 }
@@ -1828,6 +1864,8 @@ pub enum EventType {
     #[cfg(feature = "payouts")]
     PayoutReversed,
     InvoicePaid,
+    /// A scheduled routing algorithm activation was reverted by its guard-rail
+    RoutingAlgorithmRolledBack,
 }
 
 #[derive(
@@ -10643,6 +10681,11 @@ pub enum ProcessTrackerRunner {
     DisputeListWorkflow,
     InvoiceSyncflow,
     PayoutSyncWorkFlow,
+    WebhookSecretRotationWorkflow,
+    RoutingActivationWorkflow,
+    KvConsistencyCheckWorkflow,
+    KvMigrationWorkflow,
+    FrmReviewSlaWorkflow,
 }
 
 #[derive(
@@ -0,0 +1,98 @@
+use common_utils::impl_to_sql_from_sql_json;
+use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use euclid::frontend::{
+    ast::Program,
+    dir::{DirKeyKind, EuclidDirFilter},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Default window over which the velocity of a card, email or IP address is counted, 24 hours
+pub const DEFAULT_VELOCITY_WINDOW_IN_SECONDS: u32 = 86400;
+
+/// Maximum window over which the velocity of a card, email or IP address is counted, 30 days
+pub const MAX_VELOCITY_WINDOW_IN_SECONDS: u32 = 2_592_000;
+
+/// Enum representing the possible outcomes of the fraud rules of a profile
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum FrmRuleDecision {
+    /// Let the payment go through
+    #[default]
+    Accept,
+    /// Cancel the payment as fraudulent
+    Decline,
+    /// Hold the payment until it is approved or rejected by the merchant
+    ManualReview,
+}
+
+/// Struct representing the output of a fraud rule
+#[derive(Serialize, Default, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct FrmDecisionRule {
+    /// The decision taken on the payment when the rule matches
+    pub decision: FrmRuleDecision,
+}
+
+impl FrmDecisionRule {
+    /// Returns the decision
+    pub fn get_decision(&self) -> FrmRuleDecision {
+        self.decision
+    }
+}
+
+impl EuclidDirFilter for FrmDecisionRule {
+    const ALLOWED: &'static [DirKeyKind] = &[
+        DirKeyKind::PaymentMethod,
+        DirKeyKind::CardNetwork,
+        DirKeyKind::CardBin,
+        DirKeyKind::PaymentAmount,
+        DirKeyKind::PaymentCurrency,
+        DirKeyKind::AuthenticationType,
+        DirKeyKind::BillingCountry,
+        DirKeyKind::BusinessCountry,
+        DirKeyKind::IssuerName,
+        DirKeyKind::IssuerCountry,
+        DirKeyKind::CustomerDevicePlatform,
+        DirKeyKind::TimeOfDay,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::CardVelocityCount,
+        DirKeyKind::CardVelocityAmount,
+        DirKeyKind::EmailVelocityCount,
+        DirKeyKind::EmailVelocityAmount,
+        DirKeyKind::IpVelocityCount,
+        DirKeyKind::IpVelocityAmount,
+    ];
+}
+
+/// Whether the fraud rules are evaluated before or after the payment is authorized
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum NativeFrmFlowType {
+    /// Before the payment is sent to the processor, a declined payment is never authorized
+    #[default]
+    Pre,
+    /// After the payment is authorized, the capture is held until the payment is accepted
+    Post,
+}
+
+/// Configuration of the built-in rule based fraud engine of a profile
+#[derive(Serialize, Deserialize, Debug, Clone, FromSqlRow, AsExpression, ToSchema)]
+#[diesel(sql_type = Jsonb)]
+pub struct NativeFrmConfig {
+    /// Whether the rules are evaluated before or after the payment is authorized
+    #[serde(default)]
+    pub flow: NativeFrmFlowType,
+    /// Window over which the `*_velocity_count` and `*_velocity_amount` keys are counted
+    #[serde(default = "default_velocity_window_in_seconds")]
+    pub velocity_window_in_seconds: u32,
+    /// The rules, the decision of the first one matching the payment is taken and the default
+    /// selection is taken when none of them do
+    #[schema(value_type = Object)]
+    pub rules: Program<FrmDecisionRule>,
+}
+
+fn default_velocity_window_in_seconds() -> u32 {
+    DEFAULT_VELOCITY_WINDOW_IN_SECONDS
+}
+
+impl_to_sql_from_sql_json!(NativeFrmConfig);
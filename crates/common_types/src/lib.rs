@@ -5,6 +5,8 @@
 pub mod consts;
 pub mod customers;
 pub mod domain;
+/// types for the built-in rule based fraud engine
+pub mod fraud_rule_engine;
 pub mod payment_methods;
 pub mod payments;
 /// types that are wrappers around primitive types
@@ -1,4 +1,5 @@
 pub use bool_wrappers::*;
+pub use routing_timezone::*;
 pub use safe_string::*;
 pub use u16_wrappers::*;
 pub use u32_wrappers::*;
@@ -494,6 +495,121 @@ mod u16_wrappers {
     }
 }
 
+/// Timezone wrapper that validates input against the IANA timezone database
+mod routing_timezone {
+    use std::str::FromStr;
+
+    use chrono::{Offset, TimeZone};
+    use serde::{de::Error, Deserialize, Serialize};
+
+    /// IANA timezone in which time based routing rules are evaluated, such as `Asia/Kolkata`
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, diesel::expression::AsExpression)]
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub struct RoutingTimezone(chrono_tz::Tz);
+
+    impl RoutingTimezone {
+        /// Returns the IANA name of the timezone
+        pub fn name(&self) -> &'static str {
+            self.0.name()
+        }
+
+        /// Returns the offset from UTC of the timezone at the given instant, accounting for
+        /// daylight saving time
+        pub fn get_utc_offset_at(&self, timestamp: time::OffsetDateTime) -> time::UtcOffset {
+            chrono::DateTime::from_timestamp(timestamp.unix_timestamp(), 0)
+                .and_then(|utc_timestamp| {
+                    let offset_in_seconds = self
+                        .0
+                        .offset_from_utc_datetime(&utc_timestamp.naive_utc())
+                        .fix()
+                        .local_minus_utc();
+                    time::UtcOffset::from_whole_seconds(offset_in_seconds).ok()
+                })
+                .unwrap_or(time::UtcOffset::UTC)
+        }
+    }
+
+    impl FromStr for RoutingTimezone {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            chrono_tz::Tz::from_str(value).map(Self).map_err(|_| {
+                format!("RoutingTimezone must be an IANA timezone name such as `Asia/Kolkata`, found `{value}`")
+            })
+        }
+    }
+
+    impl Serialize for RoutingTimezone {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            self.name().serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for RoutingTimezone {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let value = String::deserialize(deserializer)?;
+            Self::from_str(&value).map_err(D::Error::custom)
+        }
+    }
+
+    impl diesel::deserialize::FromSql<diesel::sql_types::Text, diesel::pg::Pg> for RoutingTimezone {
+        fn from_sql(value: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
+            let value = <String as diesel::deserialize::FromSql<
+                diesel::sql_types::Text,
+                diesel::pg::Pg,
+            >>::from_sql(value)?;
+            Ok(Self::from_str(&value)?)
+        }
+    }
+
+    impl diesel::serialize::ToSql<diesel::sql_types::Text, diesel::pg::Pg> for RoutingTimezone {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>,
+        ) -> diesel::serialize::Result {
+            <str as diesel::serialize::ToSql<diesel::sql_types::Text, diesel::pg::Pg>>::to_sql(
+                self.name(),
+                out,
+            )
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        #![allow(clippy::unwrap_used)]
+        use super::*;
+
+        #[test]
+        fn test_routing_timezone_deserialization() {
+            let timezone: RoutingTimezone = serde_json::from_str(r#""Asia/Kolkata""#).unwrap();
+            assert_eq!(timezone.name(), "Asia/Kolkata");
+            assert!(serde_json::from_str::<RoutingTimezone>(r#""UTC+05:30""#).is_err());
+            assert!(serde_json::from_str::<RoutingTimezone>("330").is_err());
+        }
+
+        #[test]
+        fn test_routing_timezone_follows_daylight_saving_time() {
+            let timezone = RoutingTimezone::from_str("America/New_York").unwrap();
+            let winter = time::macros::datetime!(2025-01-15 12:00 UTC);
+            let summer = time::macros::datetime!(2025-07-15 12:00 UTC);
+            assert_eq!(
+                timezone.get_utc_offset_at(winter),
+                time::macros::offset!(-5)
+            );
+            assert_eq!(
+                timezone.get_utc_offset_at(summer),
+                time::macros::offset!(-4)
+            );
+        }
+    }
+}
+
 /// Safe string wrapper that validates input against XSS attacks
 mod safe_string {
     use std::ops::Deref;
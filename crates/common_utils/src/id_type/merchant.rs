@@ -244,6 +244,21 @@ impl MerchantId {
         )
     }
 
+    /// Get routing decision trace enabled key for merchant
+    pub fn get_routing_decision_trace_enabled_key(&self) -> String {
+        format!("routing_decision_trace_enabled_{}", self.get_string_repr())
+    }
+
+    /// Get the key for the rate limits of the merchant, which replace the configured defaults
+    pub fn get_rate_limits_config_key(&self) -> String {
+        format!("rate_limits_{}", self.get_string_repr())
+    }
+
+    /// Get the key for the SLA applied to fraud check manual reviews of the merchant
+    pub fn get_frm_review_sla_config_key(&self) -> String {
+        format!("frm_review_sla_{}", self.get_string_repr())
+    }
+
     /// Get should perform eligibility check key for payment
     pub fn get_should_perform_eligibility_check_key(&self) -> String {
         format!("should_perform_eligibility_{}", self.get_string_repr())
@@ -30,8 +30,10 @@ pub struct AddressNew {
     pub origin_zip: Option<Encryption>,
 }
 
-#[derive(Clone, Debug, Queryable, Identifiable, Selectable, Serialize, Deserialize)]
-#[diesel(table_name = address, primary_key(address_id), check_for_backend(diesel::pg::Pg))]
+#[derive(
+    Clone, Debug, Queryable, Identifiable, Selectable, AsChangeset, Serialize, Deserialize,
+)]
+#[diesel(table_name = address, primary_key(address_id), treat_none_as_null = true, check_for_backend(diesel::pg::Pg))]
 pub struct Address {
     pub address_id: String,
     pub city: Option<String>,
@@ -110,4 +112,68 @@ impl AddressUpdateInternal {
             ..source
         }
     }
+
+    /// Combines a later update of the same address into this one, the fields set by the later
+    /// update take precedence
+    pub fn merge(self, later: Self) -> Self {
+        Self {
+            city: later.city.or(self.city),
+            country: later.country.or(self.country),
+            line1: later.line1.or(self.line1),
+            line2: later.line2.or(self.line2),
+            line3: later.line3.or(self.line3),
+            state: later.state.or(self.state),
+            zip: later.zip.or(self.zip),
+            first_name: later.first_name.or(self.first_name),
+            last_name: later.last_name.or(self.last_name),
+            phone_number: later.phone_number.or(self.phone_number),
+            country_code: later.country_code.or(self.country_code),
+            modified_at: later.modified_at,
+            updated_by: later.updated_by,
+            email: later.email.or(self.email),
+            origin_zip: later.origin_zip.or(self.origin_zip),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_address_update(city: Option<&str>, country_code: Option<&str>) -> AddressUpdateInternal {
+        AddressUpdateInternal {
+            city: city.map(str::to_owned),
+            country: None,
+            line1: None,
+            line2: None,
+            line3: None,
+            state: None,
+            zip: None,
+            first_name: None,
+            last_name: None,
+            phone_number: None,
+            country_code: country_code.map(str::to_owned),
+            modified_at: common_utils::date_time::now(),
+            updated_by: "postgres_only".to_owned(),
+            email: None,
+            origin_zip: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_prefers_fields_of_later_update() {
+        let earlier = get_address_update(Some("Bangalore"), Some("+91"));
+        let mut later = get_address_update(Some("Chennai"), None);
+        later.modified_at = earlier.modified_at + time::Duration::seconds(1);
+        later.updated_by = "redis_kv".to_owned();
+        let later_modified_at = later.modified_at;
+
+        let merged = earlier.merge(later);
+
+        assert_eq!(merged.city.as_deref(), Some("Chennai"));
+        // Fields not set by the later update are retained
+        assert_eq!(merged.country_code.as_deref(), Some("+91"));
+        assert_eq!(merged.modified_at, later_modified_at);
+        assert_eq!(merged.updated_by, "redis_kv");
+    }
 }
@@ -11,6 +11,9 @@ pub struct BlocklistNew {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    pub range_start: Option<String>,
+    pub range_end: Option<String>,
 }
 
 #[derive(
@@ -23,4 +26,7 @@ pub struct Blocklist {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    pub range_start: Option<String>,
+    pub range_end: Option<String>,
 }
@@ -86,6 +86,9 @@ pub struct Profile {
     pub is_l2_l3_enabled: Option<bool>,
     pub network_tokenization_credentials: Option<Encryption>,
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
+    pub webhook_secret_rotation: Option<WebhookSecretRotation>,
+    pub routing_timezone: Option<primitive_wrappers::RoutingTimezone>,
+    pub native_frm_config: Option<common_types::fraud_rule_engine::NativeFrmConfig>,
 }
 
 #[cfg(feature = "v1")]
@@ -144,6 +147,8 @@ pub struct ProfileNew {
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
+    pub routing_timezone: Option<primitive_wrappers::RoutingTimezone>,
+    pub native_frm_config: Option<common_types::fraud_rule_engine::NativeFrmConfig>,
     pub is_manual_retry_enabled: Option<bool>,
     pub billing_processor_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub is_external_vault_enabled: Option<bool>,
@@ -210,6 +215,8 @@ pub struct ProfileUpdateInternal {
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
+    pub routing_timezone: Option<primitive_wrappers::RoutingTimezone>,
+    pub native_frm_config: Option<common_types::fraud_rule_engine::NativeFrmConfig>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
     pub billing_processor_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
@@ -217,6 +224,7 @@ pub struct ProfileUpdateInternal {
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
     pub network_tokenization_credentials: Option<Encryption>,
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
+    pub webhook_secret_rotation: Option<Option<WebhookSecretRotation>>,
 }
 
 #[cfg(feature = "v1")]
@@ -273,6 +281,8 @@ impl ProfileUpdateInternal {
             merchant_category_code,
             merchant_country_code,
             dispute_polling_interval,
+            routing_timezone,
+            native_frm_config,
             is_manual_retry_enabled,
             always_enable_overcapture,
             is_external_vault_enabled,
@@ -280,6 +290,7 @@ impl ProfileUpdateInternal {
             billing_processor_id,
             network_tokenization_credentials,
             payment_method_blocking,
+            webhook_secret_rotation,
         } = self;
         Profile {
             profile_id: source.profile_id,
@@ -367,6 +378,8 @@ impl ProfileUpdateInternal {
             merchant_category_code: merchant_category_code.or(source.merchant_category_code),
             merchant_country_code: merchant_country_code.or(source.merchant_country_code),
             dispute_polling_interval: dispute_polling_interval.or(source.dispute_polling_interval),
+            routing_timezone: routing_timezone.or(source.routing_timezone),
+            native_frm_config: native_frm_config.or(source.native_frm_config),
             is_manual_retry_enabled: is_manual_retry_enabled.or(source.is_manual_retry_enabled),
             always_enable_overcapture: always_enable_overcapture
                 .or(source.always_enable_overcapture),
@@ -378,6 +391,8 @@ impl ProfileUpdateInternal {
             network_tokenization_credentials: network_tokenization_credentials
                 .or(source.network_tokenization_credentials),
             payment_method_blocking: payment_method_blocking.or(source.payment_method_blocking),
+            webhook_secret_rotation: webhook_secret_rotation
+                .unwrap_or(source.webhook_secret_rotation),
         }
     }
 }
@@ -805,6 +820,8 @@ pub struct CardTestingGuardConfig {
     pub is_customer_id_blocking_enabled: bool,
     pub customer_id_blocking_threshold: i32,
     pub card_testing_guard_expiry: i32,
+    pub allowlisted_ips: Option<Vec<String>>,
+    pub allowlisted_customer_ids: Option<Vec<common_utils::id_type::CustomerId>>,
 }
 
 common_utils::impl_to_sql_from_sql_json!(CardTestingGuardConfig);
@@ -824,6 +841,8 @@ impl Default for CardTestingGuardConfig {
                 common_utils::consts::DEFAULT_CUSTOMER_ID_BLOCKING_THRESHOLD,
             card_testing_guard_expiry:
                 common_utils::consts::DEFAULT_CARD_TESTING_GUARD_EXPIRY_IN_SECS,
+            allowlisted_ips: None,
+            allowlisted_customer_ids: None,
         }
     }
 }
@@ -980,3 +999,16 @@ impl CardBlockingConfig {
 }
 
 common_utils::impl_to_sql_from_sql_json!(PaymentMethodBlockingConfig);
+
+/// State of an in-progress rotation of the webhook signing secret of a profile
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct WebhookSecretRotation {
+    /// The secret that replaces `payment_response_hash_key` once the grace period ends,
+    /// encrypted with the key of the merchant
+    pub staged_payment_response_hash_key: Encryption,
+    /// Until this time, outgoing webhooks are signed with both the current and the staged secret
+    pub grace_period_ends_at: time::PrimitiveDateTime,
+}
+
+common_utils::impl_to_sql_from_sql_json!(WebhookSecretRotation);
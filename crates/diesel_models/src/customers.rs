@@ -141,9 +141,16 @@ impl From<CustomerNew> for Customer {
 
 #[cfg(feature = "v1")]
 #[derive(
-    Clone, Debug, Identifiable, Queryable, Selectable, serde::Deserialize, serde::Serialize,
+    Clone,
+    Debug,
+    Identifiable,
+    Queryable,
+    Selectable,
+    AsChangeset,
+    serde::Deserialize,
+    serde::Serialize,
 )]
-#[diesel(table_name = customers, primary_key(customer_id, merchant_id), check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = customers, primary_key(customer_id, merchant_id), treat_none_as_null = true, check_for_backend(diesel::pg::Pg))]
 pub struct Customer {
     pub customer_id: common_utils::id_type::CustomerId,
     pub merchant_id: common_utils::id_type::MerchantId,
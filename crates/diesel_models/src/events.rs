@@ -27,6 +27,7 @@ pub struct EventNew {
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub webhook_endpoint_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -60,6 +61,7 @@ pub struct Event {
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub webhook_endpoint_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, AsExpression, diesel::FromSqlRow)]
@@ -107,6 +109,11 @@ pub enum EventMetadata {
         invoice_id: Option<common_utils::id_type::InvoiceId>,
         payment_id: Option<common_utils::id_type::PaymentId>,
     },
+    RoutingRollback {
+        schedule_id: String,
+        profile_id: common_utils::id_type::ProfileId,
+        algorithm_id: common_utils::id_type::RoutingId,
+    },
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
@@ -27,6 +27,28 @@ pub struct FraudCheck {
     pub modified_at: PrimitiveDateTime,
     pub last_step: FraudCheckLastStep,
     pub payment_capture_method: Option<storage_enums::CaptureMethod>, // In postFrm, we are updating capture method from automatic to manual. To store the merchant actual capture method, we are storing the actual capture method in payment_capture_method. It will be useful while approving the FRM decision.
+    pub review_status: Option<storage_enums::FrmReviewStatus>,
+    pub review_assigned_to: Option<String>,
+    pub review_assigned_at: Option<PrimitiveDateTime>,
+    pub review_deadline: Option<PrimitiveDateTime>,
+    pub review_notes: Option<FrmReviewNotes>,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct FrmReviewNotes(pub Vec<FrmReviewNote>);
+
+common_utils::impl_to_sql_from_sql_json!(FrmReviewNotes);
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct FrmReviewNote {
+    /// The user who wrote the note, `None` for notes added with an API key or by the review SLA
+    pub author: Option<String>,
+    pub note: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
 }
 
 #[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -68,6 +90,22 @@ pub enum FraudCheckUpdate {
         status: FraudCheckStatus,
         error_message: Option<Option<String>>,
     },
+    ReviewPendingUpdate {
+        review_deadline: Option<PrimitiveDateTime>,
+    },
+    ReviewAssignmentUpdate {
+        assigned_to: String,
+        assigned_at: PrimitiveDateTime,
+    },
+    ReviewNotesUpdate {
+        review_notes: FrmReviewNotes,
+    },
+    ReviewDecisionUpdate {
+        review_status: storage_enums::FrmReviewStatus,
+        reviewed_by: Option<String>,
+        reviewed_at: PrimitiveDateTime,
+        review_notes: Option<FrmReviewNotes>,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -79,8 +117,15 @@ pub struct FraudCheckUpdateInternal {
     frm_score: Option<i32>,
     frm_error: Option<Option<String>>,
     metadata: Option<serde_json::Value>,
-    last_step: FraudCheckLastStep,
+    last_step: Option<FraudCheckLastStep>,
     payment_capture_method: Option<storage_enums::CaptureMethod>,
+    review_status: Option<storage_enums::FrmReviewStatus>,
+    review_assigned_to: Option<String>,
+    review_assigned_at: Option<PrimitiveDateTime>,
+    review_deadline: Option<PrimitiveDateTime>,
+    review_notes: Option<FrmReviewNotes>,
+    reviewed_by: Option<String>,
+    reviewed_at: Option<PrimitiveDateTime>,
 }
 
 impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
@@ -101,7 +146,7 @@ impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
                 frm_reason,
                 frm_score,
                 metadata,
-                last_step,
+                last_step: Some(last_step),
                 payment_capture_method,
                 ..Default::default()
             },
@@ -111,6 +156,36 @@ impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
             } => Self {
                 frm_status: Some(status),
                 frm_error: error_message,
+                last_step: Some(FraudCheckLastStep::default()),
+                ..Default::default()
+            },
+            FraudCheckUpdate::ReviewPendingUpdate { review_deadline } => Self {
+                review_status: Some(storage_enums::FrmReviewStatus::Pending),
+                review_deadline,
+                ..Default::default()
+            },
+            FraudCheckUpdate::ReviewAssignmentUpdate {
+                assigned_to,
+                assigned_at,
+            } => Self {
+                review_assigned_to: Some(assigned_to),
+                review_assigned_at: Some(assigned_at),
+                ..Default::default()
+            },
+            FraudCheckUpdate::ReviewNotesUpdate { review_notes } => Self {
+                review_notes: Some(review_notes),
+                ..Default::default()
+            },
+            FraudCheckUpdate::ReviewDecisionUpdate {
+                review_status,
+                reviewed_by,
+                reviewed_at,
+                review_notes,
+            } => Self {
+                review_status: Some(review_status),
+                reviewed_by,
+                reviewed_at: Some(reviewed_at),
+                review_notes,
                 ..Default::default()
             },
         }
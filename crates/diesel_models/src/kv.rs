@@ -62,6 +62,49 @@ impl DBOperation {
             },
         }
     }
+
+    /// Combines the next operation into this one when both update the same row of a table whose
+    /// changesets only set the fields they contain, otherwise both operations are returned back
+    pub fn coalesce(self, next: Self) -> Result<Self, (Self, Self)> {
+        let (current, next) = match (self, next) {
+            (Self::Update { updatable: current }, Self::Update { updatable: next }) => {
+                (*current, *next)
+            }
+            operations => return Err(operations),
+        };
+
+        match (current, next) {
+            (Updateable::AddressUpdate(current), Updateable::AddressUpdate(next))
+                if current.orig.address_id == next.orig.address_id =>
+            {
+                Ok(Self::Update {
+                    updatable: Box::new(Updateable::AddressUpdate(Box::new(AddressUpdateMems {
+                        orig: current.orig,
+                        update_data: current.update_data.merge(next.update_data),
+                    }))),
+                })
+            }
+            (Updateable::MandateUpdate(current), Updateable::MandateUpdate(next))
+                if current.orig.merchant_id == next.orig.merchant_id
+                    && current.orig.mandate_id == next.orig.mandate_id =>
+            {
+                Ok(Self::Update {
+                    updatable: Box::new(Updateable::MandateUpdate(MandateUpdateMems {
+                        orig: current.orig,
+                        update_data: current.update_data.merge(next.update_data),
+                    })),
+                })
+            }
+            (current, next) => Err((
+                Self::Update {
+                    updatable: Box::new(current),
+                },
+                Self::Update {
+                    updatable: Box::new(next),
+                },
+            )),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -301,3 +344,120 @@ pub struct MandateUpdateMems {
     pub orig: Mandate,
     pub update_data: MandateUpdateInternal,
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::panic)]
+
+    use super::*;
+    use crate::{enums::MandateStatus, mandate::MandateUpdate};
+
+    fn get_mandate(mandate_id: &str) -> Mandate {
+        Mandate::from(&MandateNew {
+            mandate_id: mandate_id.to_owned(),
+            merchant_id: common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+                "merchant_1",
+            ))
+            .expect("valid merchant id"),
+            mandate_status: MandateStatus::Active,
+            ..Default::default()
+        })
+    }
+
+    fn get_mandate_update(mandate_id: &str, mandate_update: MandateUpdate) -> DBOperation {
+        DBOperation::Update {
+            updatable: Box::new(Updateable::MandateUpdate(MandateUpdateMems {
+                orig: get_mandate(mandate_id),
+                update_data: MandateUpdateInternal::from(mandate_update),
+            })),
+        }
+    }
+
+    fn get_mandate_update_mems(operation: DBOperation) -> MandateUpdateMems {
+        match operation {
+            DBOperation::Update { updatable } => match *updatable {
+                Updateable::MandateUpdate(mandate_update) => mandate_update,
+                updatable => panic!("Unexpected update {updatable:?}"),
+            },
+            DBOperation::Insert { insertable } => panic!("Unexpected insert {insertable:?}"),
+        }
+    }
+
+    #[test]
+    fn test_coalesce_merges_updates_of_same_row_in_order() {
+        let coalesced = get_mandate_update(
+            "mandate_1",
+            MandateUpdate::CaptureAmountUpdate {
+                amount_captured: Some(100),
+            },
+        )
+        .coalesce(get_mandate_update(
+            "mandate_1",
+            MandateUpdate::StatusUpdate {
+                mandate_status: MandateStatus::Inactive,
+            },
+        ))
+        .and_then(|coalesced| {
+            coalesced.coalesce(get_mandate_update(
+                "mandate_1",
+                MandateUpdate::StatusUpdate {
+                    mandate_status: MandateStatus::Revoked,
+                },
+            ))
+        })
+        .expect("updates of the same mandate are coalesced");
+
+        let MandateUpdateMems { orig, update_data } = get_mandate_update_mems(coalesced);
+        let mandate = update_data.apply_changeset(orig);
+
+        assert_eq!(mandate.mandate_status, MandateStatus::Revoked);
+        assert_eq!(mandate.amount_captured, Some(100));
+    }
+
+    #[test]
+    fn test_coalesce_keeps_updates_of_different_rows() {
+        let (current, next) = get_mandate_update(
+            "mandate_1",
+            MandateUpdate::StatusUpdate {
+                mandate_status: MandateStatus::Inactive,
+            },
+        )
+        .coalesce(get_mandate_update(
+            "mandate_2",
+            MandateUpdate::StatusUpdate {
+                mandate_status: MandateStatus::Revoked,
+            },
+        ))
+        .expect_err("updates of different mandates are not coalesced");
+
+        // The operations are returned in the order they are to be applied
+        assert_eq!(
+            get_mandate_update_mems(current).orig.mandate_id,
+            "mandate_1"
+        );
+        assert_eq!(get_mandate_update_mems(next).orig.mandate_id, "mandate_2");
+    }
+
+    #[test]
+    fn test_coalesce_keeps_inserts() {
+        let insert = DBOperation::Insert {
+            insertable: Box::new(Insertable::Mandate(MandateNew {
+                mandate_id: "mandate_1".to_owned(),
+                ..Default::default()
+            })),
+        };
+        let update = get_mandate_update(
+            "mandate_1",
+            MandateUpdate::StatusUpdate {
+                mandate_status: MandateStatus::Revoked,
+            },
+        );
+
+        let (current, next) = insert
+            .coalesce(update)
+            .expect_err("inserts are not coalesced");
+
+        assert_eq!(current.operation(), "insert");
+        assert_eq!(next.operation(), "update");
+    }
+}
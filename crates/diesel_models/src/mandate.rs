@@ -222,6 +222,20 @@ impl MandateUpdateInternal {
             ..source
         }
     }
+
+    /// Combines a later update of the same mandate into this one, the fields set by the later
+    /// update take precedence
+    pub fn merge(self, later: Self) -> Self {
+        Self {
+            mandate_status: later.mandate_status.or(self.mandate_status),
+            amount_captured: later.amount_captured.or(self.amount_captured),
+            connector_mandate_ids: later.connector_mandate_ids.or(self.connector_mandate_ids),
+            connector_mandate_id: later.connector_mandate_id.or(self.connector_mandate_id),
+            payment_method_id: later.payment_method_id.or(self.payment_method_id),
+            original_payment_id: later.original_payment_id.or(self.original_payment_id),
+            updated_by: later.updated_by.or(self.updated_by),
+        }
+    }
 }
 
 impl From<&MandateNew> for Mandate {
@@ -258,3 +272,29 @@ impl From<&MandateNew> for Mandate {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_prefers_fields_of_later_update() {
+        let merged = MandateUpdateInternal::from(MandateUpdate::CaptureAmountUpdate {
+            amount_captured: Some(100),
+        })
+        .merge(MandateUpdateInternal::from(MandateUpdate::StatusUpdate {
+            mandate_status: storage_enums::MandateStatus::Inactive,
+        }))
+        .merge(MandateUpdateInternal::from(MandateUpdate::StatusUpdate {
+            mandate_status: storage_enums::MandateStatus::Revoked,
+        }));
+
+        assert_eq!(
+            merged.mandate_status,
+            Some(storage_enums::MandateStatus::Revoked)
+        );
+        // Fields not set by the later updates are retained
+        assert_eq!(merged.amount_captured, Some(100));
+        assert_eq!(merged.connector_mandate_id, None);
+    }
+}
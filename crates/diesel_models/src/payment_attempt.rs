@@ -195,9 +195,18 @@ pub struct PaymentAttempt {
 
 #[cfg(feature = "v1")]
 #[derive(
-    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize, Selectable,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Identifiable,
+    Queryable,
+    Serialize,
+    Deserialize,
+    Selectable,
+    AsChangeset,
 )]
-#[diesel(table_name = payment_attempt, primary_key(attempt_id, merchant_id), check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = payment_attempt, primary_key(attempt_id, merchant_id), treat_none_as_null = true, check_for_backend(diesel::pg::Pg))]
 pub struct PaymentAttempt {
     pub payment_id: id_type::PaymentId,
     pub merchant_id: id_type::MerchantId,
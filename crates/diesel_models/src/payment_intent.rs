@@ -112,8 +112,18 @@ pub struct PaymentIntent {
 }
 
 #[cfg(feature = "v1")]
-#[derive(Clone, Debug, PartialEq, Identifiable, Queryable, Serialize, Deserialize, Selectable)]
-#[diesel(table_name = payment_intent, primary_key(payment_id, merchant_id), check_for_backend(diesel::pg::Pg))]
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Identifiable,
+    Queryable,
+    Serialize,
+    Deserialize,
+    Selectable,
+    AsChangeset,
+)]
+#[diesel(table_name = payment_intent, primary_key(payment_id, merchant_id), treat_none_as_null = true, check_for_backend(diesel::pg::Pg))]
 pub struct PaymentIntent {
     pub payment_id: common_utils::id_type::PaymentId,
     pub merchant_id: common_utils::id_type::MerchantId,
@@ -133,6 +133,13 @@ pub enum ProcessTrackerUpdate {
         retry_count: i32,
         schedule_time: PrimitiveDateTime,
     },
+    /// Update made by an operator, the description of which is appended to the events of the task
+    ManualUpdate {
+        status: storage_enums::ProcessTrackerStatus,
+        business_status: Option<String>,
+        schedule_time: Option<PrimitiveDateTime>,
+        event: Vec<String>,
+    },
 }
 
 #[derive(Debug, Clone, AsChangeset, router_derive::DebugAsDisplay)]
@@ -144,6 +151,7 @@ pub struct ProcessTrackerUpdateInternal {
     tracking_data: Option<serde_json::Value>,
     business_status: Option<String>,
     status: Option<storage_enums::ProcessTrackerStatus>,
+    event: Option<Vec<String>>,
     updated_at: Option<PrimitiveDateTime>,
 }
 
@@ -156,6 +164,7 @@ impl Default for ProcessTrackerUpdateInternal {
             tracking_data: Option::default(),
             business_status: Option::default(),
             status: Option::default(),
+            event: Option::default(),
             updated_at: Some(common_utils::date_time::now()),
         }
     }
@@ -180,6 +189,7 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
                 business_status,
                 status,
                 updated_at,
+                ..Default::default()
             },
             ProcessTrackerUpdate::StatusUpdate {
                 status,
@@ -199,6 +209,18 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
                 schedule_time: Some(schedule_time),
                 ..Default::default()
             },
+            ProcessTrackerUpdate::ManualUpdate {
+                status,
+                business_status,
+                schedule_time,
+                event,
+            } => Self {
+                status: Some(status),
+                business_status,
+                schedule_time,
+                event: Some(event),
+                ..Default::default()
+            },
         }
     }
 }
@@ -302,4 +324,39 @@ pub mod business_status {
 
     /// This status indicates the workflow has completed successfully when the invoice is paid
     pub const CALCULATE_WORKFLOW_COMPLETE: &str = "CALCULATE_WORKFLOW_COMPLETE";
+
+    /// For the ROUTING_ACTIVATION_WORKFLOW
+    ///
+    /// This status indicates that the algorithm was activated and its guard-rail is being monitored
+    pub const ROUTING_GUARDRAIL_MONITORING: &str = "ROUTING_GUARDRAIL_MONITORING";
+
+    /// This status indicates that the guard-rail was breached and the previous algorithm was restored
+    pub const ROUTING_ROLLED_BACK: &str = "ROUTING_ROLLED_BACK";
+
+    /// This status indicates that the scheduled activation was cancelled by the merchant
+    pub const ROUTING_ACTIVATION_CANCELLED: &str = "ROUTING_ACTIVATION_CANCELLED";
+
+    /// For the KV_CONSISTENCY_CHECK_WORKFLOW
+    ///
+    /// This status indicates that the KV entities checked were consistent with Postgres
+    pub const KV_CONSISTENT: &str = "KV_CONSISTENT";
+
+    /// This status indicates that some KV entities checked diverged from Postgres
+    pub const KV_DIVERGED: &str = "KV_DIVERGED";
+
+    /// For the KV_MIGRATION_WORKFLOW
+    ///
+    /// This status indicates that the migration is waiting for the drainer before switching the
+    /// storage scheme of the merchant
+    pub const KV_MIGRATION_DRAINING: &str = "KV_MIGRATION_DRAINING";
+
+    /// This status indicates that the storage scheme of the merchant has been switched, and the
+    /// migration is waiting for the drainer to apply the remaining entries
+    pub const KV_MIGRATION_SWITCHED: &str = "KV_MIGRATION_SWITCHED";
+
+    /// This status indicates that the migration completed
+    pub const KV_MIGRATION_COMPLETED: &str = "KV_MIGRATION_COMPLETED";
+
+    /// This status indicates that the migration failed, the reason is recorded in the tracking data
+    pub const KV_MIGRATION_FAILED: &str = "KV_MIGRATION_FAILED";
 }
@@ -51,3 +51,4 @@ pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
 mod utils;
+pub mod webhook_endpoint;
@@ -74,6 +74,22 @@ impl Address {
         }
     }
 
+    /// Overwrites the row with this version of the address, if the row was not modified after
+    /// `modified_at`. Returns whether the row was overwritten.
+    pub async fn overwrite_if_unmodified(
+        self,
+        conn: &PgPooledConn,
+        modified_at: time::PrimitiveDateTime,
+    ) -> StorageResult<bool> {
+        let predicate = dsl::address_id
+            .eq(self.address_id.to_owned())
+            .and(dsl::modified_at.eq(modified_at));
+
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(conn, predicate, self)
+            .await
+            .map(|updated_rows| updated_rows > 0)
+    }
+
     pub async fn delete_by_address_id(
         conn: &PgPooledConn,
         address_id: &str,
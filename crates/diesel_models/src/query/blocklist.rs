@@ -39,7 +39,12 @@ impl Blocklist {
             conn,
             dsl::merchant_id
                 .eq(merchant_id.to_owned())
-                .and(dsl::data_kind.eq(data_kind.to_owned())),
+                .and(dsl::data_kind.eq(data_kind.to_owned()))
+                .and(
+                    dsl::expires_at
+                        .is_null()
+                        .or(dsl::expires_at.gt(common_utils::date_time::now())),
+                ),
             Some(limit),
             Some(offset),
             Some(dsl::created_at.desc()),
@@ -56,7 +61,83 @@ impl Blocklist {
             conn,
             dsl::merchant_id
                 .eq(merchant_id.to_owned())
-                .and(dsl::data_kind.eq(data_kind.to_owned())),
+                .and(dsl::data_kind.eq(data_kind.to_owned()))
+                .and(
+                    dsl::expires_at
+                        .is_null()
+                        .or(dsl::expires_at.gt(common_utils::date_time::now())),
+                ),
+        )
+        .await
+    }
+
+    /// Lists the entries of a kind which have not expired, without pagination
+    pub async fn list_unexpired_by_merchant_id_data_kind(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kind: common_enums::BlocklistDataKind,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::data_kind.eq(data_kind))
+                .and(
+                    dsl::expires_at
+                        .is_null()
+                        .or(dsl::expires_at.gt(common_utils::date_time::now())),
+                ),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn find_unexpired_by_merchant_id_fingerprint_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::fingerprint_id.eq_any(fingerprint_ids))
+                .and(
+                    dsl::expires_at
+                        .is_null()
+                        .or(dsl::expires_at.gt(common_utils::date_time::now())),
+                ),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    /// Finds the entries of a range kind whose bounds hold the value
+    pub async fn find_unexpired_by_merchant_id_data_kind_range_value(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kind: common_enums::BlocklistDataKind,
+        value: String,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::data_kind.eq(data_kind))
+                .and(dsl::range_start.le(value.clone()))
+                .and(dsl::range_end.ge(value))
+                .and(
+                    dsl::expires_at
+                        .is_null()
+                        .or(dsl::expires_at.gt(common_utils::date_time::now())),
+                ),
+            Some(1),
+            None,
+            Some(dsl::created_at.desc()),
         )
         .await
     }
@@ -283,6 +283,24 @@ impl Customer {
         }
     }
 
+    /// Overwrites the row with this version of the customer, if the row was not modified after
+    /// `modified_at`. Returns whether the row was overwritten.
+    #[cfg(feature = "v1")]
+    pub async fn overwrite_if_unmodified(
+        self,
+        conn: &PgPooledConn,
+        modified_at: time::PrimitiveDateTime,
+    ) -> StorageResult<bool> {
+        let predicate = dsl::customer_id
+            .eq(self.customer_id.to_owned())
+            .and(dsl::merchant_id.eq(self.merchant_id.to_owned()))
+            .and(dsl::modified_at.eq(modified_at));
+
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(conn, predicate, self)
+            .await
+            .map(|updated_rows| updated_rows > 0)
+    }
+
     #[cfg(feature = "v1")]
     pub async fn delete_by_customer_id_merchant_id(
         conn: &PgPooledConn,
@@ -65,4 +65,62 @@ impl FraudCheck {
         )
         .await
     }
+
+    pub async fn list_by_merchant_id_review_status(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_status: common_enums::FrmReviewStatus,
+        assigned_to: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let predicate = dsl::merchant_id
+            .eq(merchant_id.to_owned())
+            .and(dsl::review_status.eq(review_status));
+
+        match assigned_to {
+            Some(assigned_to) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate.and(dsl::review_assigned_to.eq(assigned_to.to_owned())),
+                    Some(limit),
+                    Some(offset),
+                    Some(dsl::created_at.asc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    Some(limit),
+                    Some(offset),
+                    Some(dsl::created_at.asc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn count_by_merchant_id_review_status(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_status: common_enums::FrmReviewStatus,
+        assigned_to: Option<&str>,
+    ) -> StorageResult<usize> {
+        let predicate = dsl::merchant_id
+            .eq(merchant_id.to_owned())
+            .and(dsl::review_status.eq(review_status));
+
+        match assigned_to {
+            Some(assigned_to) => {
+                generics::generic_count::<<Self as HasTable>::Table, _>(
+                    conn,
+                    predicate.and(dsl::review_assigned_to.eq(assigned_to.to_owned())),
+                )
+                .await
+            }
+            None => generics::generic_count::<<Self as HasTable>::Table, _>(conn, predicate).await,
+        }
+    }
 }
@@ -59,6 +59,24 @@ impl PaymentAttempt {
         }
     }
 
+    /// Overwrites the row with this version of the payment attempt, if the row was not modified
+    /// after `modified_at`. Returns whether the row was overwritten.
+    #[cfg(feature = "v1")]
+    pub async fn overwrite_if_unmodified(
+        self,
+        conn: &PgPooledConn,
+        modified_at: time::PrimitiveDateTime,
+    ) -> StorageResult<bool> {
+        let predicate = dsl::attempt_id
+            .eq(self.attempt_id.to_owned())
+            .and(dsl::processor_merchant_id.eq(self.processor_merchant_id.to_owned()))
+            .and(dsl::modified_at.eq(modified_at));
+
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(conn, predicate, self)
+            .await
+            .map(|updated_rows| updated_rows > 0)
+    }
+
     #[cfg(feature = "v2")]
     pub async fn update_with_attempt_id(
         self,
@@ -550,4 +568,38 @@ impl PaymentAttempt {
 
         result
     }
+
+    /// Counts the attempts of a profile created in the time range by their status, considering
+    /// only the attempts which were routed through one of the given routing approaches
+    #[cfg(feature = "v1")]
+    pub async fn get_attempt_status_with_count(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        routing_approaches: Vec<enums::RoutingApproach>,
+        time_range: &common_utils::types::TimeRange,
+    ) -> StorageResult<Vec<(enums::AttemptStatus, i64)>> {
+        let mut query = <Self as HasTable>::table()
+            .group_by(dsl::status)
+            .select((dsl::status, diesel::dsl::count_star()))
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::profile_id.eq(profile_id.to_owned()))
+            .filter(dsl::routing_approach.eq_any(routing_approaches))
+            .filter(dsl::created_at.ge(time_range.start_time))
+            .into_boxed();
+
+        if let Some(end_time) = time_range.end_time {
+            query = query.filter(dsl::created_at.le(end_time));
+        }
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<(enums::AttemptStatus, i64)>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error counting payment attempts by status")
+    }
 }
@@ -72,6 +72,24 @@ impl PaymentIntent {
         }
     }
 
+    /// Overwrites the row with this version of the payment intent, if the row was not modified
+    /// after `modified_at`. Returns whether the row was overwritten.
+    #[cfg(feature = "v1")]
+    pub async fn overwrite_if_unmodified(
+        self,
+        conn: &PgPooledConn,
+        modified_at: time::PrimitiveDateTime,
+    ) -> StorageResult<bool> {
+        let predicate = dsl::payment_id
+            .eq(self.payment_id.to_owned())
+            .and(dsl::processor_merchant_id.eq(self.processor_merchant_id.to_owned()))
+            .and(dsl::modified_at.eq(modified_at));
+
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(conn, predicate, self)
+            .await
+            .map(|updated_rows| updated_rows > 0)
+    }
+
     #[cfg(feature = "v2")]
     pub async fn find_by_merchant_reference_id_merchant_id(
         conn: &PgPooledConn,
@@ -55,6 +55,23 @@ impl ProcessTracker {
         .await
     }
 
+    /// Updates the process only while it is in one of the given statuses, returning the number
+    /// of processes which were updated
+    #[instrument(skip(conn))]
+    pub async fn update_process_status_by_id_if_status_in(
+        conn: &PgPooledConn,
+        id: String,
+        statuses: Vec<enums::ProcessTrackerStatus>,
+        task_update: ProcessTrackerUpdate,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::id.eq(id).and(dsl::status.eq_any(statuses)),
+            ProcessTrackerUpdateInternal::from(task_update),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_process_by_id(conn: &PgPooledConn, id: &str) -> StorageResult<Option<Self>> {
         generics::generic_find_by_id_optional::<<Self as HasTable>::Table, _, _>(
@@ -120,6 +137,66 @@ impl ProcessTracker {
         Ok(x)
     }
 
+    /// Lists processes matching all of the provided constraints, the earliest scheduled first.
+    /// The merchant is matched against the `merchant_id` in the tracking data of the process,
+    /// which is backed by an expression index.
+    #[allow(clippy::too_many_arguments)]
+    #[instrument(skip(conn))]
+    pub async fn list_processes_by_constraints(
+        conn: &PgPooledConn,
+        runner: Option<String>,
+        status: Option<enums::ProcessTrackerStatus>,
+        merchant_id: Option<&common_utils::id_type::MerchantId>,
+        schedule_time_lower_limit: Option<PrimitiveDateTime>,
+        schedule_time_upper_limit: Option<PrimitiveDateTime>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, sql_types, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+
+        let mut query = Self::table()
+            .order((dsl::schedule_time.asc(), dsl::id.asc()))
+            .limit(limit)
+            .offset(offset)
+            .into_boxed();
+
+        if let Some(runner) = runner {
+            query = query.filter(dsl::runner.eq(runner));
+        }
+
+        if let Some(status) = status {
+            query = query.filter(dsl::status.eq(status));
+        }
+
+        if let Some(merchant_id) = merchant_id {
+            // `tracking_data` is a `json` column, which diesel has no operators for
+            query = query.filter(
+                diesel::dsl::sql::<sql_types::Bool>("tracking_data ->> 'merchant_id' = ")
+                    .bind::<sql_types::Text, _>(merchant_id.get_string_repr().to_owned()),
+            );
+        }
+
+        if let Some(schedule_time_lower_limit) = schedule_time_lower_limit {
+            query = query.filter(dsl::schedule_time.ge(schedule_time_lower_limit));
+        }
+
+        if let Some(schedule_time_upper_limit) = schedule_time_upper_limit {
+            query = query.filter(dsl::schedule_time.le(schedule_time_upper_limit));
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering processes by constraints")
+    }
+
     #[instrument(skip(conn))]
     pub async fn reinitialize_limbo_processes(
         conn: &PgPooledConn,
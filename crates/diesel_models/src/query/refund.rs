@@ -42,6 +42,23 @@ impl Refund {
         }
     }
 
+    /// Overwrites the row with this version of the refund, if the row was not modified after
+    /// `modified_at`. Returns whether the row was overwritten.
+    pub async fn overwrite_if_unmodified(
+        self,
+        conn: &PgPooledConn,
+        modified_at: time::PrimitiveDateTime,
+    ) -> StorageResult<bool> {
+        let predicate = dsl::refund_id
+            .eq(self.refund_id.to_owned())
+            .and(dsl::merchant_id.eq(self.merchant_id.to_owned()))
+            .and(dsl::modified_at.eq(modified_at));
+
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(conn, predicate, self)
+            .await
+            .map(|updated_rows| updated_rows > 0)
+    }
+
     // This is required to be changed for KV.
     pub async fn find_by_merchant_id_refund_id(
         conn: &PgPooledConn,
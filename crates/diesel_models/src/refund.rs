@@ -23,8 +23,9 @@ use crate::{schema_v2::refund, RequiredFromNullable};
     PartialEq,
     serde::Serialize,
     serde::Deserialize,
+    AsChangeset,
 )]
-#[diesel(table_name = refund, primary_key(refund_id), check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = refund, primary_key(refund_id), treat_none_as_null = true, check_for_backend(diesel::pg::Pg))]
 pub struct Refund {
     pub internal_reference_id: String,
     pub refund_id: String, //merchant_reference id
@@ -196,6 +196,11 @@ diesel::table! {
         data_kind -> BlocklistDataKind,
         metadata -> Nullable<Jsonb>,
         created_at -> Timestamp,
+        expires_at -> Nullable<Timestamp>,
+        #[max_length = 32]
+        range_start -> Nullable<Varchar>,
+        #[max_length = 32]
+        range_end -> Nullable<Varchar>,
     }
 }
 
@@ -590,6 +595,8 @@ diesel::table! {
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
         metadata -> Nullable<Jsonb>,
         is_overall_delivery_successful -> Nullable<Bool>,
+        #[max_length = 64]
+        webhook_endpoint_id -> Nullable<Varchar>,
     }
 }
 
@@ -652,6 +659,16 @@ diesel::table! {
         #[max_length = 64]
         last_step -> Varchar,
         payment_capture_method -> Nullable<CaptureMethod>,
+        #[max_length = 32]
+        review_status -> Nullable<Varchar>,
+        #[max_length = 64]
+        review_assigned_to -> Nullable<Varchar>,
+        review_assigned_at -> Nullable<Timestamp>,
+        review_deadline -> Nullable<Timestamp>,
+        review_notes -> Nullable<Jsonb>,
+        #[max_length = 64]
+        reviewed_by -> Nullable<Varchar>,
+        reviewed_at -> Nullable<Timestamp>,
     }
 }
 
@@ -1830,6 +1847,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_endpoint (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        url -> Text,
+        signing_secret -> Bytea,
+        enabled_events -> Array<Nullable<EventType>>,
+        is_enabled -> Bool,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
@@ -1882,4 +1921,5 @@ diesel::allow_tables_to_appear_in_same_query!(
     user_key_store,
     user_roles,
     users,
+    webhook_endpoint,
 );
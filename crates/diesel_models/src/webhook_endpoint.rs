@@ -0,0 +1,153 @@
+use common_utils::encryption::Encryption;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::webhook_endpoint};
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Identifiable,
+    Queryable,
+    Selectable,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[diesel(table_name = webhook_endpoint, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct WebhookEndpoint {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub url: String,
+    pub signing_secret: Encryption,
+    #[diesel(deserialize_as = super::DieselArray<storage_enums::EventType>)]
+    pub enabled_events: Vec<storage_enums::EventType>,
+    pub is_enabled: bool,
+    pub description: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl WebhookEndpoint {
+    /// Whether this endpoint should receive a webhook for the given event type.
+    ///
+    /// An empty `enabled_events` list subscribes the endpoint to all event types.
+    pub fn is_subscribed_to(&self, event_type: storage_enums::EventType) -> bool {
+        self.is_enabled
+            && (self.enabled_events.is_empty() || self.enabled_events.contains(&event_type))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub url: String,
+    pub signing_secret: Encryption,
+    pub enabled_events: Vec<storage_enums::EventType>,
+    pub is_enabled: bool,
+    pub description: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointUpdateInternal {
+    pub url: Option<String>,
+    pub signing_secret: Option<Encryption>,
+    pub enabled_events: Option<Vec<storage_enums::EventType>>,
+    pub is_enabled: Option<bool>,
+    pub description: Option<Option<String>>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum WebhookEndpointUpdate {
+    Update {
+        url: Option<String>,
+        enabled_events: Option<Vec<storage_enums::EventType>>,
+        is_enabled: Option<bool>,
+        /// `Some(None)` clears the description of the endpoint
+        description: Option<Option<String>>,
+    },
+}
+
+impl From<WebhookEndpointUpdate> for WebhookEndpointUpdateInternal {
+    fn from(value: WebhookEndpointUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match value {
+            WebhookEndpointUpdate::Update {
+                url,
+                enabled_events,
+                is_enabled,
+                description,
+            } => Self {
+                url,
+                signing_secret: None,
+                enabled_events,
+                is_enabled,
+                description,
+                modified_at: now,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn webhook_endpoint(
+        enabled_events: Vec<storage_enums::EventType>,
+        is_enabled: bool,
+    ) -> WebhookEndpoint {
+        let now = common_utils::date_time::now();
+        WebhookEndpoint {
+            id: "whep_test".to_string(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            profile_id: common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from(
+                "pro_test",
+            ))
+            .unwrap(),
+            url: "https://example.com/webhooks".to_string(),
+            signing_secret: Encryption::new(b"secret".to_vec().into()),
+            enabled_events,
+            is_enabled,
+            description: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_is_subscribed_to_all_events_when_enabled_events_is_empty() {
+        let webhook_endpoint = webhook_endpoint(Vec::new(), true);
+
+        assert!(webhook_endpoint.is_subscribed_to(storage_enums::EventType::PaymentSucceeded));
+        assert!(webhook_endpoint.is_subscribed_to(storage_enums::EventType::RefundFailed));
+    }
+
+    #[test]
+    fn test_is_subscribed_to_only_enabled_events() {
+        let webhook_endpoint =
+            webhook_endpoint(vec![storage_enums::EventType::PaymentSucceeded], true);
+
+        assert!(webhook_endpoint.is_subscribed_to(storage_enums::EventType::PaymentSucceeded));
+        assert!(!webhook_endpoint.is_subscribed_to(storage_enums::EventType::PaymentFailed));
+    }
+
+    #[test]
+    fn test_is_subscribed_to_nothing_when_disabled() {
+        let webhook_endpoint = webhook_endpoint(Vec::new(), false);
+
+        assert!(!webhook_endpoint.is_subscribed_to(storage_enums::EventType::PaymentSucceeded));
+    }
+}
@@ -0,0 +1,781 @@
+//! Entries which repeatedly fail to be applied to the database are moved from the drainer stream
+//! of a shard to its dead-letter stream, so that they do not block the entries after them.
+//!
+//! Entries failing with errors which may be caused by the database being unavailable are only
+//! dead-lettered once they have been failing for `transient_failure_timeout`, so that an outage of
+//! the database does not dead-letter the entries at the head of the streams.
+//!
+//! The entries applied after a dead-lettered entry of the same row are tracked, and replaying the
+//! dead-lettered entry is refused once any were applied, as it would overwrite their changes.
+
+use std::{collections::HashMap, sync::Arc};
+
+use common_utils::{errors::CustomResult, id_type};
+use error_stack::ResultExt;
+use redis_interface as redis;
+use router_env::{logger, tracing};
+
+use crate::{
+    errors, metrics,
+    services::StoreConfig,
+    stream::{StreamEntries, StreamReadResult},
+    utils, Store,
+};
+
+/// Prefix of the fields added to a dead-lettered entry, these are removed when it is replayed
+const DEAD_LETTER_FIELD_PREFIX: &str = "dead_letter_";
+
+/// Field of a drainer stream entry holding the ID of the row it applies to
+const GLOBAL_ID_FIELD: &str = "global_id";
+
+/// The failed attempts are forgotten after this duration, if the entry was not retried
+const FAILED_ATTEMPTS_TTL_IN_SECONDS: i64 = 24 * 60 * 60;
+
+/// The applied entries of the dead-lettered rows are tracked for this duration after the last
+/// entry of any of the rows was dead-lettered or applied
+const DEAD_LETTERED_ROWS_TTL_IN_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// Number of entries read from the dead-letter stream at once while replaying it
+const DEAD_LETTER_READ_BATCH_SIZE: u64 = 100;
+
+/// Counts a failed attempt of an entry and records the time of its first failure, returning both
+///
+/// KEYS[1]: failed attempts key of the entry
+/// ARGV[1]: current unix timestamp in seconds
+/// ARGV[2]: expiry of the failed attempts key in seconds
+const RECORD_FAILED_ATTEMPT_SCRIPT: &str = r#"
+local attempts = redis.call('HINCRBY', KEYS[1], 'attempts', 1)
+redis.call('HSETNX', KEYS[1], 'first_failed_at', ARGV[1])
+redis.call('EXPIRE', KEYS[1], ARGV[2])
+return { attempts, tonumber(redis.call('HGET', KEYS[1], 'first_failed_at')) }
+"#;
+
+/// Whether retrying an entry which failed to be applied may succeed without the entry changing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FailureKind {
+    /// The entry cannot be deserialized, or its query cannot be built or applied to the row
+    Deterministic,
+    /// The failure may be caused by the database being unavailable
+    Transient,
+}
+
+impl From<&diesel_models::errors::DatabaseError> for FailureKind {
+    fn from(error: &diesel_models::errors::DatabaseError) -> Self {
+        match error {
+            diesel_models::errors::DatabaseError::NotFound
+            | diesel_models::errors::DatabaseError::UniqueViolation
+            | diesel_models::errors::DatabaseError::NoFieldsToUpdate
+            | diesel_models::errors::DatabaseError::QueryGenerationFailed => Self::Deterministic,
+            // Constraint violations other than unique violations cannot be told apart from a lost
+            // connection, both of which are reported as unknown errors
+            diesel_models::errors::DatabaseError::DatabaseConnectionError
+            | diesel_models::errors::DatabaseError::Others => Self::Transient,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct EntryFailure {
+    pub kind: FailureKind,
+    pub error: String,
+}
+
+/// Whether an entry which has failed `attempts` times, the first of which was `failing_for_secs`
+/// seconds ago, is to be moved to the dead-letter stream
+fn should_dead_letter(
+    config: &StoreConfig,
+    kind: FailureKind,
+    attempts: u32,
+    failing_for_secs: i64,
+) -> bool {
+    attempts >= config.max_attempts
+        && match kind {
+            FailureKind::Deterministic => true,
+            FailureKind::Transient => {
+                failing_for_secs >= i64::from(config.transient_failure_timeout)
+            }
+        }
+}
+
+/// Orders the IDs of the entries of a stream, which are made of a millisecond timestamp and a
+/// sequence number, eg: `1700000000000-0`
+fn parse_entry_id(entry_id: &str) -> Option<(u64, u64)> {
+    let (timestamp, sequence) = entry_id.split_once('-')?;
+    Some((timestamp.parse().ok()?, sequence.parse().ok()?))
+}
+
+/// The entries up to the last processed entry which are the latest applied entries of rows with
+/// dead-lettered entries, by the ID of the row
+fn get_latest_entries_of_dead_lettered_rows(
+    dead_lettered_rows: &HashMap<String, String>,
+    entries: &[(String, HashMap<String, String>)],
+    last_processed_id: &str,
+) -> HashMap<String, String> {
+    let processed_count = entries
+        .iter()
+        .position(|(entry_id, _)| entry_id == last_processed_id)
+        .map_or(0, |index| index.saturating_add(1));
+
+    entries
+        .iter()
+        .take(processed_count)
+        .filter_map(|(entry_id, entry)| {
+            let global_id = entry.get(GLOBAL_ID_FIELD)?;
+            let latest_entry_id = dead_lettered_rows.get(global_id)?;
+            has_newer_entry(latest_entry_id, Some(entry_id))
+                .then(|| (global_id.clone(), entry_id.clone()))
+        })
+        .collect()
+}
+
+/// Whether an entry of the same row was applied after the dead-lettered entry, given the ID of the
+/// latest entry of the row applied so far
+fn has_newer_entry(dead_lettered_entry_id: &str, latest_entry_id: Option<&str>) -> bool {
+    latest_entry_id
+        .and_then(parse_entry_id)
+        .zip(parse_entry_id(dead_lettered_entry_id))
+        .is_some_and(|(latest_entry_id, dead_lettered_entry_id)| {
+            latest_entry_id > dead_lettered_entry_id
+        })
+}
+
+impl Store {
+    #[inline(always)]
+    pub(crate) fn get_dead_letter_stream_name(&self, stream_name: &str) -> String {
+        // Shares the hash tag of the drainer stream, eg: {shard_5}_drainer_stream_dead_letter
+        format!("{stream_name}_dead_letter")
+    }
+
+    #[inline(always)]
+    fn get_failed_attempts_key(&self, stream_name: &str, entry_id: &str) -> String {
+        format!("{stream_name}_failed_attempts_{entry_id}")
+    }
+
+    /// Latest entry applied for each row which has an entry in the dead-letter stream
+    #[inline(always)]
+    fn get_dead_lettered_rows_key(&self, stream_name: &str) -> String {
+        format!("{stream_name}_dead_lettered_rows")
+    }
+
+    /// Records a failed attempt to apply the entry, moving it to the dead-letter stream once it
+    /// has failed `max_attempts` times, and for `transient_failure_timeout` in case of a transient
+    /// failure. Returns whether the entry was dead-lettered, in which case the drainer can move
+    /// past it.
+    #[router_env::instrument(skip_all)]
+    pub(crate) async fn handle_failed_entry(
+        &self,
+        stream_name: &str,
+        entry_id: &str,
+        entry: &HashMap<String, String>,
+        failure: EntryFailure,
+    ) -> errors::DrainerResult<bool> {
+        let EntryFailure { kind, error } = failure;
+        let failed_attempts_key = self
+            .redis_conn
+            .add_prefix(&self.get_failed_attempts_key(stream_name, entry_id));
+        let now = common_utils::date_time::now_unix_timestamp();
+
+        let failed_attempt = self
+            .redis_conn
+            .evaluate_redis_script::<_, Vec<i64>>(
+                RECORD_FAILED_ATTEMPT_SCRIPT,
+                vec![failed_attempts_key],
+                vec![now, FAILED_ATTEMPTS_TTL_IN_SECONDS],
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+        let (attempts, first_failed_at) = match failed_attempt.as_slice() {
+            [attempts, first_failed_at] => (
+                u32::try_from(*attempts).unwrap_or(u32::MAX),
+                *first_failed_at,
+            ),
+            _ => {
+                return Err(errors::DrainerError::UnexpectedError(format!(
+                    "Unexpected response when recording a failed attempt: {failed_attempt:?}"
+                ))
+                .into())
+            }
+        };
+
+        metrics::STREAM_ENTRY_FAILED_ATTEMPTS.add(
+            1,
+            router_env::metric_attributes!(("stream", stream_name.to_owned())),
+        );
+
+        if !should_dead_letter(
+            &self.config,
+            kind,
+            attempts,
+            now.saturating_sub(first_failed_at),
+        ) {
+            logger::warn!(
+                stream_name,
+                entry_id,
+                attempts,
+                max_attempts = self.config.max_attempts,
+                ?kind,
+                %error,
+                "Failed to apply the drainer stream entry"
+            );
+
+            return Ok(false);
+        }
+
+        let dead_letter_stream_name = self.get_dead_letter_stream_name(stream_name);
+        let mut fields = entry
+            .iter()
+            .map(|(field, value)| (field.clone(), value.clone()))
+            .collect::<Vec<_>>();
+        fields.extend([
+            (
+                format!("{DEAD_LETTER_FIELD_PREFIX}entry_id"),
+                entry_id.to_owned(),
+            ),
+            (format!("{DEAD_LETTER_FIELD_PREFIX}error"), error.clone()),
+            (
+                format!("{DEAD_LETTER_FIELD_PREFIX}attempts"),
+                attempts.to_string(),
+            ),
+            (
+                format!("{DEAD_LETTER_FIELD_PREFIX}at"),
+                common_utils::date_time::now_unix_timestamp().to_string(),
+            ),
+        ]);
+
+        self.redis_conn
+            .stream_append_entry(
+                &dead_letter_stream_name.as_str().into(),
+                &redis::RedisEntryId::AutoGeneratedID,
+                fields,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        if let Some(global_id) = entry.get(GLOBAL_ID_FIELD) {
+            self.redis_conn
+                .set_hash_fields(
+                    &self.get_dead_lettered_rows_key(stream_name).as_str().into(),
+                    vec![(global_id.clone(), entry_id.to_owned())],
+                    Some(DEAD_LETTERED_ROWS_TTL_IN_SECONDS),
+                )
+                .await
+                .map_err(errors::DrainerError::from)?;
+        }
+
+        // The failed attempts expire on their own, a failure here is harmless
+        let _ = self
+            .redis_conn
+            .delete_key(
+                &self
+                    .get_failed_attempts_key(stream_name, entry_id)
+                    .as_str()
+                    .into(),
+            )
+            .await
+            .inspect_err(|error| logger::error!(?error, "Failed to clear failed attempts"));
+
+        let stream_attributes = router_env::metric_attributes!(("stream", stream_name.to_owned()));
+        metrics::STREAM_ENTRIES_DEAD_LETTERED.add(1, stream_attributes);
+        if let Ok(length) = self
+            .redis_conn
+            .stream_get_length(&dead_letter_stream_name.as_str().into())
+            .await
+        {
+            metrics::DEAD_LETTER_STREAM_LENGTH
+                .record(u64::try_from(length).unwrap_or(u64::MAX), stream_attributes);
+        }
+
+        logger::error!(
+            alert = "drainer_entry_dead_lettered",
+            stream_name,
+            %dead_letter_stream_name,
+            entry_id,
+            attempts,
+            %error,
+            "Moved the drainer stream entry to the dead-letter stream"
+        );
+
+        Ok(true)
+    }
+
+    /// Records the latest applied entries of the rows which have dead-lettered entries, so that
+    /// replaying the dead-lettered entries does not overwrite the changes applied after them
+    #[router_env::instrument(skip_all)]
+    pub(crate) async fn track_entries_of_dead_lettered_rows(
+        &self,
+        stream_name: &str,
+        entries: &[(String, HashMap<String, String>)],
+        last_processed_id: &str,
+    ) -> errors::DrainerResult<()> {
+        let dead_lettered_rows_key = self.get_dead_lettered_rows_key(stream_name);
+        let dead_lettered_rows = self.get_dead_lettered_rows(stream_name).await?;
+        if dead_lettered_rows.is_empty() {
+            return Ok(());
+        }
+
+        let latest_entries = get_latest_entries_of_dead_lettered_rows(
+            &dead_lettered_rows,
+            entries,
+            last_processed_id,
+        );
+        if latest_entries.is_empty() {
+            return Ok(());
+        }
+
+        self.redis_conn
+            .set_hash_fields(
+                &dead_lettered_rows_key.as_str().into(),
+                latest_entries.into_iter().collect::<Vec<_>>(),
+                Some(DEAD_LETTERED_ROWS_TTL_IN_SECONDS),
+            )
+            .await
+            .map_err(|error| errors::DrainerError::from(error).into())
+    }
+
+    async fn get_dead_lettered_rows(
+        &self,
+        stream_name: &str,
+    ) -> errors::DrainerResult<HashMap<String, String>> {
+        self.redis_conn
+            .get_hash_fields::<HashMap<String, String>>(
+                &self.get_dead_lettered_rows_key(stream_name).as_str().into(),
+            )
+            .await
+            .map_err(|error| errors::DrainerError::from(error).into())
+    }
+
+    /// The rows are no longer tracked once the dead-letter stream is empty
+    async fn clear_dead_lettered_rows_if_empty(
+        &self,
+        stream_name: &str,
+        dead_letter_stream_name: &str,
+    ) -> errors::DrainerResult<()> {
+        let length = self
+            .redis_conn
+            .stream_get_length(&dead_letter_stream_name.into())
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        if length == 0 {
+            self.redis_conn
+                .delete_key(&self.get_dead_lettered_rows_key(stream_name).as_str().into())
+                .await
+                .map_err(errors::DrainerError::from)?;
+        }
+
+        Ok(())
+    }
+
+    async fn read_dead_letter_entries(
+        &self,
+        dead_letter_stream_name: &str,
+        after_entry_id: &str,
+        count: u64,
+    ) -> errors::DrainerResult<StreamEntries> {
+        let read_result = self
+            .redis_conn
+            .stream_read_entries(dead_letter_stream_name, after_entry_id, Some(count))
+            .await;
+        self.parse_dead_letter_entries(dead_letter_stream_name, read_result)
+    }
+
+    /// Reads the first entries of the dead-letter stream from a replica if enabled, listing them
+    /// does not need to observe the latest entries
+    async fn list_dead_letter_entries(
+        &self,
+        dead_letter_stream_name: &str,
+        count: u64,
+    ) -> errors::DrainerResult<StreamEntries> {
+        let read_result = self
+            .redis_conn
+            .stream_read_entries_from_replica(dead_letter_stream_name, "0-0", Some(count))
+            .await;
+        self.parse_dead_letter_entries(dead_letter_stream_name, read_result)
+    }
+
+    fn parse_dead_letter_entries(
+        &self,
+        dead_letter_stream_name: &str,
+        read_result: CustomResult<StreamReadResult, redis::errors::RedisError>,
+    ) -> errors::DrainerResult<StreamEntries> {
+        match read_result {
+            Ok(read_result) => utils::parse_stream_entries(
+                &read_result,
+                self.redis_conn.add_prefix(dead_letter_stream_name).as_str(),
+            )
+            .cloned(),
+            Err(error) => match error.current_context() {
+                redis::errors::RedisError::StreamEmptyOrNotAvailable => Ok(Vec::new()),
+                _ => Err(errors::DrainerError::from(error).into()),
+            },
+        }
+    }
+
+    /// Appends the entry back to the drainer stream and removes it from the dead-letter stream
+    async fn replay_dead_letter_entry(
+        &self,
+        stream_name: &str,
+        dead_letter_stream_name: &str,
+        (entry_id, entry): (String, HashMap<String, String>),
+    ) -> errors::DrainerResult<()> {
+        let fields = entry
+            .into_iter()
+            .filter(|(field, _)| !field.starts_with(DEAD_LETTER_FIELD_PREFIX))
+            .collect::<Vec<_>>();
+
+        self.redis_conn
+            .stream_append_entry(
+                &stream_name.into(),
+                &redis::RedisEntryId::AutoGeneratedID,
+                fields,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        self.delete_from_stream(dead_letter_stream_name, &entry_id)
+            .await
+    }
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum DeadLetterCommand {
+    /// List the entries in the dead-letter stream of a shard
+    List {
+        #[command(flatten)]
+        target: DeadLetterTarget,
+        /// Maximum number of entries to list
+        #[arg(long, default_value_t = 100)]
+        count: u64,
+    },
+    /// Move entries from the dead-letter stream back to the drainer stream of a shard
+    Replay {
+        #[command(flatten)]
+        target: DeadLetterTarget,
+        /// Entry to replay, all entries are replayed if not specified
+        #[arg(long)]
+        entry_id: Option<String>,
+        /// Replay entries even if entries of the same row were applied after them, which would
+        /// overwrite the changes of those entries
+        #[arg(long)]
+        force: bool,
+    },
+    /// Delete an entry from the dead-letter stream of a shard without applying it
+    Discard {
+        #[command(flatten)]
+        target: DeadLetterTarget,
+        #[arg(long)]
+        entry_id: String,
+    },
+}
+
+#[derive(Debug, clap::Args)]
+pub struct DeadLetterTarget {
+    /// Tenant whose drainer streams are inspected
+    #[arg(long)]
+    pub tenant: String,
+    /// Index of the shard, between 0 and the number of partitions
+    #[arg(long)]
+    pub shard: u8,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DeadLetterEntry {
+    id: String,
+    fields: HashMap<String, String>,
+}
+
+/// Executes the dead-letter command, printing its outcome as JSON
+pub async fn execute_dead_letter_command(
+    stores: &HashMap<id_type::TenantId, Arc<Store>>,
+    command: DeadLetterCommand,
+) -> errors::DrainerResult<()> {
+    let target = match &command {
+        DeadLetterCommand::List { target, .. }
+        | DeadLetterCommand::Replay { target, .. }
+        | DeadLetterCommand::Discard { target, .. } => target,
+    };
+
+    let store = stores
+        .iter()
+        .find(|(tenant_id, _)| tenant_id.get_string_repr() == target.tenant)
+        .map(|(_, store)| store)
+        .ok_or_else(|| {
+            errors::DrainerError::UnexpectedError(format!("Unknown tenant {}", target.tenant))
+        })?;
+
+    if target.shard >= store.config.drainer_num_partitions {
+        return Err(errors::DrainerError::UnexpectedError(format!(
+            "Shard should be less than the number of partitions {}",
+            store.config.drainer_num_partitions
+        ))
+        .into());
+    }
+
+    let stream_name = store.get_drainer_stream_name(target.shard);
+    let dead_letter_stream_name = store.get_dead_letter_stream_name(&stream_name);
+
+    let output = match command {
+        DeadLetterCommand::List { count, .. } => {
+            let entries = store
+                .list_dead_letter_entries(&dead_letter_stream_name, count)
+                .await?
+                .into_iter()
+                .map(|(id, fields)| DeadLetterEntry { id, fields })
+                .collect::<Vec<_>>();
+
+            serde_json::json!({
+                "dead_letter_stream": dead_letter_stream_name,
+                "entries": entries,
+            })
+        }
+        DeadLetterCommand::Replay {
+            entry_id, force, ..
+        } => {
+            let dead_lettered_rows = store.get_dead_lettered_rows(&stream_name).await?;
+            let mut replayed = Vec::new();
+            let mut skipped = Vec::new();
+            let mut last_read_id = String::from("0-0");
+
+            loop {
+                let entries = store
+                    .read_dead_letter_entries(
+                        &dead_letter_stream_name,
+                        &last_read_id,
+                        DEAD_LETTER_READ_BATCH_SIZE,
+                    )
+                    .await?;
+                let Some((last_id, _)) = entries.last() else {
+                    break;
+                };
+                last_read_id = last_id.clone();
+
+                for entry in entries {
+                    if entry_id
+                        .as_ref()
+                        .is_some_and(|entry_id| *entry_id != entry.0)
+                    {
+                        continue;
+                    }
+                    let id = entry.0.clone();
+                    let global_id = entry.1.get(GLOBAL_ID_FIELD).cloned();
+                    let is_superseded = global_id
+                        .as_ref()
+                        .zip(entry.1.get(&format!("{DEAD_LETTER_FIELD_PREFIX}entry_id")))
+                        .is_some_and(|(global_id, dead_lettered_entry_id)| {
+                            has_newer_entry(
+                                dead_lettered_entry_id,
+                                dead_lettered_rows.get(global_id).map(String::as_str),
+                            )
+                        });
+
+                    if is_superseded && !force {
+                        logger::warn!(
+                            entry_id = %id,
+                            ?global_id,
+                            "Skipped replaying the entry, newer entries of its row were applied"
+                        );
+                        skipped.push(serde_json::json!({
+                            "id": id,
+                            "global_id": global_id,
+                        }));
+                        continue;
+                    }
+
+                    store
+                        .replay_dead_letter_entry(&stream_name, &dead_letter_stream_name, entry)
+                        .await?;
+                    replayed.push(id);
+                }
+
+                if entry_id.is_some() && !(replayed.is_empty() && skipped.is_empty()) {
+                    break;
+                }
+            }
+
+            store
+                .clear_dead_lettered_rows_if_empty(&stream_name, &dead_letter_stream_name)
+                .await?;
+
+            if let Some(entry_id) = entry_id.as_ref().filter(|_| !skipped.is_empty()) {
+                return Err(errors::DrainerError::UnexpectedError(format!(
+                    "Entries of the same row were applied after entry {entry_id}, replaying it \
+                     would overwrite their changes. Use --force to replay it anyway"
+                ))
+                .into());
+            }
+
+            if let Some(entry_id) = entry_id.filter(|_| replayed.is_empty()) {
+                return Err(errors::DrainerError::UnexpectedError(format!(
+                    "Entry {entry_id} not found in {dead_letter_stream_name}"
+                ))
+                .into());
+            }
+
+            serde_json::json!({
+                "dead_letter_stream": dead_letter_stream_name,
+                "stream": stream_name,
+                "replayed": replayed,
+                "skipped": skipped,
+            })
+        }
+        DeadLetterCommand::Discard { entry_id, .. } => {
+            let deleted = store
+                .redis_conn
+                .stream_delete_entries(&dead_letter_stream_name.as_str().into(), entry_id.as_str())
+                .await
+                .map_err(errors::DrainerError::from)?;
+
+            if deleted == 0 {
+                return Err(errors::DrainerError::UnexpectedError(format!(
+                    "Entry {entry_id} not found in {dead_letter_stream_name}"
+                ))
+                .into());
+            }
+
+            store
+                .clear_dead_lettered_rows_if_empty(&stream_name, &dead_letter_stream_name)
+                .await?;
+
+            serde_json::json!({
+                "dead_letter_stream": dead_letter_stream_name,
+                "discarded": [entry_id],
+            })
+        }
+    };
+
+    let output = serde_json::to_string_pretty(&output).change_context(
+        errors::DrainerError::UnexpectedError("Failed to serialize the output".to_string()),
+    )?;
+
+    #[allow(clippy::print_stdout)]
+    {
+        println!("{output}");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::DrainerBatchSettings;
+
+    fn get_store_config() -> StoreConfig {
+        StoreConfig {
+            drainer_stream_name: "drainer_stream".to_owned(),
+            drainer_num_partitions: 64,
+            use_legacy_version: false,
+            max_attempts: 3,
+            transient_failure_timeout: 600,
+            batch: DrainerBatchSettings::default(),
+        }
+    }
+
+    fn get_entry(entry_id: &str, global_id: &str) -> (String, HashMap<String, String>) {
+        (
+            entry_id.to_owned(),
+            HashMap::from([(GLOBAL_ID_FIELD.to_owned(), global_id.to_owned())]),
+        )
+    }
+
+    #[test]
+    fn test_deterministic_failures_are_dead_lettered_after_max_attempts() {
+        let config = get_store_config();
+
+        assert!(!should_dead_letter(
+            &config,
+            FailureKind::Deterministic,
+            2,
+            0
+        ));
+        assert!(should_dead_letter(
+            &config,
+            FailureKind::Deterministic,
+            3,
+            0
+        ));
+    }
+
+    #[test]
+    fn test_transient_failures_are_dead_lettered_after_max_attempts_and_timeout() {
+        let config = get_store_config();
+
+        assert!(!should_dead_letter(
+            &config,
+            FailureKind::Transient,
+            100,
+            599
+        ));
+        assert!(!should_dead_letter(&config, FailureKind::Transient, 2, 600));
+        assert!(should_dead_letter(&config, FailureKind::Transient, 3, 600));
+    }
+
+    #[test]
+    fn test_failure_kind_from_database_error() {
+        use diesel_models::errors::DatabaseError;
+
+        assert_eq!(
+            FailureKind::from(&DatabaseError::NotFound),
+            FailureKind::Deterministic
+        );
+        assert_eq!(
+            FailureKind::from(&DatabaseError::QueryGenerationFailed),
+            FailureKind::Deterministic
+        );
+        assert_eq!(
+            FailureKind::from(&DatabaseError::DatabaseConnectionError),
+            FailureKind::Transient
+        );
+        assert_eq!(
+            FailureKind::from(&DatabaseError::Others),
+            FailureKind::Transient
+        );
+    }
+
+    #[test]
+    fn test_parse_entry_id() {
+        assert_eq!(
+            parse_entry_id("1700000000000-12"),
+            Some((1700000000000, 12))
+        );
+        assert_eq!(parse_entry_id("1700000000000"), None);
+        assert_eq!(parse_entry_id("entry-1"), None);
+    }
+
+    #[test]
+    fn test_has_newer_entry() {
+        // Entry IDs are compared numerically, not lexicographically
+        assert!(has_newer_entry("999-0", Some("1000-0")));
+        assert!(has_newer_entry("1000-2", Some("1000-10")));
+        assert!(!has_newer_entry("1000-0", Some("1000-0")));
+        assert!(!has_newer_entry("1000-1", Some("1000-0")));
+        assert!(!has_newer_entry("1000-0", None));
+    }
+
+    #[test]
+    fn test_get_latest_entries_of_dead_lettered_rows() {
+        let dead_lettered_rows = HashMap::from([
+            ("row_1".to_owned(), "1000-0".to_owned()),
+            ("row_2".to_owned(), "1003-0".to_owned()),
+        ]);
+        let entries = vec![
+            get_entry("1001-0", "row_1"),
+            get_entry("1002-0", "row_1"),
+            get_entry("1002-1", "row_2"),
+            get_entry("1002-2", "row_3"),
+            get_entry("1004-0", "row_2"),
+        ];
+
+        // The entries after the last processed entry were not applied
+        let latest_entries =
+            get_latest_entries_of_dead_lettered_rows(&dead_lettered_rows, &entries, "1002-2");
+
+        assert_eq!(
+            latest_entries,
+            HashMap::from([("row_1".to_owned(), "1002-0".to_owned())])
+        );
+        assert!(
+            get_latest_entries_of_dead_lettered_rows(&dead_lettered_rows, &entries, "").is_empty()
+        );
+    }
+}
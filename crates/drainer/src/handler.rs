@@ -11,8 +11,10 @@ use tokio::{
 };
 
 use crate::{
-    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
-    Store, StreamData,
+    dead_letter, errors, instrument, logger, metrics,
+    query::{self, ExecuteQuery},
+    settings::DrainerBatchSettings,
+    tracing, utils, DrainerSettings, Store, StreamData,
 };
 
 /// Handler handles the spawning and closing of drainer
@@ -245,9 +247,69 @@ async fn drainer(
 
     let mut last_processed_id = String::new();
 
-    for (entry_id, entry) in entries.clone() {
-        let data = match StreamData::from_hashmap(entry) {
-            Ok(data) => data,
+    if store.config.batch.enabled {
+        apply_entries_in_batches(&store, stream_name, entries, &mut last_processed_id).await?;
+    } else {
+        apply_entries(&store, stream_name, entries, &mut last_processed_id).await?;
+    }
+
+    if !last_processed_id.is_empty() {
+        // Replaying the dead-lettered entries is refused once newer entries of their rows were
+        // applied, failing to track them does not affect draining the stream
+        let _ = store
+            .track_entries_of_dead_lettered_rows(stream_name, entries, &last_processed_id)
+            .await
+            .inspect_err(|error| {
+                logger::error!(?error, "Failed to track the entries of dead-lettered rows")
+            });
+    }
+
+    if !(last_processed_id.is_empty() || store.use_legacy_version()) {
+        let entries_trimmed = store
+            .trim_from_stream(stream_name, &last_processed_id)
+            .await?;
+        if read_count != entries_trimmed {
+            logger::error!(
+                read_entries = %read_count,
+                trimmed_entries = %entries_trimmed,
+                ?entries,
+                "Assertion Failed no. of entries read from the stream doesn't match no. of entries trimmed"
+            );
+        }
+    } else {
+        logger::error!(read_entries = %read_count,?entries,"No streams were processed in this session");
+    }
+
+    Ok(())
+}
+
+/// Applies the entries one at a time, stopping at the first entry which fails. Returns whether all
+/// the entries were applied or dead-lettered.
+async fn apply_entries(
+    store: &Arc<Store>,
+    stream_name: &str,
+    entries: &[(String, HashMap<String, String>)],
+    last_processed_id: &mut String,
+) -> errors::DrainerResult<bool> {
+    for (entry_id, entry) in entries {
+        let failure = match StreamData::from_hashmap(entry.clone()) {
+            Ok(data) => {
+                tracing::Span::current().record("request_id", data.request_id);
+                tracing::Span::current().record("global_id", data.global_id);
+
+                match data.typed_sql.execute_query(store, data.pushed_at).await {
+                    Ok(_) => None,
+                    Err(err) => match err.current_context() {
+                        // In case of Uniqueviolation we can't really do anything to fix it so just
+                        // clear it from the stream
+                        diesel_models::errors::DatabaseError::UniqueViolation => None,
+                        error => Some(dead_letter::EntryFailure {
+                            kind: dead_letter::FailureKind::from(error),
+                            error: format!("{err:?}"),
+                        }),
+                    },
+                }
+            }
             Err(err) => {
                 logger::error!(operation = "deserialization", err=?err);
                 metrics::STREAM_PARSE_FAIL.add(
@@ -255,51 +317,150 @@ async fn drainer(
                     router_env::metric_attributes!(("operation", "deserialization")),
                 );
 
-                // break from the loop in case of a deser error
-                break;
+                Some(dead_letter::EntryFailure {
+                    kind: dead_letter::FailureKind::Deterministic,
+                    error: format!("{err:?}"),
+                })
             }
         };
 
-        tracing::Span::current().record("request_id", data.request_id);
-        tracing::Span::current().record("global_id", data.global_id);
-
-        match data.typed_sql.execute_query(&store, data.pushed_at).await {
-            Ok(_) => {
-                last_processed_id = entry_id;
+        if let Some(failure) = failure {
+            // stop at the entry until it has failed enough times to be dead-lettered
+            if !store
+                .handle_failed_entry(stream_name, entry_id, entry, failure)
+                .await?
+            {
+                return Ok(false);
             }
-            Err(err) => match err.current_context() {
-                // In case of Uniqueviolation we can't really do anything to fix it so just clear
-                // it from the stream
-                diesel_models::errors::DatabaseError::UniqueViolation => {
-                    last_processed_id = entry_id;
-                }
-                // break from the loop in case of an error in query
-                _ => break,
-            },
         }
+        last_processed_id.clone_from(entry_id);
 
         if store.use_legacy_version() {
-            store
-                .delete_from_stream(stream_name, &last_processed_id)
-                .await?;
+            store.delete_from_stream(stream_name, entry_id).await?;
         }
     }
 
-    if !(last_processed_id.is_empty() || store.use_legacy_version()) {
-        let entries_trimmed = store
-            .trim_from_stream(stream_name, &last_processed_id)
-            .await?;
-        if read_count != entries_trimmed {
-            logger::error!(
-                read_entries = %read_count,
-                trimmed_entries = %entries_trimmed,
-                ?entries,
-                "Assertion Failed no. of entries read from the stream doesn't match no. of entries trimmed"
-            );
+    Ok(true)
+}
+
+/// Applies consecutive entries in a single transaction each, bounded by the batch settings. The
+/// entries of a batch which is rolled back are applied one at a time, so that the failing entry
+/// is retried or dead-lettered on its own.
+async fn apply_entries_in_batches(
+    store: &Arc<Store>,
+    stream_name: &str,
+    entries: &[(String, HashMap<String, String>)],
+    last_processed_id: &mut String,
+) -> errors::DrainerResult<()> {
+    let mut remaining = entries;
+
+    while !remaining.is_empty() {
+        let (batch, rest) = remaining.split_at(get_batch_len(remaining, &store.config.batch));
+        remaining = rest;
+
+        let committed = match batch
+            .iter()
+            .map(|(_, entry)| StreamData::from_hashmap(entry.clone()))
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(batch_data) => query::execute_batch(store, stream_name, batch_data)
+                .await
+                .is_ok(),
+            Err(_) => false,
+        };
+
+        if !committed {
+            if apply_entries(store, stream_name, batch, last_processed_id).await? {
+                continue;
+            }
+            // The entries after the failing entry are applied in the next run
+            break;
+        }
+
+        if let Some((entry_id, _)) = batch.last() {
+            last_processed_id.clone_from(entry_id);
+        }
+        if store.use_legacy_version() {
+            for (entry_id, _) in batch {
+                store.delete_from_stream(stream_name, entry_id).await?;
+            }
         }
-    } else {
-        logger::error!(read_entries = %read_count,?entries,"No streams were processed in this session");
     }
 
     Ok(())
 }
+
+/// Number of entries from the start of the slice which fit in a batch, at least one entry
+fn get_batch_len(
+    entries: &[(String, HashMap<String, String>)],
+    batch_settings: &DrainerBatchSettings,
+) -> usize {
+    let mut batch_bytes = 0usize;
+
+    entries
+        .iter()
+        .take(batch_settings.max_entries.max(1))
+        .enumerate()
+        .take_while(|(index, (_, entry))| {
+            let entry_bytes = entry
+                .iter()
+                .map(|(field, value)| field.len().saturating_add(value.len()))
+                .fold(0usize, usize::saturating_add);
+            batch_bytes = batch_bytes.saturating_add(entry_bytes);
+
+            *index == 0 || batch_bytes <= batch_settings.max_bytes
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_entries(entry_sizes: &[usize]) -> Vec<(String, HashMap<String, String>)> {
+        entry_sizes
+            .iter()
+            .enumerate()
+            .map(|(index, size)| {
+                (
+                    format!("1000-{index}"),
+                    // The field name is a single byte
+                    HashMap::from([("x".to_owned(), "a".repeat(size.saturating_sub(1)))]),
+                )
+            })
+            .collect()
+    }
+
+    fn get_batch_settings(max_entries: usize, max_bytes: usize) -> DrainerBatchSettings {
+        DrainerBatchSettings {
+            enabled: true,
+            max_entries,
+            max_bytes,
+        }
+    }
+
+    #[test]
+    fn test_get_batch_len_is_bounded_by_max_entries() {
+        let entries = get_entries(&[10, 10, 10, 10]);
+
+        assert_eq!(get_batch_len(&entries, &get_batch_settings(3, 1000)), 3);
+        assert_eq!(get_batch_len(&entries, &get_batch_settings(10, 1000)), 4);
+    }
+
+    #[test]
+    fn test_get_batch_len_is_bounded_by_max_bytes() {
+        let entries = get_entries(&[10, 10, 10, 10]);
+
+        assert_eq!(get_batch_len(&entries, &get_batch_settings(10, 30)), 3);
+        assert_eq!(get_batch_len(&entries, &get_batch_settings(10, 29)), 2);
+    }
+
+    #[test]
+    fn test_get_batch_len_includes_at_least_one_entry() {
+        let entries = get_entries(&[100, 10]);
+
+        assert_eq!(get_batch_len(&entries, &get_batch_settings(10, 50)), 1);
+        assert_eq!(get_batch_len(&entries, &get_batch_settings(0, 1000)), 1);
+        assert_eq!(get_batch_len(&[], &get_batch_settings(10, 1000)), 0);
+    }
+}
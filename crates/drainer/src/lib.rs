@@ -1,4 +1,5 @@
 mod connection;
+pub mod dead_letter;
 pub mod errors;
 mod handler;
 mod health_check;
@@ -6,10 +6,13 @@ use router_env::tracing::Instrument;
 #[tokio::main]
 async fn main() -> DrainerResult<()> {
     // Get configuration
-    let cmd_line = <settings::CmdLineConf as clap::Parser>::parse();
+    let settings::CmdLineConf {
+        config_path,
+        command,
+    } = <settings::CmdLineConf as clap::Parser>::parse();
 
     #[allow(clippy::expect_used)]
-    let conf = settings::Settings::with_config_path(cmd_line.config_path)
+    let conf = settings::Settings::with_config_path(config_path)
         .expect("Unable to construct application configuration");
     #[allow(clippy::expect_used)]
     conf.validate()
@@ -35,6 +38,10 @@ async fn main() -> DrainerResult<()> {
         [router_env::service_name!()],
     );
 
+    if let Some(settings::DrainerCommand::DeadLetter { command }) = command {
+        return drainer::dead_letter::execute_dead_letter_command(&stores, command).await;
+    }
+
     #[allow(clippy::expect_used)]
     let web_server = Box::pin(start_web_server(
         state.conf.as_ref().clone(),
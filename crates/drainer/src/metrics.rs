@@ -12,6 +12,11 @@ counter_metric!(SUCCESSFUL_SHUTDOWN, DRAINER_METER);
 counter_metric!(STREAM_EMPTY, DRAINER_METER);
 counter_metric!(STREAM_PARSE_FAIL, DRAINER_METER);
 counter_metric!(DRAINER_HEALTH, DRAINER_METER);
+counter_metric!(STREAM_ENTRY_FAILED_ATTEMPTS, DRAINER_METER);
+counter_metric!(STREAM_ENTRIES_DEAD_LETTERED, DRAINER_METER);
+counter_metric!(BATCHES_COMMITTED, DRAINER_METER);
+counter_metric!(BATCHES_ROLLED_BACK, DRAINER_METER);
+counter_metric!(OPERATIONS_COALESCED, DRAINER_METER);
 
 histogram_metric_f64!(QUERY_EXECUTION_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric_f64!(REDIS_STREAM_READ_TIME, DRAINER_METER); // Time in (ms) milliseconds
@@ -19,3 +24,6 @@ histogram_metric_f64!(REDIS_STREAM_TRIM_TIME, DRAINER_METER); // Time in (ms) mi
 histogram_metric_f64!(CLEANUP_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric_u64!(DRAINER_DELAY_SECONDS, DRAINER_METER); // Time in (s) seconds
 histogram_metric_f64!(REDIS_STREAM_DEL_TIME, DRAINER_METER); // Time in (ms) milliseconds
+histogram_metric_u64!(DEAD_LETTER_STREAM_LENGTH, DRAINER_METER); // Number of entries
+histogram_metric_f64!(BATCH_EXECUTION_TIME, DRAINER_METER); // Time in (ms) milliseconds
+histogram_metric_u64!(BATCH_SIZE, DRAINER_METER); // Number of entries
@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
+use async_bb8_diesel::AsyncConnection;
 use common_utils::errors::CustomResult;
 use diesel_models::errors::DatabaseError;
 
-use crate::{kv, logger, metrics, pg_connection, services::Store};
+use crate::{kv, logger, metrics, pg_connection, services::Store, StreamData};
 
 #[async_trait::async_trait]
 pub trait ExecuteQuery {
@@ -48,6 +49,97 @@ impl ExecuteQuery for kv::DBOperation {
     }
 }
 
+/// Applies the operations of consecutive stream entries in a single transaction, coalescing
+/// successive updates to the same row. None of the operations are applied if any of them fails.
+pub async fn execute_batch(
+    store: &Arc<Store>,
+    stream_name: &str,
+    batch: Vec<StreamData>,
+) -> CustomResult<(), DatabaseError> {
+    let batch_size = batch.len();
+    let stream_tags = router_env::metric_attributes!(("stream", stream_name.to_owned()));
+
+    let mut executed_entries = Vec::with_capacity(batch_size);
+    let mut operations: Vec<kv::DBOperation> = Vec::with_capacity(batch_size);
+    for data in batch {
+        executed_entries.push((
+            data.typed_sql.operation(),
+            data.typed_sql.table(),
+            data.pushed_at,
+        ));
+
+        let operation = match operations.pop() {
+            Some(previous) => match previous.coalesce(data.typed_sql) {
+                Ok(coalesced) => {
+                    metrics::OPERATIONS_COALESCED.add(1, stream_tags);
+                    coalesced
+                }
+                Err((previous, next)) => {
+                    operations.push(previous);
+                    next
+                }
+            },
+            None => data.typed_sql,
+        };
+        operations.push(operation);
+    }
+    let operations_count = operations.len();
+
+    let conn = pg_connection(&store.master_pool).await;
+    let (result, execution_time) = Box::pin(common_utils::date_time::time_it(|| {
+        conn.transaction_async(|conn| async move {
+            for operation in operations {
+                let operation_name = operation.operation();
+                let table = operation.table();
+
+                operation.execute(&conn).await.map_err(|err| {
+                    logger::error!(operation = operation_name, table = table, ?err);
+                    *err.current_context()
+                })?;
+            }
+
+            Ok::<_, DatabaseError>(())
+        })
+    }))
+    .await;
+
+    metrics::BATCH_EXECUTION_TIME.record(execution_time, stream_tags);
+
+    match result {
+        Ok(()) => {
+            logger::info!(
+                stream_name,
+                entries = batch_size,
+                operations = operations_count,
+                "Committed the batch of drainer stream entries"
+            );
+            metrics::BATCHES_COMMITTED.add(1, stream_tags);
+            metrics::BATCH_SIZE.record(u64::try_from(batch_size).unwrap_or(u64::MAX), stream_tags);
+
+            for (operation, table, pushed_at) in executed_entries {
+                let tags =
+                    router_env::metric_attributes!(("operation", operation), ("table", table));
+
+                push_drainer_delay(pushed_at, operation, table, tags);
+                metrics::SUCCESSFUL_QUERY_EXECUTION.add(1, tags);
+            }
+
+            Ok(())
+        }
+        Err(err) => {
+            logger::error!(
+                stream_name,
+                entries = batch_size,
+                ?err,
+                "Rolled back the batch of drainer stream entries"
+            );
+            metrics::BATCHES_ROLLED_BACK.add(1, stream_tags);
+
+            Err(err.into())
+        }
+    }
+}
+
 #[inline(always)]
 fn push_drainer_delay(
     pushed_at: i64,
@@ -7,7 +7,7 @@ use redis_interface::RedisConnectionPool;
 use crate::{
     connection::{diesel_make_pg_pool, PgPool},
     logger,
-    settings::Tenant,
+    settings::{DrainerBatchSettings, Tenant},
 };
 
 #[derive(Clone)]
@@ -23,6 +23,9 @@ pub struct StoreConfig {
     pub drainer_stream_name: String,
     pub drainer_num_partitions: u8,
     pub use_legacy_version: bool,
+    pub max_attempts: u32,
+    pub transient_failure_timeout: u32,
+    pub batch: DrainerBatchSettings,
 }
 
 impl Store {
@@ -47,6 +50,9 @@ impl Store {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
                 use_legacy_version: config.redis.use_legacy_version,
+                max_attempts: config.drainer.max_attempts,
+                transient_failure_timeout: config.drainer.transient_failure_timeout,
+                batch: config.drainer.batch.clone(),
             },
             request_id: None,
         }
@@ -17,7 +17,7 @@ pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use router_env::{env, logger};
 use serde::Deserialize;
 
-use crate::{errors, secrets_transformers};
+use crate::{dead_letter, errors, secrets_transformers};
 
 #[derive(clap::Parser, Default)]
 #[cfg_attr(feature = "vergen", command(version = router_env::version!()))]
@@ -26,6 +26,18 @@ pub struct CmdLineConf {
     /// Application will look for "config/config.toml" if this option isn't specified.
     #[arg(short = 'f', long, value_name = "FILE")]
     pub config_path: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Option<DrainerCommand>,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum DrainerCommand {
+    /// Inspect, replay or discard the entries in the dead-letter streams
+    DeadLetter {
+        #[command(subcommand)]
+        command: dead_letter::DeadLetterCommand,
+    },
 }
 
 #[derive(Clone)]
@@ -114,6 +126,24 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    /// Number of times an entry is attempted before it is moved to the dead-letter stream
+    pub max_attempts: u32,
+    /// Duration for which an entry failing with an error which may be caused by the database
+    /// being unavailable is retried, in addition to `max_attempts`, before it is dead-lettered
+    pub transient_failure_timeout: u32, // in seconds
+    pub batch: DrainerBatchSettings,
+}
+
+/// Applies consecutive entries of a stream in a single database transaction instead of executing
+/// a query per entry
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DrainerBatchSettings {
+    pub enabled: bool,
+    /// Maximum number of entries applied in a transaction
+    pub max_entries: usize,
+    /// Maximum size of the entries applied in a transaction, in bytes
+    pub max_bytes: usize,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -224,6 +254,19 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 100,      // in milliseconds
+            max_attempts: 5,
+            transient_failure_timeout: 1800, // in seconds
+            batch: DrainerBatchSettings::default(),
+        }
+    }
+}
+
+impl Default for DrainerBatchSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_entries: 50,
+            max_bytes: 1024 * 1024,
         }
     }
 }
@@ -274,6 +317,30 @@ impl DrainerSettings {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        common_utils::fp_utils::when(self.max_attempts == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer max attempts must be greater than zero".into(),
+            ))
+        })?;
+
+        self.batch.validate()
+    }
+}
+
+impl DrainerBatchSettings {
+    fn validate(&self) -> Result<(), errors::DrainerError> {
+        common_utils::fp_utils::when(self.enabled && self.max_entries == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer batch max entries must be greater than zero".into(),
+            ))
+        })?;
+
+        common_utils::fp_utils::when(self.enabled && self.max_bytes == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer batch max bytes must be greater than zero".into(),
+            ))
         })
     }
 }
@@ -307,7 +374,8 @@ impl Settings<SecuredSecret> {
                     .try_parsing(true)
                     .separator("__")
                     .list_separator(",")
-                    .with_list_parse_key("redis.cluster_urls"),
+                    .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("redis.sentinel_urls"),
             )
             .build()?;
 
@@ -62,6 +62,8 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
             payment_type: None,
         },
         issuer_data: None,
+        time_data: None,
+        velocity_data: None,
         acquirer_data: None,
         customer_device_data: None,
     };
@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 use crate::{
     enums,
     frontend::dir::enums::{
-        CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType, TransactionInitiator,
+        CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType, DayOfWeek,
+        TransactionInitiator,
     },
 };
 
@@ -57,6 +58,33 @@ pub struct IssuerDataInput {
     pub country: Option<enums::Country>,
 }
 
+/// Local time at which the payment is being routed, in the timezone configured on the profile
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeDataInput {
+    /// Time of the day as HHMM, e.g. `1430` for 2:30 PM
+    pub time_of_day: u16,
+    pub day_of_week: DayOfWeek,
+    /// Date as YYYYMMDD, e.g. `20250131`
+    pub date: u32,
+}
+
+/// Number and total amount of the payments made with an identifier over a sliding window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VelocityCounter {
+    pub count: u32,
+    /// Sum of the amounts of the payments made in the currency of the evaluated payment
+    pub amount: common_utils::types::MinorUnit,
+}
+
+/// Payments made with the same card, email and IP address as the evaluated payment over the
+/// velocity window of the fraud rules, the evaluated payment included
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VelocityDataInput {
+    pub card: Option<VelocityCounter>,
+    pub email: Option<VelocityCounter>,
+    pub ip: Option<VelocityCounter>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackendInput {
     pub metadata: Option<FxHashMap<String, String>>,
@@ -66,4 +94,8 @@ pub struct BackendInput {
     pub customer_device_data: Option<CustomerDeviceDataInput>,
     pub issuer_data: Option<IssuerDataInput>,
     pub mandate: MandateData,
+    #[serde(default)]
+    pub time_data: Option<TimeDataInput>,
+    #[serde(default)]
+    pub velocity_data: Option<VelocityDataInput>,
 }
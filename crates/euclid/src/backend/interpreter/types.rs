@@ -1,5 +1,6 @@
 use std::{collections::HashMap, fmt, ops::Deref, string::ToString};
 
+use common_utils::types::MinorUnit;
 use serde::Serialize;
 
 use crate::{backend::inputs, frontend::ast::ValueType, types::EuclidKey};
@@ -74,6 +75,75 @@ impl From<inputs::BackendInput> for Context {
                 EuclidKey::PaymentCurrency.to_string(),
                 Some(ValueType::EnumVariant(input.payment.currency.to_string())),
             ),
+            (
+                EuclidKey::TimeOfDay.to_string(),
+                input
+                    .time_data
+                    .as_ref()
+                    .map(|td| ValueType::Number(MinorUnit::new(i64::from(td.time_of_day)))),
+            ),
+            (
+                EuclidKey::DayOfWeek.to_string(),
+                input
+                    .time_data
+                    .as_ref()
+                    .map(|td| ValueType::EnumVariant(td.day_of_week.to_string())),
+            ),
+            (
+                EuclidKey::CalendarDate.to_string(),
+                input
+                    .time_data
+                    .as_ref()
+                    .map(|td| ValueType::Number(MinorUnit::new(i64::from(td.date)))),
+            ),
+            (
+                EuclidKey::CardVelocityCount.to_string(),
+                input
+                    .velocity_data
+                    .as_ref()
+                    .and_then(|vd| vd.card.as_ref())
+                    .map(|counter| ValueType::Number(MinorUnit::new(i64::from(counter.count)))),
+            ),
+            (
+                EuclidKey::CardVelocityAmount.to_string(),
+                input
+                    .velocity_data
+                    .as_ref()
+                    .and_then(|vd| vd.card.as_ref())
+                    .map(|counter| ValueType::Number(counter.amount)),
+            ),
+            (
+                EuclidKey::EmailVelocityCount.to_string(),
+                input
+                    .velocity_data
+                    .as_ref()
+                    .and_then(|vd| vd.email.as_ref())
+                    .map(|counter| ValueType::Number(MinorUnit::new(i64::from(counter.count)))),
+            ),
+            (
+                EuclidKey::EmailVelocityAmount.to_string(),
+                input
+                    .velocity_data
+                    .as_ref()
+                    .and_then(|vd| vd.email.as_ref())
+                    .map(|counter| ValueType::Number(counter.amount)),
+            ),
+            (
+                EuclidKey::IpVelocityCount.to_string(),
+                input
+                    .velocity_data
+                    .as_ref()
+                    .and_then(|vd| vd.ip.as_ref())
+                    .map(|counter| ValueType::Number(MinorUnit::new(i64::from(counter.count)))),
+            ),
+            (
+                EuclidKey::IpVelocityAmount.to_string(),
+                input
+                    .velocity_data
+                    .as_ref()
+                    .and_then(|vd| vd.ip.as_ref())
+                    .map(|counter| ValueType::Number(counter.amount)),
+            ),
         ]);
 
         Self(ctx)
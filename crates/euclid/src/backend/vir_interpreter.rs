@@ -110,7 +110,7 @@ mod test {
     use rustc_hash::FxHashMap;
 
     use super::*;
-    use crate::{enums, types::DummyOutput};
+    use crate::{enums, frontend::dir::enums::DayOfWeek, types::DummyOutput};
 
     #[test]
     fn test_execution() {
@@ -158,6 +158,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time_data: None,
+            velocity_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -204,6 +206,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time_data: None,
+            velocity_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -251,6 +255,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time_data: None,
+            velocity_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -298,6 +304,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time_data: None,
+            velocity_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -345,6 +353,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time_data: None,
+            velocity_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -392,6 +402,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time_data: None,
+            velocity_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -439,6 +451,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time_data: None,
+            velocity_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -486,6 +500,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time_data: None,
+            velocity_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -533,6 +549,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time_data: None,
+            velocity_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -582,6 +600,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time_data: None,
+            velocity_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -629,6 +649,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time_data: None,
+            velocity_data: None,
         };
         let mut inp_equal = inp_greater.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -685,6 +707,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time_data: None,
+            velocity_data: None,
         };
         let mut inp_equal = inp_lower.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -700,4 +724,205 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_time_based_rules() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["adyen"]
+        {
+           time_of_day >= 200 & time_of_day < 400 & day_of_week = (saturday, sunday)
+        }
+        rule_2: ["stripe"]
+        {
+           date >= 20250101 & date <= 20250131
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_downtime = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                transaction_initiator: None,
+                card_bin: None,
+                extended_card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+                card_discovery: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            time_data: Some(inputs::TimeDataInput {
+                time_of_day: 230,
+                day_of_week: DayOfWeek::Sunday,
+                date: 20250209,
+            }),
+            velocity_data: None,
+        };
+        let mut inp_weekday = inp_downtime.clone();
+        inp_weekday.time_data = Some(inputs::TimeDataInput {
+            time_of_day: 230,
+            day_of_week: DayOfWeek::Monday,
+            date: 20250113,
+        });
+        let mut inp_no_time = inp_downtime.clone();
+        inp_no_time.time_data = None;
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_downtime = backend.execute(inp_downtime).expect("Execution");
+        let result_weekday = backend.execute(inp_weekday).expect("Execution");
+        let result_no_time = backend.execute(inp_no_time).expect("Execution");
+        assert_eq!(
+            result_downtime.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+        assert_eq!(
+            result_weekday.rule_name.expect("Rule Name").as_str(),
+            "rule_2"
+        );
+        assert!(result_no_time.rule_name.is_none());
+    }
+
+    #[test]
+    fn test_invalid_calendar_dates_are_rejected() {
+        for date in ["20250231", "20251301", "20250100", "99991232"] {
+            let program_str = format!(
+                r#"
+                default: ["stripe"]
+
+                rule_1: ["adyen"]
+                {{
+                   date = {date}
+                }}
+                "#
+            );
+            let (_, program) = ast::parser::program::<DummyOutput>(&program_str).expect("Program");
+            assert!(
+                VirInterpreterBackend::<DummyOutput>::with_program(program).is_err(),
+                "{date} should be rejected"
+            );
+        }
+
+        let program_str = r#"
+        default: ["stripe"]
+
+        rule_1: ["adyen"]
+        {
+           date = 20240229
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        assert!(VirInterpreterBackend::<DummyOutput>::with_program(program).is_ok());
+    }
+
+    #[test]
+    fn test_velocity_based_rules() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["adyen"]
+        {
+           card_velocity_count > 3 | ip_velocity_amount >= 100000
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_velocity = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                transaction_initiator: None,
+                card_bin: None,
+                extended_card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: Some(enums::PaymentMethodType::Credit),
+                card_network: None,
+                card_discovery: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            time_data: None,
+            velocity_data: Some(inputs::VelocityDataInput {
+                card: Some(inputs::VelocityCounter {
+                    count: 4,
+                    amount: MinorUnit::new(480),
+                }),
+                email: None,
+                ip: None,
+            }),
+        };
+        let mut inp_ip_amount = inp_velocity.clone();
+        inp_ip_amount.velocity_data = Some(inputs::VelocityDataInput {
+            card: Some(inputs::VelocityCounter {
+                count: 1,
+                amount: MinorUnit::new(120),
+            }),
+            email: None,
+            ip: Some(inputs::VelocityCounter {
+                count: 2,
+                amount: MinorUnit::new(100120),
+            }),
+        });
+        let mut inp_no_velocity = inp_velocity.clone();
+        inp_no_velocity.velocity_data = None;
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_velocity = backend.execute(inp_velocity).expect("Execution");
+        let result_ip_amount = backend.execute(inp_ip_amount).expect("Execution");
+        let result_no_velocity = backend.execute(inp_no_velocity).expect("Execution");
+        assert_eq!(
+            result_velocity.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+        assert_eq!(
+            result_ip_amount.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+        assert!(result_no_velocity.rule_name.is_none());
+    }
+
+    #[test]
+    fn test_invalid_time_of_day() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["adyen"]
+        {
+           time_of_day > 2460
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        assert!(VirInterpreterBackend::<DummyOutput>::with_program(program).is_err());
+    }
 }
@@ -1,3 +1,4 @@
+use common_utils::types::MinorUnit;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
@@ -58,6 +59,8 @@ impl Context {
         let customer_device_data = input.customer_device_data;
         let issuer_data = input.issuer_data;
         let payment_mandate = input.mandate;
+        let time_data = input.time_data;
+        let velocity_data = input.velocity_data;
 
         let mut enum_values: FxHashSet<EuclidValue> =
             FxHashSet::from_iter([EuclidValue::PaymentCurrency(payment.currency)]);
@@ -156,7 +159,7 @@ impl Context {
             }
         }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
                 number: payment.amount,
@@ -164,6 +167,61 @@ impl Context {
             }),
         )]);
 
+        // Handle the local time at which the payment is being routed
+        if let Some(time) = time_data {
+            enum_values.insert(EuclidValue::DayOfWeek(time.day_of_week));
+            numeric_values.insert(
+                EuclidKey::TimeOfDay,
+                EuclidValue::TimeOfDay(types::NumValue {
+                    number: MinorUnit::new(i64::from(time.time_of_day)),
+                    refinement: None,
+                }),
+            );
+            numeric_values.insert(
+                EuclidKey::CalendarDate,
+                EuclidValue::CalendarDate(types::NumValue {
+                    number: MinorUnit::new(i64::from(time.date)),
+                    refinement: None,
+                }),
+            );
+        }
+
+        // Handle the payments made with the same card, email and IP address
+        if let Some(velocity) = velocity_data {
+            let counters: [(_, fn(types::NumValue) -> EuclidValue, fn(types::NumValue) -> EuclidValue); 3] = [
+                (
+                    velocity.card,
+                    EuclidValue::CardVelocityCount,
+                    EuclidValue::CardVelocityAmount,
+                ),
+                (
+                    velocity.email,
+                    EuclidValue::EmailVelocityCount,
+                    EuclidValue::EmailVelocityAmount,
+                ),
+                (
+                    velocity.ip,
+                    EuclidValue::IpVelocityCount,
+                    EuclidValue::IpVelocityAmount,
+                ),
+            ];
+            for (counter, count_value, amount_value) in counters {
+                let Some(counter) = counter else {
+                    continue;
+                };
+                let count = count_value(types::NumValue {
+                    number: MinorUnit::new(i64::from(counter.count)),
+                    refinement: None,
+                });
+                let amount = amount_value(types::NumValue {
+                    number: counter.amount,
+                    refinement: None,
+                });
+                numeric_values.insert(count.get_key(), count);
+                numeric_values.insert(amount.get_key(), amount);
+            }
+        }
+
         Self {
             atomic_values: enum_values,
             numeric_values,
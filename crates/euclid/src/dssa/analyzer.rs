@@ -9,7 +9,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     dssa::{
-        graph::CgraphExt,
+        graph::{self, CgraphExt},
         state_machine, truth,
         types::{self, EuclidAnalysable},
     },
@@ -18,7 +18,7 @@ use crate::{
         dir::{self, EuclidDirFilter},
         vir,
     },
-    types::{DataType, Metadata},
+    types::{DataType, Metadata, NumValue, NumValueRefinement},
 };
 
 /// Analyses conflicting assertions on the same key in a conjunctive context.
@@ -238,6 +238,349 @@ pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
     dir::lowering::lower_program(dir_program)
 }
 
+/// Analyses the program for rules that can never fire or that interact with earlier rules in
+/// ways that are likely unintended. Unlike [`analyze`], none of these make the program invalid.
+///
+/// Rules are evaluated in order and the first matching rule wins, so a rule is shadowed when
+/// every payment it matches is already matched by earlier rules. When a knowledge graph is
+/// provided, connectors that the graph rejects under every condition of their rule are
+/// reported as well.
+///
+/// The checks are conservative, a shadowed rule or an unreachable connector is only reported
+/// when it can be proven from the conditions of the program.
+pub fn analyze_warnings<O: EuclidAnalysable>(
+    program: &dir::DirProgram<O>,
+    knowledge_graph: Option<&ConstraintGraph<dir::DirValue>>,
+) -> Result<Vec<types::AnalysisWarning>, types::AnalysisError> {
+    let selection_data = state_machine::make_connector_selection_data(program);
+    let rule_paths: Vec<Vec<ConditionPath<'_>>> =
+        program.rules.iter().map(get_rule_paths).collect();
+    let mut warnings = Vec::new();
+
+    for (rule_idx, rule) in program.rules.iter().enumerate() {
+        let paths = rule_paths
+            .get(rule_idx)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let outputs = selection_data
+            .get(rule_idx)
+            .map(|data| data.iter().map(|(value, _)| value).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let mut shadowed_by = Vec::new();
+        let mut uncovered_paths = paths.to_vec();
+
+        for (earlier_rule, earlier_paths) in
+            program.rules.iter().zip(rule_paths.iter()).take(rule_idx)
+        {
+            let covers_any = uncovered_paths.iter().any(|path| {
+                earlier_paths
+                    .iter()
+                    .any(|earlier_path| path_implies(path, earlier_path))
+            });
+
+            if covers_any {
+                shadowed_by.push(earlier_rule.name.clone());
+                uncovered_paths.retain(|path| {
+                    !earlier_paths
+                        .iter()
+                        .any(|earlier_path| path_implies(path, earlier_path))
+                });
+            }
+        }
+
+        if !paths.is_empty() && uncovered_paths.is_empty() {
+            warnings.push(types::AnalysisWarning::ShadowedRule {
+                rule_name: rule.name.clone(),
+                shadowed_by,
+            });
+            continue;
+        }
+
+        for (earlier_idx, earlier_rule) in program.rules.iter().enumerate().take(rule_idx) {
+            let earlier_outputs = selection_data
+                .get(earlier_idx)
+                .map(|data| data.iter().map(|(value, _)| value).collect::<Vec<_>>())
+                .unwrap_or_default();
+
+            let overlaps = rule_paths.get(earlier_idx).is_some_and(|earlier_paths| {
+                paths.iter().any(|path| {
+                    earlier_paths.iter().any(|earlier_path| {
+                        is_satisfiable(path.iter().chain(earlier_path.iter()).copied())
+                    })
+                })
+            });
+
+            if overlaps && earlier_outputs != outputs {
+                warnings.push(types::AnalysisWarning::OverlappingRules {
+                    rule_name: rule.name.clone(),
+                    overlapping_rule_name: earlier_rule.name.clone(),
+                });
+            }
+        }
+
+        if let Some(graph) = knowledge_graph {
+            let connectors = outputs
+                .iter()
+                .filter(|value| matches!(value, dir::DirValue::Connector(_)))
+                .copied()
+                .collect::<Vec<_>>();
+
+            for connector in find_unreachable_values(graph, rule, &connectors)? {
+                if let dir::DirValue::Connector(choice) = connector {
+                    warnings.push(types::AnalysisWarning::UnreachableConnector {
+                        rule_name: rule.name.clone(),
+                        connector: choice.connector,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(warnings)
+}
+
+/// The comparisons along a path from a top level statement of a rule to one of its innermost
+/// statements. The rule matches a payment when all the comparisons of any of its paths hold.
+type ConditionPath<'a> = Vec<&'a dir::DirComparison>;
+
+fn get_rule_paths<O>(rule: &dir::DirRule<O>) -> Vec<ConditionPath<'_>> {
+    fn collect_paths<'a>(
+        statement: &'a dir::DirIfStatement,
+        prefix: &mut ConditionPath<'a>,
+        paths: &mut Vec<ConditionPath<'a>>,
+    ) {
+        let prefix_len = prefix.len();
+        prefix.extend(statement.condition.iter());
+
+        match &statement.nested {
+            None => paths.push(prefix.clone()),
+            Some(nested) => nested
+                .iter()
+                .for_each(|nested_statement| collect_paths(nested_statement, prefix, paths)),
+        }
+
+        prefix.truncate(prefix_len);
+    }
+
+    let mut paths = Vec::new();
+    for statement in &rule.statements {
+        collect_paths(statement, &mut Vec::new(), &mut paths);
+    }
+    paths
+}
+
+fn get_comparison_key(comparison: &dir::DirComparison) -> Option<dir::DirKey> {
+    comparison.values.first().map(dir::DirValue::get_key)
+}
+
+fn is_number_key(key: &dir::DirKey) -> bool {
+    matches!(key.kind.get_type(), DataType::Number)
+}
+
+/// Inclusive range of numbers a numeric value matches, `None` for `/=` refinements which
+/// cannot be represented as a single range
+fn get_num_range(value: &NumValue) -> Option<(i64, i64)> {
+    let number = value.number.get_amount_as_i64();
+    match value.refinement {
+        None => Some((number, number)),
+        Some(NumValueRefinement::GreaterThan) => Some((number.saturating_add(1), i64::MAX)),
+        Some(NumValueRefinement::GreaterThanEqual) => Some((number, i64::MAX)),
+        Some(NumValueRefinement::LessThan) => Some((i64::MIN, number.saturating_sub(1))),
+        Some(NumValueRefinement::LessThanEqual) => Some((i64::MIN, number)),
+        Some(NumValueRefinement::NotEqual) => None,
+    }
+}
+
+/// Whether every payment matching `narrow` also matches `broad`
+fn value_implies(narrow: &dir::DirValue, broad: &dir::DirValue) -> bool {
+    match (
+        narrow.get_num_value().as_ref().and_then(get_num_range),
+        broad.get_num_value().as_ref().and_then(get_num_range),
+    ) {
+        (Some((narrow_min, narrow_max)), Some((broad_min, broad_max))) => {
+            broad_min <= narrow_min && narrow_max <= broad_max
+        }
+        _ => narrow == broad,
+    }
+}
+
+/// Whether every payment satisfying the `narrow` comparison also satisfies the `broad` one,
+/// given that both compare the same key
+fn comparison_implies(
+    key: &dir::DirKey,
+    narrow: &dir::DirComparison,
+    broad: &dir::DirComparison,
+) -> bool {
+    use dir::DirComparisonLogic::{NegativeConjunction, PositiveDisjunction};
+
+    match (&narrow.logic, &broad.logic) {
+        (PositiveDisjunction, PositiveDisjunction) => narrow.values.iter().all(|narrow_value| {
+            broad
+                .values
+                .iter()
+                .any(|broad_value| value_implies(narrow_value, broad_value))
+        }),
+        _ if is_number_key(key) => false,
+        (PositiveDisjunction, NegativeConjunction) => narrow
+            .values
+            .iter()
+            .all(|narrow_value| !broad.values.contains(narrow_value)),
+        (NegativeConjunction, NegativeConjunction) => broad
+            .values
+            .iter()
+            .all(|broad_value| narrow.values.contains(broad_value)),
+        (NegativeConjunction, PositiveDisjunction) => {
+            key.kind.get_value_set().is_some_and(|value_set| {
+                value_set
+                    .iter()
+                    .filter(|value| !narrow.values.contains(value))
+                    .all(|value| broad.values.contains(value))
+            })
+        }
+    }
+}
+
+/// Whether every payment matching the `narrow` path also matches the `broad` path
+fn path_implies(narrow: &ConditionPath<'_>, broad: &ConditionPath<'_>) -> bool {
+    broad.iter().all(|broad_comparison| {
+        get_comparison_key(broad_comparison).is_some_and(|key| {
+            narrow.iter().any(|narrow_comparison| {
+                get_comparison_key(narrow_comparison).as_ref() == Some(&key)
+                    && comparison_implies(&key, narrow_comparison, broad_comparison)
+            })
+        })
+    })
+}
+
+/// Whether some payment can satisfy all the given comparisons at once. Negated numbers are
+/// not taken into account, which can only make the result more permissive.
+fn is_satisfiable<'a>(comparisons: impl Iterator<Item = &'a dir::DirComparison>) -> bool {
+    let mut keywise_comparisons: FxHashMap<dir::DirKey, Vec<&dir::DirComparison>> =
+        FxHashMap::default();
+
+    for comparison in comparisons {
+        if let Some(key) = get_comparison_key(comparison) {
+            keywise_comparisons.entry(key).or_default().push(comparison);
+        }
+    }
+
+    keywise_comparisons.iter().all(|(key, comparisons)| {
+        if is_number_key(key) {
+            let mut ranges = vec![(i64::MIN, i64::MAX)];
+
+            for comparison in comparisons.iter().filter(|comparison| {
+                matches!(
+                    comparison.logic,
+                    dir::DirComparisonLogic::PositiveDisjunction
+                )
+            }) {
+                let allowed = comparison
+                    .values
+                    .iter()
+                    .map(|value| {
+                        value
+                            .get_num_value()
+                            .as_ref()
+                            .and_then(get_num_range)
+                            .unwrap_or((i64::MIN, i64::MAX))
+                    })
+                    .collect::<Vec<_>>();
+
+                ranges = ranges
+                    .iter()
+                    .flat_map(|(min, max)| {
+                        allowed
+                            .iter()
+                            .filter_map(move |(allowed_min, allowed_max)| {
+                                let range = ((*min).max(*allowed_min), (*max).min(*allowed_max));
+                                (range.0 <= range.1).then_some(range)
+                            })
+                    })
+                    .collect();
+            }
+
+            !ranges.is_empty()
+        } else {
+            let mut allowed: Option<FxHashSet<&dir::DirValue>> = None;
+            let mut negated: FxHashSet<&dir::DirValue> = FxHashSet::default();
+
+            for comparison in comparisons {
+                match comparison.logic {
+                    dir::DirComparisonLogic::PositiveDisjunction => {
+                        let values = comparison.values.iter().collect::<FxHashSet<_>>();
+                        allowed = Some(match allowed {
+                            Some(allowed) => &allowed & &values,
+                            None => values,
+                        });
+                    }
+                    dir::DirComparisonLogic::NegativeConjunction => {
+                        negated.extend(comparison.values.iter());
+                    }
+                }
+            }
+
+            match allowed {
+                Some(allowed) => allowed.iter().any(|value| !negated.contains(value)),
+                None => key
+                    .kind
+                    .get_value_set()
+                    .is_none_or(|value_set| value_set.iter().any(|value| !negated.contains(value))),
+            }
+        }
+    })
+}
+
+/// Returns the values which the knowledge graph rejects in every context the rule can match
+fn find_unreachable_values<'a, O>(
+    knowledge_graph: &ConstraintGraph<dir::DirValue>,
+    rule: &dir::DirRule<O>,
+    values: &[&'a dir::DirValue],
+) -> Result<Vec<&'a dir::DirValue>, types::AnalysisError> {
+    let mut unreachable =
+        values
+            .iter()
+            .copied()
+            .filter(|value| {
+                knowledge_graph.value_map.contains_key(
+                    &hyperswitch_constraint_graph::NodeValue::Value((*value).clone()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+    let mut ctx_manager = state_machine::RuleContextManager::new(rule, &[]);
+
+    while let Some(ctx) = ctx_manager.advance().map_err(|err| types::AnalysisError {
+        metadata: Default::default(),
+        error_type: types::AnalysisErrorType::StateMachine(err),
+    })? {
+        if unreachable.is_empty() {
+            break;
+        }
+
+        let analysis_ctx = graph::AnalysisContext::from_dir_values(
+            ctx.iter()
+                .filter_map(|ctx_val| ctx_val.value.get_assertion().cloned()),
+        );
+
+        // Errors while walking the graph are not treated as a rejection of the value, so that
+        // only connectors which are provably unreachable are reported
+        unreachable.retain(|value| {
+            !knowledge_graph
+                .check_value_validity(
+                    (*value).clone(),
+                    &analysis_ctx,
+                    &mut Memoization::new(),
+                    &mut hyperswitch_constraint_graph::CycleCheck::new(),
+                    None,
+                )
+                .unwrap_or(true)
+        });
+    }
+
+    Ok(unreachable)
+}
+
 #[cfg(all(test, feature = "ast_parser"))]
 mod tests {
     use std::{ops::Deref, sync::Weak};
@@ -452,4 +795,151 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn test_shadowed_and_overlapping_rule_warnings() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            card_rule: ["stripe"]
+            {
+                payment_method = card
+            }
+
+            credit_rule: ["adyen"]
+            {
+                payment_method = card & card_type = credit
+            }
+
+            wallet_rule: ["adyen"]
+            {
+                payment_method = wallet & amount > 500
+            }
+
+            large_wallet_rule: ["stripe"]
+            {
+                payment_method = (wallet, pay_later) & amount > 1000
+            }
+
+            small_wallet_rule: ["checkout"]
+            {
+                payment_method = wallet & amount < 100
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let dir_program = ast::lowering::lower_program(program).expect("Lowering");
+        let warnings = analyze_warnings(&dir_program, None).expect("Warnings");
+
+        assert_eq!(warnings.len(), 2, "Expected exactly two warnings");
+        assert!(warnings.iter().any(|warning| matches!(
+            warning,
+            types::AnalysisWarning::ShadowedRule { rule_name, shadowed_by }
+                if rule_name == "credit_rule" && shadowed_by == &vec!["card_rule".to_string()]
+        )));
+        assert!(warnings.iter().any(|warning| matches!(
+            warning,
+            types::AnalysisWarning::OverlappingRules { rule_name, overlapping_rule_name }
+                if rule_name == "large_wallet_rule" && overlapping_rule_name == "wallet_rule"
+        )));
+    }
+
+    /// Output of the test programs which selects connectors, so that the connectors of the rules
+    /// can be checked against a knowledge graph
+    #[derive(Clone, Debug)]
+    struct TestConnectorSelection(ast::ConnectorSelection);
+
+    impl EuclidDirFilter for TestConnectorSelection {
+        const ALLOWED: &'static [dir::DirKeyKind] = DummyOutput::ALLOWED;
+    }
+
+    impl ast::parser::EuclidParsable for TestConnectorSelection {
+        fn parse_output(input: &str) -> ast::parser::ParseResult<&str, Self> {
+            ast::parser::connector_selection(input).map(|(rest, selection)| (rest, Self(selection)))
+        }
+    }
+
+    impl EuclidAnalysable for TestConnectorSelection {
+        fn get_dir_value_for_analysis(&self, _rule_name: String) -> Vec<(dir::DirValue, Metadata)> {
+            let choices = match &self.0 {
+                ast::ConnectorSelection::Priority(choices) => choices.clone(),
+                ast::ConnectorSelection::VolumeSplit(splits) => {
+                    splits.iter().map(|split| split.connector.clone()).collect()
+                }
+            };
+
+            choices
+                .into_iter()
+                .map(|choice| {
+                    (
+                        dir::DirValue::Connector(Box::new(ast::ConnectorChoice {
+                            connector: choice.connector,
+                        })),
+                        Metadata::default(),
+                    )
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_unreachable_connector_warnings() {
+        let program_str = r#"
+            default: [stripe]
+
+            card_rule: [stripe]
+            {
+                payment_method = card
+            }
+
+            wallet_rule: [stripe, adyen]
+            {
+                payment_method = wallet
+            }
+        "#;
+
+        let mut builder = cgraph::ConstraintGraphBuilder::new();
+        let card_node = builder.make_value_node(
+            cgraph::NodeValue::Value(dirval!(PaymentMethod = Card)),
+            None,
+            None::<()>,
+        );
+        let stripe_node = builder.make_value_node(
+            cgraph::NodeValue::Value(dirval!(Connector = Stripe)),
+            None,
+            None::<()>,
+        );
+        builder
+            .make_edge(
+                card_node,
+                stripe_node,
+                cgraph::Strength::Strong,
+                cgraph::Relation::Positive,
+                None::<cgraph::DomainId>,
+            )
+            .expect("Edge");
+        let graph = builder.build();
+
+        let (_, program) =
+            ast::parser::program::<TestConnectorSelection>(program_str).expect("Program");
+        let dir_program = ast::lowering::lower_program(program).expect("Lowering");
+        let warnings = analyze_warnings(&dir_program, Some(&graph)).expect("Warnings");
+
+        let unreachable = warnings
+            .iter()
+            .filter_map(|warning| match warning {
+                types::AnalysisWarning::UnreachableConnector {
+                    rule_name,
+                    connector,
+                } => Some((rule_name.as_str(), *connector)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            unreachable,
+            vec![("wallet_rule", crate::enums::RoutableConnectors::Stripe)],
+            "Only stripe of the wallet rule requires a card payment"
+        );
+    }
 }
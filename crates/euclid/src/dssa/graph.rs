@@ -86,10 +86,32 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::TransactionInitiator(transaction_initiator) => transaction_initiator.to_string(),
             Self::NetworkTokenType(ntt) => ntt.to_string(),
             Self::CardDiscovery(card_discovery) => card_discovery.to_string(),
+            Self::TimeOfDay(time_of_day) => time_of_day.number.to_string(),
+            Self::DayOfWeek(day_of_week) => day_of_week.to_string(),
+            Self::CalendarDate(date) => date.number.to_string(),
+            Self::CardVelocityCount(val) => val.number.to_string(),
+            Self::CardVelocityAmount(val) => val.number.to_string(),
+            Self::EmailVelocityCount(val) => val.number.to_string(),
+            Self::EmailVelocityAmount(val) => val.number.to_string(),
+            Self::IpVelocityCount(val) => val.number.to_string(),
+            Self::IpVelocityAmount(val) => val.number.to_string(),
         }
     }
 }
 
+impl cgraph::ExplainableValue for dir::DirValue {
+    fn describe_key(key: &dir::DirKey) -> String {
+        match &key.value {
+            Some(value) => format!("{}[{value}]", key.kind),
+            None => key.kind.to_string(),
+        }
+    }
+
+    fn describe_value(&self) -> String {
+        cgraph::NodeViz::viz(self)
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(tag = "type", content = "details", rename_all = "snake_case")]
 pub enum AnalysisError<V: cgraph::ValueNode> {
@@ -259,6 +281,17 @@ pub trait CgraphExt {
         domains: Option<&[String]>,
     ) -> Result<bool, cgraph::GraphError<dir::DirValue>>;
 
+    /// Same as `check_value_validity`, but explains why the value is not valid instead of
+    /// returning `false`
+    fn explain_value_validity(
+        &self,
+        val: dir::DirValue,
+        analysis_ctx: &AnalysisContext,
+        memo: &mut cgraph::Memoization<dir::DirValue>,
+        cycle_map: &mut cgraph::CycleCheck,
+        domains: Option<&[String]>,
+    ) -> Result<Option<cgraph::Explanation<dir::DirValue>>, cgraph::GraphError<dir::DirValue>>;
+
     fn key_value_analysis(
         &self,
         val: dir::DirValue,
@@ -376,6 +409,39 @@ impl CgraphExt for cgraph::ConstraintGraph<dir::DirValue> {
         }
     }
 
+    fn explain_value_validity(
+        &self,
+        val: dir::DirValue,
+        analysis_ctx: &AnalysisContext,
+        memo: &mut cgraph::Memoization<dir::DirValue>,
+        cycle_map: &mut cgraph::CycleCheck,
+        domains: Option<&[String]>,
+    ) -> Result<Option<cgraph::Explanation<dir::DirValue>>, cgraph::GraphError<dir::DirValue>> {
+        let value = cgraph::NodeValue::Value(val);
+
+        let Some(node_id) = self.value_map.get(&value) else {
+            return Ok(Some(cgraph::Explanation::unknown_value(value)));
+        };
+
+        let result = self.check_node(
+            analysis_ctx,
+            *node_id,
+            cgraph::Relation::Positive,
+            cgraph::Strength::Weak,
+            memo,
+            cycle_map,
+            domains,
+        );
+
+        match result {
+            Ok(_) => Ok(None),
+            Err(e) => {
+                let trace = e.get_analysis_trace()?;
+                Ok(Some(cgraph::Explanation::from_trace(&trace)))
+            }
+        }
+    }
+
     fn key_value_analysis(
         &self,
         val: dir::DirValue,
@@ -154,6 +154,29 @@ pub enum AnalysisErrorType {
     NotSupported,
 }
 
+/// Issues in a program which do not make it invalid, but are most likely mistakes
+#[derive(Debug, Clone, Serialize, thiserror::Error)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+pub enum AnalysisWarning {
+    #[error("Rule '{rule_name}' can never be selected as earlier rules match all of its payments")]
+    ShadowedRule {
+        rule_name: String,
+        shadowed_by: Vec<String>,
+    },
+    #[error(
+        "Rule '{rule_name}' overlaps with the earlier rule '{overlapping_rule_name}' which selects a different output"
+    )]
+    OverlappingRules {
+        rule_name: String,
+        overlapping_rule_name: String,
+    },
+    #[error("Connector '{connector}' of rule '{rule_name}' can never accept the payments matched by the rule")]
+    UnreachableConnector {
+        rule_name: String,
+        connector: crate::enums::RoutableConnectors,
+    },
+}
+
 #[derive(Debug, Clone)]
 pub enum ValueType {
     EnumVariants(Vec<EuclidValue>),
@@ -1,3 +1,4 @@
+pub mod formatter;
 pub mod lowering;
 #[cfg(feature = "ast_parser")]
 pub mod parser;
@@ -0,0 +1,344 @@
+//! Renders an AST program back into the canonical text DSL accepted by the parser.
+//!
+//! Metadata attached to the program or to individual comparisons is rendered as a JSON object
+//! following an `@`, with its keys sorted.
+
+use std::{collections::BTreeMap, fmt::Write};
+
+use crate::{
+    enums::RoutableConnectors,
+    frontend::ast,
+    types::{DummyOutput, Metadata},
+};
+
+const INDENT: &str = "    ";
+
+/// Implemented by the outputs that a program can select, so that they can be rendered as text
+pub trait EuclidFormattable {
+    fn format_output(&self) -> String;
+}
+
+impl EuclidFormattable for DummyOutput {
+    fn format_output(&self) -> String {
+        format_output_list(self.outputs.iter().map(|output| format!("\"{output}\"")))
+    }
+}
+
+impl EuclidFormattable for ast::ConnectorSelection {
+    fn format_output(&self) -> String {
+        match self {
+            Self::Priority(choices) => format_output_list(choices.iter().map(|choice| {
+                format_connector_choice(&choice.connector, choice.merchant_connector_id.as_ref())
+            })),
+            Self::VolumeSplit(splits) => format_output_list(splits.iter().map(|split| {
+                format_volume_split(
+                    &split.connector.connector,
+                    split.connector.merchant_connector_id.as_ref(),
+                    split.split,
+                )
+            })),
+        }
+    }
+}
+
+/// Renders a list of formatted outputs, eg: `[stripe, adyen]`
+pub fn format_output_list(outputs: impl IntoIterator<Item = String>) -> String {
+    format!("[{}]", outputs.into_iter().collect::<Vec<_>>().join(", "))
+}
+
+/// Renders a connector, along with the merchant connector account if one is pinned,
+/// eg: `stripe` or `stripe("mca_123")`
+pub fn format_connector_choice(
+    connector: &RoutableConnectors,
+    merchant_connector_id: Option<&common_utils::id_type::MerchantConnectorAccountId>,
+) -> String {
+    match merchant_connector_id {
+        Some(mca_id) => format!("{connector}(\"{}\")", mca_id.get_string_repr()),
+        None => connector.to_string(),
+    }
+}
+
+/// Renders a single entry of a volume split, eg: `stripe: 60%`
+pub fn format_volume_split(
+    connector: &RoutableConnectors,
+    merchant_connector_id: Option<&common_utils::id_type::MerchantConnectorAccountId>,
+    split: u8,
+) -> String {
+    format!(
+        "{}: {split}%",
+        format_connector_choice(connector, merchant_connector_id)
+    )
+}
+
+pub fn format_comparison_type(comparison_type: &ast::ComparisonType) -> &'static str {
+    match comparison_type {
+        ast::ComparisonType::Equal => "=",
+        ast::ComparisonType::NotEqual => "/=",
+        ast::ComparisonType::LessThan => "<",
+        ast::ComparisonType::LessThanEqual => "<=",
+        ast::ComparisonType::GreaterThan => ">",
+        ast::ComparisonType::GreaterThanEqual => ">=",
+    }
+}
+
+pub fn format_value(value: &ast::ValueType) -> String {
+    match value {
+        ast::ValueType::Number(number) => number.get_amount_as_i64().to_string(),
+        ast::ValueType::EnumVariant(variant) => variant.clone(),
+        ast::ValueType::MetadataVariant(metadata) => format!("\"{}\"", metadata.value),
+        ast::ValueType::StrValue(value) => format!("\"{value}\""),
+        ast::ValueType::NumberArray(numbers) => format!(
+            "({})",
+            numbers
+                .iter()
+                .map(|number| number.get_amount_as_i64().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        ast::ValueType::EnumVariantArray(variants) => format!("({})", variants.join(", ")),
+        ast::ValueType::NumberComparisonArray(comparisons) => format!(
+            "({})",
+            comparisons
+                .iter()
+                .map(|comparison| format!(
+                    "{} {}",
+                    format_comparison_type(&comparison.comparison_type),
+                    comparison.number.get_amount_as_i64()
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Renders metadata as a JSON object following an `@`, eg: `@{"source": "dashboard"}`. Empty
+/// metadata is not rendered.
+pub fn format_metadata(metadata: &Metadata) -> Option<String> {
+    if metadata.is_empty() {
+        return None;
+    }
+
+    serde_json::to_string(&metadata.iter().collect::<BTreeMap<_, _>>())
+        .ok()
+        .map(|object| format!("@{object}"))
+}
+
+pub fn format_comparison(comparison: &ast::Comparison) -> String {
+    let lhs = match &comparison.value {
+        ast::ValueType::MetadataVariant(metadata) => format!("\"{}\"", metadata.key),
+        _ => comparison.lhs.clone(),
+    };
+
+    let mut buffer = format!(
+        "{lhs} {} {}",
+        format_comparison_type(&comparison.comparison),
+        format_value(&comparison.value)
+    );
+    if let Some(metadata) = format_metadata(&comparison.metadata) {
+        let _ = write!(buffer, " {metadata}");
+    }
+
+    buffer
+}
+
+fn write_if_statement(buffer: &mut String, statement: &ast::IfStatement, depth: usize) {
+    let indent = INDENT.repeat(depth);
+    let condition = statement
+        .condition
+        .iter()
+        .map(format_comparison)
+        .collect::<Vec<_>>()
+        .join(" & ");
+
+    let _ = write!(buffer, "{indent}{condition}");
+
+    if let Some(nested) = &statement.nested {
+        buffer.push_str(" {\n");
+        for nested_statement in nested {
+            write_if_statement(buffer, nested_statement, depth + 1);
+        }
+        let _ = write!(buffer, "{indent}}}");
+    }
+
+    buffer.push('\n');
+}
+
+/// Rule names that are not plain identifiers are quoted so that they can be parsed back
+fn format_rule_name(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if is_identifier && name != "default" {
+        name.to_string()
+    } else {
+        format!("\"{name}\"")
+    }
+}
+
+pub fn format_rule<O: EuclidFormattable>(rule: &ast::Rule<O>) -> String {
+    let mut buffer = format!(
+        "{}: {} {{\n",
+        format_rule_name(&rule.name),
+        rule.connector_selection.format_output()
+    );
+
+    for statement in &rule.statements {
+        write_if_statement(&mut buffer, statement, 1);
+    }
+
+    buffer.push('}');
+    buffer
+}
+
+/// Renders the program in its canonical text form. Formatting the result of parsing this text
+/// yields the same text again, which keeps diffs between two versions of a program minimal.
+///
+/// ```text
+/// @{"owner":"payments-team"}
+/// default: [stripe, adyen]
+///
+/// card_rule: [stripe: 60%, adyen("mca_123"): 40%] {
+///     payment_method = card & amount > 1000 @{"source":"dashboard"} {
+///         card_network = (visa, mastercard)
+///     }
+/// }
+/// ```
+pub fn format_program<O: EuclidFormattable>(program: &ast::Program<O>) -> String {
+    let mut buffer = format_metadata(&program.metadata)
+        .map(|metadata| format!("{metadata}\n"))
+        .unwrap_or_default();
+    let _ = writeln!(
+        buffer,
+        "default: {}",
+        program.default_selection.format_output()
+    );
+
+    for rule in &program.rules {
+        let _ = write!(buffer, "\n{}\n", format_rule(rule));
+    }
+
+    buffer
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod tests {
+    use super::*;
+    use crate::frontend::ast::parser;
+
+    #[test]
+    fn test_format_round_trip() {
+        let program_str = r#"
+            default: [stripe, adyen("mca_123")]
+
+            card_rule: [stripe: 60%, adyen: 40%]
+            {
+                payment_method = card & amount >= 1000 & "tier" = "gold" {
+                    card_network = (visa, mastercard)
+                    amount = (> 500, < 2000)
+                }
+                amount /= (10, 20)
+            }
+
+            "rule with spaces": []
+            {
+                billing_country = "India"
+            }
+        "#;
+
+        let program = parser::parse_program::<ast::ConnectorSelection>(program_str)
+            .expect("Program should parse");
+        let formatted = format_program(&program);
+
+        assert_eq!(
+            formatted,
+            r#"default: [stripe, adyen("mca_123")]
+
+card_rule: [stripe: 60%, adyen: 40%] {
+    payment_method = card & amount >= 1000 & "tier" = "gold" {
+        card_network = (visa, mastercard)
+        amount = (> 500, < 2000)
+    }
+    amount /= (10, 20)
+}
+
+"rule with spaces": [] {
+    billing_country = "India"
+}
+"#
+        );
+
+        let reparsed = parser::parse_program::<ast::ConnectorSelection>(&formatted)
+            .expect("Formatted program should parse");
+        assert_eq!(format_program(&reparsed), formatted);
+    }
+
+    #[test]
+    fn test_dummy_output_round_trip() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen", "stripe"]
+            {
+                payment_method = wallet
+            }
+        "#;
+
+        let program =
+            parser::parse_program::<DummyOutput>(program_str).expect("Program should parse");
+        let formatted = format_program(&program);
+        let reparsed = parser::parse_program::<DummyOutput>(&formatted)
+            .expect("Formatted program should parse");
+
+        assert_eq!(format_program(&reparsed), formatted);
+    }
+
+    #[test]
+    fn test_metadata_round_trip() {
+        let program_str = r#"
+            @{"owner": "payments-team", "version": 2}
+            default: [stripe]
+
+            card_rule: [adyen]
+            {
+                payment_method = card @{"source": "dashboard"} & "tier" = "gold" @{"note": "vip"}
+            }
+        "#;
+
+        let program = parser::parse_program::<ast::ConnectorSelection>(program_str)
+            .expect("Program should parse");
+        assert_eq!(
+            program.metadata.get("owner"),
+            Some(&serde_json::json!("payments-team"))
+        );
+
+        let formatted = format_program(&program);
+        assert_eq!(
+            formatted,
+            r#"@{"owner":"payments-team","version":2}
+default: [stripe]
+
+card_rule: [adyen] {
+    payment_method = card @{"source":"dashboard"} & "tier" = "gold" @{"note":"vip"}
+}
+"#
+        );
+
+        let reparsed = parser::parse_program::<ast::ConnectorSelection>(&formatted)
+            .expect("Formatted program should parse");
+        assert_eq!(format_program(&reparsed), formatted);
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let program_str = "default: [stripe]\n\nrule_1: [stripe]\n{\n    amount >> 10\n}\n";
+
+        let err = parser::parse_program::<ast::ConnectorSelection>(program_str)
+            .expect_err("Program should not parse");
+
+        assert_eq!(err.line, 5);
+        assert_eq!(err.column, 13);
+    }
+}
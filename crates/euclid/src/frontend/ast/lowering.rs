@@ -5,6 +5,8 @@
 
 use std::str::FromStr;
 
+use common_utils::types::MinorUnit;
+
 use crate::{
     dssa::types::{AnalysisError, AnalysisErrorType},
     enums::CollectVariants,
@@ -67,19 +69,23 @@ macro_rules! lower_enum {
 /// payment_amount = 17052001
 /// ```notrust
 /// This is for the cases in which there are numerical values involved and they are lowered
-/// accordingly on basis of the supplied key, currently payment_amount is the only key having this
-/// use case
+/// accordingly on basis of the supplied key, an optional closure can be supplied to validate each
+/// of the numbers
 macro_rules! lower_number {
-    ($key:ident, $value:ident, $comp:ident) => {
+    ($key:ident, $value:ident, $comp:ident $(, $validation_closure:expr)?) => {
         match $value {
-            ast::ValueType::Number(num) => Ok(vec![dir::DirValue::$key(types::NumValue {
-                number: num,
-                refinement: $comp.into(),
-            })]),
+            ast::ValueType::Number(num) => {
+                $($validation_closure(&num)?;)?
+                Ok(vec![dir::DirValue::$key(types::NumValue {
+                    number: num,
+                    refinement: $comp.into(),
+                })])
+            }
 
             ast::ValueType::NumberArray(na) => na
                 .into_iter()
                 .map(|num| {
+                    $($validation_closure(&num)?;)?
                     Ok(dir::DirValue::$key(types::NumValue {
                         number: num,
                         refinement: $comp.clone().into(),
@@ -90,6 +96,7 @@ macro_rules! lower_number {
             ast::ValueType::NumberComparisonArray(nca) => nca
                 .into_iter()
                 .map(|nc| {
+                    $($validation_closure(&nc.number)?;)?
                     Ok(dir::DirValue::$key(types::NumValue {
                         number: nc.number,
                         refinement: nc.comparison_type.into(),
@@ -292,6 +299,57 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
         dir::DirKeyKind::TransactionInitiator => lower_enum!(TransactionInitiator, value),
         dir::DirKeyKind::NetworkTokenType => lower_enum!(NetworkTokenType, value),
         dir::DirKeyKind::CardDiscovery => lower_enum!(CardDiscovery, value),
+        dir::DirKeyKind::TimeOfDay => {
+            let validation_closure = |num: &MinorUnit| -> Result<(), AnalysisErrorType> {
+                let time = num.get_amount_as_i64();
+                if (0..2400).contains(&time) && time % 100 < 60 {
+                    Ok(())
+                } else {
+                    Err(AnalysisErrorType::InvalidValue {
+                        key: dir::DirKeyKind::TimeOfDay,
+                        value: time.to_string(),
+                        message: Some("Expected a time of the day as HHMM".to_string()),
+                    })
+                }
+            };
+            lower_number!(TimeOfDay, value, comparison, validation_closure)
+        }
+        dir::DirKeyKind::DayOfWeek => lower_enum!(DayOfWeek, value),
+        dir::DirKeyKind::CalendarDate => {
+            let validation_closure = |num: &MinorUnit| -> Result<(), AnalysisErrorType> {
+                let date = num.get_amount_as_i64();
+                let (year, month, day) = (date / 10000, (date / 100) % 100, date % 100);
+                let is_valid_date = (1000..=9999).contains(&year)
+                    && i32::try_from(year)
+                        .ok()
+                        .zip(u8::try_from(month).ok())
+                        .zip(u8::try_from(day).ok())
+                        .and_then(|((year, month), day)| {
+                            time::Month::try_from(month).ok().and_then(|month| {
+                                time::Date::from_calendar_date(year, month, day).ok()
+                            })
+                        })
+                        .is_some();
+                if is_valid_date {
+                    Ok(())
+                } else {
+                    Err(AnalysisErrorType::InvalidValue {
+                        key: dir::DirKeyKind::CalendarDate,
+                        value: date.to_string(),
+                        message: Some("Expected a date as YYYYMMDD".to_string()),
+                    })
+                }
+            };
+            lower_number!(CalendarDate, value, comparison, validation_closure)
+        }
+        dir::DirKeyKind::CardVelocityCount => lower_number!(CardVelocityCount, value, comparison),
+        dir::DirKeyKind::CardVelocityAmount => lower_number!(CardVelocityAmount, value, comparison),
+        dir::DirKeyKind::EmailVelocityCount => lower_number!(EmailVelocityCount, value, comparison),
+        dir::DirKeyKind::EmailVelocityAmount => {
+            lower_number!(EmailVelocityAmount, value, comparison)
+        }
+        dir::DirKeyKind::IpVelocityCount => lower_number!(IpVelocityCount, value, comparison),
+        dir::DirKeyKind::IpVelocityAmount => lower_number!(IpVelocityAmount, value, comparison),
     }
 }
 
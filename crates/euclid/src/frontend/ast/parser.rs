@@ -3,9 +3,16 @@ use nom::{
     branch, bytes::complete, character::complete as pchar, combinator, error, multi, sequence,
 };
 
-use crate::{frontend::ast, types::DummyOutput};
+use crate::{
+    enums::RoutableConnectors,
+    frontend::ast,
+    types::{DummyOutput, Metadata},
+};
 pub type ParseResult<T, U> = nom::IResult<T, U, error::VerboseError<T>>;
 
+/// Maximum number of characters of the offending input quoted in a parse error
+const ERROR_SNIPPET_LENGTH: usize = 20;
+
 pub enum EuclidError {
     InvalidPercentage(String),
     InvalidConnector(String),
@@ -13,10 +20,88 @@ pub enum EuclidError {
     InvalidNumber(String),
 }
 
+/// A syntax error in a program, pointing at the position where parsing stopped
+#[derive(Debug, Clone, PartialEq, serde::Serialize, thiserror::Error)]
+#[error("{message} at line {line}, column {column}")]
+pub struct ProgramParseError {
+    /// 1-indexed line of the offending input
+    pub line: usize,
+    /// 1-indexed column of the offending input
+    pub column: usize,
+    pub message: String,
+}
+
+impl ProgramParseError {
+    fn new(input: &str, remaining: &str, expected: Option<&str>) -> Self {
+        let remaining = remaining.trim_start();
+        let consumed = &input[..input.len() - remaining.len()];
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed
+            .rsplit('\n')
+            .next()
+            .map_or(0, |current_line| current_line.chars().count())
+            + 1;
+
+        let found = if remaining.is_empty() {
+            "end of input".to_string()
+        } else {
+            let snippet: String = remaining
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .take(ERROR_SNIPPET_LENGTH)
+                .collect();
+            format!("'{snippet}'")
+        };
+
+        let message = match expected {
+            Some(expected) => format!("expected {expected}, found {found}"),
+            None => format!("unexpected {found}"),
+        };
+
+        Self {
+            line,
+            column,
+            message,
+        }
+    }
+
+    fn from_verbose_error(input: &str, err: error::VerboseError<&str>) -> Self {
+        // The error furthest into the input is the most specific one, and the outermost
+        // context recorded at that position names what was expected there
+        let furthest = err
+            .errors
+            .iter()
+            .map(|(remaining, _)| *remaining)
+            .min_by_key(|remaining| remaining.len())
+            .unwrap_or(input);
+
+        let expected = err
+            .errors
+            .iter()
+            .rev()
+            .find_map(|(remaining, kind)| match kind {
+                error::VerboseErrorKind::Context(context) if remaining.len() == furthest.len() => {
+                    Some(context.replace('_', " "))
+                }
+                _ => None,
+            });
+
+        Self::new(input, furthest, expected.as_deref())
+    }
+}
+
 pub trait EuclidParsable: Sized {
     fn parse_output(input: &str) -> ParseResult<&str, Self>;
 }
 
+impl EuclidParsable for ast::ConnectorSelection {
+    fn parse_output(input: &str) -> ParseResult<&str, Self> {
+        connector_selection(input)
+    }
+}
+
 impl EuclidParsable for DummyOutput {
     fn parse_output(input: &str) -> ParseResult<&str, Self> {
         let string_w = sequence::delimited(
@@ -102,12 +187,14 @@ pub fn percentage(input: &str) -> ParseResult<&str, u8> {
         "volume_split_percentage",
         combinator::map_res(
             sequence::terminated(
-                complete::take_while_m_n(1, 2, |c: char| c.is_ascii_digit()),
+                complete::take_while_m_n(1, 3, |c: char| c.is_ascii_digit()),
                 complete::tag("%"),
             ),
             |o: &str| {
                 o.parse::<u8>()
-                    .map_err(|_| EuclidError::InvalidPercentage(o.to_string()))
+                    .ok()
+                    .filter(|percentage| *percentage <= 100)
+                    .ok_or_else(|| EuclidError::InvalidPercentage(o.to_string()))
             },
         ),
     )(input)
@@ -216,7 +303,7 @@ pub fn number_comparison(input: &str) -> ParseResult<&str, ast::NumberComparison
     error::context(
         "number_comparison",
         combinator::map(
-            sequence::pair(operator, num_i64),
+            sequence::pair(operator, skip_ws(num_i64)),
             |tup: (ast::ComparisonType, i64)| ast::NumberComparison {
                 comparison_type: tup.0,
                 number: MinorUnit::new(tup.1),
@@ -289,6 +376,27 @@ pub fn comparison_type(input: &str) -> ParseResult<&str, ast::ComparisonType> {
     )(input)
 }
 
+/// Metadata attached to the program or to a comparison, written as a JSON object following an
+/// `@`, eg: `@{"source": "dashboard"}`
+pub fn metadata(input: &str) -> ParseResult<&str, Metadata> {
+    let (remaining, _) = complete::tag("@")(input)?;
+    let mut objects = serde_json::Deserializer::from_str(remaining)
+        .into_iter::<serde_json::Map<String, serde_json::Value>>();
+
+    match objects.next() {
+        Some(Ok(object)) => Ok((
+            remaining.get(objects.byte_offset()..).unwrap_or_default(),
+            object.into_iter().collect(),
+        )),
+        _ => Err(nom::Err::Failure(error::VerboseError {
+            errors: vec![(
+                remaining,
+                error::VerboseErrorKind::Context("metadata_object"),
+            )],
+        })),
+    }
+}
+
 pub fn comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
     error::context(
         "condition",
@@ -299,12 +407,13 @@ pub fn comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
                 })),
                 skip_ws(comparison_type),
                 skip_ws(value_type),
+                combinator::opt(skip_ws(metadata)),
             )),
-            |tup: (&str, ast::ComparisonType, ast::ValueType)| ast::Comparison {
+            |tup: (&str, ast::ComparisonType, ast::ValueType, Option<Metadata>)| ast::Comparison {
                 lhs: tup.0.to_string(),
                 comparison: tup.1,
                 value: tup.2,
-                metadata: std::collections::HashMap::new(),
+                metadata: tup.3.unwrap_or_default(),
             },
         ),
     )(input)
@@ -318,15 +427,16 @@ pub fn arbitrary_comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
                 skip_ws(string_str),
                 skip_ws(comparison_type),
                 skip_ws(string_str),
+                combinator::opt(skip_ws(metadata)),
             )),
-            |tup: (String, ast::ComparisonType, String)| ast::Comparison {
+            |tup: (String, ast::ComparisonType, String, Option<Metadata>)| ast::Comparison {
                 lhs: "metadata".to_string(),
                 comparison: tup.1,
                 value: ast::ValueType::MetadataVariant(ast::MetadataValue {
                     key: tup.0,
                     value: tup.2,
                 }),
-                metadata: std::collections::HashMap::new(),
+                metadata: tup.3.unwrap_or_default(),
             },
         ),
     )(input)
@@ -335,11 +445,14 @@ pub fn arbitrary_comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
 pub fn comparison_array(input: &str) -> ParseResult<&str, Vec<ast::Comparison>> {
     let many_with_ampersand = error::context(
         "many_with_amp",
-        multi::many0(sequence::preceded(skip_ws(complete::tag("&")), comparison)),
+        multi::many0(sequence::preceded(
+            skip_ws(complete::tag("&")),
+            branch::alt((arbitrary_comparison, comparison)),
+        )),
     );
 
     let full_sequence = sequence::pair(
-        skip_ws(branch::alt((comparison, arbitrary_comparison))),
+        skip_ws(branch::alt((arbitrary_comparison, comparison))),
         many_with_ampersand,
     );
 
@@ -387,16 +500,7 @@ pub fn rule_conditions_array(input: &str) -> ParseResult<&str, Vec<ast::IfStatem
 }
 
 pub fn rule<O: EuclidParsable>(input: &str) -> ParseResult<&str, ast::Rule<O>> {
-    let rule_name = error::context(
-        "rule_name",
-        combinator::map(
-            skip_ws(sequence::pair(
-                complete::take_while1(|c: char| c.is_ascii_alphabetic() || c == '_'),
-                complete::take_while(|c: char| c.is_ascii_alphanumeric() || c == '_'),
-            )),
-            |out: (&str, &str)| out.0.to_string() + out.1,
-        ),
-    );
+    let rule_name = error::context("rule_name", skip_ws(branch::alt((identifier, string_str))));
 
     let connector_selection = error::context(
         "parse_output",
@@ -416,6 +520,83 @@ pub fn rule<O: EuclidParsable>(input: &str) -> ParseResult<&str, ast::Rule<O>> {
     )(input)
 }
 
+/// A connector, optionally pinned to a merchant connector account, eg: `stripe("mca_123")`
+pub fn connector_choice(input: &str) -> ParseResult<&str, ast::RoutableConnectorChoice> {
+    let connector = combinator::map_res(
+        complete::take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_'),
+        |o: &str| {
+            o.parse::<RoutableConnectors>()
+                .map_err(|_| EuclidError::InvalidConnector(o.to_string()))
+        },
+    );
+
+    let merchant_connector_id = combinator::map_res(
+        sequence::delimited(
+            skip_ws(complete::tag("(")),
+            skip_ws(string_str),
+            skip_ws(complete::tag(")")),
+        ),
+        |o: String| {
+            common_utils::id_type::MerchantConnectorAccountId::wrap(o.clone())
+                .map_err(|_| EuclidError::InvalidConnector(o))
+        },
+    );
+
+    error::context(
+        "connector",
+        combinator::map(
+            sequence::pair(skip_ws(connector), combinator::opt(merchant_connector_id)),
+            |tup: (
+                RoutableConnectors,
+                Option<common_utils::id_type::MerchantConnectorAccountId>,
+            )| ast::RoutableConnectorChoice {
+                choice_kind: ast::RoutableChoiceKind::FullStruct,
+                connector: tup.0,
+                merchant_connector_id: tup.1,
+            },
+        ),
+    )(input)
+}
+
+pub fn connector_volume_split(input: &str) -> ParseResult<&str, ast::ConnectorVolumeSplit> {
+    error::context(
+        "volume_split",
+        combinator::map(
+            sequence::separated_pair(
+                connector_choice,
+                skip_ws(complete::tag(":")),
+                skip_ws(percentage),
+            ),
+            |tup: (ast::RoutableConnectorChoice, u8)| ast::ConnectorVolumeSplit {
+                connector: tup.0,
+                split: tup.1,
+            },
+        ),
+    )(input)
+}
+
+/// Either a priority list, eg: `[stripe, adyen]`, or a volume split,
+/// eg: `[stripe: 60%, adyen: 40%]`
+pub fn connector_selection(input: &str) -> ParseResult<&str, ast::ConnectorSelection> {
+    let volume_split = combinator::map(
+        multi::separated_list1(skip_ws(complete::tag(",")), connector_volume_split),
+        ast::ConnectorSelection::VolumeSplit,
+    );
+    let priority = combinator::map(
+        multi::separated_list0(skip_ws(complete::tag(",")), connector_choice),
+        ast::ConnectorSelection::Priority,
+    );
+
+    error::context(
+        "connector_selection",
+        sequence::delimited(
+            skip_ws(complete::tag("[")),
+            branch::alt((volume_split, priority)),
+            skip_ws(complete::tag("]")),
+        ),
+    )(input)
+}
+
 pub fn output<O: EuclidParsable>(input: &str) -> ParseResult<&str, O> {
     O::parse_output(input)
 }
@@ -434,12 +615,39 @@ pub fn program<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&str, as
     error::context(
         "program",
         combinator::map(
-            sequence::pair(default_output, multi::many1(skip_ws(rule::<O>))),
-            |tup: (O, Vec<ast::Rule<O>>)| ast::Program {
-                default_selection: tup.0,
-                rules: tup.1,
-                metadata: std::collections::HashMap::new(),
+            sequence::tuple((
+                combinator::opt(skip_ws(metadata)),
+                default_output,
+                multi::many1(skip_ws(rule::<O>)),
+            )),
+            |tup: (Option<Metadata>, O, Vec<ast::Rule<O>>)| ast::Program {
+                default_selection: tup.1,
+                rules: tup.2,
+                metadata: tup.0.unwrap_or_default(),
             },
         ),
     )(input)
 }
+
+/// Parses a complete program, reporting the line and column at which parsing failed
+pub fn parse_program<O: EuclidParsable + 'static>(
+    input: &str,
+) -> Result<ast::Program<O>, ProgramParseError> {
+    match sequence::terminated(program::<O>, pchar::multispace0)(input) {
+        Ok(("", parsed)) => Ok(parsed),
+        // Rules are parsed until one of them fails, so the remaining input starts with the
+        // rule that could not be parsed. Parse it again on its own to locate the error.
+        Ok((remaining, _)) => match skip_ws(rule::<O>)(remaining) {
+            Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+                Err(ProgramParseError::from_verbose_error(input, err))
+            }
+            Ok(_) | Err(nom::Err::Incomplete(_)) => {
+                Err(ProgramParseError::new(input, remaining, None))
+            }
+        },
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            Err(ProgramParseError::from_verbose_error(input, err))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(ProgramParseError::new(input, "", None)),
+    }
+}
@@ -362,6 +362,69 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "card_discovery")]
     CardDiscovery,
+    #[strum(
+        serialize = "time_of_day",
+        detailed_message = "Local time at which the payment is routed, as HHMM in the profile's routing timezone (e.g. 1430)",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "time_of_day")]
+    TimeOfDay,
+    #[strum(
+        serialize = "day_of_week",
+        detailed_message = "Local day of the week on which the payment is routed, in the profile's routing timezone",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "day_of_week")]
+    DayOfWeek,
+    #[strum(
+        serialize = "date",
+        detailed_message = "Local date on which the payment is routed, as YYYYMMDD in the profile's routing timezone (e.g. 20250131)",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "date")]
+    CalendarDate,
+    #[strum(
+        serialize = "card_velocity_count",
+        detailed_message = "Number of payments made with the same card over the velocity window of the fraud rules, including this one",
+        props(Category = "Velocity")
+    )]
+    #[serde(rename = "card_velocity_count")]
+    CardVelocityCount,
+    #[strum(
+        serialize = "card_velocity_amount",
+        detailed_message = "Total amount of the payments made with the same card over the velocity window of the fraud rules, in the currency of this payment",
+        props(Category = "Velocity")
+    )]
+    #[serde(rename = "card_velocity_amount")]
+    CardVelocityAmount,
+    #[strum(
+        serialize = "email_velocity_count",
+        detailed_message = "Number of payments made with the same customer email over the velocity window of the fraud rules, including this one",
+        props(Category = "Velocity")
+    )]
+    #[serde(rename = "email_velocity_count")]
+    EmailVelocityCount,
+    #[strum(
+        serialize = "email_velocity_amount",
+        detailed_message = "Total amount of the payments made with the same customer email over the velocity window of the fraud rules, in the currency of this payment",
+        props(Category = "Velocity")
+    )]
+    #[serde(rename = "email_velocity_amount")]
+    EmailVelocityAmount,
+    #[strum(
+        serialize = "ip_velocity_count",
+        detailed_message = "Number of payments made from the same IP address over the velocity window of the fraud rules, including this one",
+        props(Category = "Velocity")
+    )]
+    #[serde(rename = "ip_velocity_count")]
+    IpVelocityCount,
+    #[strum(
+        serialize = "ip_velocity_amount",
+        detailed_message = "Total amount of the payments made from the same IP address over the velocity window of the fraud rules, in the currency of this payment",
+        props(Category = "Velocity")
+    )]
+    #[serde(rename = "ip_velocity_amount")]
+    IpVelocityAmount,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -423,6 +486,15 @@ impl DirKeyKind {
             Self::TransactionInitiator => types::DataType::EnumVariant,
             Self::NetworkTokenType => types::DataType::EnumVariant,
             Self::CardDiscovery => types::DataType::EnumVariant,
+            Self::TimeOfDay => types::DataType::Number,
+            Self::DayOfWeek => types::DataType::EnumVariant,
+            Self::CalendarDate => types::DataType::Number,
+            Self::CardVelocityCount => types::DataType::Number,
+            Self::CardVelocityAmount => types::DataType::Number,
+            Self::EmailVelocityCount => types::DataType::Number,
+            Self::EmailVelocityAmount => types::DataType::Number,
+            Self::IpVelocityCount => types::DataType::Number,
+            Self::IpVelocityAmount => types::DataType::Number,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -603,6 +675,15 @@ impl DirKeyKind {
                     .map(DirValue::CardDiscovery)
                     .collect(),
             ),
+            Self::TimeOfDay => None,
+            Self::DayOfWeek => Some(enums::DayOfWeek::iter().map(DirValue::DayOfWeek).collect()),
+            Self::CalendarDate => None,
+            Self::CardVelocityCount => None,
+            Self::CardVelocityAmount => None,
+            Self::EmailVelocityCount => None,
+            Self::EmailVelocityAmount => None,
+            Self::IpVelocityCount => None,
+            Self::IpVelocityAmount => None,
         }
     }
 }
@@ -696,6 +777,24 @@ pub enum DirValue {
     NetworkTokenType(enums::NetworkTokenType),
     #[serde(rename = "card_discovery")]
     CardDiscovery(enums::CardDiscovery),
+    #[serde(rename = "time_of_day")]
+    TimeOfDay(types::NumValue),
+    #[serde(rename = "day_of_week")]
+    DayOfWeek(enums::DayOfWeek),
+    #[serde(rename = "date")]
+    CalendarDate(types::NumValue),
+    #[serde(rename = "card_velocity_count")]
+    CardVelocityCount(types::NumValue),
+    #[serde(rename = "card_velocity_amount")]
+    CardVelocityAmount(types::NumValue),
+    #[serde(rename = "email_velocity_count")]
+    EmailVelocityCount(types::NumValue),
+    #[serde(rename = "email_velocity_amount")]
+    EmailVelocityAmount(types::NumValue),
+    #[serde(rename = "ip_velocity_count")]
+    IpVelocityCount(types::NumValue),
+    #[serde(rename = "ip_velocity_amount")]
+    IpVelocityAmount(types::NumValue),
 }
 
 impl DirValue {
@@ -743,6 +842,15 @@ impl DirValue {
             Self::TransactionInitiator(_) => (DirKeyKind::TransactionInitiator, None),
             Self::NetworkTokenType(_) => (DirKeyKind::NetworkTokenType, None),
             Self::CardDiscovery(_) => (DirKeyKind::CardDiscovery, None),
+            Self::TimeOfDay(_) => (DirKeyKind::TimeOfDay, None),
+            Self::DayOfWeek(_) => (DirKeyKind::DayOfWeek, None),
+            Self::CalendarDate(_) => (DirKeyKind::CalendarDate, None),
+            Self::CardVelocityCount(_) => (DirKeyKind::CardVelocityCount, None),
+            Self::CardVelocityAmount(_) => (DirKeyKind::CardVelocityAmount, None),
+            Self::EmailVelocityCount(_) => (DirKeyKind::EmailVelocityCount, None),
+            Self::EmailVelocityAmount(_) => (DirKeyKind::EmailVelocityAmount, None),
+            Self::IpVelocityCount(_) => (DirKeyKind::IpVelocityCount, None),
+            Self::IpVelocityAmount(_) => (DirKeyKind::IpVelocityAmount, None),
         };
 
         DirKey::new(kind, data)
@@ -791,6 +899,15 @@ impl DirValue {
             Self::TransactionInitiator(_) => None,
             Self::NetworkTokenType(_) => None,
             Self::CardDiscovery(_) => None,
+            Self::TimeOfDay(_) => None,
+            Self::DayOfWeek(_) => None,
+            Self::CalendarDate(_) => None,
+            Self::CardVelocityCount(_) => None,
+            Self::CardVelocityAmount(_) => None,
+            Self::EmailVelocityCount(_) => None,
+            Self::EmailVelocityAmount(_) => None,
+            Self::IpVelocityCount(_) => None,
+            Self::IpVelocityAmount(_) => None,
         }
     }
 
@@ -807,6 +924,14 @@ impl DirValue {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
             Self::AcquirerFraudRate(val) => Some(val.clone()),
+            Self::TimeOfDay(val) => Some(val.clone()),
+            Self::CalendarDate(val) => Some(val.clone()),
+            Self::CardVelocityCount(val) => Some(val.clone()),
+            Self::CardVelocityAmount(val) => Some(val.clone()),
+            Self::EmailVelocityCount(val) => Some(val.clone()),
+            Self::EmailVelocityAmount(val) => Some(val.clone()),
+            Self::IpVelocityCount(val) => Some(val.clone()),
+            Self::IpVelocityAmount(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -849,6 +974,15 @@ impl DirValue {
             (Self::AcquirerFraudRate(r1), Self::AcquirerFraudRate(r2)) => r1 == r2,
             (Self::TransactionInitiator(ti1), Self::TransactionInitiator(ti2)) => ti1 == ti2,
             (Self::NetworkTokenType(ntt1), Self::NetworkTokenType(ntt2)) => ntt1 == ntt2,
+            (Self::TimeOfDay(t1), Self::TimeOfDay(t2)) => t1 == t2,
+            (Self::DayOfWeek(d1), Self::DayOfWeek(d2)) => d1 == d2,
+            (Self::CalendarDate(d1), Self::CalendarDate(d2)) => d1 == d2,
+            (Self::CardVelocityCount(v1), Self::CardVelocityCount(v2)) => v1 == v2,
+            (Self::CardVelocityAmount(v1), Self::CardVelocityAmount(v2)) => v1 == v2,
+            (Self::EmailVelocityCount(v1), Self::EmailVelocityCount(v2)) => v1 == v2,
+            (Self::EmailVelocityAmount(v1), Self::EmailVelocityAmount(v2)) => v1 == v2,
+            (Self::IpVelocityCount(v1), Self::IpVelocityCount(v2)) => v1 == v2,
+            (Self::IpVelocityAmount(v1), Self::IpVelocityAmount(v2)) => v1 == v2,
             _ => false,
         }
     }
@@ -1042,6 +1176,11 @@ mod test {
             dirval!(CaptureMethod = Manual),
             dirval!(BillingCountry = UnitedStatesOfAmerica),
             dirval!(BusinessCountry = France),
+            dirval!(TimeOfDay = 200),
+            dirval!(DayOfWeek = Sunday),
+            dirval!(CalendarDate = 20250131),
+            dirval!(CardVelocityCount = 5),
+            dirval!(IpVelocityAmount = 100000),
         ];
 
         for val in values {
@@ -302,6 +302,15 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::TransactionInitiator(ti) => EuclidValue::TransactionInitiator(ti),
         dir::DirValue::NetworkTokenType(nt) => EuclidValue::PaymentMethodType(nt.into()),
         dir::DirValue::CardDiscovery(cd) => EuclidValue::CardDiscovery(cd),
+        dir::DirValue::TimeOfDay(tod) => EuclidValue::TimeOfDay(tod),
+        dir::DirValue::DayOfWeek(dow) => EuclidValue::DayOfWeek(dow),
+        dir::DirValue::CalendarDate(date) => EuclidValue::CalendarDate(date),
+        dir::DirValue::CardVelocityCount(val) => EuclidValue::CardVelocityCount(val),
+        dir::DirValue::CardVelocityAmount(val) => EuclidValue::CardVelocityAmount(val),
+        dir::DirValue::EmailVelocityCount(val) => EuclidValue::EmailVelocityCount(val),
+        dir::DirValue::EmailVelocityAmount(val) => EuclidValue::EmailVelocityAmount(val),
+        dir::DirValue::IpVelocityCount(val) => EuclidValue::IpVelocityCount(val),
+        dir::DirValue::IpVelocityAmount(val) => EuclidValue::IpVelocityAmount(val),
     })
 }
 
@@ -12,7 +12,7 @@ use crate::{
         ast,
         dir::{
             enums::{
-                CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType,
+                CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType, DayOfWeek,
                 TransactionInitiator,
             },
             DirKeyKind, DirValue, EuclidDirFilter,
@@ -89,6 +89,24 @@ pub enum EuclidKey {
     TransactionInitiator,
     #[strum(serialize = "card_discovery")]
     CardDiscovery,
+    #[strum(serialize = "time_of_day")]
+    TimeOfDay,
+    #[strum(serialize = "day_of_week")]
+    DayOfWeek,
+    #[strum(serialize = "date")]
+    CalendarDate,
+    #[strum(serialize = "card_velocity_count")]
+    CardVelocityCount,
+    #[strum(serialize = "card_velocity_amount")]
+    CardVelocityAmount,
+    #[strum(serialize = "email_velocity_count")]
+    EmailVelocityCount,
+    #[strum(serialize = "email_velocity_amount")]
+    EmailVelocityAmount,
+    #[strum(serialize = "ip_velocity_count")]
+    IpVelocityCount,
+    #[strum(serialize = "ip_velocity_amount")]
+    IpVelocityAmount,
 }
 
 impl EuclidDirFilter for DummyOutput {
@@ -109,6 +127,15 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::PaymentType,
         DirKeyKind::SetupFutureUsage,
         DirKeyKind::TransactionInitiator,
+        DirKeyKind::TimeOfDay,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::CalendarDate,
+        DirKeyKind::CardVelocityCount,
+        DirKeyKind::CardVelocityAmount,
+        DirKeyKind::EmailVelocityCount,
+        DirKeyKind::EmailVelocityAmount,
+        DirKeyKind::IpVelocityCount,
+        DirKeyKind::IpVelocityAmount,
     ];
 }
 impl EuclidAnalysable for DummyOutput {
@@ -182,6 +209,15 @@ impl EuclidKey {
             Self::CustomerDevicePlatform => DataType::EnumVariant,
             Self::TransactionInitiator => DataType::EnumVariant,
             Self::CardDiscovery => DataType::EnumVariant,
+            Self::TimeOfDay => DataType::Number,
+            Self::DayOfWeek => DataType::EnumVariant,
+            Self::CalendarDate => DataType::Number,
+            Self::CardVelocityCount => DataType::Number,
+            Self::CardVelocityAmount => DataType::Number,
+            Self::EmailVelocityCount => DataType::Number,
+            Self::EmailVelocityAmount => DataType::Number,
+            Self::IpVelocityCount => DataType::Number,
+            Self::IpVelocityAmount => DataType::Number,
         }
     }
 }
@@ -309,12 +345,29 @@ pub enum EuclidValue {
     CustomerDevicePlatform(CustomerDevicePlatform),
     TransactionInitiator(TransactionInitiator),
     CardDiscovery(enums::CardDiscovery),
+    TimeOfDay(NumValue),
+    DayOfWeek(DayOfWeek),
+    CalendarDate(NumValue),
+    CardVelocityCount(NumValue),
+    CardVelocityAmount(NumValue),
+    EmailVelocityCount(NumValue),
+    EmailVelocityAmount(NumValue),
+    IpVelocityCount(NumValue),
+    IpVelocityAmount(NumValue),
 }
 
 impl EuclidValue {
     pub fn get_num_value(&self) -> Option<NumValue> {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
+            Self::TimeOfDay(val) => Some(val.clone()),
+            Self::CalendarDate(val) => Some(val.clone()),
+            Self::CardVelocityCount(val) => Some(val.clone()),
+            Self::CardVelocityAmount(val) => Some(val.clone()),
+            Self::EmailVelocityCount(val) => Some(val.clone()),
+            Self::EmailVelocityAmount(val) => Some(val.clone()),
+            Self::IpVelocityCount(val) => Some(val.clone()),
+            Self::IpVelocityAmount(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -349,6 +402,15 @@ impl EuclidValue {
             Self::CustomerDevicePlatform(_) => EuclidKey::CustomerDevicePlatform,
             Self::TransactionInitiator(_) => EuclidKey::TransactionInitiator,
             Self::CardDiscovery(_) => EuclidKey::CardDiscovery,
+            Self::TimeOfDay(_) => EuclidKey::TimeOfDay,
+            Self::DayOfWeek(_) => EuclidKey::DayOfWeek,
+            Self::CalendarDate(_) => EuclidKey::CalendarDate,
+            Self::CardVelocityCount(_) => EuclidKey::CardVelocityCount,
+            Self::CardVelocityAmount(_) => EuclidKey::CardVelocityAmount,
+            Self::EmailVelocityCount(_) => EuclidKey::EmailVelocityCount,
+            Self::EmailVelocityAmount(_) => EuclidKey::EmailVelocityAmount,
+            Self::IpVelocityCount(_) => EuclidKey::IpVelocityCount,
+            Self::IpVelocityAmount(_) => EuclidKey::IpVelocityAmount,
         }
     }
 }
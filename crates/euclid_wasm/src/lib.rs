@@ -11,7 +11,9 @@ use api_models::{
     enums as api_model_enums, routing::ConnectorSelection,
     surcharge_decision_configs::SurchargeDecisionConfigs,
 };
-use common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule;
+use common_types::{
+    fraud_rule_engine::FrmDecisionRule, three_ds_decision_rule_engine::ThreeDSDecisionRule,
+};
 use connector_configs::{
     common_config::{ConnectorApiIntegrationPayload, DashboardRequestPayload},
     connector,
@@ -250,11 +252,16 @@ pub fn get_valid_connectors_for_rule(rule: JsValue) -> JsResult {
     Ok(serde_wasm_bindgen::to_value(&valid_connectors)?)
 }
 
+/// Validates the program, returning the warnings found for rules that are shadowed, overlap
+/// with earlier rules or select connectors which can never accept their payments
 #[wasm_bindgen(js_name = analyzeProgram)]
 pub fn analyze_program(js_program: JsValue) -> JsResult {
     let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
-    analyzer::analyze(program, SEED_DATA.get().map(|sd| &sd.cgraph)).err_to_js()?;
-    Ok(JsValue::NULL)
+    let knowledge_graph = SEED_DATA.get().map(|sd| &sd.cgraph);
+    let dir_program = ast::lowering::lower_program(program.clone()).err_to_js()?;
+    analyzer::analyze(program, knowledge_graph).err_to_js()?;
+    let warnings = analyzer::analyze_warnings(&dir_program, knowledge_graph).err_to_js()?;
+    Ok(serde_wasm_bindgen::to_value(&warnings)?)
 }
 
 #[wasm_bindgen(js_name = runProgram)]
@@ -270,6 +277,23 @@ pub fn run_program(program: JsValue, input: JsValue) -> JsResult {
     Ok(serde_wasm_bindgen::to_value(&res)?)
 }
 
+/// Parses a program written in the routing DSL. Syntax errors are returned as an object with
+/// the `line`, `column` and `message` of the error.
+#[wasm_bindgen(js_name = parseProgram)]
+pub fn parse_program(program_text: &str) -> JsResult {
+    match ast::parser::parse_program::<ConnectorSelection>(program_text) {
+        Ok(program) => Ok(serde_wasm_bindgen::to_value(&program)?),
+        Err(err) => Err(serde_wasm_bindgen::to_value(&err)?),
+    }
+}
+
+/// Renders a program in the canonical text form of the routing DSL
+#[wasm_bindgen(js_name = formatProgram)]
+pub fn format_program(js_program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
+    Ok(JsValue::from_str(&ast::formatter::format_program(&program)))
+}
+
 #[wasm_bindgen(js_name = getAllConnectors)]
 pub fn get_all_connectors() -> JsResult {
     Ok(serde_wasm_bindgen::to_value(RoutableConnectors::VARIANTS)?)
@@ -286,6 +310,13 @@ pub fn get_all_keys() -> JsResult {
         "customer_device_display_size",
         "acquirer_country",
         "acquirer_fraud_rate",
+        // Velocity keys are only populated when evaluating fraud rules
+        "card_velocity_count",
+        "card_velocity_amount",
+        "email_velocity_count",
+        "email_velocity_amount",
+        "ip_velocity_count",
+        "ip_velocity_amount",
     ];
 
     let keys: Vec<&'static str> = dir::DirKeyKind::VARIANTS
@@ -321,6 +352,12 @@ pub fn get_three_ds_decision_rule_keys() -> JsResult {
     Ok(serde_wasm_bindgen::to_value(keys)?)
 }
 
+#[wasm_bindgen(js_name= getFraudRuleKeys)]
+pub fn get_fraud_rule_keys() -> JsResult {
+    let keys = <FrmDecisionRule as EuclidDirFilter>::ALLOWED;
+    Ok(serde_wasm_bindgen::to_value(keys)?)
+}
+
 #[wasm_bindgen(js_name=parseToString)]
 pub fn parser(val: String) -> String {
     ron_parser::my_parse(val)
@@ -367,6 +404,7 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         }
         dir::DirKeyKind::NetworkTokenType => dir_enums::NetworkTokenType::VARIANTS,
         dir::DirKeyKind::CardDiscovery => dir_enums::CardDiscovery::VARIANTS,
+        dir::DirKeyKind::DayOfWeek => dir_enums::DayOfWeek::VARIANTS,
 
         dir::DirKeyKind::PaymentAmount
         | dir::DirKeyKind::Connector
@@ -375,7 +413,15 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         | dir::DirKeyKind::BusinessLabel
         | dir::DirKeyKind::MetaData
         | dir::DirKeyKind::IssuerName
-        | dir::DirKeyKind::AcquirerFraudRate => Err("Key does not have variants".to_string())?,
+        | dir::DirKeyKind::AcquirerFraudRate
+        | dir::DirKeyKind::TimeOfDay
+        | dir::DirKeyKind::CalendarDate
+        | dir::DirKeyKind::CardVelocityCount
+        | dir::DirKeyKind::CardVelocityAmount
+        | dir::DirKeyKind::EmailVelocityCount
+        | dir::DirKeyKind::EmailVelocityAmount
+        | dir::DirKeyKind::IpVelocityCount
+        | dir::DirKeyKind::IpVelocityAmount => Err("Key does not have variants".to_string())?,
     };
 
     Ok(serde_wasm_bindgen::to_value(variants)?)
@@ -566,5 +612,7 @@ pub fn get_valid_webhook_status(key: &str) -> JsResult {
                 .collect();
             Ok(serde_wasm_bindgen::to_value(&statuses)?)
         }
+        // Routing events are not raised on a change in the status of a resource
+        EventClass::Routing => Ok(serde_wasm_bindgen::to_value(&Vec::<String>::new())?),
     }
 }
@@ -21,6 +21,8 @@ use dynamic_routing::{DynamicRoutingClientConfig, RoutingStrategy};
 use health_check_client::HealthCheckClient;
 #[cfg(any(feature = "dynamic_routing", feature = "revenue_recovery"))]
 use hyper_util::client::legacy::connect::HttpConnector;
+#[cfg(feature = "dynamic_routing")]
+use redis_interface::RedisConnectionPool;
 #[cfg(any(feature = "dynamic_routing", feature = "revenue_recovery"))]
 use router_env::logger;
 use router_env::RequestId;
@@ -78,8 +80,12 @@ impl GrpcClientSettings {
     ///
     /// This function will panic if it fails to establish a connection with the gRPC server.
     /// This function will be called at service startup.
+    /// The Redis connection backs the native dynamic routing engine, when it is configured.
     #[allow(clippy::expect_used)]
-    pub async fn get_grpc_client_interface(&self) -> Arc<GrpcClients> {
+    pub async fn get_grpc_client_interface(
+        &self,
+        #[cfg(feature = "dynamic_routing")] redis_conn: Option<Arc<RedisConnectionPool>>,
+    ) -> Arc<GrpcClients> {
         #[cfg(any(feature = "dynamic_routing", feature = "revenue_recovery"))]
         let client =
             hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new())
@@ -90,7 +96,7 @@ impl GrpcClientSettings {
         let dynamic_routing_connection = self
             .dynamic_routing_client
             .clone()
-            .map(|config| config.get_dynamic_routing_connection(client.clone()))
+            .map(|config| config.get_dynamic_routing_connection(client.clone(), redis_conn))
             .transpose()
             .expect("Failed to establish a connection with the Dynamic Routing Server")
             .flatten();
@@ -1,18 +1,22 @@
 /// Module for Contract based routing
 pub mod contract_routing_client;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 
 use common_utils::errors::CustomResult;
+use redis_interface::RedisConnectionPool;
 use router_env::logger;
 /// Elimination Routing Client Interface Implementation
 pub mod elimination_based_client;
+/// Redis backed Success Rate and Elimination Routing Implementation
+pub mod native_client;
 /// Success Routing Client Interface Implementation
 pub mod success_rate_client;
 
-pub use contract_routing_client::ContractScoreCalculatorClient;
-pub use elimination_based_client::EliminationAnalyserClient;
-pub use success_rate_client::SuccessRateCalculatorClient;
+pub use contract_routing_client::{ContractBasedDynamicRouting, ContractScoreCalculatorClient};
+pub use elimination_based_client::{EliminationAnalyserClient, EliminationBasedRouting};
+pub use native_client::{NativeDynamicRoutingClient, NativeDynamicRoutingConfig};
+pub use success_rate_client::{SuccessBasedDynamicRouting, SuccessRateCalculatorClient};
 
 use super::Client;
 /// Result type for Dynamic Routing
@@ -40,17 +44,20 @@ pub enum DynamicRoutingError {
     /// Error from Dynamic Routing Server while perfrming elimination
     #[error("Error from Dynamic Routing Server while perfrming elimination : {0}")]
     EliminationRateRoutingFailure(String),
+    /// Error from the Redis store backing the native dynamic routing engine
+    #[error("Error from the native dynamic routing store : {0}")]
+    NativeRoutingStoreFailure(String),
 }
 
 /// Type that consists of all the services provided by the client
 #[derive(Debug, Clone)]
 pub struct RoutingStrategy {
     /// success rate service for Dynamic Routing
-    pub success_rate_client: SuccessRateCalculatorClient<Client>,
-    /// contract based routing service for Dynamic Routing
-    pub contract_based_client: ContractScoreCalculatorClient<Client>,
+    pub success_rate_client: Box<dyn SuccessBasedDynamicRouting>,
+    /// contract based routing service for Dynamic Routing, only offered by the gRPC service
+    pub contract_based_client: Option<Box<dyn ContractBasedDynamicRouting>>,
     /// elimination service for Dynamic Routing
-    pub elimination_based_client: EliminationAnalyserClient<Client>,
+    pub elimination_based_client: Box<dyn EliminationBasedRouting>,
 }
 
 /// Contains the Dynamic Routing Client Config
@@ -66,6 +73,11 @@ pub enum DynamicRoutingClientConfig {
         /// Service name
         service: String,
     },
+    /// If success rate and elimination routing should be computed in Redis by the application
+    Native {
+        /// Configs for the native engine
+        native: NativeDynamicRoutingConfig,
+    },
     #[default]
     /// If the dynamic routing client config has been disabled
     Disabled,
@@ -76,6 +88,7 @@ impl DynamicRoutingClientConfig {
     pub fn get_dynamic_routing_connection(
         self,
         client: Client,
+        redis_conn: Option<Arc<RedisConnectionPool>>,
     ) -> Result<Option<RoutingStrategy>, Box<dyn std::error::Error>> {
         match self {
             Self::Enabled { host, port, .. } => {
@@ -89,9 +102,21 @@ impl DynamicRoutingClientConfig {
                 );
 
                 Ok(Some(RoutingStrategy {
-                    success_rate_client,
-                    contract_based_client,
-                    elimination_based_client,
+                    success_rate_client: Box::new(success_rate_client),
+                    contract_based_client: Some(Box::new(contract_based_client)),
+                    elimination_based_client: Box::new(elimination_based_client),
+                }))
+            }
+            Self::Native { native } => {
+                let redis_conn =
+                    redis_conn.ok_or("Redis connection is required for native dynamic routing")?;
+                let native_client = NativeDynamicRoutingClient::new(redis_conn, native);
+                logger::info!("Native dynamic routing engine initialized");
+
+                Ok(Some(RoutingStrategy {
+                    success_rate_client: Box::new(native_client.clone()),
+                    contract_based_client: None,
+                    elimination_based_client: Box::new(native_client),
                 }))
             }
             Self::Disabled => Ok(None),
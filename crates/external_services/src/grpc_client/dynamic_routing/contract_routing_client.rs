@@ -31,7 +31,7 @@ pub use tonic::Code;
 use super::{Client, DynamicRoutingError, DynamicRoutingResult};
 /// The trait ContractBasedDynamicRouting would have the functions required to support the calculation and updation window
 #[async_trait::async_trait]
-pub trait ContractBasedDynamicRouting: dyn_clone::DynClone + Send + Sync {
+pub trait ContractBasedDynamicRouting: dyn_clone::DynClone + Send + Sync + std::fmt::Debug {
     /// To calculate the contract scores for the list of chosen connectors
     async fn calculate_contract_score(
         &self,
@@ -59,6 +59,8 @@ pub trait ContractBasedDynamicRouting: dyn_clone::DynClone + Send + Sync {
     ) -> DynamicRoutingResult<InvalidateContractResponse>;
 }
 
+dyn_clone::clone_trait_object!(ContractBasedDynamicRouting);
+
 #[async_trait::async_trait]
 impl ContractBasedDynamicRouting for ContractScoreCalculatorClient<Client> {
     async fn calculate_contract_score(
@@ -26,7 +26,7 @@ use crate::grpc_client::{self, GrpcHeaders};
 
 /// The trait Elimination Based Routing would have the functions required to support performance, calculation and invalidation bucket
 #[async_trait::async_trait]
-pub trait EliminationBasedRouting: dyn_clone::DynClone + Send + Sync {
+pub trait EliminationBasedRouting: dyn_clone::DynClone + Send + Sync + std::fmt::Debug {
     /// To perform the elimination based routing for the list of connectors
     async fn perform_elimination_routing(
         &self,
@@ -53,6 +53,8 @@ pub trait EliminationBasedRouting: dyn_clone::DynClone + Send + Sync {
     ) -> DynamicRoutingResult<InvalidateBucketResponse>;
 }
 
+dyn_clone::clone_trait_object!(EliminationBasedRouting);
+
 #[async_trait::async_trait]
 impl EliminationBasedRouting for EliminationAnalyserClient<Client> {
     #[instrument(skip_all)]
@@ -0,0 +1,931 @@
+use std::{collections::HashMap, sync::Arc};
+
+use api_models::routing::{
+    EliminationAnalyserConfig as EliminationConfig, EliminationRoutingConfig,
+    RoutableConnectorChoice, RoutableConnectorChoiceWithBucketName,
+    RoutableConnectorChoiceWithStatus, SuccessBasedRoutingConfig, SuccessBasedRoutingConfigBody,
+    SuccessRateSpecificityLevel,
+};
+use common_utils::{date_time, ext_traits::OptionExt, transformers::ForeignTryFrom};
+use error_stack::ResultExt;
+use rand::{seq::SliceRandom, Rng};
+use redis_interface::{RedisConnectionPool, RedisKey};
+use router_env::{instrument, logger, tracing};
+
+use super::{
+    elimination_based_client::{
+        elimination_rate::{
+            invalidate_bucket_response::InvalidationStatus as BucketInvalidationStatus,
+            update_elimination_bucket_response::UpdationStatus as BucketUpdationStatus,
+            BucketInformation, EliminationInformation,
+            LabelWithStatus as EliminationLabelWithStatus,
+        },
+        EliminationBasedRouting, EliminationBucketConfig, EliminationResponse,
+        InvalidateBucketResponse, UpdateEliminationBucketResponse,
+    },
+    success_rate_client::{
+        success_rate::{
+            invalidate_windows_response::InvalidationStatus as WindowInvalidationStatus,
+            update_success_rate_window_response::UpdationStatus as WindowUpdationStatus,
+            LabelWithScore, RoutingApproach,
+        },
+        CalGlobalSuccessRateResponse, CalSuccessRateResponse, InvalidateWindowsResponse,
+        SuccessBasedDynamicRouting, UpdateSuccessRateWindowResponse,
+    },
+    DynamicRoutingError, DynamicRoutingResult,
+};
+use crate::grpc_client::GrpcHeaders;
+
+/// Time to live of a success rate window when no block duration is configured
+const DEFAULT_WINDOW_TTL_IN_SECS: i64 = 7 * 24 * 60 * 60;
+
+const SUCCESS_RATE_ENGINE: &str = "success_rate";
+const ELIMINATION_ENGINE: &str = "elimination";
+const GLOBAL_SCOPE: &str = "global";
+
+/// Field of a success rate window holding the identifier of the block being filled
+const CURRENT_BLOCK_FIELD: &str = "current";
+
+/// Records an outcome in a success rate window.
+///
+/// A window is a hash of blocks, each holding the start time, the total and the successful
+/// outcomes recorded in it. The current block is closed once it holds `max_total_count`
+/// outcomes or once `duration_in_secs` have elapsed since it was opened, whichever is first, and
+/// the block which falls out of the last `max_aggregates_size` blocks is dropped.
+///
+/// KEYS[1]: window, ARGV: now, outcome (1 or 0), max_total_count, duration_in_secs (0 when
+/// blocks are not time bound), max_aggregates_size, ttl
+const UPDATE_SUCCESS_RATE_WINDOW_SCRIPT: &str = r#"
+local key = KEYS[1]
+local now = tonumber(ARGV[1])
+local max_total_count = tonumber(ARGV[3])
+local duration = tonumber(ARGV[4])
+local max_aggregates_size = tonumber(ARGV[5])
+
+local current = tonumber(redis.call('HGET', key, 'current'))
+if current == nil then
+    current = 0
+    redis.call('HSET', key, 'current', current, current .. ':start', now)
+else
+    local start = tonumber(redis.call('HGET', key, current .. ':start')) or now
+    local total = tonumber(redis.call('HGET', key, current .. ':total')) or 0
+    if total >= max_total_count or (duration > 0 and now - start >= duration) then
+        local dropped = current + 1 - max_aggregates_size
+        if dropped >= 0 then
+            redis.call('HDEL', key, dropped .. ':start', dropped .. ':total', dropped .. ':success')
+        end
+        current = current + 1
+        redis.call('HSET', key, 'current', current, current .. ':start', now)
+    end
+end
+
+redis.call('HINCRBY', key, current .. ':total', 1)
+redis.call('HINCRBY', key, current .. ':success', tonumber(ARGV[2]))
+redis.call('EXPIRE', key, tonumber(ARGV[6]))
+return current
+"#;
+
+/// Adds a failure to an elimination bucket.
+///
+/// Every bucket name of a label is a leaky bucket, whose level drops by one every
+/// `bucket_leak_interval_in_secs` and which cannot hold more than `bucket_size` failures.
+///
+/// KEYS[1]: buckets of the label, ARGV: bucket name, now, bucket_leak_interval_in_secs,
+/// bucket_size, ttl
+const UPDATE_ELIMINATION_BUCKET_SCRIPT: &str = r#"
+local key = KEYS[1]
+local bucket_name = ARGV[1]
+local now = tonumber(ARGV[2])
+local leak_interval = tonumber(ARGV[3])
+
+local level = tonumber(redis.call('HGET', key, bucket_name .. ':level')) or 0
+local leaked_at = tonumber(redis.call('HGET', key, bucket_name .. ':leaked_at')) or now
+local leaked = math.floor((now - leaked_at) / leak_interval)
+if leaked > 0 then
+    level = math.max(level - leaked, 0)
+    leaked_at = leaked_at + leaked * leak_interval
+end
+if level == 0 then
+    leaked_at = now
+end
+level = math.min(level + 1, tonumber(ARGV[4]))
+
+redis.call('HSET', key, bucket_name .. ':level', level, bucket_name .. ':leaked_at', leaked_at)
+redis.call('EXPIRE', key, tonumber(ARGV[5]))
+return level
+"#;
+
+/// Configs for the Redis backed dynamic routing engine
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct NativeDynamicRoutingConfig {
+    /// Prefix for all the keys written by the engine
+    pub key_prefix: String,
+}
+
+impl Default for NativeDynamicRoutingConfig {
+    fn default() -> Self {
+        Self {
+            key_prefix: "dynamic_routing".to_string(),
+        }
+    }
+}
+
+/// Success rate and elimination routing engine which keeps its windows in Redis, used when
+/// no external dynamic routing service is deployed.
+///
+/// Success rate windows are kept per label as the last `max_aggregates_size` blocks of outcomes,
+/// a block spanning `duration_in_mins` or `max_total_count` outcomes. Elimination buckets are
+/// kept per label as leaky buckets of `bucket_size` failures, leaking one failure every
+/// `bucket_leak_interval_in_secs`. Invalidation bumps a version which is part of every key, so
+/// that stale windows are dropped once their TTL elapses.
+#[derive(Clone)]
+pub struct NativeDynamicRoutingClient {
+    redis_conn: Arc<RedisConnectionPool>,
+    config: NativeDynamicRoutingConfig,
+}
+
+impl std::fmt::Debug for NativeDynamicRoutingClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NativeDynamicRoutingClient")
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl NativeDynamicRoutingClient {
+    /// Construct the client from a Redis connection pool
+    pub fn new(redis_conn: Arc<RedisConnectionPool>, config: NativeDynamicRoutingConfig) -> Self {
+        Self { redis_conn, config }
+    }
+
+    fn versions_key(&self, tenant_id: &str, id: &str) -> RedisKey {
+        format!("{}:{tenant_id}:{id}:versions", self.config.key_prefix).into()
+    }
+
+    fn window_key(
+        &self,
+        tenant_id: &str,
+        engine: &str,
+        scope: &str,
+        params: &str,
+        label: &str,
+    ) -> String {
+        format!(
+            "{}:{tenant_id}:{engine}:{scope}:{params}:{label}",
+            self.config.key_prefix
+        )
+    }
+
+    fn entity_scope(id: &str, version: i64) -> String {
+        format!("{id}:v{version}")
+    }
+
+    async fn get_version(
+        &self,
+        tenant_id: &str,
+        id: &str,
+        engine: &str,
+    ) -> DynamicRoutingResult<i64> {
+        self.redis_conn
+            .get_hash_field::<Option<i64>>(&self.versions_key(tenant_id, id), engine)
+            .await
+            .map(|version| version.unwrap_or_default())
+            .change_context(DynamicRoutingError::NativeRoutingStoreFailure(
+                "Failed to fetch the window version".to_string(),
+            ))
+    }
+
+    async fn bump_version(
+        &self,
+        tenant_id: &str,
+        id: &str,
+        engine: &str,
+    ) -> DynamicRoutingResult<()> {
+        self.redis_conn
+            .increment_fields_in_hash(&self.versions_key(tenant_id, id), &[(engine, 1)])
+            .await
+            .map(|_| ())
+            .change_context(DynamicRoutingError::NativeRoutingStoreFailure(
+                "Failed to bump the window version".to_string(),
+            ))
+    }
+
+    /// Record an outcome in a success rate window
+    async fn push_outcome(
+        &self,
+        key: RedisKey,
+        status: bool,
+        window_config: &SuccessRateWindowConfig,
+    ) -> DynamicRoutingResult<()> {
+        let args = vec![
+            date_time::now_unix_timestamp().to_string(),
+            u8::from(status).to_string(),
+            window_config.max_total_count.to_string(),
+            window_config.duration_in_secs().to_string(),
+            window_config.max_aggregates_size.to_string(),
+            window_config.ttl().to_string(),
+        ];
+
+        self.redis_conn
+            .evaluate_redis_script::<_, i64>(
+                UPDATE_SUCCESS_RATE_WINDOW_SCRIPT,
+                vec![key.tenant_aware_key(&self.redis_conn)],
+                args,
+            )
+            .await
+            .map(|_| ())
+            .change_context(DynamicRoutingError::NativeRoutingStoreFailure(
+                "Failed to update the success rate window".to_string(),
+            ))
+    }
+
+    /// Score a success rate window, falling back to the default rate until enough aggregates exist
+    async fn score_window(
+        &self,
+        key: RedisKey,
+        window_config: &SuccessRateWindowConfig,
+    ) -> DynamicRoutingResult<f64> {
+        let window = self
+            .redis_conn
+            .get_hash_fields::<HashMap<String, i64>>(&key)
+            .await
+            .change_context(DynamicRoutingError::NativeRoutingStoreFailure(
+                "Failed to fetch the success rate window".to_string(),
+            ))?;
+
+        Ok(get_success_rate(
+            &window,
+            window_config,
+            date_time::now_unix_timestamp(),
+        ))
+    }
+
+    async fn record_bucket_failure(
+        &self,
+        key: RedisKey,
+        bucket_name: &str,
+        bucket_config: &EliminationBucketConfig,
+    ) -> DynamicRoutingResult<()> {
+        // A bucket is drained completely once it has leaked for `bucket_size` intervals
+        let ttl = bucket_config
+            .bucket_size
+            .saturating_mul(bucket_config.bucket_leak_interval_in_secs.max(1));
+        let args = vec![
+            bucket_name.to_string(),
+            date_time::now_unix_timestamp().to_string(),
+            bucket_config
+                .bucket_leak_interval_in_secs
+                .max(1)
+                .to_string(),
+            bucket_config.bucket_size.to_string(),
+            ttl.max(1).to_string(),
+        ];
+
+        self.redis_conn
+            .evaluate_redis_script::<_, i64>(
+                UPDATE_ELIMINATION_BUCKET_SCRIPT,
+                vec![key.tenant_aware_key(&self.redis_conn)],
+                args,
+            )
+            .await
+            .map(|_| ())
+            .change_context(DynamicRoutingError::NativeRoutingStoreFailure(
+                "Failed to update the elimination bucket".to_string(),
+            ))
+    }
+
+    async fn get_bucket_information(
+        &self,
+        key: RedisKey,
+        bucket_config: &EliminationBucketConfig,
+    ) -> DynamicRoutingResult<BucketInformation> {
+        let buckets = self
+            .redis_conn
+            .get_hash_fields::<HashMap<String, i64>>(&key)
+            .await
+            .change_context(DynamicRoutingError::NativeRoutingStoreFailure(
+                "Failed to fetch the elimination buckets".to_string(),
+            ))?;
+
+        let bucket_name =
+            get_full_buckets(&buckets, bucket_config, date_time::now_unix_timestamp());
+
+        Ok(BucketInformation {
+            is_eliminated: !bucket_name.is_empty(),
+            bucket_name,
+        })
+    }
+}
+
+/// Resolved success rate window configuration
+struct SuccessRateWindowConfig {
+    min_aggregates_size: usize,
+    max_aggregates_size: usize,
+    max_total_count: usize,
+    duration_in_mins: Option<u64>,
+    default_success_rate: f64,
+}
+
+impl SuccessRateWindowConfig {
+    /// Time after which a block is closed, zero when blocks are only closed on their count
+    fn duration_in_secs(&self) -> u64 {
+        self.duration_in_mins
+            .map(|duration| duration.saturating_mul(60))
+            .unwrap_or_default()
+    }
+
+    fn ttl(&self) -> i64 {
+        self.duration_in_mins
+            .and_then(|duration| {
+                u64::try_from(self.max_aggregates_size)
+                    .ok()
+                    .map(|aggregates| duration.saturating_mul(aggregates).saturating_mul(60))
+            })
+            .and_then(|ttl| i64::try_from(ttl).ok())
+            .unwrap_or(DEFAULT_WINDOW_TTL_IN_SECS)
+    }
+}
+
+impl ForeignTryFrom<SuccessBasedRoutingConfigBody> for SuccessRateWindowConfig {
+    type Error = error_stack::Report<DynamicRoutingError>;
+    fn foreign_try_from(config: SuccessBasedRoutingConfigBody) -> Result<Self, Self::Error> {
+        let current_block_threshold = config.current_block_threshold;
+        Ok(Self {
+            min_aggregates_size: required_field(config.min_aggregates_size, "min_aggregates_size")
+                .map(to_usize)?,
+            max_aggregates_size: required_field(config.max_aggregates_size, "max_aggregates_size")
+                .map(to_usize)?,
+            max_total_count: current_block_threshold
+                .as_ref()
+                .and_then(|threshold| threshold.max_total_count)
+                .map(to_usize)
+                .unwrap_or(1)
+                .max(1),
+            duration_in_mins: current_block_threshold
+                .and_then(|threshold| threshold.duration_in_mins),
+            default_success_rate: required_field(
+                config.default_success_rate,
+                "default_success_rate",
+            )?,
+        })
+    }
+}
+
+fn required_field<T>(value: Option<T>, field: &'static str) -> DynamicRoutingResult<T> {
+    value
+        .get_required_value(field)
+        .change_context(DynamicRoutingError::MissingRequiredField {
+            field: field.to_string(),
+        })
+}
+
+fn to_usize<T: TryInto<usize>>(value: T) -> usize {
+    value.try_into().unwrap_or(usize::MAX)
+}
+
+/// Success rate over the blocks of a window which have not aged out of it, the default success
+/// rate while fewer than `min_aggregates_size` blocks hold outcomes
+#[allow(clippy::as_conversions)]
+fn get_success_rate(
+    window: &HashMap<String, i64>,
+    window_config: &SuccessRateWindowConfig,
+    now: i64,
+) -> f64 {
+    let Some(current) = window.get(CURRENT_BLOCK_FIELD).copied() else {
+        return window_config.default_success_rate;
+    };
+    let oldest = current
+        .saturating_add(1)
+        .saturating_sub(i64::try_from(window_config.max_aggregates_size).unwrap_or(i64::MAX))
+        .max(0);
+    // Blocks of windows which received no outcomes for a while are only time bound when the
+    // blocks themselves are
+    let window_duration = i64::try_from(window_config.duration_in_secs())
+        .unwrap_or(i64::MAX)
+        .saturating_mul(i64::try_from(window_config.max_aggregates_size).unwrap_or(i64::MAX));
+
+    let (mut aggregates, mut successes, mut total) = (0, 0, 0);
+    for block in oldest..=current {
+        let block_total = window.get(&format!("{block}:total")).copied().unwrap_or(0);
+        let block_start = window
+            .get(&format!("{block}:start"))
+            .copied()
+            .unwrap_or(now);
+        if block_total == 0 || (window_duration > 0 && now - block_start >= window_duration) {
+            continue;
+        }
+        aggregates += 1;
+        total += block_total;
+        successes += window
+            .get(&format!("{block}:success"))
+            .copied()
+            .unwrap_or(0);
+    }
+
+    if total == 0 || aggregates < window_config.min_aggregates_size {
+        return window_config.default_success_rate;
+    }
+
+    successes as f64 * 100.0 / total as f64
+}
+
+/// Level of a leaky bucket, after leaking one failure per elapsed interval since `leaked_at`
+fn get_bucket_level(
+    level: i64,
+    leaked_at: i64,
+    now: i64,
+    bucket_leak_interval_in_secs: u64,
+) -> i64 {
+    let leak_interval = i64::try_from(bucket_leak_interval_in_secs.max(1)).unwrap_or(i64::MAX);
+    let leaked = now.saturating_sub(leaked_at).max(0) / leak_interval;
+    level.saturating_sub(leaked).max(0)
+}
+
+/// Names of the buckets of a label which are full, that is which hold `bucket_size` failures
+fn get_full_buckets(
+    buckets: &HashMap<String, i64>,
+    bucket_config: &EliminationBucketConfig,
+    now: i64,
+) -> Vec<String> {
+    let bucket_size = i64::try_from(bucket_config.bucket_size).unwrap_or(i64::MAX);
+    let mut full_buckets = buckets
+        .iter()
+        .filter_map(|(field, level)| {
+            let bucket_name = field.strip_suffix(":level")?;
+            let leaked_at = buckets
+                .get(&format!("{bucket_name}:leaked_at"))
+                .copied()
+                .unwrap_or(now);
+            (get_bucket_level(
+                *level,
+                leaked_at,
+                now,
+                bucket_config.bucket_leak_interval_in_secs,
+            ) >= bucket_size)
+                .then(|| bucket_name.to_string())
+        })
+        .collect::<Vec<_>>();
+    full_buckets.sort();
+    full_buckets
+}
+
+/// Sort the labels by score, with exploration shuffling the order and exploitation only
+/// shuffling ties when asked to
+fn rank_labels(
+    mut labels_with_score: Vec<LabelWithScore>,
+    exploration_percent: Option<f64>,
+    shuffle_on_tie_during_exploitation: bool,
+) -> CalSuccessRateResponse {
+    let mut rng = rand::thread_rng();
+
+    let routing_approach = if rng.gen_range(0.0..100.0) < exploration_percent.unwrap_or_default() {
+        labels_with_score.shuffle(&mut rng);
+        RoutingApproach::Exploration
+    } else {
+        if shuffle_on_tie_during_exploitation {
+            labels_with_score.shuffle(&mut rng);
+        }
+        sort_by_score(&mut labels_with_score);
+        RoutingApproach::Exploitation
+    };
+
+    CalSuccessRateResponse {
+        labels_with_score,
+        routing_approach: routing_approach.into(),
+    }
+}
+
+fn sort_by_score(labels_with_score: &mut [LabelWithScore]) {
+    labels_with_score.sort_by(|a, b| b.score.total_cmp(&a.score));
+}
+
+fn get_window_config(
+    success_rate_based_config: SuccessBasedRoutingConfig,
+) -> DynamicRoutingResult<SuccessBasedRoutingConfigBody> {
+    required_field(success_rate_based_config.config, "config")
+}
+
+fn get_bucket_config(
+    configs: Option<EliminationConfig>,
+) -> DynamicRoutingResult<EliminationBucketConfig> {
+    configs
+        .or(EliminationRoutingConfig::default().elimination_analyser_config)
+        .map(EliminationBucketConfig::foreign_try_from)
+        .transpose()?
+        .get_required_value("elimination_analyser_config")
+        .change_context(DynamicRoutingError::MissingRequiredField {
+            field: "elimination_analyser_config".to_string(),
+        })
+}
+
+#[async_trait::async_trait]
+impl SuccessBasedDynamicRouting for NativeDynamicRoutingClient {
+    #[instrument(skip_all)]
+    async fn calculate_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        params: String,
+        label_input: Vec<RoutableConnectorChoice>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<CalSuccessRateResponse> {
+        let config_body = get_window_config(success_rate_based_config)?;
+        let specificity_level = config_body.specificity_level.clone();
+        let exploration_percent = config_body.exploration_percent;
+        let shuffle_on_tie = config_body
+            .shuffle_on_tie_during_exploitation
+            .unwrap_or(false);
+        let window_config = SuccessRateWindowConfig::foreign_try_from(config_body)?;
+
+        let version = self
+            .get_version(&headers.tenant_id, &id, SUCCESS_RATE_ENGINE)
+            .await?;
+        let entity_scope = Self::entity_scope(&id, version);
+
+        let mut labels_with_score = Vec::with_capacity(label_input.len());
+        for conn_choice in label_input {
+            let label = conn_choice.to_string();
+            let key = match specificity_level {
+                SuccessRateSpecificityLevel::Merchant => self.window_key(
+                    &headers.tenant_id,
+                    SUCCESS_RATE_ENGINE,
+                    &entity_scope,
+                    &params,
+                    &label,
+                ),
+                SuccessRateSpecificityLevel::Global => self.window_key(
+                    &headers.tenant_id,
+                    SUCCESS_RATE_ENGINE,
+                    GLOBAL_SCOPE,
+                    &params,
+                    &conn_choice.connector.to_string(),
+                ),
+            };
+            let score = self.score_window(key.into(), &window_config).await?;
+            labels_with_score.push(LabelWithScore { score, label });
+        }
+
+        let response = rank_labels(labels_with_score, exploration_percent, shuffle_on_tie);
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        params: String,
+        label_input: Vec<RoutableConnectorChoiceWithStatus>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<UpdateSuccessRateWindowResponse> {
+        let window_config = SuccessRateWindowConfig::foreign_try_from(get_window_config(
+            success_rate_based_config,
+        )?)?;
+
+        let version = self
+            .get_version(&headers.tenant_id, &id, SUCCESS_RATE_ENGINE)
+            .await?;
+        let entity_scope = Self::entity_scope(&id, version);
+
+        for conn_choice in label_input {
+            let entity_key = self.window_key(
+                &headers.tenant_id,
+                SUCCESS_RATE_ENGINE,
+                &entity_scope,
+                &params,
+                &conn_choice.routable_connector_choice.to_string(),
+            );
+            let global_key = self.window_key(
+                &headers.tenant_id,
+                SUCCESS_RATE_ENGINE,
+                GLOBAL_SCOPE,
+                &params,
+                &conn_choice.routable_connector_choice.connector.to_string(),
+            );
+
+            self.push_outcome(entity_key.into(), conn_choice.status, &window_config)
+                .await?;
+            self.push_outcome(global_key.into(), conn_choice.status, &window_config)
+                .await?;
+        }
+
+        let response = UpdateSuccessRateWindowResponse {
+            status: WindowUpdationStatus::WindowUpdationSucceeded.into(),
+        };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn invalidate_success_rate_routing_keys(
+        &self,
+        id: String,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<InvalidateWindowsResponse> {
+        self.bump_version(&headers.tenant_id, &id, SUCCESS_RATE_ENGINE)
+            .await?;
+
+        let response = InvalidateWindowsResponse {
+            status: WindowInvalidationStatus::WindowInvalidationSucceeded.into(),
+        };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn calculate_entity_and_global_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        params: String,
+        label_input: Vec<RoutableConnectorChoice>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<CalGlobalSuccessRateResponse> {
+        let window_config = SuccessRateWindowConfig::foreign_try_from(get_window_config(
+            success_rate_based_config,
+        )?)?;
+
+        let version = self
+            .get_version(&headers.tenant_id, &id, SUCCESS_RATE_ENGINE)
+            .await?;
+        let entity_scope = Self::entity_scope(&id, version);
+
+        let mut entity_scores_with_labels = Vec::with_capacity(label_input.len());
+        let mut global_scores_with_labels = Vec::with_capacity(label_input.len());
+        for conn_choice in label_input {
+            let label = conn_choice.to_string();
+            let entity_key = self.window_key(
+                &headers.tenant_id,
+                SUCCESS_RATE_ENGINE,
+                &entity_scope,
+                &params,
+                &label,
+            );
+            let score = self.score_window(entity_key.into(), &window_config).await?;
+            entity_scores_with_labels.push(LabelWithScore { score, label });
+
+            let global_label = conn_choice.connector.to_string();
+            if global_scores_with_labels
+                .iter()
+                .any(|label_with_score| label_with_score.label == global_label)
+            {
+                continue;
+            }
+            let global_key = self.window_key(
+                &headers.tenant_id,
+                SUCCESS_RATE_ENGINE,
+                GLOBAL_SCOPE,
+                &params,
+                &global_label,
+            );
+            let score = self.score_window(global_key.into(), &window_config).await?;
+            global_scores_with_labels.push(LabelWithScore {
+                score,
+                label: global_label,
+            });
+        }
+
+        sort_by_score(&mut entity_scores_with_labels);
+        sort_by_score(&mut global_scores_with_labels);
+
+        let response = CalGlobalSuccessRateResponse {
+            entity_scores_with_labels,
+            global_scores_with_labels,
+        };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+}
+
+#[async_trait::async_trait]
+impl EliminationBasedRouting for NativeDynamicRoutingClient {
+    #[instrument(skip_all)]
+    async fn perform_elimination_routing(
+        &self,
+        id: String,
+        params: String,
+        label_input: Vec<RoutableConnectorChoice>,
+        configs: Option<EliminationConfig>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<EliminationResponse> {
+        let bucket_config = get_bucket_config(configs)?;
+
+        let version = self
+            .get_version(&headers.tenant_id, &id, ELIMINATION_ENGINE)
+            .await?;
+        let entity_scope = Self::entity_scope(&id, version);
+
+        let mut labels_with_status = Vec::with_capacity(label_input.len());
+        for conn_choice in label_input {
+            let label = conn_choice.to_string();
+            let entity_key = self.window_key(
+                &headers.tenant_id,
+                ELIMINATION_ENGINE,
+                &entity_scope,
+                &params,
+                &label,
+            );
+            let global_key = self.window_key(
+                &headers.tenant_id,
+                ELIMINATION_ENGINE,
+                GLOBAL_SCOPE,
+                &params,
+                &conn_choice.connector.to_string(),
+            );
+
+            let entity = self
+                .get_bucket_information(entity_key.into(), &bucket_config)
+                .await?;
+            let global = self
+                .get_bucket_information(global_key.into(), &bucket_config)
+                .await?;
+
+            labels_with_status.push(EliminationLabelWithStatus {
+                label,
+                elimination_information: Some(EliminationInformation {
+                    entity: Some(entity),
+                    global: Some(global),
+                }),
+            });
+        }
+
+        let response = EliminationResponse { labels_with_status };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_elimination_bucket_config(
+        &self,
+        id: String,
+        params: String,
+        report: Vec<RoutableConnectorChoiceWithBucketName>,
+        configs: Option<EliminationConfig>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<UpdateEliminationBucketResponse> {
+        let bucket_config = get_bucket_config(configs)?;
+
+        let version = self
+            .get_version(&headers.tenant_id, &id, ELIMINATION_ENGINE)
+            .await?;
+        let entity_scope = Self::entity_scope(&id, version);
+
+        for conn_choice_with_bucket in report {
+            let entity_key = self.window_key(
+                &headers.tenant_id,
+                ELIMINATION_ENGINE,
+                &entity_scope,
+                &params,
+                &conn_choice_with_bucket
+                    .routable_connector_choice
+                    .to_string(),
+            );
+            let global_key = self.window_key(
+                &headers.tenant_id,
+                ELIMINATION_ENGINE,
+                GLOBAL_SCOPE,
+                &params,
+                &conn_choice_with_bucket
+                    .routable_connector_choice
+                    .connector
+                    .to_string(),
+            );
+
+            self.record_bucket_failure(
+                entity_key.into(),
+                &conn_choice_with_bucket.bucket_name,
+                &bucket_config,
+            )
+            .await?;
+            self.record_bucket_failure(
+                global_key.into(),
+                &conn_choice_with_bucket.bucket_name,
+                &bucket_config,
+            )
+            .await?;
+        }
+
+        let response = UpdateEliminationBucketResponse {
+            status: BucketUpdationStatus::BucketUpdationSucceeded.into(),
+        };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn invalidate_elimination_bucket(
+        &self,
+        id: String,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<InvalidateBucketResponse> {
+        self.bump_version(&headers.tenant_id, &id, ELIMINATION_ENGINE)
+            .await?;
+
+        let response = InvalidateBucketResponse {
+            status: BucketInvalidationStatus::BucketInvalidationSucceeded.into(),
+        };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window_config(duration_in_mins: Option<u64>) -> SuccessRateWindowConfig {
+        SuccessRateWindowConfig {
+            min_aggregates_size: 2,
+            max_aggregates_size: 3,
+            max_total_count: 4,
+            duration_in_mins,
+            default_success_rate: 100.0,
+        }
+    }
+
+    fn window(current: i64, blocks: &[(i64, i64, i64, i64)]) -> HashMap<String, i64> {
+        let mut window = HashMap::from([(CURRENT_BLOCK_FIELD.to_string(), current)]);
+        for (block, start, total, success) in blocks {
+            window.insert(format!("{block}:start"), *start);
+            window.insert(format!("{block}:total"), *total);
+            window.insert(format!("{block}:success"), *success);
+        }
+        window
+    }
+
+    #[test]
+    fn test_success_rate_defaults_until_min_aggregates() {
+        let config = window_config(None);
+
+        assert_eq!(get_success_rate(&HashMap::new(), &config, 0), 100.0);
+        assert_eq!(
+            get_success_rate(&window(0, &[(0, 0, 4, 1)]), &config, 0),
+            100.0
+        );
+    }
+
+    #[test]
+    fn test_success_rate_over_last_blocks() {
+        let config = window_config(None);
+        // Block 0 is no longer part of the last `max_aggregates_size` blocks
+        let window = window(3, &[(0, 0, 4, 0), (1, 0, 4, 4), (2, 0, 4, 2), (3, 0, 2, 0)]);
+
+        assert_eq!(get_success_rate(&window, &config, 0), 60.0);
+    }
+
+    #[test]
+    fn test_success_rate_skips_aged_out_blocks() {
+        let config = window_config(Some(1));
+        let window = window(2, &[(0, 0, 4, 0), (1, 100, 4, 4), (2, 150, 4, 2)]);
+
+        // The window spans 3 minutes, block 0 started more than 3 minutes ago
+        assert_eq!(get_success_rate(&window, &config, 200), 75.0);
+        // Only one block remains, which is less than `min_aggregates_size`
+        assert_eq!(get_success_rate(&window, &config, 290), 100.0);
+    }
+
+    #[test]
+    fn test_bucket_level_leaks_per_interval() {
+        assert_eq!(get_bucket_level(5, 100, 100, 10), 5);
+        assert_eq!(get_bucket_level(5, 100, 119, 10), 4);
+        assert_eq!(get_bucket_level(5, 100, 200, 10), 0);
+    }
+
+    #[test]
+    fn test_full_buckets() {
+        let bucket_config = EliminationBucketConfig {
+            bucket_size: 3,
+            bucket_leak_interval_in_secs: 60,
+        };
+        let buckets = HashMap::from([
+            ("bucket_1:level".to_string(), 3),
+            ("bucket_1:leaked_at".to_string(), 1000),
+            ("bucket_2:level".to_string(), 3),
+            ("bucket_2:leaked_at".to_string(), 900),
+            ("bucket_3:level".to_string(), 1),
+            ("bucket_3:leaked_at".to_string(), 1000),
+        ]);
+
+        assert_eq!(
+            get_full_buckets(&buckets, &bucket_config, 1030),
+            vec!["bucket_1".to_string()]
+        );
+        assert!(get_full_buckets(&buckets, &bucket_config, 1060).is_empty());
+    }
+}
@@ -0,0 +1,598 @@
+//! Turns the analysis trace of a failed check into a minimal, structured explanation of the
+//! requirements that were not satisfied, along with a plain text rendering of it.
+
+use std::{
+    fmt,
+    sync::{Arc, Weak},
+};
+
+use rustc_hash::FxHashSet;
+
+use crate::{
+    error::{AnalysisTrace, GraphError, ValueTracePredecessor},
+    types::{Metadata, NodeValue, Relation, ValueNode},
+};
+
+/// Implemented by value nodes which can be described in an explanation rendered as text
+pub trait ExplainableValue: ValueNode {
+    fn describe_key(key: &Self::Key) -> String;
+
+    fn describe_value(&self) -> String;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExplanationNodeKind {
+    Value,
+    AllAggregator,
+    AnyAggregator,
+}
+
+/// A node through which the failure of a requirement was propagated
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ExplanationNode<V: ValueNode> {
+    pub kind: ExplanationNodeKind,
+    pub value: Option<NodeValue<V>>,
+    pub info: Option<&'static str>,
+    pub metadata: Option<Arc<dyn Metadata>>,
+}
+
+/// The requirement which was not satisfied by the checking context
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum UnsatisfiedRequirement<V: ValueNode> {
+    /// The value was required to be present in the context (positive relation), or to be absent
+    /// from it (negative relation)
+    Value {
+        value: NodeValue<V>,
+        relation: Relation,
+    },
+    /// The value of the key in the context was required to be one of the expected values
+    /// (positive relation), or none of them (negative relation)
+    In {
+        key: Option<V::Key>,
+        expected: Vec<V>,
+        found: Option<V>,
+        relation: Relation,
+    },
+    /// The value is not a part of the graph, so it can never be satisfied
+    UnknownValue { value: NodeValue<V> },
+    /// Requirements reached through a cycle in the graph contradict each other
+    Contradiction,
+}
+
+/// A single unsatisfied requirement, along with the nodes leading up to it starting from the
+/// node that was checked
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FailureCause<V: ValueNode> {
+    pub path: Vec<ExplanationNode<V>>,
+    pub requirement: UnsatisfiedRequirement<V>,
+    pub info: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Explanation<V: ValueNode> {
+    pub causes: Vec<FailureCause<V>>,
+}
+
+impl<V: ValueNode> Explanation<V> {
+    /// Builds the explanation from the trace of a failed check. The trace is owned by the
+    /// memoization used for the check, so this must be called before the memoization is dropped.
+    ///
+    /// When any one of several alternatives could have satisfied a node, only the alternatives
+    /// closest to being satisfied are explained. An alternative for another payment method, for
+    /// instance, fails because its value is absent from the context and is left out in favour of
+    /// the one that was present and failed on a requirement further down.
+    pub fn from_trace(trace: &Weak<AnalysisTrace<V>>) -> Self {
+        let mut path = Vec::new();
+        let mut visited = FxHashSet::default();
+
+        Self {
+            causes: collect_causes(trace, &mut path, &mut visited),
+        }
+    }
+
+    /// Explanation for a value which does not have a node in the graph
+    pub fn unknown_value(value: NodeValue<V>) -> Self {
+        Self {
+            causes: vec![FailureCause {
+                path: Vec::new(),
+                requirement: UnsatisfiedRequirement::UnknownValue { value },
+                info: None,
+            }],
+        }
+    }
+}
+
+impl<V: ValueNode> GraphError<V> {
+    /// Explains the failure if this is an analysis error, see [`Explanation::from_trace`]
+    pub fn explain(&self) -> Option<Explanation<V>> {
+        match self {
+            Self::AnalysisError(trace) => Some(Explanation::from_trace(trace)),
+            _ => None,
+        }
+    }
+}
+
+fn collect_causes<V: ValueNode>(
+    trace: &Weak<AnalysisTrace<V>>,
+    path: &mut Vec<ExplanationNode<V>>,
+    visited: &mut FxHashSet<*const AnalysisTrace<V>>,
+) -> Vec<FailureCause<V>> {
+    // Traces are shared between nodes through memoization, each one is explained only once
+    if !visited.insert(trace.as_ptr()) {
+        return Vec::new();
+    }
+
+    let Some(trace) = trace.upgrade() else {
+        return Vec::new();
+    };
+
+    match trace.as_ref() {
+        AnalysisTrace::Value {
+            value,
+            relation,
+            predecessors: None,
+            info,
+            ..
+        } => vec![FailureCause {
+            path: path.clone(),
+            requirement: UnsatisfiedRequirement::Value {
+                value: value.clone(),
+                relation: *relation,
+            },
+            info: *info,
+        }],
+
+        AnalysisTrace::Value {
+            value,
+            predecessors: Some(predecessors),
+            info,
+            metadata,
+            ..
+        } => {
+            path.push(ExplanationNode {
+                kind: ExplanationNodeKind::Value,
+                value: Some(value.clone()),
+                info: *info,
+                metadata: metadata.clone(),
+            });
+            let causes = match predecessors {
+                ValueTracePredecessor::Mandatory(predecessor) => {
+                    collect_causes(predecessor, path, visited)
+                }
+                ValueTracePredecessor::OneOf(predecessors) => {
+                    collect_alternative_causes(predecessors, path, visited)
+                }
+            };
+            path.pop();
+            causes
+        }
+
+        AnalysisTrace::AllAggregation {
+            unsatisfied,
+            info,
+            metadata,
+        } => {
+            path.push(ExplanationNode {
+                kind: ExplanationNodeKind::AllAggregator,
+                value: None,
+                info: *info,
+                metadata: metadata.clone(),
+            });
+            let causes = unsatisfied
+                .iter()
+                .flat_map(|trace| collect_causes(trace, path, visited))
+                .collect();
+            path.pop();
+            causes
+        }
+
+        AnalysisTrace::AnyAggregation {
+            unsatisfied,
+            info,
+            metadata,
+        } => {
+            path.push(ExplanationNode {
+                kind: ExplanationNodeKind::AnyAggregator,
+                value: None,
+                info: *info,
+                metadata: metadata.clone(),
+            });
+            let causes = collect_alternative_causes(unsatisfied, path, visited);
+            path.pop();
+            causes
+        }
+
+        AnalysisTrace::InAggregation {
+            expected,
+            found,
+            relation,
+            info,
+            ..
+        } => vec![FailureCause {
+            path: path.clone(),
+            requirement: UnsatisfiedRequirement::In {
+                key: found.as_ref().or(expected.first()).map(ValueNode::get_key),
+                expected: expected.clone(),
+                found: found.clone(),
+                relation: *relation,
+            },
+            info: *info,
+        }],
+
+        AnalysisTrace::Contradiction { .. } => vec![FailureCause {
+            path: path.clone(),
+            requirement: UnsatisfiedRequirement::Contradiction,
+            info: None,
+        }],
+    }
+}
+
+fn collect_alternative_causes<V: ValueNode>(
+    alternatives: &[Weak<AnalysisTrace<V>>],
+    path: &mut Vec<ExplanationNode<V>>,
+    visited: &mut FxHashSet<*const AnalysisTrace<V>>,
+) -> Vec<FailureCause<V>> {
+    let explained = alternatives
+        .iter()
+        .map(|trace| {
+            let mut alternative_visited = visited.clone();
+            let causes = collect_causes(trace, path, &mut alternative_visited);
+            (causes, alternative_visited)
+        })
+        .filter(|(causes, _)| !causes.is_empty())
+        .collect::<Vec<_>>();
+
+    let Some(closest) = explained
+        .iter()
+        .map(|(causes, _)| distance_from_satisfaction(causes))
+        .min()
+    else {
+        return Vec::new();
+    };
+
+    explained
+        .into_iter()
+        .filter(|(causes, _)| distance_from_satisfaction(causes) == closest)
+        .flat_map(|(causes, alternative_visited)| {
+            visited.extend(alternative_visited);
+            causes
+        })
+        .collect()
+}
+
+/// Alternatives which required fewer values that were absent from the context, and then fewer
+/// requirements overall, are closer to having been satisfied
+fn distance_from_satisfaction<V: ValueNode>(causes: &[FailureCause<V>]) -> (usize, usize) {
+    let absent_values = causes
+        .iter()
+        .filter(|cause| {
+            matches!(
+                cause.requirement,
+                UnsatisfiedRequirement::Value {
+                    relation: Relation::Positive,
+                    ..
+                }
+            )
+        })
+        .count();
+
+    (absent_values, causes.len())
+}
+
+fn describe_node_value<V: ExplainableValue>(value: &NodeValue<V>) -> String {
+    match value {
+        NodeValue::Key(key) => V::describe_key(key),
+        NodeValue::Value(value) => format!(
+            "{} = {}",
+            V::describe_key(&value.get_key()),
+            value.describe_value()
+        ),
+    }
+}
+
+fn describe_values<V: ExplainableValue>(values: &[V]) -> String {
+    let mut descriptions = values
+        .iter()
+        .map(ExplainableValue::describe_value)
+        .collect::<Vec<_>>();
+    // The expected values come from a hash set, sort them for a stable rendering
+    descriptions.sort();
+    format!("[{}]", descriptions.join(", "))
+}
+
+impl<V: ExplainableValue> fmt::Display for UnsatisfiedRequirement<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Value {
+                value: NodeValue::Key(key),
+                relation: Relation::Positive,
+            } => write!(f, "{} must be provided", V::describe_key(key)),
+            Self::Value {
+                value: NodeValue::Key(key),
+                relation: Relation::Negative,
+            } => write!(f, "{} must not be provided", V::describe_key(key)),
+            Self::Value {
+                value: NodeValue::Value(value),
+                relation,
+            } => write!(
+                f,
+                "{} must {}be {}",
+                V::describe_key(&value.get_key()),
+                match relation {
+                    Relation::Positive => "",
+                    Relation::Negative => "not ",
+                },
+                value.describe_value()
+            ),
+            Self::In {
+                key,
+                expected,
+                found,
+                relation,
+            } => {
+                let key = key
+                    .as_ref()
+                    .map(V::describe_key)
+                    .unwrap_or_else(|| "value".to_string());
+                let expectation = match relation {
+                    Relation::Positive => "must be one of",
+                    Relation::Negative => "must not be one of",
+                };
+                match found {
+                    Some(found) => write!(
+                        f,
+                        "{key} {expectation} {}, found {}",
+                        describe_values(expected),
+                        found.describe_value()
+                    ),
+                    None => write!(
+                        f,
+                        "{key} {expectation} {}, but was not provided",
+                        describe_values(expected)
+                    ),
+                }
+            }
+            Self::UnknownValue { value } => {
+                write!(f, "{} is not configured", describe_node_value(value))
+            }
+            Self::Contradiction => f.write_str("the requirements contradict each other"),
+        }
+    }
+}
+
+impl<V: ExplainableValue> fmt::Display for ExplanationNode<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.info, &self.value) {
+            (Some(info), Some(value)) => write!(f, "{info} ({})", describe_node_value(value)),
+            (Some(info), None) => f.write_str(info),
+            (None, Some(value)) => f.write_str(&describe_node_value(value)),
+            (None, None) => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+impl<V: ExplainableValue> fmt::Display for FailureCause<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.requirement)?;
+        if let Some(info) = self.info {
+            write!(f, " ({info})")?;
+        }
+        if !self.path.is_empty() {
+            let path = self
+                .path
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            write!(f, ", required by {path}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders one unsatisfied requirement per line, eg:
+///
+/// ```text
+/// - payment_currency must be one of [EUR, USD], found ILS (Configs for Currency), required by Connector (connector = stripe) -> ...
+/// ```
+impl<V: ExplainableValue> fmt::Display for Explanation<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, cause) in self.causes.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "- {cause}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::KeyNode;
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize)]
+    enum TestKey {
+        PaymentMethod,
+        Currency,
+        Connector,
+    }
+
+    impl KeyNode for TestKey {}
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize)]
+    enum TestValue {
+        PaymentMethod(&'static str),
+        Currency(&'static str),
+        Connector(&'static str),
+    }
+
+    impl ValueNode for TestValue {
+        type Key = TestKey;
+
+        fn get_key(&self) -> Self::Key {
+            match self {
+                Self::PaymentMethod(_) => TestKey::PaymentMethod,
+                Self::Currency(_) => TestKey::Currency,
+                Self::Connector(_) => TestKey::Connector,
+            }
+        }
+    }
+
+    impl ExplainableValue for TestValue {
+        fn describe_key(key: &Self::Key) -> String {
+            match key {
+                TestKey::PaymentMethod => "payment_method",
+                TestKey::Currency => "payment_currency",
+                TestKey::Connector => "connector",
+            }
+            .to_string()
+        }
+
+        fn describe_value(&self) -> String {
+            match self {
+                Self::PaymentMethod(value) | Self::Currency(value) | Self::Connector(value) => {
+                    value.to_string()
+                }
+            }
+        }
+    }
+
+    fn currency_trace(found: &'static str) -> Arc<AnalysisTrace<TestValue>> {
+        Arc::new(AnalysisTrace::InAggregation {
+            expected: vec![TestValue::Currency("USD"), TestValue::Currency("EUR")],
+            found: Some(TestValue::Currency(found)),
+            relation: Relation::Positive,
+            info: Some("Configs for Currency"),
+            metadata: None,
+        })
+    }
+
+    fn absent_value_trace(value: TestValue) -> Arc<AnalysisTrace<TestValue>> {
+        Arc::new(AnalysisTrace::Value {
+            value: NodeValue::Value(value),
+            relation: Relation::Positive,
+            predecessors: None,
+            info: None,
+            metadata: None,
+        })
+    }
+
+    #[test]
+    fn test_explanation_of_mandatory_requirement() {
+        let currency = currency_trace("ILS");
+        let connector = Arc::new(AnalysisTrace::Value {
+            value: NodeValue::Value(TestValue::Connector("stripe")),
+            relation: Relation::Positive,
+            predecessors: Some(ValueTracePredecessor::Mandatory(Box::new(Arc::downgrade(
+                &currency,
+            )))),
+            info: None,
+            metadata: None,
+        });
+
+        let explanation = Explanation::from_trace(&Arc::downgrade(&connector));
+
+        assert_eq!(
+            explanation.to_string(),
+            "- payment_currency must be one of [EUR, USD], found ILS (Configs for Currency), \
+             required by connector = stripe"
+        );
+    }
+
+    #[test]
+    fn test_explanation_keeps_closest_alternatives() {
+        let absent_wallet = absent_value_trace(TestValue::PaymentMethod("wallet"));
+        let currency = currency_trace("ILS");
+        let any = Arc::new(AnalysisTrace::AnyAggregation {
+            unsatisfied: vec![Arc::downgrade(&absent_wallet), Arc::downgrade(&currency)],
+            info: None,
+            metadata: None,
+        });
+
+        let explanation = Explanation::from_trace(&Arc::downgrade(&any));
+
+        assert_eq!(explanation.causes.len(), 1);
+        assert!(explanation.causes.iter().all(|cause| matches!(
+            cause.requirement,
+            UnsatisfiedRequirement::In {
+                found: Some(TestValue::Currency("ILS")),
+                ..
+            }
+        )));
+        assert!(explanation.causes.iter().all(|cause| cause
+            .path
+            .iter()
+            .map(|node| node.kind)
+            .eq([ExplanationNodeKind::AnyAggregator])));
+    }
+
+    #[test]
+    fn test_explanation_of_shared_traces() {
+        let currency = currency_trace("ILS");
+        let all = Arc::new(AnalysisTrace::AllAggregation {
+            unsatisfied: vec![Arc::downgrade(&currency), Arc::downgrade(&currency)],
+            info: Some("Connector requirements"),
+            metadata: None,
+        });
+
+        let explanation = Explanation::from_trace(&Arc::downgrade(&all));
+
+        assert_eq!(
+            explanation.to_string(),
+            "- payment_currency must be one of [EUR, USD], found ILS (Configs for Currency), \
+             required by Connector requirements"
+        );
+    }
+
+    #[test]
+    fn test_explanation_of_dropped_trace_is_empty() {
+        let trace = Arc::downgrade(&currency_trace("ILS"));
+
+        assert!(Explanation::from_trace(&trace).causes.is_empty());
+    }
+
+    #[test]
+    fn test_unsatisfied_requirement_rendering() {
+        let key_absence = UnsatisfiedRequirement::<TestValue>::Value {
+            value: NodeValue::Key(TestKey::Currency),
+            relation: Relation::Negative,
+        };
+        let value_absence = UnsatisfiedRequirement::Value {
+            value: NodeValue::Value(TestValue::PaymentMethod("wallet")),
+            relation: Relation::Negative,
+        };
+        let missing_in = UnsatisfiedRequirement::In {
+            key: Some(TestKey::Currency),
+            expected: vec![TestValue::Currency("USD")],
+            found: None,
+            relation: Relation::Positive,
+        };
+
+        assert_eq!(
+            key_absence.to_string(),
+            "payment_currency must not be provided"
+        );
+        assert_eq!(
+            value_absence.to_string(),
+            "payment_method must not be wallet"
+        );
+        assert_eq!(
+            missing_in.to_string(),
+            "payment_currency must be one of [USD], but was not provided"
+        );
+        assert_eq!(
+            Explanation::unknown_value(NodeValue::Value(TestValue::Connector("adyen"))).to_string(),
+            "- connector = adyen is not configured"
+        );
+    }
+
+    #[test]
+    fn test_only_analysis_errors_are_explained() {
+        assert!(GraphError::<TestValue>::NodeNotFound.explain().is_none());
+    }
+}
@@ -1,11 +1,13 @@
 pub mod builder;
 mod dense_map;
 pub mod error;
+pub mod explanation;
 pub mod graph;
 pub mod types;
 
 pub use builder::ConstraintGraphBuilder;
 pub use error::{AnalysisTrace, GraphError};
+pub use explanation::{ExplainableValue, Explanation};
 pub use graph::ConstraintGraph;
 #[cfg(feature = "viz")]
 pub use types::NodeViz;
@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use common_enums::enums as api_enums;
 use common_types::{domain::AcquirerConfig, primitive_wrappers};
 use common_utils::{
-    crypto::{OptionalEncryptableName, OptionalEncryptableValue},
+    crypto::{EncryptableName, OptionalEncryptableName, OptionalEncryptableValue},
     date_time,
     encryption::Encryption,
     errors::{CustomResult, ValidationError},
@@ -87,12 +87,15 @@ pub struct Profile {
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
+    pub routing_timezone: Option<primitive_wrappers::RoutingTimezone>,
+    pub native_frm_config: Option<common_types::fraud_rule_engine::NativeFrmConfig>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
     pub external_vault_details: ExternalVaultDetails,
     pub billing_processor_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub network_tokenization_credentials: OptionalEncryptableValue,
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
+    pub webhook_secret_rotation: Option<WebhookSecretRotation>,
 }
 
 #[cfg(feature = "v1")]
@@ -247,6 +250,8 @@ pub struct ProfileSetter {
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
+    pub routing_timezone: Option<primitive_wrappers::RoutingTimezone>,
+    pub native_frm_config: Option<common_types::fraud_rule_engine::NativeFrmConfig>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
     pub external_vault_details: ExternalVaultDetails,
@@ -317,12 +322,15 @@ impl From<ProfileSetter> for Profile {
             merchant_category_code: value.merchant_category_code,
             merchant_country_code: value.merchant_country_code,
             dispute_polling_interval: value.dispute_polling_interval,
+            routing_timezone: value.routing_timezone,
+            native_frm_config: value.native_frm_config,
             is_manual_retry_enabled: value.is_manual_retry_enabled,
             always_enable_overcapture: value.always_enable_overcapture,
             external_vault_details: value.external_vault_details,
             billing_processor_id: value.billing_processor_id,
             network_tokenization_credentials: value.network_tokenization_credentials,
             payment_method_blocking: value.payment_method_blocking,
+            webhook_secret_rotation: None,
         }
     }
 }
@@ -337,6 +345,60 @@ impl Profile {
     pub fn get_id(&self) -> &common_utils::id_type::ProfileId {
         &self.id
     }
+
+    /// Returns the key outgoing webhooks are signed with, along with the staged key while a
+    /// signing secret rotation is within its grace period.
+    /// Once the grace period has ended, the staged key is used even if the rotation has not yet
+    /// been completed by the scheduler.
+    #[cfg(feature = "v1")]
+    pub fn get_webhook_signing_keys(&self) -> (Option<Secret<String>>, Option<Secret<String>>) {
+        let current_key = self.payment_response_hash_key.clone().map(Secret::new);
+        match &self.webhook_secret_rotation {
+            Some(rotation) => rotation.get_signing_keys(current_key),
+            None => (current_key, None),
+        }
+    }
+}
+
+/// State of an in-progress rotation of the webhook signing secret of a profile
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug)]
+pub struct WebhookSecretRotation {
+    /// The secret that replaces `payment_response_hash_key` once the grace period ends
+    pub staged_payment_response_hash_key: EncryptableName,
+    /// Until this time, outgoing webhooks are signed with both the current and the staged secret
+    pub grace_period_ends_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+impl WebhookSecretRotation {
+    pub fn is_grace_period_active(&self) -> bool {
+        date_time::now() < self.grace_period_ends_at
+    }
+
+    fn get_signing_keys(
+        &self,
+        current_key: Option<Secret<String>>,
+    ) -> (Option<Secret<String>>, Option<Secret<String>>) {
+        let staged_key = self.staged_payment_response_hash_key.clone().into_inner();
+        if self.is_grace_period_active() {
+            (current_key, Some(staged_key))
+        } else {
+            (Some(staged_key), None)
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl From<WebhookSecretRotation> for storage_types::WebhookSecretRotation {
+    fn from(webhook_secret_rotation: WebhookSecretRotation) -> Self {
+        Self {
+            staged_payment_response_hash_key: Encryption::from(
+                webhook_secret_rotation.staged_payment_response_hash_key,
+            ),
+            grace_period_ends_at: webhook_secret_rotation.grace_period_ends_at,
+        }
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -389,6 +451,8 @@ pub struct ProfileGeneralUpdate {
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
+    pub routing_timezone: Option<primitive_wrappers::RoutingTimezone>,
+    pub native_frm_config: Option<common_types::fraud_rule_engine::NativeFrmConfig>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
     pub is_external_vault_enabled: Option<common_enums::ExternalVaultEnabled>,
@@ -426,6 +490,10 @@ pub enum ProfileUpdate {
     AcquirerConfigMapUpdate {
         acquirer_config_map: Option<common_types::domain::AcquirerConfigMap>,
     },
+    WebhookSecretRotationUpdate {
+        payment_response_hash_key: Option<String>,
+        webhook_secret_rotation: Option<WebhookSecretRotation>,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -480,6 +548,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     merchant_category_code,
                     merchant_country_code,
                     dispute_polling_interval,
+                    routing_timezone,
+                    native_frm_config,
                     always_request_extended_authorization,
                     is_manual_retry_enabled,
                     always_enable_overcapture,
@@ -550,6 +620,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     merchant_category_code,
                     merchant_country_code,
                     dispute_polling_interval,
+                    routing_timezone,
+                    native_frm_config,
                     is_manual_retry_enabled,
                     always_enable_overcapture,
                     is_external_vault_enabled,
@@ -558,6 +630,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     network_tokenization_credentials: network_tokenization_credentials
                         .map(Encryption::from),
                     payment_method_blocking,
+                    webhook_secret_rotation: None,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -614,6 +687,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 dispute_polling_interval: None,
+                routing_timezone: None,
+                native_frm_config: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
                 is_external_vault_enabled: None,
@@ -622,6 +697,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_l2_l3_enabled: None,
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
+                webhook_secret_rotation: None,
             },
             ProfileUpdate::DynamicRoutingAlgorithmUpdate {
                 dynamic_routing_algorithm,
@@ -675,6 +751,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 dispute_polling_interval: None,
+                routing_timezone: None,
+                native_frm_config: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
                 is_external_vault_enabled: None,
@@ -683,6 +761,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_l2_l3_enabled: None,
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
+                webhook_secret_rotation: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -736,6 +815,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 dispute_polling_interval: None,
+                routing_timezone: None,
+                native_frm_config: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
                 is_external_vault_enabled: None,
@@ -744,6 +825,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_l2_l3_enabled: None,
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
+                webhook_secret_rotation: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -797,6 +879,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 dispute_polling_interval: None,
+                routing_timezone: None,
+                native_frm_config: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
                 is_external_vault_enabled: None,
@@ -805,6 +889,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_l2_l3_enabled: None,
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
+                webhook_secret_rotation: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -859,6 +944,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 dispute_polling_interval: None,
+                routing_timezone: None,
+                native_frm_config: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
                 is_external_vault_enabled: None,
@@ -868,6 +955,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 network_tokenization_credentials: network_tokenization_credentials
                     .map(Encryption::from),
                 payment_method_blocking: None,
+                webhook_secret_rotation: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -921,6 +1009,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 dispute_polling_interval: None,
+                routing_timezone: None,
+                native_frm_config: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
                 is_external_vault_enabled: None,
@@ -929,6 +1019,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_l2_l3_enabled: None,
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
+                webhook_secret_rotation: None,
             },
             ProfileUpdate::AcquirerConfigMapUpdate {
                 acquirer_config_map,
@@ -982,6 +1073,73 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 dispute_polling_interval: None,
+                routing_timezone: None,
+                native_frm_config: None,
+                is_manual_retry_enabled: None,
+                always_enable_overcapture: None,
+                is_external_vault_enabled: None,
+                external_vault_connector_details: None,
+                billing_processor_id: None,
+                is_l2_l3_enabled: None,
+                network_tokenization_credentials: None,
+                payment_method_blocking: None,
+                webhook_secret_rotation: None,
+            },
+            ProfileUpdate::WebhookSecretRotationUpdate {
+                payment_response_hash_key,
+                webhook_secret_rotation,
+            } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key,
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: None,
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                dynamic_routing_algorithm: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                always_request_extended_authorization: None,
+                is_click_to_pay_enabled: None,
+                authentication_product_ids: None,
+                card_testing_guard_config: None,
+                card_testing_secret_key: None,
+                is_clear_pan_retries_enabled: None,
+                force_3ds_challenge: None,
+                is_debit_routing_enabled: None,
+                merchant_business_country: None,
+                is_iframe_redirection_enabled: None,
+                is_pre_network_tokenization_enabled: None,
+                three_ds_decision_rule_algorithm: None,
+                acquirer_config_map: None,
+                merchant_category_code: None,
+                merchant_country_code: None,
+                dispute_polling_interval: None,
+                routing_timezone: None,
+                native_frm_config: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
                 is_external_vault_enabled: None,
@@ -990,6 +1148,9 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_l2_l3_enabled: None,
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
+                webhook_secret_rotation: Some(
+                    webhook_secret_rotation.map(storage_types::WebhookSecretRotation::from),
+                ),
             },
         }
     }
@@ -1067,6 +1228,8 @@ impl Conversion for Profile {
             merchant_category_code: self.merchant_category_code,
             merchant_country_code: self.merchant_country_code,
             dispute_polling_interval: self.dispute_polling_interval,
+            routing_timezone: self.routing_timezone,
+            native_frm_config: self.native_frm_config,
             is_manual_retry_enabled: self.is_manual_retry_enabled,
             always_enable_overcapture: self.always_enable_overcapture,
             is_external_vault_enabled,
@@ -1076,6 +1239,9 @@ impl Conversion for Profile {
                 .network_tokenization_credentials
                 .map(|name| name.into()),
             payment_method_blocking: self.payment_method_blocking,
+            webhook_secret_rotation: self
+                .webhook_secret_rotation
+                .map(storage_types::WebhookSecretRotation::from),
         })
     }
 
@@ -1093,6 +1259,7 @@ impl Conversion for Profile {
             outgoing_webhook_custom_http_headers,
             card_testing_secret_key,
             network_tokenization_credentials,
+            webhook_secret_rotation,
         ) = async {
             let outgoing_webhook_custom_http_headers = item
                 .outgoing_webhook_custom_http_headers
@@ -1139,10 +1306,29 @@ impl Conversion for Profile {
                 })
                 .await?;
 
+            let webhook_secret_rotation = match item.webhook_secret_rotation {
+                Some(webhook_secret_rotation) => Some(WebhookSecretRotation {
+                    staged_payment_response_hash_key: crypto_operation(
+                        state,
+                        type_name!(Self::DstType),
+                        CryptoOperation::Decrypt(
+                            webhook_secret_rotation.staged_payment_response_hash_key,
+                        ),
+                        key_manager_identifier.clone(),
+                        key.peek(),
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())?,
+                    grace_period_ends_at: webhook_secret_rotation.grace_period_ends_at,
+                }),
+                None => None,
+            };
+
             Ok::<_, error_stack::Report<common_utils::errors::CryptoError>>((
                 outgoing_webhook_custom_http_headers,
                 card_testing_secret_key,
                 network_tokenization_credentials,
+                webhook_secret_rotation,
             ))
         }
         .await
@@ -1217,12 +1403,15 @@ impl Conversion for Profile {
             merchant_category_code: item.merchant_category_code,
             merchant_country_code: item.merchant_country_code,
             dispute_polling_interval: item.dispute_polling_interval,
+            routing_timezone: item.routing_timezone,
+            native_frm_config: item.native_frm_config,
             is_manual_retry_enabled: item.is_manual_retry_enabled,
             always_enable_overcapture: item.always_enable_overcapture,
             external_vault_details,
             billing_processor_id: item.billing_processor_id,
             network_tokenization_credentials,
             payment_method_blocking: item.payment_method_blocking,
+            webhook_secret_rotation,
         })
     }
 
@@ -1288,6 +1477,8 @@ impl Conversion for Profile {
             merchant_category_code: self.merchant_category_code,
             merchant_country_code: self.merchant_country_code,
             dispute_polling_interval: self.dispute_polling_interval,
+            routing_timezone: self.routing_timezone,
+            native_frm_config: self.native_frm_config,
             is_manual_retry_enabled: self.is_manual_retry_enabled,
             is_external_vault_enabled,
             external_vault_connector_details,
@@ -2700,3 +2891,53 @@ where
         merchant_id: &common_utils::id_type::MerchantId,
     ) -> CustomResult<Vec<Profile>, Self::Error>;
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use super::*;
+
+    fn get_webhook_secret_rotation(
+        grace_period_ends_at: time::PrimitiveDateTime,
+    ) -> WebhookSecretRotation {
+        WebhookSecretRotation {
+            staged_payment_response_hash_key: EncryptableName::new(
+                Secret::new("staged_key".to_string()),
+                Secret::new(Vec::new()),
+            ),
+            grace_period_ends_at,
+        }
+    }
+
+    #[test]
+    fn test_signing_keys_within_grace_period() {
+        let rotation =
+            get_webhook_secret_rotation(date_time::now().saturating_add(time::Duration::hours(1)));
+
+        let (current_key, staged_key) =
+            rotation.get_signing_keys(Some(Secret::new("current_key".to_string())));
+
+        assert_eq!(
+            current_key.map(ExposeInterface::expose),
+            Some("current_key".to_string())
+        );
+        assert_eq!(
+            staged_key.map(ExposeInterface::expose),
+            Some("staged_key".to_string())
+        );
+    }
+
+    #[test]
+    fn test_signing_keys_after_grace_period() {
+        let rotation =
+            get_webhook_secret_rotation(date_time::now().saturating_sub(time::Duration::hours(1)));
+
+        let (current_key, staged_key) =
+            rotation.get_signing_keys(Some(Secret::new("current_key".to_string())));
+
+        assert_eq!(
+            current_key.map(ExposeInterface::expose),
+            Some("staged_key".to_string())
+        );
+        assert!(staged_key.is_none());
+    }
+}
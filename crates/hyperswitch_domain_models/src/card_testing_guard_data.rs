@@ -4,9 +4,15 @@ use serde::{self, Deserialize, Serialize};
 pub struct CardTestingGuardData {
     pub is_card_ip_blocking_enabled: bool,
     pub card_ip_blocking_cache_key: String,
+    pub card_ip_blocking_threshold: i32,
     pub is_guest_user_card_blocking_enabled: bool,
     pub guest_user_card_blocking_cache_key: String,
+    pub guest_user_card_blocking_threshold: i32,
     pub is_customer_id_blocking_enabled: bool,
     pub customer_id_blocking_cache_key: String,
+    pub customer_id_blocking_threshold: i32,
     pub card_testing_guard_expiry: i32,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub ip_address: Option<std::net::IpAddr>,
+    pub customer_id: Option<common_utils::id_type::CustomerId>,
 }
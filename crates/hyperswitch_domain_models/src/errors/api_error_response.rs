@@ -312,6 +312,14 @@ pub enum ApiErrorResponse {
         message = "Access forbidden, invalid Basic authentication credentials"
     )]
     InvalidBasicAuth,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_52", message = "Too many requests, retry after {retry_after_seconds} seconds")]
+    TooManyRequests { retry_after_seconds: u64 },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_53", message = "Invalid routing program: {message} at line {line}, column {column}")]
+    InvalidRoutingProgram {
+        message: String,
+        line: usize,
+        column: usize,
+    },
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_02", message = "Bad request received in webhook")]
@@ -609,6 +617,8 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             }
             Self::InvalidJwtToken => AER::Unauthorized(ApiError::new("IR", 17, "Access forbidden, invalid JWT token was used", None)),
             Self::InvalidBasicAuth => AER::Unauthorized(ApiError::new("IR", 51, "Access forbidden, invalid Basic authentication credentials", None)),
+            Self::TooManyRequests { retry_after_seconds } => AER::TooManyRequests(ApiError::new("IR", 52, format!("Too many requests, retry after {retry_after_seconds} seconds"), None), *retry_after_seconds),
+            Self::InvalidRoutingProgram { message, line, column } => AER::BadRequest(ApiError::new("IR", 53, format!("Invalid routing program: {message}"), Some(Extra { data: Some(serde_json::json!({ "line": line, "column": column })), ..Default::default() }))),
             Self::GenericUnauthorized { message } => {
                 AER::Unauthorized(ApiError::new("IR", 18, message.to_string(), None))
             },
@@ -252,6 +252,15 @@ pub trait PaymentAttemptInterface {
         card_network: Option<Vec<storage_enums::CardNetwork>>,
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<i64, Self::Error>;
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn get_attempt_status_with_count(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        routing_approaches: Vec<storage_enums::RoutingApproach>,
+        time_range: &common_utils::types::TimeRange,
+    ) -> error_stack::Result<Vec<(storage_enums::AttemptStatus, i64)>, Self::Error>;
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
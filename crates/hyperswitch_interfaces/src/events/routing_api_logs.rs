@@ -17,6 +17,8 @@ pub enum RoutingEngine {
     IntelligentRouter,
     /// Decision engine for routing
     DecisionEngine,
+    /// Euclid rules evaluated within the application
+    Euclid,
 }
 
 /// Method type enum
@@ -27,6 +29,8 @@ pub enum ApiMethod {
     Grpc,
     /// Rest call
     Rest(Method),
+    /// Evaluated within the application, without a network call
+    Internal,
 }
 
 impl fmt::Display for ApiMethod {
@@ -34,6 +38,7 @@ impl fmt::Display for ApiMethod {
         match self {
             Self::Grpc => write!(f, "Grpc"),
             Self::Rest(method) => write!(f, "Rest ({method})"),
+            Self::Internal => write!(f, "Internal"),
         }
     }
 }
@@ -0,0 +1,97 @@
+//! Eligibility checks of connectors and payment methods against the graph built from the merchant
+//! connector accounts, explaining why a value is not eligible whenever it is rejected.
+
+use euclid::{
+    dssa::graph::{AnalysisContext, CgraphExt},
+    frontend::dir,
+};
+use hyperswitch_constraint_graph as cgraph;
+
+use crate::error::KgraphError;
+
+#[derive(Debug, Clone)]
+pub enum Eligibility {
+    Eligible,
+    Ineligible(cgraph::Explanation<dir::DirValue>),
+}
+
+impl Eligibility {
+    pub fn is_eligible(&self) -> bool {
+        matches!(self, Self::Eligible)
+    }
+
+    pub fn explanation(&self) -> Option<&cgraph::Explanation<dir::DirValue>> {
+        match self {
+            Self::Eligible => None,
+            Self::Ineligible(explanation) => Some(explanation),
+        }
+    }
+}
+
+/// Checks whether a connector chosen by routing can process the payment described by the
+/// context. Connectors which are absent from the graph are not eligible.
+pub fn check_connector_eligibility(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    connector: dir::DirValue,
+    context: &AnalysisContext,
+    domains: Option<&[String]>,
+) -> Result<Eligibility, KgraphError> {
+    graph
+        .explain_value_validity(
+            connector,
+            context,
+            &mut cgraph::Memoization::new(),
+            &mut cgraph::CycleCheck::new(),
+            domains,
+        )
+        .map(|explanation| explanation.map_or(Eligibility::Eligible, Eligibility::Ineligible))
+        .map_err(KgraphError::GraphAnalysisError)
+}
+
+/// Same as [`check_connector_eligibility`], without building the explanation of a rejection
+pub fn is_connector_eligible(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    connector: dir::DirValue,
+    context: &AnalysisContext,
+    domains: Option<&[String]>,
+) -> Result<bool, KgraphError> {
+    graph
+        .check_value_validity(
+            connector,
+            context,
+            &mut cgraph::Memoization::new(),
+            &mut cgraph::CycleCheck::new(),
+            domains,
+        )
+        .map_err(KgraphError::GraphAnalysisError)
+}
+
+/// Checks whether a payment method can be offered for the payment described by the context.
+/// Both the key of the payment method and the payment method itself must be satisfied.
+pub fn check_payment_method_eligibility(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    payment_method: dir::DirValue,
+    context: &AnalysisContext,
+    domains: Option<&[String]>,
+) -> Result<Eligibility, KgraphError> {
+    // The trace of a failure lives in the memoization, which has to outlive the explanation
+    let mut memo = cgraph::Memoization::new();
+
+    match graph.key_value_analysis(
+        payment_method,
+        context,
+        &mut memo,
+        &mut cgraph::CycleCheck::new(),
+        domains,
+    ) {
+        Ok(()) => Ok(Eligibility::Eligible),
+        Err(error) => {
+            let trace = error
+                .get_analysis_trace()
+                .map_err(KgraphError::GraphAnalysisError)?;
+            Ok(Eligibility::Ineligible(cgraph::Explanation::from_trace(
+                &trace,
+            )))
+        }
+    }
+}
@@ -1377,6 +1377,39 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_ineligibility_explanation() {
+        let graph = build_test_data();
+
+        let eligibility = crate::eligibility::check_connector_eligibility(
+            &graph,
+            dirval!(Connector = Stripe),
+            &AnalysisContext::from_dir_values([
+                dirval!(Connector = Stripe),
+                dirval!(PaymentMethod = Card),
+                dirval!(CardType = Credit),
+                dirval!(CardNetwork = Visa),
+                dirval!(PaymentCurrency = USD),
+                dirval!(PaymentAmount = 101),
+            ]),
+            None,
+        )
+        .expect("eligibility check");
+
+        let explanation = eligibility
+            .explanation()
+            .expect("stripe should not be eligible");
+
+        // Only the credit card branch is explained, the debit card branch is not applicable
+        assert_eq!(explanation.causes.len(), 1);
+        let cause = explanation.causes.first().expect("cause");
+        assert_eq!(cause.info, Some("Accepted Currencies"));
+        assert_eq!(
+            cause.requirement.to_string(),
+            "payment_currency must be one of [INR], found USD"
+        );
+    }
+
     #[test]
     fn test_sandbox_applepay_bug_usecase() {
         let value = serde_json::json!([
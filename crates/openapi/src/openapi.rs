@@ -189,6 +189,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::blocklist::list_blocked_payment_methods,
         routes::blocklist::add_entry_to_blocklist,
         routes::blocklist::toggle_blocklist_guard,
+        routes::blocklist::import_blocklist_entries,
+        routes::blocklist::export_blocklist_entries,
 
         // Routes for payouts
         routes::payouts::payouts_create,
@@ -304,6 +306,10 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::payments::StripeChargeResponseData,
         common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule,
         common_types::three_ds_decision_rule_engine::ThreeDSDecision,
+        common_types::fraud_rule_engine::NativeFrmConfig,
+        common_types::fraud_rule_engine::NativeFrmFlowType,
+        common_types::fraud_rule_engine::FrmDecisionRule,
+        common_types::fraud_rule_engine::FrmRuleDecision,
         common_types::payments::MerchantCountryCode,
         common_types::payments::BillingDescriptor,
         common_types::payments::PartnerMerchantIdentifierDetails,
@@ -852,6 +858,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::RoutingConfigRequest,
         api_models::routing::RoutingDictionaryRecord,
         api_models::routing::RoutingKind,
+        api_models::routing::RoutingRollbackDetails,
+        api_models::routing::RoutingRollbackGuardrail,
         api_models::routing::RoutableConnectorChoice,
         api_models::routing::DynamicRoutingFeatures,
         api_models::routing::SuccessBasedRoutingConfig,
@@ -897,6 +905,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::payments::PaymentLinkStatus,
         api_models::blocklist::BlocklistRequest,
+        api_models::blocklist::AddToBlocklistRequest,
+        api_models::blocklist::BlocklistImportResponse,
+        api_models::blocklist::BlocklistImportFailure,
         api_models::blocklist::BlocklistResponse,
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
@@ -17,7 +17,7 @@ pub async fn toggle_blocklist_guard() {}
 #[utoipa::path(
     post,
     path = "/blocklist",
-    request_body = BlocklistRequest,
+    request_body = AddToBlocklistRequest,
     responses(
         (status = 200, description = "Fingerprint Blocked", body = BlocklistResponse),
         (status = 400, description = "Invalid Data")
@@ -57,3 +57,33 @@ pub async fn remove_entry_from_blocklist() {}
     security(("api_key" = []))
 )]
 pub async fn list_blocked_payment_methods() {}
+
+#[utoipa::path(
+    post,
+    path = "/blocklist/import",
+    request_body(content_type = "multipart/form-data", description = "A CSV `file` with the columns `data_kind`, `data` and `ttl_in_seconds`"),
+    responses(
+        (status = 200, description = "Blocklist entries imported", body = BlocklistImportResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Import blocklist entries",
+    security(("api_key" = []))
+)]
+pub async fn import_blocklist_entries() {}
+
+#[utoipa::path(
+    get,
+    path = "/blocklist/export",
+    params (
+        ("data_kind" = Option<BlocklistDataKind>, Query, description = "Kind of the entries to export, all the entries are exported when not provided"),
+    ),
+    responses(
+        (status = 200, description = "A CSV file with the columns `data_kind`, `data`, `created_at` and `expires_at`", content_type = "text/csv"),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Export blocklist entries",
+    security(("api_key" = []))
+)]
+pub async fn export_blocklist_entries() {}
@@ -13,21 +13,19 @@ use common_utils::{
 };
 use error_stack::{report, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, ListInterface, SetsInterface, StreamsInterface},
-    prelude::{LuaInterface, RedisErrorKind},
+    prelude::RedisErrorKind,
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
-        MultipleValues, RedisMap, RedisValue, ScanType, Scanner, SetOptions, XCap, XReadResponse,
+        MultipleValues, RedisMap, RedisValue, ScanType, SetOptions, XReadResponse,
     },
 };
-use futures::StreamExt;
 use tracing::instrument;
 
 use crate::{
     errors,
     types::{
         DelReply, HsetnxReply, MsetnxReply, RedisEntryId, RedisKey, SaddReply, SetGetReply,
-        SetnxReply,
+        SetnxReply, StreamCapKind, StreamCapTrim,
     },
 };
 
@@ -52,7 +50,6 @@ impl super::RedisConnectionPool {
                 value,
                 Some(Expiration::EX(self.config.default_ttl.into())),
                 None,
-                false,
             )
             .await
             .change_context(errors::RedisError::SetFailed)
@@ -73,7 +70,6 @@ impl super::RedisConnectionPool {
                 value,
                 Some(Expiration::KEEPTTL),
                 None,
-                false,
             )
             .await
             .change_context(errors::RedisError::SetFailed)
@@ -163,7 +159,6 @@ impl super::RedisConnectionPool {
                 serialized.as_slice(),
                 Some(Expiration::EX(seconds)),
                 None,
-                false,
             )
             .await
             .change_context(errors::RedisError::SetExFailed)
@@ -198,6 +193,40 @@ impl super::RedisConnectionPool {
         }
     }
 
+    /// Reads the key from a replica if `read_from_replicas` is enabled, for the readers which can
+    /// tolerate not observing the latest writes
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_key_from_replica<V>(
+        &self,
+        key: &RedisKey,
+    ) -> CustomResult<V, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        match self
+            .pool
+            .get_from_replica(key.tenant_aware_key(self))
+            .await
+            .change_context(errors::RedisError::GetFailed)
+        {
+            Ok(v) => Ok(v),
+            Err(_err) => {
+                #[cfg(not(feature = "multitenancy_fallback"))]
+                {
+                    Err(_err)
+                }
+
+                #[cfg(feature = "multitenancy_fallback")]
+                {
+                    self.pool
+                        .get_from_replica(key.tenant_unaware_key(self))
+                        .await
+                        .change_context(errors::RedisError::GetFailed)
+                }
+            }
+        }
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     async fn get_multiple_keys_with_mget<V>(
         &self,
@@ -430,7 +459,6 @@ impl super::RedisConnectionPool {
                 value,
                 Some(Expiration::EX(seconds)),
                 None,
-                false,
             )
             .await
             .change_context(errors::RedisError::SetExFailed)
@@ -455,7 +483,6 @@ impl super::RedisConnectionPool {
                     seconds.unwrap_or(self.config.default_ttl.into()),
                 )),
                 Some(SetOptions::NX),
-                false,
             )
             .await
             .change_context(errors::RedisError::SetFailed)
@@ -614,25 +641,22 @@ impl super::RedisConnectionPool {
     ) -> CustomResult<Vec<String>, errors::RedisError> {
         Ok(self
             .pool
-            .next()
-            .hscan::<&str, &str>(&key.tenant_aware_key(self), pattern, count)
-            .filter_map(|value| async move {
-                match value {
-                    Ok(mut v) => {
-                        let v = v.take_results()?;
-
-                        let v: Vec<String> =
-                            v.iter().filter_map(|(_, val)| val.as_string()).collect();
-                        Some(futures::stream::iter(v))
-                    }
-                    Err(err) => {
-                        tracing::error!(redis_err=?err, "Redis error while executing hscan command");
-                        None
-                    }
-                }
-            })
-            .flatten()
-            .collect::<Vec<_>>()
+            .hscan(key.tenant_aware_key(self), pattern, count)
+            .await)
+    }
+
+    /// Scans the hash on a replica if `read_from_replicas` is enabled, for the readers which can
+    /// tolerate not observing the latest writes
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn hscan_from_replica(
+        &self,
+        key: &RedisKey,
+        pattern: &str,
+        count: Option<u32>,
+    ) -> CustomResult<Vec<String>, errors::RedisError> {
+        Ok(self
+            .pool
+            .hscan_from_replica(key.tenant_aware_key(self), pattern, count)
             .await)
     }
 
@@ -645,25 +669,28 @@ impl super::RedisConnectionPool {
     ) -> CustomResult<Vec<String>, errors::RedisError> {
         Ok(self
             .pool
-            .next()
-            .scan(pattern.tenant_aware_key(self), count, scan_type)
-            .filter_map(|value| async move {
-                match value {
-                    Ok(mut v) => {
-                        let v = v.take_results()?;
-
-                        let v: Vec<String> =
-                            v.into_iter().filter_map(|val| val.into_string()).collect();
-                        Some(futures::stream::iter(v))
-                    }
-                    Err(err) => {
-                        tracing::error!(redis_err=?err, "Redis error while executing scan command");
-                        None
-                    }
-                }
-            })
-            .flatten()
-            .collect::<Vec<_>>()
+            .scan(pattern.tenant_aware_key(self), count, scan_type, None)
+            .await)
+    }
+
+    /// Scans the keys matching the pattern until `limit` keys are found, instead of iterating over
+    /// the whole keyspace
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn scan_with_limit(
+        &self,
+        pattern: &RedisKey,
+        count: Option<u32>,
+        scan_type: Option<ScanType>,
+        limit: usize,
+    ) -> CustomResult<Vec<String>, errors::RedisError> {
+        Ok(self
+            .pool
+            .scan(
+                pattern.tenant_aware_key(self),
+                count,
+                scan_type,
+                Some(limit),
+            )
             .await)
     }
 
@@ -720,6 +747,41 @@ impl super::RedisConnectionPool {
         }
     }
 
+    /// Reads the hash field from a replica if `read_from_replicas` is enabled, for the readers
+    /// which can tolerate not observing the latest writes
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_hash_field_from_replica<V>(
+        &self,
+        key: &RedisKey,
+        field: &str,
+    ) -> CustomResult<V, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        match self
+            .pool
+            .hget_from_replica(key.tenant_aware_key(self), field)
+            .await
+            .change_context(errors::RedisError::GetHashFieldFailed)
+        {
+            Ok(v) => Ok(v),
+            Err(_err) => {
+                #[cfg(feature = "multitenancy_fallback")]
+                {
+                    self.pool
+                        .hget_from_replica(key.tenant_unaware_key(self), field)
+                        .await
+                        .change_context(errors::RedisError::GetHashFieldFailed)
+                }
+
+                #[cfg(not(feature = "multitenancy_fallback"))]
+                {
+                    Err(_err)
+                }
+            }
+        }
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_hash_fields<V>(&self, key: &RedisKey) -> CustomResult<V, errors::RedisError>
     where
@@ -770,6 +832,18 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::JsonDeserializationFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_hash_field(
+        &self,
+        key: &RedisKey,
+        field: &str,
+    ) -> CustomResult<usize, errors::RedisError> {
+        self.pool
+            .hdel(key.tenant_aware_key(self), field)
+            .await
+            .change_context(errors::RedisError::DeleteHashFieldFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn sadd<V>(
         &self,
@@ -798,7 +872,7 @@ impl super::RedisConnectionPool {
         F::Error: Into<fred::error::RedisError> + Send + Sync,
     {
         self.pool
-            .xadd(stream.tenant_aware_key(self), false, None, entry_id, fields)
+            .xadd(stream.tenant_aware_key(self), entry_id, fields)
             .await
             .change_context(errors::RedisError::StreamAppendFailed)
     }
@@ -819,17 +893,19 @@ impl super::RedisConnectionPool {
     }
 
     #[instrument(level = "DEBUG", skip(self))]
-    pub async fn stream_trim_entries<C>(
+    pub async fn stream_trim_entries<T>(
         &self,
         stream: &RedisKey,
-        xcap: C,
+        (kind, trim, threshold): (StreamCapKind, StreamCapTrim, T),
     ) -> CustomResult<usize, errors::RedisError>
     where
-        C: TryInto<XCap> + Debug + Send + Sync,
-        C::Error: Into<fred::error::RedisError> + Send + Sync,
+        T: Into<String> + Debug + Send + Sync,
     {
         self.pool
-            .xtrim(stream.tenant_aware_key(self), xcap)
+            .xtrim(
+                stream.tenant_aware_key(self),
+                (kind, trim, threshold.into()),
+            )
             .await
             .change_context(errors::RedisError::StreamTrimFailed)
     }
@@ -904,6 +980,36 @@ impl super::RedisConnectionPool {
             })
     }
 
+    /// Reads the streams from a replica if `read_from_replicas` is enabled, for the readers which
+    /// can tolerate not observing the latest entries
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_read_entries_from_replica<K, Ids>(
+        &self,
+        streams: K,
+        ids: Ids,
+        read_count: Option<u64>,
+    ) -> CustomResult<XReadResponse<String, String, String, String>, errors::RedisError>
+    where
+        K: Into<MultipleKeys> + Debug + Send + Sync,
+        Ids: Into<MultipleIDs> + Debug + Send + Sync,
+    {
+        let strms = self.get_keys_with_prefix(streams);
+        self.pool
+            .xread_map_from_replica(
+                Some(read_count.unwrap_or(self.config.default_stream_read_count)),
+                None,
+                strms,
+                ids,
+            )
+            .await
+            .map_err(|err| match err.kind() {
+                RedisErrorKind::NotFound | RedisErrorKind::Parse => {
+                    report!(err).change_context(errors::RedisError::StreamEmptyOrNotAvailable)
+                }
+                _ => report!(err).change_context(errors::RedisError::StreamReadFailed),
+            })
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_read_with_options<K, Ids>(
         &self,
@@ -925,7 +1031,6 @@ impl super::RedisConnectionPool {
                         consumer_name,
                         count,
                         block,
-                        false,
                         self.get_keys_with_prefix(streams),
                         ids,
                     )
@@ -1076,11 +1181,6 @@ impl super::RedisConnectionPool {
                 consumer,
                 min_idle_time,
                 ids,
-                None,
-                None,
-                None,
-                false,
-                false,
             )
             .await
             .change_context(errors::RedisError::ConsumerGroupClaimFailed)
@@ -1149,30 +1249,10 @@ impl super::RedisConnectionPool {
         let redis_key = key.tenant_aware_key(self);
         let ttl_seconds = ttl.unwrap_or(self.config.default_ttl.into());
 
-        // Get a client from the pool and start transaction
-        let trx = self.get_transaction();
-
-        // Try to set if not exists with expiry - queue the command
-        trx.set::<(), _, _>(
-            &redis_key,
-            value,
-            Some(Expiration::EX(ttl_seconds)),
-            Some(SetOptions::NX),
-            false,
-        )
-        .await
-        .change_context(errors::RedisError::SetFailed)
-        .attach_printable("Failed to queue set command")?;
-
-        // Always get the value after the SET attempt - queue the command
-        trx.get::<V, _>(&redis_key)
-            .await
-            .change_context(errors::RedisError::GetFailed)
-            .attach_printable("Failed to queue get command")?;
-
-        // Execute transaction
-        let mut results: Vec<RedisValue> = trx
-            .exec(true)
+        // Set if not exists with expiry, and always get the value after the SET attempt
+        let mut results: Vec<RedisValue> = self
+            .pool
+            .set_if_not_exists_and_get(redis_key, value, ttl_seconds)
             .await
             .change_context(errors::RedisError::SetFailed)
             .attach_printable("Failed to execute the redis transaction")?;
@@ -0,0 +1,1609 @@
+//! An in-process stand-in for a Redis server, so that code depending on Redis can be tested
+//! without a live server.
+//!
+//! Commands are applied to a single keyspace guarded by a mutex and reply with the values Redis
+//! would reply with, so that the same `FromRedis` conversions apply to both backends. Blocking
+//! reads return immediately, and Lua scripts cannot be evaluated: a handler emulating the script
+//! has to be registered with [`InMemoryRedis::register_script`] instead.
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    sync::{Arc, Mutex, MutexGuard, PoisonError, Weak},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use fred::{
+    error::{RedisError, RedisErrorKind},
+    types::{
+        Expiration, FromRedis, Message, MessageKind, MultipleIDs, MultipleKeys,
+        MultipleOrderedPairs, MultipleStrings, MultipleValues, RedisKey, RedisMap, RedisValue,
+        ScanType, Server, SetOptions, XReadResponse, XID,
+    },
+};
+use tokio::sync::broadcast;
+
+use crate::types::{StreamCapKind, StreamCapTrim};
+
+type RedisResult<T> = Result<T, RedisError>;
+
+/// Emulates a Lua script, receiving the keys and arguments the script was evaluated with
+pub type ScriptHandler = Arc<
+    dyn Fn(&InMemoryRedis, Vec<String>, Vec<RedisValue>) -> RedisResult<RedisValue> + Send + Sync,
+>;
+
+#[derive(Default)]
+pub struct InMemoryRedis {
+    keyspace: Mutex<Keyspace>,
+    scripts: Mutex<HashMap<String, ScriptHandler>>,
+    subscriptions: Mutex<Vec<Weak<Subscription>>>,
+}
+
+/// Channels a subscriber client is subscribed to, and the sender of the messages published to
+/// them
+pub struct Subscription {
+    channels: Mutex<HashSet<String>>,
+    messages: broadcast::Sender<Message>,
+}
+
+#[derive(Default)]
+struct Keyspace {
+    entries: HashMap<String, Entry>,
+}
+
+struct Entry {
+    value: Value,
+    expires_at: Option<Instant>,
+}
+
+enum Value {
+    String(Vec<u8>),
+    Hash(HashMap<String, Vec<u8>>),
+    List(VecDeque<Vec<u8>>),
+    Set(HashSet<Vec<u8>>),
+    Stream(Stream),
+}
+
+#[derive(Default)]
+struct Stream {
+    entries: BTreeMap<StreamId, Vec<(String, Vec<u8>)>>,
+    last_id: StreamId,
+    groups: HashMap<String, ConsumerGroup>,
+}
+
+struct ConsumerGroup {
+    last_delivered_id: StreamId,
+    pending: BTreeMap<StreamId, PendingEntry>,
+    consumers: HashSet<String>,
+}
+
+struct PendingEntry {
+    consumer: String,
+    delivered_at: Instant,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+struct StreamId {
+    milliseconds: u64,
+    sequence_number: u64,
+}
+
+/// Position in a stream after which entries are read
+enum ReadFrom {
+    After(StreamId),
+    /// `$`, only entries appended after the read
+    LastEntry,
+    /// `>`, entries not yet delivered to the consumer group
+    Undelivered,
+}
+
+impl StreamId {
+    fn parse(id: &str) -> RedisResult<Self> {
+        let (milliseconds, sequence_number) = id.split_once('-').unwrap_or((id, "0"));
+
+        milliseconds
+            .parse::<u64>()
+            .ok()
+            .zip(sequence_number.parse::<u64>().ok())
+            .map(|(milliseconds, sequence_number)| Self {
+                milliseconds,
+                sequence_number,
+            })
+            .ok_or_else(|| {
+                error(
+                    RedisErrorKind::InvalidArgument,
+                    "ERR Invalid stream ID specified as stream command argument",
+                )
+            })
+    }
+
+    fn next_after(last_id: Self) -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+            .unwrap_or_default();
+
+        if now > last_id.milliseconds {
+            Self {
+                milliseconds: now,
+                sequence_number: 0,
+            }
+        } else {
+            Self {
+                milliseconds: last_id.milliseconds,
+                sequence_number: last_id.sequence_number.saturating_add(1),
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for StreamId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.milliseconds, self.sequence_number)
+    }
+}
+
+impl ReadFrom {
+    fn parse(id: &XID) -> RedisResult<Self> {
+        match id {
+            XID::Max => Ok(Self::LastEntry),
+            XID::NewInGroup => Ok(Self::Undelivered),
+            XID::Manual(id) => match &**id {
+                "$" => Ok(Self::LastEntry),
+                ">" => Ok(Self::Undelivered),
+                id => StreamId::parse(id).map(Self::After),
+            },
+            XID::Auto => Err(error(
+                RedisErrorKind::InvalidArgument,
+                "ERR Invalid stream ID specified as stream command argument",
+            )),
+        }
+    }
+}
+
+impl Entry {
+    fn new(value: Value) -> Self {
+        Self {
+            value,
+            expires_at: None,
+        }
+    }
+
+    fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= Instant::now())
+    }
+}
+
+impl Value {
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::Hash(hash) => hash.is_empty(),
+            Self::List(list) => list.is_empty(),
+            Self::Set(set) => set.is_empty(),
+            // Strings and streams are not removed when they become empty
+            Self::String(_) | Self::Stream(_) => false,
+        }
+    }
+}
+
+/// Generates the accessors of a kind of value, failing with `WRONGTYPE` if the key holds another
+/// kind of value
+macro_rules! value_accessor {
+    (get $name:ident, $variant:ident, $type:ty) => {
+        fn $name(&mut self, key: &str) -> RedisResult<Option<&mut $type>> {
+            match self.get(key) {
+                None => Ok(None),
+                Some(Entry {
+                    value: Value::$variant(value),
+                    ..
+                }) => Ok(Some(value)),
+                Some(_) => Err(wrong_type()),
+            }
+        }
+    };
+    (get_or_create $name:ident, $variant:ident, $type:ty) => {
+        fn $name(&mut self, key: &str) -> RedisResult<&mut $type> {
+            match self.get_or_insert(key, || Value::$variant(Default::default())) {
+                Entry {
+                    value: Value::$variant(value),
+                    ..
+                } => Ok(value),
+                _ => Err(wrong_type()),
+            }
+        }
+    };
+}
+
+impl Keyspace {
+    /// Keys are expired lazily, when they are accessed
+    fn get(&mut self, key: &str) -> Option<&mut Entry> {
+        if self.entries.get(key).is_some_and(Entry::is_expired) {
+            self.entries.remove(key);
+        }
+        self.entries.get_mut(key)
+    }
+
+    fn get_or_insert(&mut self, key: &str, value: impl FnOnce() -> Value) -> &mut Entry {
+        if self.entries.get(key).is_some_and(Entry::is_expired) {
+            self.entries.remove(key);
+        }
+        self.entries
+            .entry(key.to_owned())
+            .or_insert_with(|| Entry::new(value()))
+    }
+
+    fn contains(&mut self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    fn remove_if_empty(&mut self, key: &str) {
+        if self
+            .entries
+            .get(key)
+            .is_some_and(|entry| entry.value.is_empty())
+        {
+            self.entries.remove(key);
+        }
+    }
+
+    value_accessor!(get string, String, Vec<u8>);
+    value_accessor!(get hash, Hash, HashMap<String, Vec<u8>>);
+    value_accessor!(get_or_create hash_or_create, Hash, HashMap<String, Vec<u8>>);
+    value_accessor!(get list, List, VecDeque<Vec<u8>>);
+    value_accessor!(get_or_create list_or_create, List, VecDeque<Vec<u8>>);
+    value_accessor!(get_or_create set_or_create, Set, HashSet<Vec<u8>>);
+    value_accessor!(get stream, Stream, Stream);
+    value_accessor!(get_or_create stream_or_create, Stream, Stream);
+
+    fn consumer_group(&mut self, key: &str, group: &str) -> RedisResult<&mut ConsumerGroup> {
+        self.stream(key)?
+            .and_then(|stream| stream.groups.get_mut(group))
+            .ok_or_else(|| no_group(key, group))
+    }
+
+    fn set_string(&mut self, key: &str, value: Vec<u8>, expiration: Option<Expiration>) {
+        let expires_at = match expiration {
+            Some(Expiration::KEEPTTL) => self.get(key).and_then(|entry| entry.expires_at),
+            Some(expiration) => get_expires_at(expiration),
+            None => None,
+        };
+
+        self.entries.insert(
+            key.to_owned(),
+            Entry {
+                value: Value::String(value),
+                expires_at,
+            },
+        );
+    }
+}
+
+impl InMemoryRedis {
+    fn keyspace(&self) -> MutexGuard<'_, Keyspace> {
+        lock(&self.keyspace)
+    }
+
+    /// Registers the handler emulating the Lua script, scripts are identified by their source
+    pub fn register_script(&self, lua_script: &str, handler: ScriptHandler) {
+        lock(&self.scripts).insert(lua_script.to_owned(), handler);
+    }
+
+    /// Creates the subscription of a subscriber client, which receives the messages published to
+    /// the channels it subscribes to
+    pub fn subscription(&self, capacity: usize) -> Arc<Subscription> {
+        let (messages, _) = broadcast::channel(capacity.max(1));
+        let subscription = Arc::new(Subscription {
+            channels: Mutex::default(),
+            messages,
+        });
+
+        lock(&self.subscriptions).push(Arc::downgrade(&subscription));
+        subscription
+    }
+
+    /// Publishes the message to the subscriptions of the channel, returning their count
+    pub fn publish(&self, channel: &str, value: RedisValue) -> usize {
+        let mut subscriptions = lock(&self.subscriptions);
+        subscriptions.retain(|subscription| subscription.strong_count() > 0);
+
+        let subscribers = subscriptions
+            .iter()
+            .filter_map(Weak::upgrade)
+            .filter(|subscription| lock(&subscription.channels).contains(channel))
+            .collect::<Vec<_>>();
+
+        for subscriber in &subscribers {
+            // Sending fails only if nobody is listening to the messages of the subscription
+            let _ = subscriber.messages.send(Message {
+                channel: channel.into(),
+                value: value.clone(),
+                kind: MessageKind::Message,
+                server: Server::new("in-memory", 0),
+            });
+        }
+        subscribers.len()
+    }
+
+    pub fn set<V>(
+        &self,
+        key: &str,
+        value: V,
+        expiration: Option<Expiration>,
+        options: Option<SetOptions>,
+    ) -> RedisResult<RedisValue>
+    where
+        V: TryInto<RedisValue>,
+        V::Error: Into<RedisError>,
+    {
+        let value = to_bytes(value)?;
+        let mut keyspace = self.keyspace();
+
+        let should_set = match options {
+            Some(SetOptions::NX) => !keyspace.contains(key),
+            Some(SetOptions::XX) => keyspace.contains(key),
+            None => true,
+        };
+        if !should_set {
+            return Ok(RedisValue::Null);
+        }
+
+        keyspace.set_string(key, value, expiration);
+        Ok(ok())
+    }
+
+    /// `SET key value EX ttl NX` followed by `GET key`, as a transaction
+    pub fn set_if_not_exists_and_get<V>(
+        &self,
+        key: &str,
+        value: V,
+        ttl_in_seconds: i64,
+    ) -> RedisResult<Vec<RedisValue>>
+    where
+        V: TryInto<RedisValue>,
+        V::Error: Into<RedisError>,
+    {
+        let value = to_bytes(value)?;
+        let mut keyspace = self.keyspace();
+
+        let set_result = if keyspace.contains(key) {
+            RedisValue::Null
+        } else {
+            keyspace.set_string(key, value, Some(Expiration::EX(ttl_in_seconds)));
+            ok()
+        };
+        let get_result = keyspace
+            .string(key)?
+            .map_or(RedisValue::Null, |value| to_value(value));
+
+        Ok(vec![set_result, get_result])
+    }
+
+    pub fn msetnx<V>(&self, values: V) -> RedisResult<RedisValue>
+    where
+        V: TryInto<RedisMap>,
+        V::Error: Into<RedisError>,
+    {
+        let values = to_pairs(values)?;
+        let mut keyspace = self.keyspace();
+
+        if values.iter().any(|(key, _)| keyspace.contains(key)) {
+            return Ok(RedisValue::Integer(0));
+        }
+        for (key, value) in values {
+            keyspace.set_string(&key, value, None);
+        }
+        Ok(RedisValue::Integer(1))
+    }
+
+    pub fn get(&self, key: &str) -> RedisResult<RedisValue> {
+        Ok(self
+            .keyspace()
+            .string(key)?
+            .map_or(RedisValue::Null, |value| to_value(value)))
+    }
+
+    pub fn mget(&self, keys: &[String]) -> RedisResult<RedisValue> {
+        let mut keyspace = self.keyspace();
+
+        Ok(RedisValue::Array(
+            keys.iter()
+                .map(|key| match keyspace.get(key) {
+                    Some(Entry {
+                        value: Value::String(value),
+                        ..
+                    }) => to_value(value),
+                    _ => RedisValue::Null,
+                })
+                .collect(),
+        ))
+    }
+
+    pub fn exists(&self, key: &str) -> RedisResult<RedisValue> {
+        Ok(integer(usize::from(self.keyspace().contains(key))))
+    }
+
+    pub fn del(&self, key: &str) -> RedisResult<RedisValue> {
+        let mut keyspace = self.keyspace();
+        let deleted = keyspace.contains(key) && keyspace.entries.remove(key).is_some();
+
+        Ok(integer(usize::from(deleted)))
+    }
+
+    pub fn expire(&self, key: &str, seconds: i64) -> RedisResult<RedisValue> {
+        self.set_expiration(key, Expiration::EX(seconds))
+    }
+
+    pub fn expire_at(&self, key: &str, timestamp: i64) -> RedisResult<RedisValue> {
+        self.set_expiration(key, Expiration::EXAT(timestamp))
+    }
+
+    fn set_expiration(&self, key: &str, expiration: Expiration) -> RedisResult<RedisValue> {
+        let mut keyspace = self.keyspace();
+        let Some(entry) = keyspace.get(key) else {
+            return Ok(RedisValue::Integer(0));
+        };
+
+        entry.expires_at = get_expires_at(expiration);
+        // A key with an expiry in the past is deleted right away
+        if entry.is_expired() {
+            keyspace.entries.remove(key);
+        }
+        Ok(RedisValue::Integer(1))
+    }
+
+    pub fn ttl(&self, key: &str) -> RedisResult<RedisValue> {
+        Ok(RedisValue::Integer(match self.keyspace().get(key) {
+            None => -2,
+            Some(Entry {
+                expires_at: None, ..
+            }) => -1,
+            Some(Entry {
+                expires_at: Some(expires_at),
+                ..
+            }) => {
+                let remaining = expires_at.saturating_duration_since(Instant::now());
+                // Rounded to the nearest second, as Redis does
+                i64::try_from(remaining.as_millis().saturating_add(500) / 1000).unwrap_or(i64::MAX)
+            }
+        }))
+    }
+
+    pub fn hset<V>(&self, key: &str, values: V) -> RedisResult<RedisValue>
+    where
+        V: TryInto<RedisMap>,
+        V::Error: Into<RedisError>,
+    {
+        let values = to_pairs(values)?;
+        let mut keyspace = self.keyspace();
+        let hash = keyspace.hash_or_create(key)?;
+
+        let added = values
+            .into_iter()
+            .map(|(field, value)| hash.insert(field, value))
+            .filter(Option::is_none)
+            .count();
+        Ok(integer(added))
+    }
+
+    pub fn hsetnx<V>(&self, key: &str, field: &str, value: V) -> RedisResult<RedisValue>
+    where
+        V: TryInto<RedisValue>,
+        V::Error: Into<RedisError>,
+    {
+        let value = to_bytes(value)?;
+        let mut keyspace = self.keyspace();
+        let hash = keyspace.hash_or_create(key)?;
+
+        if hash.contains_key(field) {
+            return Ok(RedisValue::Integer(0));
+        }
+        hash.insert(field.to_owned(), value);
+        Ok(RedisValue::Integer(1))
+    }
+
+    pub fn hincrby(&self, key: &str, field: &str, increment: i64) -> RedisResult<RedisValue> {
+        let mut keyspace = self.keyspace();
+        let hash = keyspace.hash_or_create(key)?;
+
+        let current = hash
+            .get(field)
+            .map(|value| {
+                std::str::from_utf8(value)
+                    .ok()
+                    .and_then(|value| value.parse::<i64>().ok())
+                    .ok_or_else(|| {
+                        error(
+                            RedisErrorKind::InvalidArgument,
+                            "ERR hash value is not an integer",
+                        )
+                    })
+            })
+            .transpose()?
+            .unwrap_or_default();
+        let updated = current.checked_add(increment).ok_or_else(|| {
+            error(
+                RedisErrorKind::InvalidArgument,
+                "ERR increment or decrement would overflow",
+            )
+        })?;
+
+        hash.insert(field.to_owned(), updated.to_string().into_bytes());
+        Ok(RedisValue::Integer(updated))
+    }
+
+    pub fn hget(&self, key: &str, field: &str) -> RedisResult<RedisValue> {
+        Ok(self
+            .keyspace()
+            .hash(key)?
+            .and_then(|hash| hash.get(field))
+            .map_or(RedisValue::Null, |value| to_value(value)))
+    }
+
+    pub fn hgetall(&self, key: &str) -> RedisResult<RedisValue> {
+        Ok(RedisValue::Array(
+            self.keyspace()
+                .hash(key)?
+                .map(|hash| {
+                    hash.iter()
+                        .flat_map(|(field, value)| {
+                            [RedisValue::from(field.as_str()), to_value(value)]
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        ))
+    }
+
+    pub fn hdel(&self, key: &str, field: &str) -> RedisResult<RedisValue> {
+        let mut keyspace = self.keyspace();
+        let deleted = keyspace
+            .hash(key)?
+            .is_some_and(|hash| hash.remove(field).is_some());
+        keyspace.remove_if_empty(key);
+
+        Ok(integer(usize::from(deleted)))
+    }
+
+    /// Values of the fields of the hash matching the pattern
+    pub fn hscan(&self, key: &str, pattern: &str) -> RedisResult<Vec<String>> {
+        Ok(self
+            .keyspace()
+            .hash(key)?
+            .map(|hash| {
+                hash.iter()
+                    .filter(|(field, _)| glob_match(pattern.as_bytes(), field.as_bytes()))
+                    .filter_map(|(_, value)| String::from_utf8(value.clone()).ok())
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Keys matching the pattern, optionally only the ones holding the given kind of value
+    pub fn scan(&self, pattern: &str, scan_type: Option<ScanType>) -> Vec<String> {
+        let mut keyspace = self.keyspace();
+        keyspace.entries.retain(|_, entry| !entry.is_expired());
+
+        keyspace
+            .entries
+            .iter()
+            .filter(|(key, entry)| {
+                glob_match(pattern.as_bytes(), key.as_bytes())
+                    && scan_type.as_ref().is_none_or(|scan_type| {
+                        matches!(
+                            (&entry.value, scan_type),
+                            (Value::String(_), ScanType::String)
+                                | (Value::Hash(_), ScanType::Hash)
+                                | (Value::List(_), ScanType::List)
+                                | (Value::Set(_), ScanType::Set)
+                                | (Value::Stream(_), ScanType::Stream)
+                        )
+                    })
+            })
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    pub fn sadd<V>(&self, key: &str, members: V) -> RedisResult<RedisValue>
+    where
+        V: TryInto<MultipleValues>,
+        V::Error: Into<RedisError>,
+    {
+        let members = to_multiple_bytes(members)?;
+        let mut keyspace = self.keyspace();
+        let set = keyspace.set_or_create(key)?;
+
+        let added = members
+            .into_iter()
+            .map(|member| set.insert(member))
+            .filter(|inserted| *inserted)
+            .count();
+        Ok(integer(added))
+    }
+
+    pub fn rpush<V>(&self, key: &str, elements: V) -> RedisResult<RedisValue>
+    where
+        V: TryInto<MultipleValues>,
+        V::Error: Into<RedisError>,
+    {
+        let elements = to_multiple_bytes(elements)?;
+        let mut keyspace = self.keyspace();
+        let list = keyspace.list_or_create(key)?;
+
+        list.extend(elements);
+        Ok(integer(list.len()))
+    }
+
+    pub fn lrange(&self, key: &str, start: i64, stop: i64) -> RedisResult<RedisValue> {
+        let mut keyspace = self.keyspace();
+        let Some(list) = keyspace.list(key)? else {
+            return Ok(RedisValue::Array(Vec::new()));
+        };
+
+        let length = i64::try_from(list.len()).unwrap_or(i64::MAX);
+        let resolve = |index: i64| if index < 0 { length + index } else { index };
+        let start = usize::try_from(resolve(start).max(0)).unwrap_or(usize::MAX);
+        let stop = usize::try_from(resolve(stop).min(length - 1)).ok();
+
+        Ok(RedisValue::Array(
+            stop.filter(|stop| start <= *stop)
+                .map(|stop| {
+                    list.iter()
+                        .skip(start)
+                        .take(stop - start + 1)
+                        .map(|element| to_value(element))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        ))
+    }
+
+    pub fn llen(&self, key: &str) -> RedisResult<RedisValue> {
+        Ok(integer(
+            self.keyspace().list(key)?.map_or(0, |list| list.len()),
+        ))
+    }
+
+    pub fn lpop(&self, key: &str, count: Option<usize>) -> RedisResult<RedisValue> {
+        let mut keyspace = self.keyspace();
+        let Some(list) = keyspace.list(key)? else {
+            return Ok(RedisValue::Null);
+        };
+
+        let popped = list
+            .drain(..count.unwrap_or(1).min(list.len()))
+            .map(|element| to_value(&element))
+            .collect::<Vec<_>>();
+        keyspace.remove_if_empty(key);
+
+        // A single element is returned as is when the count is not specified
+        Ok(match count {
+            Some(_) => RedisValue::Array(popped),
+            None => popped.into_iter().next().unwrap_or(RedisValue::Null),
+        })
+    }
+
+    pub fn xadd<F>(&self, key: &str, id: &XID, fields: F) -> RedisResult<RedisValue>
+    where
+        F: TryInto<MultipleOrderedPairs>,
+        F::Error: Into<RedisError>,
+    {
+        let fields = fields
+            .try_into()
+            .map_err(Into::<RedisError>::into)?
+            .inner()
+            .iter()
+            .map(|(field, value)| {
+                to_bytes(value.clone()).map(|value| (key_to_string(field), value))
+            })
+            .collect::<RedisResult<Vec<_>>>()?;
+        let mut keyspace = self.keyspace();
+        let stream = keyspace.stream_or_create(key)?;
+
+        let id = match id {
+            XID::Auto => StreamId::next_after(stream.last_id),
+            XID::Manual(id) if &**id == "*" => StreamId::next_after(stream.last_id),
+            XID::Manual(id) => StreamId::parse(id)?,
+            XID::Max | XID::NewInGroup => {
+                return Err(error(
+                    RedisErrorKind::InvalidArgument,
+                    "ERR Invalid stream ID specified as stream command argument",
+                ))
+            }
+        };
+        if id <= stream.last_id {
+            return Err(error(
+                RedisErrorKind::InvalidArgument,
+                "ERR The ID specified in XADD is equal or smaller than the target stream top item",
+            ));
+        }
+
+        stream.entries.insert(id, fields);
+        stream.last_id = id;
+        Ok(RedisValue::from(id.to_string()))
+    }
+
+    pub fn xdel<I>(&self, key: &str, ids: I) -> RedisResult<RedisValue>
+    where
+        I: Into<MultipleStrings>,
+    {
+        let ids = ids
+            .into()
+            .inner()
+            .iter()
+            .map(|id| StreamId::parse(id))
+            .collect::<RedisResult<Vec<_>>>()?;
+        let mut keyspace = self.keyspace();
+        let Some(stream) = keyspace.stream(key)? else {
+            return Ok(RedisValue::Integer(0));
+        };
+
+        let deleted = ids
+            .iter()
+            .filter(|id| stream.entries.remove(id).is_some())
+            .count();
+        Ok(integer(deleted))
+    }
+
+    pub fn xtrim(
+        &self,
+        key: &str,
+        (kind, _trim, threshold): (StreamCapKind, StreamCapTrim, String),
+    ) -> RedisResult<RedisValue> {
+        let mut keyspace = self.keyspace();
+        let Some(stream) = keyspace.stream(key)? else {
+            return Ok(RedisValue::Integer(0));
+        };
+
+        // Trimming is always exact, which is allowed for approximate trimming as well
+        let retained = match kind {
+            StreamCapKind::MinID => stream.entries.split_off(&StreamId::parse(&threshold)?),
+            StreamCapKind::MaxLen => {
+                let max_length = threshold.parse::<usize>().map_err(|_| {
+                    error(
+                        RedisErrorKind::InvalidArgument,
+                        "ERR value is not an integer or out of range",
+                    )
+                })?;
+                let trimmed = stream.entries.len().saturating_sub(max_length);
+                match stream.entries.keys().nth(trimmed).copied() {
+                    Some(first_retained_id) => stream.entries.split_off(&first_retained_id),
+                    None => BTreeMap::new(),
+                }
+            }
+        };
+
+        let trimmed = std::mem::replace(&mut stream.entries, retained).len();
+        Ok(integer(trimmed))
+    }
+
+    pub fn xlen(&self, key: &str) -> RedisResult<RedisValue> {
+        Ok(integer(
+            self.keyspace()
+                .stream(key)?
+                .map_or(0, |stream| stream.entries.len()),
+        ))
+    }
+
+    pub fn xread<V>(
+        &self,
+        count: Option<u64>,
+        keys: &MultipleKeys,
+        ids: MultipleIDs,
+    ) -> RedisResult<XReadResponse<String, String, String, V>>
+    where
+        V: FromRedis,
+    {
+        let count = to_count(count);
+        let reads = zip_streams(keys, ids)?;
+        let mut keyspace = self.keyspace();
+        let mut response = HashMap::new();
+
+        for (key, read_from) in reads {
+            let Some(stream) = keyspace.stream(&key)? else {
+                continue;
+            };
+            let after = match read_from {
+                ReadFrom::After(id) => id,
+                ReadFrom::LastEntry => continue,
+                ReadFrom::Undelivered => {
+                    return Err(error(
+                        RedisErrorKind::InvalidArgument,
+                        "ERR The > ID can be specified only when calling XREADGROUP",
+                    ))
+                }
+            };
+
+            let entries = entries_after(stream, after)
+                .take(count)
+                .map(|(id, fields)| to_stream_entry(*id, Some(fields)))
+                .collect::<RedisResult<Vec<_>>>()?;
+            if !entries.is_empty() {
+                response.insert(key, entries);
+            }
+        }
+
+        non_empty_read(response)
+    }
+
+    pub fn xreadgroup<V>(
+        &self,
+        group: &str,
+        consumer: &str,
+        count: Option<u64>,
+        keys: &MultipleKeys,
+        ids: MultipleIDs,
+    ) -> RedisResult<XReadResponse<String, String, String, V>>
+    where
+        V: FromRedis,
+    {
+        let count = to_count(count);
+        let reads = zip_streams(keys, ids)?;
+        let mut keyspace = self.keyspace();
+        let mut response = HashMap::new();
+
+        for (key, read_from) in reads {
+            let stream = keyspace
+                .stream(&key)?
+                .ok_or_else(|| no_group(&key, group))?;
+            let consumer_group = stream
+                .groups
+                .get_mut(group)
+                .ok_or_else(|| no_group(&key, group))?;
+            consumer_group.consumers.insert(consumer.to_owned());
+
+            let entries = match read_from {
+                ReadFrom::Undelivered => {
+                    let delivered = stream
+                        .entries
+                        .range(next_id(consumer_group.last_delivered_id)..)
+                        .take(count)
+                        .map(|(id, fields)| (*id, fields))
+                        .collect::<Vec<_>>();
+
+                    for (id, _) in &delivered {
+                        consumer_group.last_delivered_id = *id;
+                        consumer_group.pending.insert(
+                            *id,
+                            PendingEntry {
+                                consumer: consumer.to_owned(),
+                                delivered_at: Instant::now(),
+                            },
+                        );
+                    }
+                    delivered
+                        .into_iter()
+                        .map(|(id, fields)| to_stream_entry(id, Some(fields)))
+                        .collect::<RedisResult<Vec<_>>>()?
+                }
+                // The entries delivered to the consumer which are yet to be acknowledged
+                ReadFrom::After(after) => consumer_group
+                    .pending
+                    .range(next_id(after)..)
+                    .filter(|(_, pending)| pending.consumer == consumer)
+                    .take(count)
+                    .map(|(id, _)| to_stream_entry(*id, stream.entries.get(id)))
+                    .collect::<RedisResult<Vec<_>>>()?,
+                ReadFrom::LastEntry => {
+                    return Err(error(
+                        RedisErrorKind::InvalidArgument,
+                        "ERR The $ ID is meaningless in the context of XREADGROUP",
+                    ))
+                }
+            };
+            if !entries.is_empty() {
+                response.insert(key, entries);
+            }
+        }
+
+        non_empty_read(response)
+    }
+
+    pub fn xack<I>(&self, key: &str, group: &str, ids: I) -> RedisResult<RedisValue>
+    where
+        I: Into<MultipleIDs>,
+    {
+        let ids = to_stream_ids(ids)?;
+        let mut keyspace = self.keyspace();
+        let consumer_group = match keyspace.consumer_group(key, group) {
+            Ok(consumer_group) => consumer_group,
+            Err(_) => return Ok(RedisValue::Integer(0)),
+        };
+
+        let acknowledged = ids
+            .iter()
+            .filter(|id| consumer_group.pending.remove(id).is_some())
+            .count();
+        Ok(integer(acknowledged))
+    }
+
+    pub fn xgroup_create(
+        &self,
+        key: &str,
+        group: &str,
+        id: &XID,
+        mkstream: bool,
+    ) -> RedisResult<RedisValue> {
+        let mut keyspace = self.keyspace();
+        let stream = if mkstream {
+            keyspace.stream_or_create(key)?
+        } else {
+            keyspace.stream(key)?.ok_or_else(|| {
+                error(
+                    RedisErrorKind::InvalidArgument,
+                    "ERR The XGROUP subcommand requires the key to exist",
+                )
+            })?
+        };
+
+        if stream.groups.contains_key(group) {
+            return Err(error(
+                RedisErrorKind::InvalidArgument,
+                "BUSYGROUP Consumer Group name already exists",
+            ));
+        }
+        let last_delivered_id = group_id(stream, id)?;
+        stream.groups.insert(
+            group.to_owned(),
+            ConsumerGroup {
+                last_delivered_id,
+                pending: BTreeMap::new(),
+                consumers: HashSet::new(),
+            },
+        );
+        Ok(ok())
+    }
+
+    pub fn xgroup_destroy(&self, key: &str, group: &str) -> RedisResult<RedisValue> {
+        let destroyed = self
+            .keyspace()
+            .stream(key)?
+            .is_some_and(|stream| stream.groups.remove(group).is_some());
+
+        Ok(integer(usize::from(destroyed)))
+    }
+
+    pub fn xgroup_delconsumer(
+        &self,
+        key: &str,
+        group: &str,
+        consumer: &str,
+    ) -> RedisResult<RedisValue> {
+        let mut keyspace = self.keyspace();
+        let consumer_group = keyspace.consumer_group(key, group)?;
+
+        let pending_before = consumer_group.pending.len();
+        consumer_group
+            .pending
+            .retain(|_, pending| pending.consumer != consumer);
+        consumer_group.consumers.remove(consumer);
+
+        Ok(integer(
+            pending_before.saturating_sub(consumer_group.pending.len()),
+        ))
+    }
+
+    pub fn xgroup_setid(&self, key: &str, group: &str, id: &XID) -> RedisResult<RedisValue> {
+        let mut keyspace = self.keyspace();
+        let stream = keyspace.stream(key)?.ok_or_else(|| no_group(key, group))?;
+
+        let last_delivered_id = group_id(stream, id)?;
+        stream
+            .groups
+            .get_mut(group)
+            .ok_or_else(|| no_group(key, group))?
+            .last_delivered_id = last_delivered_id;
+        Ok(ok())
+    }
+
+    /// Transfers the pending entries idle for at least `min_idle_time` milliseconds to the
+    /// consumer, replying with the claimed entries
+    pub fn xclaim<I>(
+        &self,
+        key: &str,
+        group: &str,
+        consumer: &str,
+        min_idle_time: u64,
+        ids: I,
+    ) -> RedisResult<RedisValue>
+    where
+        I: Into<MultipleIDs>,
+    {
+        let ids = to_stream_ids(ids)?;
+        let min_idle_time = Duration::from_millis(min_idle_time);
+        let mut keyspace = self.keyspace();
+        let stream = keyspace.stream(key)?.ok_or_else(|| no_group(key, group))?;
+        let consumer_group = stream
+            .groups
+            .get_mut(group)
+            .ok_or_else(|| no_group(key, group))?;
+        consumer_group.consumers.insert(consumer.to_owned());
+
+        let mut claimed = Vec::new();
+        for id in ids {
+            let Some(pending) = consumer_group
+                .pending
+                .get_mut(&id)
+                .filter(|pending| pending.delivered_at.elapsed() >= min_idle_time)
+            else {
+                continue;
+            };
+            consumer.clone_into(&mut pending.consumer);
+            pending.delivered_at = Instant::now();
+
+            if let Some(fields) = stream.entries.get(&id) {
+                claimed.push(RedisValue::Array(vec![
+                    RedisValue::from(id.to_string()),
+                    RedisValue::Array(
+                        fields
+                            .iter()
+                            .flat_map(|(field, value)| {
+                                [RedisValue::from(field.as_str()), to_value(value)]
+                            })
+                            .collect(),
+                    ),
+                ]));
+            }
+        }
+        Ok(RedisValue::Array(claimed))
+    }
+
+    pub fn eval<V>(&self, lua_script: &str, keys: Vec<String>, values: V) -> RedisResult<RedisValue>
+    where
+        V: TryInto<MultipleValues>,
+        V::Error: Into<RedisError>,
+    {
+        let values = values
+            .try_into()
+            .map_err(Into::<RedisError>::into)?
+            .inner()
+            .to_vec();
+        let handler = lock(&self.scripts)
+            .get(lua_script)
+            .cloned()
+            .ok_or_else(|| {
+                error(
+                    RedisErrorKind::InvalidCommand,
+                    "NOSCRIPT No handler registered for the script in the in-memory backend",
+                )
+            })?;
+
+        // The handler is called without holding any lock, as it is expected to run commands
+        handler(self, keys, values)
+    }
+}
+
+impl Subscription {
+    pub fn subscribe(&self, channel: &str) {
+        lock(&self.channels).insert(channel.to_owned());
+    }
+
+    pub fn message_rx(&self) -> broadcast::Receiver<Message> {
+        self.messages.subscribe()
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // The state is consistent after every command, so a panic while holding the lock is harmless
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn error(kind: RedisErrorKind, message: &'static str) -> RedisError {
+    RedisError::new(kind, message)
+}
+
+fn wrong_type() -> RedisError {
+    error(
+        RedisErrorKind::InvalidArgument,
+        "WRONGTYPE Operation against a key holding the wrong kind of value",
+    )
+}
+
+fn no_group(key: &str, group: &str) -> RedisError {
+    RedisError::new(
+        RedisErrorKind::InvalidArgument,
+        format!("NOGROUP No such key '{key}' or consumer group '{group}'"),
+    )
+}
+
+fn ok() -> RedisValue {
+    RedisValue::from("OK")
+}
+
+fn integer(value: usize) -> RedisValue {
+    RedisValue::Integer(i64::try_from(value).unwrap_or(i64::MAX))
+}
+
+fn to_count(count: Option<u64>) -> usize {
+    count.map_or(usize::MAX, |count| {
+        usize::try_from(count).unwrap_or(usize::MAX)
+    })
+}
+
+fn get_expires_at(expiration: Expiration) -> Option<Instant> {
+    let now_in_millis = || {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| i64::try_from(duration.as_millis()).unwrap_or(i64::MAX))
+            .unwrap_or_default()
+    };
+    let ttl_in_millis = match expiration {
+        Expiration::EX(seconds) => seconds.saturating_mul(1000),
+        Expiration::PX(milliseconds) => milliseconds,
+        Expiration::EXAT(timestamp) => timestamp
+            .saturating_mul(1000)
+            .saturating_sub(now_in_millis()),
+        Expiration::PXAT(timestamp) => timestamp.saturating_sub(now_in_millis()),
+        Expiration::KEEPTTL => return None,
+    };
+
+    Some(Instant::now() + Duration::from_millis(u64::try_from(ttl_in_millis).unwrap_or_default()))
+}
+
+/// Values are stored as the bytes Redis would store, and are read back as strings when valid UTF-8
+fn to_bytes<V>(value: V) -> RedisResult<Vec<u8>>
+where
+    V: TryInto<RedisValue>,
+    V::Error: Into<RedisError>,
+{
+    match value.try_into().map_err(Into::<RedisError>::into)? {
+        RedisValue::Bytes(bytes) => Ok(bytes.to_vec()),
+        RedisValue::String(string) => Ok(string.as_bytes().to_vec()),
+        RedisValue::Integer(integer) => Ok(integer.to_string().into_bytes()),
+        RedisValue::Double(double) => Ok(double.to_string().into_bytes()),
+        RedisValue::Boolean(boolean) => Ok(if boolean { "true" } else { "false" }.into()),
+        _ => Err(error(
+            RedisErrorKind::InvalidArgument,
+            "ERR Only strings, bytes and numbers can be stored",
+        )),
+    }
+}
+
+fn to_value(bytes: &[u8]) -> RedisValue {
+    match std::str::from_utf8(bytes) {
+        Ok(string) => RedisValue::from(string),
+        Err(_) => RedisValue::Bytes(bytes.to_vec().into()),
+    }
+}
+
+fn key_to_string(key: &RedisKey) -> String {
+    key.as_str_lossy().into_owned()
+}
+
+fn to_pairs<V>(values: V) -> RedisResult<Vec<(String, Vec<u8>)>>
+where
+    V: TryInto<RedisMap>,
+    V::Error: Into<RedisError>,
+{
+    values
+        .try_into()
+        .map_err(Into::<RedisError>::into)?
+        .inner()
+        .iter()
+        .map(|(key, value)| to_bytes(value.clone()).map(|value| (key_to_string(key), value)))
+        .collect()
+}
+
+fn to_multiple_bytes<V>(values: V) -> RedisResult<Vec<Vec<u8>>>
+where
+    V: TryInto<MultipleValues>,
+    V::Error: Into<RedisError>,
+{
+    values
+        .try_into()
+        .map_err(Into::<RedisError>::into)?
+        .inner()
+        .iter()
+        .map(|value| to_bytes(value.clone()))
+        .collect()
+}
+
+fn to_stream_ids<I>(ids: I) -> RedisResult<Vec<StreamId>>
+where
+    I: Into<MultipleIDs>,
+{
+    ids.into()
+        .inner()
+        .iter()
+        .map(|id| match id {
+            XID::Manual(id) => StreamId::parse(id),
+            _ => Err(error(
+                RedisErrorKind::InvalidArgument,
+                "ERR Invalid stream ID specified as stream command argument",
+            )),
+        })
+        .collect()
+}
+
+/// Pairs the streams to read with the ID to read each of them from
+fn zip_streams(keys: &MultipleKeys, ids: MultipleIDs) -> RedisResult<Vec<(String, ReadFrom)>> {
+    let ids = ids
+        .inner()
+        .iter()
+        .map(ReadFrom::parse)
+        .collect::<RedisResult<Vec<_>>>()?;
+
+    if keys.inner().len() != ids.len() {
+        return Err(error(
+            RedisErrorKind::InvalidArgument,
+            "ERR Unbalanced XREAD list of streams: for each stream key an ID must be specified",
+        ));
+    }
+    Ok(keys.inner().iter().map(key_to_string).zip(ids).collect())
+}
+
+fn group_id(stream: &Stream, id: &XID) -> RedisResult<StreamId> {
+    match ReadFrom::parse(id)? {
+        ReadFrom::After(id) => Ok(id),
+        ReadFrom::LastEntry => Ok(stream.last_id),
+        ReadFrom::Undelivered => Err(error(
+            RedisErrorKind::InvalidArgument,
+            "ERR Invalid stream ID specified as stream command argument",
+        )),
+    }
+}
+
+fn next_id(id: StreamId) -> StreamId {
+    StreamId {
+        milliseconds: id.milliseconds,
+        sequence_number: id.sequence_number.saturating_add(1),
+    }
+}
+
+fn entries_after(
+    stream: &Stream,
+    id: StreamId,
+) -> impl Iterator<Item = (&StreamId, &Vec<(String, Vec<u8>)>)> {
+    stream.entries.range(next_id(id)..)
+}
+
+fn to_stream_entry<V: FromRedis>(
+    id: StreamId,
+    fields: Option<&Vec<(String, Vec<u8>)>>,
+) -> RedisResult<(String, HashMap<String, V>)> {
+    // Entries deleted after being delivered to a consumer are read without their fields
+    let fields = fields
+        .into_iter()
+        .flatten()
+        .map(|(field, value)| V::from_value(to_value(value)).map(|value| (field.clone(), value)))
+        .collect::<RedisResult<HashMap<_, _>>>()?;
+
+    Ok((id.to_string(), fields))
+}
+
+/// Redis replies with `nil` when there are no entries to read from any of the streams
+fn non_empty_read<V>(
+    response: XReadResponse<String, String, String, V>,
+) -> RedisResult<XReadResponse<String, String, String, V>> {
+    if response.is_empty() {
+        Err(error(
+            RedisErrorKind::NotFound,
+            "No entries to read from the streams",
+        ))
+    } else {
+        Ok(response)
+    }
+}
+
+/// Matches the glob-style patterns of `SCAN` and `HSCAN`, supporting `*`, `?` and `\` escapes
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => (0..=text.len()).any(|skipped| {
+            text.get(skipped..)
+                .is_some_and(|text| glob_match(rest, text))
+        }),
+        Some((b'?', rest)) => text
+            .split_first()
+            .is_some_and(|(_, text)| glob_match(rest, text)),
+        Some((first, rest)) => {
+            let (expected, rest) = match (first, rest.split_first()) {
+                (b'\\', Some((escaped, rest))) => (escaped, rest),
+                _ => (first, rest),
+            };
+            text.split_first()
+                .is_some_and(|(actual, text)| actual == expected && glob_match(rest, text))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use fred::types::RedisValue as FredRedisValue;
+
+    use super::InMemoryRedis;
+    use crate::{
+        errors::RedisError, types::SetnxReply, RedisConnectionPool, RedisEntryId, RedisSettings,
+        RedisValue, SetGetReply,
+    };
+
+    fn pool() -> RedisConnectionPool {
+        RedisConnectionPool::new_in_memory(&RedisSettings::default())
+    }
+
+    #[tokio::test]
+    async fn test_keys_with_expiry() {
+        let pool = pool();
+
+        pool.set_key_with_expiry(&"key".into(), "value", 60)
+            .await
+            .unwrap();
+        let reply = pool
+            .set_key_if_not_exists_with_expiry(&"key".into(), "other", None)
+            .await
+            .unwrap();
+
+        assert!(reply == SetnxReply::KeyNotSet);
+        assert_eq!(
+            pool.get_key::<String>(&"key".into()).await.unwrap(),
+            "value"
+        );
+        assert_eq!(pool.get_ttl(&"key".into()).await.unwrap(), 60);
+
+        pool.set_expire_at(&"key".into(), 0).await.unwrap();
+
+        assert!(!pool.exists::<String>(&"key".into()).await.unwrap());
+        assert_eq!(pool.get_ttl(&"key".into()).await.unwrap(), -2);
+    }
+
+    #[tokio::test]
+    async fn test_set_key_if_not_exists_and_get_value() {
+        let pool = pool();
+
+        let first = pool
+            .set_key_if_not_exists_and_get_value(&"key".into(), "first".to_string(), None)
+            .await
+            .unwrap();
+        let second = pool
+            .set_key_if_not_exists_and_get_value(&"key".into(), "second".to_string(), None)
+            .await
+            .unwrap();
+
+        assert!(matches!(first, SetGetReply::ValueSet(value) if value == "first"));
+        assert!(matches!(second, SetGetReply::ValueExists(value) if value == "first"));
+    }
+
+    #[tokio::test]
+    async fn test_hash_fields() {
+        let pool = pool();
+        let key = "mid_merchant_pid_payment".into();
+
+        pool.set_hash_fields(
+            &key,
+            vec![
+                ("pa_attempt", "attempt"),
+                ("pa_attempt_ref_refund", "refund"),
+            ],
+            None,
+        )
+        .await
+        .unwrap();
+        pool.increment_fields_in_hash(&key, &[("count", 2), ("count", 3)])
+            .await
+            .unwrap();
+
+        let refunds = pool.hscan(&key, "pa_*_ref_*", None).await.unwrap();
+        assert_eq!(refunds, vec!["refund".to_string()]);
+
+        pool.delete_hash_field(&key, "pa_attempt").await.unwrap();
+        let fields = pool
+            .get_hash_fields::<HashMap<String, String>>(&key)
+            .await
+            .unwrap();
+        assert_eq!(
+            fields,
+            HashMap::from([
+                ("pa_attempt_ref_refund".to_string(), "refund".to_string()),
+                ("count".to_string(), "5".to_string()),
+            ])
+        );
+
+        let keys = pool
+            .scan(&"mid_merchant_*".into(), None, None)
+            .await
+            .unwrap();
+        assert_eq!(keys, vec!["mid_merchant_pid_payment".to_string()]);
+
+        let keys = pool
+            .scan_with_limit(&"mid_merchant_*".into(), None, None, 0)
+            .await
+            .unwrap();
+        assert!(keys.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_lists() {
+        let pool = pool();
+
+        pool.append_elements_to_list(&"list".into(), vec!["a", "b", "c"])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            pool.get_list_elements(&"list".into(), 1, -1).await.unwrap(),
+            vec!["b".to_string(), "c".to_string()]
+        );
+        assert_eq!(
+            pool.lpop_list_elements(&"list".into(), Some(2))
+                .await
+                .unwrap(),
+            vec!["a".to_string(), "b".to_string()]
+        );
+        assert_eq!(pool.get_list_length(&"list".into()).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_stream_consumer_group() {
+        let pool = &pool();
+        let stream = "stream".into();
+
+        pool.consumer_group_create(&stream, "group", &RedisEntryId::AfterLastID)
+            .await
+            .unwrap();
+        for value in ["first", "second"] {
+            pool.stream_append_entry(
+                &stream,
+                &RedisEntryId::AutoGeneratedID,
+                vec![("field", value)],
+            )
+            .await
+            .unwrap();
+        }
+
+        let read = || async move {
+            pool.stream_read_with_options(
+                "stream",
+                RedisEntryId::UndeliveredEntryID,
+                Some(1),
+                None,
+                Some(("group", "consumer")),
+            )
+            .await
+        };
+        let first = read().await.unwrap();
+        let (first_id, first_fields) = first
+            .get("stream")
+            .and_then(|entries| entries.first())
+            .unwrap();
+        assert_eq!(
+            first_fields.get("field").cloned().flatten().as_deref(),
+            Some("first")
+        );
+
+        pool.stream_acknowledge_entries(&stream, "group", first_id.as_str())
+            .await
+            .unwrap();
+        assert!(read().await.is_ok());
+        assert_eq!(
+            read().await.unwrap_err().current_context(),
+            &RedisError::StreamEmptyOrNotAvailable
+        );
+
+        let entries = pool
+            .stream_read_entries("stream", "0-0", Some(10))
+            .await
+            .unwrap();
+        let last_id = entries
+            .get("stream")
+            .and_then(|entries| entries.last())
+            .map(|(id, _)| id.clone())
+            .unwrap();
+        pool.stream_trim_entries(
+            &stream,
+            (
+                crate::StreamCapKind::MinID,
+                crate::StreamCapTrim::Exact,
+                last_id,
+            ),
+        )
+        .await
+        .unwrap();
+        assert_eq!(pool.stream_get_length(&stream).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_scripts() {
+        let store = Arc::new(InMemoryRedis::default());
+        let pool = RedisConnectionPool::from_in_memory_store(
+            &RedisSettings::default(),
+            Arc::clone(&store),
+        );
+        let lua_script = "return redis.call('INCRBY', KEYS[1], ARGV[1])";
+
+        assert!(pool
+            .evaluate_redis_script::<_, i64>(
+                lua_script,
+                vec!["key".to_string()],
+                vec!["1".to_string()],
+            )
+            .await
+            .is_err());
+
+        store.register_script(
+            lua_script,
+            Arc::new(
+                |redis: &InMemoryRedis, keys: Vec<String>, values: Vec<FredRedisValue>| {
+                    let key = keys.first().cloned().unwrap_or_default();
+                    let increment = values
+                        .first()
+                        .and_then(|value| value.as_string()?.parse().ok())
+                        .unwrap_or(0);
+                    redis.hincrby(&key, "value", increment)
+                },
+            ),
+        );
+
+        let value = pool
+            .evaluate_redis_script::<_, i64>(
+                lua_script,
+                vec!["key".to_string()],
+                vec!["5".to_string()],
+            )
+            .await
+            .unwrap();
+        assert_eq!(value, 5);
+    }
+
+    #[tokio::test]
+    async fn test_pub_sub() {
+        let pool = pool();
+        let mut rx = pool.subscriber.message_rx();
+
+        pool.subscriber.subscribe("channel").await.unwrap();
+        let receivers = pool
+            .publisher
+            .publish("channel", RedisValue::from_string("message".to_string()))
+            .await
+            .unwrap();
+        let message = rx.recv().await.unwrap();
+
+        assert_eq!(receivers, 1);
+        assert_eq!(&*message.channel, "channel");
+        assert_eq!(message.value.as_string().as_deref(), Some("message"));
+    }
+
+    #[tokio::test]
+    async fn test_reads_from_replica() {
+        // The in-memory backend has no replicas, the reads fall back to the primary
+        let pool = RedisConnectionPool::new_in_memory(&RedisSettings {
+            read_from_replicas: true,
+            ..RedisSettings::default()
+        });
+        let key = "mid_merchant_pid_payment".into();
+
+        pool.set_key(&"key".into(), "value").await.unwrap();
+        pool.set_hash_fields(&key, vec![("pa_attempt", "attempt")], None)
+            .await
+            .unwrap();
+        pool.stream_append_entry(
+            &"stream".into(),
+            &RedisEntryId::AutoGeneratedID,
+            vec![("field", "value")],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            pool.get_key_from_replica::<String>(&"key".into())
+                .await
+                .unwrap(),
+            "value"
+        );
+        assert_eq!(
+            pool.get_hash_field_from_replica::<String>(&key, "pa_attempt")
+                .await
+                .unwrap(),
+            "attempt"
+        );
+        assert_eq!(
+            pool.hscan_from_replica(&key, "pa_*", None).await.unwrap(),
+            vec!["attempt".to_string()]
+        );
+        let entries = pool
+            .stream_read_entries_from_replica("stream", "0-0", None)
+            .await
+            .unwrap();
+        assert_eq!(entries.get("stream").map(Vec::len), Some(1));
+    }
+}
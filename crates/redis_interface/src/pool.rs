@@ -0,0 +1,701 @@
+//! The backend the commands are sent to, either a pool of connections to a Redis server or an
+//! in-process stand-in for one
+//!
+//! The methods mirror the `fred` commands used by [`crate::commands`], so that both backends
+//! reply with the same values and fail with the same kinds of errors.
+
+use std::{fmt::Debug, sync::Arc};
+
+use fred::{
+    clients::Replicas,
+    error::RedisError,
+    interfaces::{
+        ClientLike, HashesInterface, KeysInterface, ListInterface, LuaInterface, SetsInterface,
+        StreamsInterface, TransactionInterface,
+    },
+    types::{
+        ClusterHash, CustomCommand, Expiration, FromRedis, MultipleIDs, MultipleKeys,
+        MultipleOrderedPairs, MultipleStrings, MultipleValues, RedisMap, RedisValue, ScanType,
+        Scanner, SetOptions, XReadResponse, XID,
+    },
+};
+use futures::StreamExt;
+
+use crate::{
+    in_memory::InMemoryRedis,
+    types::{RedisEntryId, StreamCapKind, StreamCapTrim},
+};
+
+type RedisResult<T> = Result<T, RedisError>;
+
+pub enum RedisPool {
+    Fred {
+        pool: fred::prelude::RedisPool,
+        /// Whether the `*_from_replica` reads are sent to the replicas of the primary, in which
+        /// case they may not observe the latest writes. All the other commands are sent to the
+        /// primary.
+        read_from_replicas: bool,
+    },
+    InMemory(Arc<InMemoryRedis>),
+}
+
+impl RedisPool {
+    pub async fn set<R, V>(
+        &self,
+        key: String,
+        value: V,
+        expiration: Option<Expiration>,
+        options: Option<SetOptions>,
+    ) -> RedisResult<R>
+    where
+        R: FromRedis,
+        V: TryInto<RedisValue> + Debug + Send + Sync,
+        V::Error: Into<RedisError> + Send + Sync,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.set(key, value, expiration, options, false).await,
+            Self::InMemory(redis) => redis
+                .set(&key, value, expiration, options)
+                .and_then(R::from_value),
+        }
+    }
+
+    pub async fn msetnx<R, V>(&self, values: V) -> RedisResult<R>
+    where
+        R: FromRedis,
+        V: TryInto<RedisMap> + Debug + Send + Sync,
+        V::Error: Into<RedisError> + Send + Sync,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.msetnx(values).await,
+            Self::InMemory(redis) => redis.msetnx(values).and_then(R::from_value),
+        }
+    }
+
+    pub async fn get<R, K>(&self, key: K) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<String> + Send,
+    {
+        let key = key.into();
+        match self {
+            Self::Fred { pool, .. } => pool.get(key).await,
+            Self::InMemory(redis) => redis.get(&key).and_then(R::from_value),
+        }
+    }
+
+    /// [`Self::get`] from a replica if enabled, which may not observe the latest writes
+    pub async fn get_from_replica<R, K>(&self, key: K) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<String> + Send,
+    {
+        match self {
+            Self::Fred {
+                pool,
+                read_from_replicas: true,
+            } => pool.next().replicas().get(key.into()).await,
+            _ => self.get(key).await,
+        }
+    }
+
+    pub async fn mget<R>(&self, keys: Vec<String>) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.mget(keys).await,
+            Self::InMemory(redis) => redis.mget(&keys).and_then(R::from_value),
+        }
+    }
+
+    pub async fn exists<R>(&self, key: String) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.exists(key).await,
+            Self::InMemory(redis) => redis.exists(&key).and_then(R::from_value),
+        }
+    }
+
+    pub async fn del<R>(&self, key: String) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.del(key).await,
+            Self::InMemory(redis) => redis.del(&key).and_then(R::from_value),
+        }
+    }
+
+    pub async fn expire<R>(&self, key: String, seconds: i64) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.expire(key, seconds).await,
+            Self::InMemory(redis) => redis.expire(&key, seconds).and_then(R::from_value),
+        }
+    }
+
+    pub async fn expire_at<R>(&self, key: String, timestamp: i64) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.expire_at(key, timestamp).await,
+            Self::InMemory(redis) => redis.expire_at(&key, timestamp).and_then(R::from_value),
+        }
+    }
+
+    pub async fn ttl<R>(&self, key: String) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.ttl(key).await,
+            Self::InMemory(redis) => redis.ttl(&key).and_then(R::from_value),
+        }
+    }
+
+    pub async fn hset<R, V>(&self, key: String, values: V) -> RedisResult<R>
+    where
+        R: FromRedis,
+        V: TryInto<RedisMap> + Debug + Send + Sync,
+        V::Error: Into<RedisError> + Send + Sync,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.hset(key, values).await,
+            Self::InMemory(redis) => redis.hset(&key, values).and_then(R::from_value),
+        }
+    }
+
+    pub async fn hsetnx<R, V>(&self, key: String, field: &str, value: V) -> RedisResult<R>
+    where
+        R: FromRedis,
+        V: TryInto<RedisValue> + Debug + Send + Sync,
+        V::Error: Into<RedisError> + Send + Sync,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.hsetnx(key, field, value).await,
+            Self::InMemory(redis) => redis.hsetnx(&key, field, value).and_then(R::from_value),
+        }
+    }
+
+    pub async fn hincrby<R>(&self, key: String, field: String, increment: i64) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.hincrby(key, field, increment).await,
+            Self::InMemory(redis) => redis
+                .hincrby(&key, &field, increment)
+                .and_then(R::from_value),
+        }
+    }
+
+    pub async fn hget<R>(&self, key: String, field: &str) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.hget(key, field).await,
+            Self::InMemory(redis) => redis.hget(&key, field).and_then(R::from_value),
+        }
+    }
+
+    /// [`Self::hget`] from a replica if enabled, which may not observe the latest writes
+    pub async fn hget_from_replica<R>(&self, key: String, field: &str) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred {
+                pool,
+                read_from_replicas: true,
+            } => pool.next().replicas().hget(key, field).await,
+            _ => self.hget(key, field).await,
+        }
+    }
+
+    pub async fn hgetall<R>(&self, key: String) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.hgetall(key).await,
+            Self::InMemory(redis) => redis.hgetall(&key).and_then(R::from_value),
+        }
+    }
+
+    pub async fn hdel<R>(&self, key: String, field: &str) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.hdel(key, field).await,
+            Self::InMemory(redis) => redis.hdel(&key, field).and_then(R::from_value),
+        }
+    }
+
+    /// Values of the fields of the hash matching the pattern, errors while scanning are logged
+    pub async fn hscan(&self, key: String, pattern: &str, count: Option<u32>) -> Vec<String> {
+        match self {
+            Self::Fred { pool, .. } => {
+                pool.next()
+                    .hscan::<&str, &str>(&key, pattern, count)
+                    .filter_map(|value| async move {
+                        match value {
+                            Ok(mut v) => {
+                                let v = v.take_results()?;
+
+                                let v: Vec<String> =
+                                    v.iter().filter_map(|(_, val)| val.as_string()).collect();
+                                Some(futures::stream::iter(v))
+                            }
+                            Err(err) => {
+                                tracing::error!(redis_err=?err, "Redis error while executing hscan command");
+                                None
+                            }
+                        }
+                    })
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .await
+            }
+            Self::InMemory(redis) => redis.hscan(&key, pattern).unwrap_or_else(|err| {
+                tracing::error!(redis_err=?err, "Redis error while executing hscan command");
+                Vec::new()
+            }),
+        }
+    }
+
+    /// [`Self::hscan`] on a replica if enabled, which may not observe the latest writes
+    pub async fn hscan_from_replica(
+        &self,
+        key: String,
+        pattern: &str,
+        count: Option<u32>,
+    ) -> Vec<String> {
+        match self {
+            Self::Fred {
+                pool,
+                read_from_replicas: true,
+            } => replica_hscan(&pool.next().replicas(), &key, pattern, count)
+                .await
+                .unwrap_or_else(|err| {
+                    tracing::error!(redis_err=?err, "Redis error while executing hscan command");
+                    Vec::new()
+                }),
+            _ => self.hscan(key, pattern, count).await,
+        }
+    }
+
+    /// Keys matching the pattern, errors while scanning are logged. The scan stops once `limit`
+    /// keys are found.
+    pub async fn scan(
+        &self,
+        pattern: String,
+        count: Option<u32>,
+        scan_type: Option<ScanType>,
+        limit: Option<usize>,
+    ) -> Vec<String> {
+        let limit = limit.unwrap_or(usize::MAX);
+        match self {
+            Self::Fred { pool, .. } => {
+                pool.next()
+                    .scan(pattern, count, scan_type)
+                    .filter_map(|value| async move {
+                        match value {
+                            Ok(mut v) => {
+                                let v = v.take_results()?;
+
+                                let v: Vec<String> =
+                                    v.into_iter().filter_map(|val| val.into_string()).collect();
+                                Some(futures::stream::iter(v))
+                            }
+                            Err(err) => {
+                                tracing::error!(redis_err=?err, "Redis error while executing scan command");
+                                None
+                            }
+                        }
+                    })
+                    .flatten()
+                    .take(limit)
+                    .collect::<Vec<_>>()
+                    .await
+            }
+            Self::InMemory(redis) => redis
+                .scan(&pattern, scan_type)
+                .into_iter()
+                .take(limit)
+                .collect(),
+        }
+    }
+
+    pub async fn sadd<R, V>(&self, key: String, members: V) -> RedisResult<R>
+    where
+        R: FromRedis,
+        V: TryInto<MultipleValues> + Debug + Send,
+        V::Error: Into<RedisError> + Send,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.sadd(key, members).await,
+            Self::InMemory(redis) => redis.sadd(&key, members).and_then(R::from_value),
+        }
+    }
+
+    pub async fn xadd<R, F>(&self, key: String, id: &RedisEntryId, fields: F) -> RedisResult<R>
+    where
+        R: FromRedis,
+        F: TryInto<MultipleOrderedPairs> + Debug + Send + Sync,
+        F::Error: Into<RedisError> + Send + Sync,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.xadd(key, false, None, id, fields).await,
+            Self::InMemory(redis) => redis
+                .xadd(&key, &XID::from(id), fields)
+                .and_then(R::from_value),
+        }
+    }
+
+    pub async fn xdel<R, I>(&self, key: String, ids: I) -> RedisResult<R>
+    where
+        R: FromRedis,
+        I: Into<MultipleStrings> + Debug + Send + Sync,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.xdel(key, ids).await,
+            Self::InMemory(redis) => redis.xdel(&key, ids).and_then(R::from_value),
+        }
+    }
+
+    pub async fn xtrim<R>(
+        &self,
+        key: String,
+        (kind, trim, threshold): (StreamCapKind, StreamCapTrim, String),
+    ) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.xtrim(key, (kind, trim, threshold)).await,
+            Self::InMemory(redis) => redis
+                .xtrim(&key, (kind, trim, threshold))
+                .and_then(R::from_value),
+        }
+    }
+
+    pub async fn xack<R, I>(&self, key: String, group: &str, ids: I) -> RedisResult<R>
+    where
+        R: FromRedis,
+        I: Into<MultipleIDs> + Debug + Send + Sync,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.xack(key, group, ids).await,
+            Self::InMemory(redis) => redis.xack(&key, group, ids).and_then(R::from_value),
+        }
+    }
+
+    pub async fn xlen<R>(&self, key: String) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.xlen(key).await,
+            Self::InMemory(redis) => redis.xlen(&key).and_then(R::from_value),
+        }
+    }
+
+    /// The in-memory backend does not block, it replies right away if there are no entries
+    pub async fn xread_map<V, I>(
+        &self,
+        count: Option<u64>,
+        block: Option<u64>,
+        keys: MultipleKeys,
+        ids: I,
+    ) -> RedisResult<XReadResponse<String, String, String, V>>
+    where
+        V: FromRedis,
+        I: Into<MultipleIDs> + Debug + Send + Sync,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.xread_map(count, block, keys, ids).await,
+            Self::InMemory(redis) => redis.xread(count, &keys, ids.into()),
+        }
+    }
+
+    /// [`Self::xread_map`] from a replica if enabled, which may not observe the latest entries
+    pub async fn xread_map_from_replica<V, I>(
+        &self,
+        count: Option<u64>,
+        block: Option<u64>,
+        keys: MultipleKeys,
+        ids: I,
+    ) -> RedisResult<XReadResponse<String, String, String, V>>
+    where
+        V: FromRedis,
+        I: Into<MultipleIDs> + Debug + Send + Sync,
+    {
+        match self {
+            Self::Fred {
+                pool,
+                read_from_replicas: true,
+            } => {
+                pool.next()
+                    .replicas()
+                    .xread_map(count, block, keys, ids)
+                    .await
+            }
+            _ => self.xread_map(count, block, keys, ids).await,
+        }
+    }
+
+    /// The in-memory backend does not block, it replies right away if there are no entries
+    pub async fn xreadgroup_map<V, I>(
+        &self,
+        group: &str,
+        consumer: &str,
+        count: Option<u64>,
+        block: Option<u64>,
+        keys: MultipleKeys,
+        ids: I,
+    ) -> RedisResult<XReadResponse<String, String, String, V>>
+    where
+        V: FromRedis,
+        I: Into<MultipleIDs> + Debug + Send + Sync,
+    {
+        match self {
+            Self::Fred { pool, .. } => {
+                pool.xreadgroup_map(group, consumer, count, block, false, keys, ids)
+                    .await
+            }
+            Self::InMemory(redis) => redis.xreadgroup(group, consumer, count, &keys, ids.into()),
+        }
+    }
+
+    pub async fn rpush<R, V>(&self, key: String, elements: V) -> RedisResult<R>
+    where
+        R: FromRedis,
+        V: TryInto<MultipleValues> + Debug + Send,
+        V::Error: Into<RedisError> + Send,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.rpush(key, elements).await,
+            Self::InMemory(redis) => redis.rpush(&key, elements).and_then(R::from_value),
+        }
+    }
+
+    pub async fn lrange<R>(&self, key: String, start: i64, stop: i64) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.lrange(key, start, stop).await,
+            Self::InMemory(redis) => redis.lrange(&key, start, stop).and_then(R::from_value),
+        }
+    }
+
+    pub async fn llen<R>(&self, key: String) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.llen(key).await,
+            Self::InMemory(redis) => redis.llen(&key).and_then(R::from_value),
+        }
+    }
+
+    pub async fn lpop<R>(&self, key: String, count: Option<usize>) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.lpop(key, count).await,
+            Self::InMemory(redis) => redis.lpop(&key, count).and_then(R::from_value),
+        }
+    }
+
+    pub async fn xgroup_create<R>(
+        &self,
+        key: String,
+        group: &str,
+        id: &RedisEntryId,
+        mkstream: bool,
+    ) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.xgroup_create(key, group, id, mkstream).await,
+            Self::InMemory(redis) => redis
+                .xgroup_create(&key, group, &XID::from(id), mkstream)
+                .and_then(R::from_value),
+        }
+    }
+
+    pub async fn xgroup_destroy<R>(&self, key: String, group: &str) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.xgroup_destroy(key, group).await,
+            Self::InMemory(redis) => redis.xgroup_destroy(&key, group).and_then(R::from_value),
+        }
+    }
+
+    pub async fn xgroup_delconsumer<R>(
+        &self,
+        key: String,
+        group: &str,
+        consumer: &str,
+    ) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.xgroup_delconsumer(key, group, consumer).await,
+            Self::InMemory(redis) => redis
+                .xgroup_delconsumer(&key, group, consumer)
+                .and_then(R::from_value),
+        }
+    }
+
+    pub async fn xgroup_setid<R>(
+        &self,
+        key: String,
+        group: &str,
+        id: &RedisEntryId,
+    ) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.xgroup_setid(key, group, id).await,
+            Self::InMemory(redis) => redis
+                .xgroup_setid(&key, group, &XID::from(id))
+                .and_then(R::from_value),
+        }
+    }
+
+    pub async fn xclaim<R, I>(
+        &self,
+        key: String,
+        group: &str,
+        consumer: &str,
+        min_idle_time: u64,
+        ids: I,
+    ) -> RedisResult<R>
+    where
+        R: FromRedis,
+        I: Into<MultipleIDs> + Debug + Send + Sync,
+    {
+        match self {
+            Self::Fred { pool, .. } => {
+                pool.xclaim(
+                    key,
+                    group,
+                    consumer,
+                    min_idle_time,
+                    ids,
+                    None,
+                    None,
+                    None,
+                    false,
+                    false,
+                )
+                .await
+            }
+            Self::InMemory(redis) => redis
+                .xclaim(&key, group, consumer, min_idle_time, ids)
+                .and_then(R::from_value),
+        }
+    }
+
+    /// Scripts have to be registered with the in-memory backend before they are evaluated
+    pub async fn eval<R, V>(
+        &self,
+        lua_script: &'static str,
+        keys: Vec<String>,
+        values: V,
+    ) -> RedisResult<R>
+    where
+        R: FromRedis,
+        V: TryInto<MultipleValues> + Debug + Send + Sync,
+        V::Error: Into<RedisError> + Send + Sync,
+    {
+        match self {
+            Self::Fred { pool, .. } => pool.eval(lua_script, keys, values).await,
+            Self::InMemory(redis) => redis.eval(lua_script, keys, values).and_then(R::from_value),
+        }
+    }
+
+    /// Runs `SET key value EX ttl NX` and `GET key` in a transaction, returning their replies
+    pub async fn set_if_not_exists_and_get<V>(
+        &self,
+        key: String,
+        value: V,
+        ttl_in_seconds: i64,
+    ) -> RedisResult<Vec<RedisValue>>
+    where
+        V: TryInto<RedisValue> + Debug + FromRedis + Send + Sync,
+        V::Error: Into<RedisError> + Send + Sync,
+    {
+        match self {
+            Self::Fred { pool, .. } => {
+                let trx = pool.next().multi();
+
+                trx.set::<(), _, _>(
+                    &key,
+                    value,
+                    Some(Expiration::EX(ttl_in_seconds)),
+                    Some(SetOptions::NX),
+                    false,
+                )
+                .await?;
+                trx.get::<V, _>(&key).await?;
+
+                trx.exec(true).await
+            }
+            Self::InMemory(redis) => redis.set_if_not_exists_and_get(&key, value, ttl_in_seconds),
+        }
+    }
+}
+
+/// `fred` only scans through the primary, so the cursor is driven by hand on the replicas
+async fn replica_hscan(
+    replicas: &Replicas,
+    key: &str,
+    pattern: &str,
+    count: Option<u32>,
+) -> RedisResult<Vec<String>> {
+    let mut cursor = String::from("0");
+    let mut values = Vec::new();
+
+    loop {
+        let mut args: Vec<RedisValue> = vec![
+            key.into(),
+            cursor.as_str().into(),
+            "MATCH".into(),
+            pattern.into(),
+        ];
+        if let Some(count) = count {
+            args.extend(["COUNT".into(), count.into()]);
+        }
+
+        let (next_cursor, fields): (String, Vec<String>) = replicas
+            .custom(
+                CustomCommand::new_static("HSCAN", Some(ClusterHash::FirstKey), false),
+                args,
+            )
+            .await?;
+        // The fields & their values are interleaved
+        values.extend(fields.into_iter().skip(1).step_by(2));
+
+        if next_cursor == "0" {
+            return Ok(values);
+        }
+        cursor = next_cursor;
+    }
+}
@@ -3,6 +3,7 @@
 
 use common_utils::errors::CustomResult;
 use fred::types::RedisValue as FredRedisValue;
+use hyperswitch_masking::Secret;
 
 use crate::{errors, RedisConnectionPool};
 
@@ -51,6 +52,18 @@ pub struct RedisSettings {
     pub port: u16,
     pub cluster_enabled: bool,
     pub cluster_urls: Vec<String>,
+    /// Discover the primary through Redis Sentinel, `host` and `port` being those of a sentinel
+    pub sentinel_enabled: bool,
+    /// Addresses of the other sentinels, as `host:port`
+    pub sentinel_urls: Vec<String>,
+    /// Name under which the sentinels monitor the primary
+    pub sentinel_service_name: String,
+    /// Credentials of the sentinels, if they require authentication
+    pub sentinel_username: Option<String>,
+    pub sentinel_password: Option<Secret<String>>,
+    /// Send the `*_from_replica` reads, which can tolerate replication lag, to the replicas of the
+    /// primary, falling back to the primary if no replica is reachable
+    pub read_from_replicas: bool,
     pub use_legacy_version: bool,
     pub pool_size: usize,
     pub reconnect_max_attempts: u32,
@@ -88,6 +101,22 @@ impl RedisSettings {
             ))
         })?;
 
+        when(self.cluster_enabled && self.sentinel_enabled, || {
+            Err(errors::RedisError::InvalidConfiguration(
+                "Redis `cluster_enabled` and `sentinel_enabled` cannot both be `true`".into(),
+            ))
+        })?;
+
+        when(
+            self.sentinel_enabled && self.sentinel_service_name.is_default_or_empty(),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel_service_name` must be specified if `sentinel_enabled` is `true`"
+                        .into(),
+                ))
+            },
+        )?;
+
         when(
             self.default_command_timeout < self.unresponsive_timeout,
             || {
@@ -107,6 +136,12 @@ impl Default for RedisSettings {
             port: 6379,
             cluster_enabled: false,
             cluster_urls: vec![],
+            sentinel_enabled: false,
+            sentinel_urls: vec![],
+            sentinel_service_name: String::new(),
+            sentinel_username: None,
+            sentinel_password: None,
+            read_from_replicas: false,
             use_legacy_version: false,
             pool_size: 5,
             reconnect_max_attempts: 5,
@@ -0,0 +1,158 @@
+//! Checks the entities written to redis by the KV store of a merchant against Postgres, or
+//! schedules the check to be performed by the scheduler.
+
+use std::{path::PathBuf, sync::Arc};
+
+use common_utils::{date_time, id_type};
+use error_stack::ResultExt;
+use router::{
+    configs::settings::Settings,
+    core::{
+        errors::{self, RouterResult},
+        kv_consistency::{self, KvConsistencyCheckRequest, KvConsistencyTrackingData},
+    },
+    routes, services,
+};
+use tokio::sync::oneshot;
+
+#[derive(clap::Parser)]
+#[cfg_attr(feature = "vergen", command(version = router_env::version!()))]
+struct KvConsistencyCmdLineConf {
+    /// Config file.
+    /// Application will look for "config/config.toml" if this option isn't specified.
+    #[arg(short = 'f', long, value_name = "FILE")]
+    config_path: Option<PathBuf>,
+    /// Tenant whose KV store is checked, the default tenant if not specified
+    #[arg(long)]
+    tenant: Option<String>,
+    #[command(subcommand)]
+    command: KvConsistencyCommand,
+}
+
+#[derive(clap::Subcommand)]
+enum KvConsistencyCommand {
+    /// Check the KV store of the merchant now, printing the report as JSON
+    Check {
+        #[command(flatten)]
+        args: KvConsistencyCheckArgs,
+    },
+    /// Schedule the check of the KV store of the merchant with the process tracker
+    Schedule {
+        #[command(flatten)]
+        args: KvConsistencyCheckArgs,
+        /// Repeat the check after this interval, the check runs once if not specified
+        #[arg(long)]
+        interval_in_seconds: Option<i64>,
+    },
+}
+
+#[derive(clap::Args)]
+struct KvConsistencyCheckArgs {
+    #[arg(long)]
+    merchant_id: String,
+    /// Maximum number of KV partitions to check, all the partitions are checked if not specified
+    #[arg(long)]
+    sample_size: Option<usize>,
+    /// Evict the entities from redis for which Postgres holds the later version
+    #[arg(long)]
+    repair: bool,
+    /// Entities modified in redis within this duration are considered pending in the drainer
+    #[arg(long, default_value_t = kv_consistency::DEFAULT_DRAIN_GRACE_PERIOD_IN_SECONDS)]
+    drain_grace_period_in_seconds: i64,
+}
+
+impl KvConsistencyCheckArgs {
+    fn into_request(self) -> RouterResult<KvConsistencyCheckRequest> {
+        let merchant_id = id_type::MerchantId::wrap(self.merchant_id).change_context(
+            errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "merchant_id",
+            },
+        )?;
+
+        Ok(KvConsistencyCheckRequest {
+            merchant_id,
+            sample_size: self.sample_size,
+            repair: self.repair,
+            drain_grace_period_in_seconds: self.drain_grace_period_in_seconds,
+        })
+    }
+}
+
+#[tokio::main]
+async fn main() -> RouterResult<()> {
+    let cmd_line = <KvConsistencyCmdLineConf as clap::Parser>::parse();
+
+    #[allow(clippy::expect_used)]
+    let conf = Settings::with_config_path(cmd_line.config_path)
+        .expect("Unable to construct application configuration");
+    let _guard = router_env::setup(&conf.log, "kv_consistency", [router_env::service_name!()]);
+
+    let api_client = Box::new(
+        services::ProxyClient::new(&conf.proxy)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+    );
+    let (redis_shutdown_signal_tx, _redis_shutdown_signal_rx) = oneshot::channel();
+    let app_state = Box::pin(routes::AppState::new(
+        conf,
+        redis_shutdown_signal_tx,
+        api_client,
+    ))
+    .await;
+
+    let tenant_id = cmd_line
+        .tenant
+        .map(id_type::TenantId::try_from_string)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "tenant",
+        })?
+        .unwrap_or_else(id_type::TenantId::get_default_tenant_id);
+    let state = Arc::new(app_state).get_session_state(&tenant_id, None, || {
+        errors::ApiErrorResponse::InvalidTenant {
+            tenant_id: tenant_id.get_string_repr().to_owned(),
+        }
+        .into()
+    })?;
+
+    let output = match cmd_line.command {
+        KvConsistencyCommand::Check { args } => {
+            let report =
+                kv_consistency::check_kv_consistency(&state, &args.into_request()?).await?;
+            serde_json::to_value(report)
+        }
+        KvConsistencyCommand::Schedule {
+            args,
+            interval_in_seconds,
+        } => {
+            if interval_in_seconds.is_some_and(|interval| interval <= 0) {
+                return Err(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "interval_in_seconds",
+                }
+                .into());
+            }
+            let tracking_data = KvConsistencyTrackingData {
+                request: args.into_request()?,
+                interval_in_seconds,
+                report: None,
+            };
+            let process = kv_consistency::add_kv_consistency_check_task(
+                &*state.store,
+                &tracking_data,
+                date_time::now(),
+                state.conf.application_source,
+            )
+            .await?;
+            Ok(serde_json::json!({ "process_tracker_id": process.id }))
+        }
+    }
+    .and_then(|output| serde_json::to_string_pretty(&output))
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize the output")?;
+
+    #[allow(clippy::print_stdout)]
+    {
+        println!("{output}");
+    }
+
+    Ok(())
+}
@@ -336,6 +336,82 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PayoutSyncWorkFlow => {
                     Ok(Box::new(workflows::payout_sync::PayoutSyncWorkFlow))
                 }
+                storage::ProcessTrackerRunner::WebhookSecretRotationWorkflow => {
+                    #[cfg(feature = "v1")]
+                    {
+                        Ok(Box::new(
+                            workflows::webhook_secret_rotation::WebhookSecretRotationWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "v1"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run webhook secret rotation workflow when v1 feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::RoutingActivationWorkflow => {
+                    #[cfg(all(feature = "olap", feature = "v1"))]
+                    {
+                        Ok(Box::new(
+                            workflows::routing_activation::RoutingActivationWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(all(feature = "olap", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run routing activation workflow when olap or v1 feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::KvConsistencyCheckWorkflow => {
+                    #[cfg(feature = "v1")]
+                    {
+                        Ok(Box::new(
+                            workflows::kv_consistency::KvConsistencyCheckWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "v1"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run KV consistency check workflow when v1 feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::KvMigrationWorkflow => {
+                    #[cfg(feature = "v1")]
+                    {
+                        Ok(Box::new(workflows::kv_migration::KvMigrationWorkflow))
+                    }
+
+                    #[cfg(not(feature = "v1"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run KV migration workflow when v1 feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::FrmReviewSlaWorkflow => {
+                    #[cfg(all(feature = "frm", feature = "v1"))]
+                    {
+                        Ok(Box::new(workflows::frm_review_sla::FrmReviewSlaWorkflow))
+                    }
+
+                    #[cfg(not(all(feature = "frm", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run manual review SLA workflow when frm or v1 feature is disabled",
+                            )
+                    }
+                }
             }
         };
 
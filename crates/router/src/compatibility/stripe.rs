@@ -1,8 +1,12 @@
 pub mod app;
 pub mod customers;
+#[cfg(feature = "olap")]
+pub mod events;
 pub mod payment_intents;
+pub mod payment_methods;
 pub mod refunds;
 pub mod setup_intents;
+pub mod webhook_endpoints;
 pub mod webhooks;
 #[cfg(feature = "v1")]
 use actix_web::{web, Scope};
@@ -18,13 +22,19 @@ impl StripeApis {
     pub fn server(state: routes::AppState) -> Scope {
         let max_depth = 10;
         let strict = false;
-        web::scope("/vs/v1")
+        let mut route = web::scope("/vs/v1")
             .app_data(web::Data::new(serde_qs::Config::new(max_depth, strict)))
             .service(app::SetupIntents::server(state.clone()))
             .service(app::PaymentIntents::server(state.clone()))
             .service(app::Refunds::server(state.clone()))
             .service(app::Customers::server(state.clone()))
             .service(app::Webhooks::server(state.clone()))
-            .service(app::Mandates::server(state))
+            .service(app::PaymentMethods::server(state.clone()))
+            .service(app::WebhookEndpoints::server(state.clone()));
+        #[cfg(feature = "olap")]
+        {
+            route = route.service(app::Events::server(state));
+        }
+        route
     }
 }
@@ -1,9 +1,11 @@
 use actix_web::{web, Scope};
 
+#[cfg(feature = "olap")]
+use super::events::*;
 #[cfg(feature = "v1")]
 use super::{customers::*, payment_intents::*, setup_intents::*};
-use super::{refunds::*, webhooks::*};
-use crate::routes::{self, mandates, webhooks};
+use super::{payment_methods::*, refunds::*, webhook_endpoints::*, webhooks::*};
+use crate::routes::{self, webhooks};
 
 pub struct PaymentIntents;
 
@@ -117,12 +119,46 @@ impl Webhooks {
     }
 }
 
-pub struct Mandates;
+pub struct PaymentMethods;
 
-impl Mandates {
+impl PaymentMethods {
     pub fn server(config: routes::AppState) -> Scope {
         web::scope("/payment_methods")
             .app_data(web::Data::new(config))
-            .service(web::resource("/{id}/detach").route(web::post().to(mandates::revoke_mandate)))
+            .service(web::resource("").route(web::post().to(payment_method_create)))
+            .service(web::resource("/{id}").route(web::get().to(payment_method_retrieve)))
+            .service(web::resource("/{id}/attach").route(web::post().to(payment_method_attach)))
+            .service(web::resource("/{id}/detach").route(web::post().to(payment_method_detach)))
+    }
+}
+
+pub struct Events;
+
+#[cfg(feature = "olap")]
+impl Events {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/events")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(event_list)))
+    }
+}
+
+pub struct WebhookEndpoints;
+
+impl WebhookEndpoints {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/webhook_endpoints")
+            .app_data(web::Data::new(config))
+            .service(
+                web::resource("")
+                    .route(web::post().to(webhook_endpoint_create))
+                    .route(web::get().to(webhook_endpoint_list)),
+            )
+            .service(
+                web::resource("/{id}")
+                    .route(web::get().to(webhook_endpoint_retrieve))
+                    .route(web::post().to(webhook_endpoint_update))
+                    .route(web::delete().to(webhook_endpoint_delete)),
+            )
     }
 }
@@ -291,6 +291,8 @@ pub enum StripeErrorCode {
     ProfileAcquirerNotFound,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "Subscription Error", message = "Subscription operation: {operation} failed with connector")]
     SubscriptionError { operation: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly")]
+    RateLimit { retry_after_seconds: u64 },
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -399,7 +401,6 @@ pub enum StripeErrorCode {
         PostalCodeInvalid,
         ProcessingError,
         ProductInactive,
-        RateLimit,
         ReferToCustomer,
         RefundDisputedPayment,
         ResourceAlreadyExists,
@@ -593,6 +594,13 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::InvalidRequestData { message } => {
                 Self::InvalidRequestData { message }
             }
+            errors::ApiErrorResponse::InvalidRoutingProgram {
+                message,
+                line,
+                column,
+            } => Self::InvalidRequestData {
+                message: format!("{message} at line {line}, column {column}"),
+            },
             errors::ApiErrorResponse::PreconditionFailed { message } => {
                 Self::PreconditionFailed { message }
             }
@@ -710,6 +718,11 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 object: "tokenization record".to_owned(),
                 id,
             },
+            errors::ApiErrorResponse::TooManyRequests {
+                retry_after_seconds,
+            } => Self::RateLimit {
+                retry_after_seconds,
+            },
             errors::ApiErrorResponse::SubscriptionError { operation } => {
                 Self::SubscriptionError { operation }
             }
@@ -812,15 +825,22 @@ impl actix_web::ResponseError for StripeErrorCode {
             }
             Self::LockTimeout => StatusCode::LOCKED,
             Self::ProfileAcquirerNotFound => StatusCode::NOT_FOUND,
+            Self::RateLimit { .. } => StatusCode::TOO_MANY_REQUESTS,
         }
     }
 
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::http::header;
 
-        actix_web::HttpResponseBuilder::new(self.status_code())
-            .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
-            .body(self.to_string())
+        let mut response = actix_web::HttpResponseBuilder::new(self.status_code());
+        response.insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON));
+        if let Self::RateLimit {
+            retry_after_seconds,
+        } = self
+        {
+            response.insert_header((header::RETRY_AFTER, retry_after_seconds.to_string()));
+        }
+        response.body(self.to_string())
     }
 }
 
@@ -0,0 +1,143 @@
+pub mod types;
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::webhook_events::{EventListConstraints, EventListRequestInternal};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{
+        api_locking,
+        errors::{self as core_errors, RouterResult},
+        webhooks::webhook_events,
+    },
+    routes::{self, SessionState},
+    services::{api, authentication as auth},
+};
+
+/// Events are listed from the most recent one, hence the events following the `starting_after`
+/// cursor are the ones created before the cursor event
+async fn apply_starting_after_cursor(
+    state: &SessionState,
+    auth: &auth::AuthenticationData,
+    constraints: &mut EventListConstraints,
+    starting_after: &str,
+) -> RouterResult<()> {
+    let key_store = auth.platform.get_processor().get_key_store();
+    let cursor_event = match auth.profile.as_ref() {
+        Some(profile) => {
+            state
+                .store
+                .find_initial_event_by_profile_id_initial_attempt_id(
+                    profile.get_id(),
+                    starting_after,
+                    key_store,
+                )
+                .await
+        }
+        None => {
+            state
+                .store
+                .find_initial_event_by_merchant_id_initial_attempt_id(
+                    auth.platform.get_processor().get_account().get_id(),
+                    starting_after,
+                    key_store,
+                )
+                .await
+        }
+    }
+    .change_context(core_errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to find the `starting_after` event")?
+    .ok_or(core_errors::ApiErrorResponse::InvalidRequestData {
+        message: format!("`starting_after` event `{starting_after}` does not exist"),
+    })?;
+
+    // The `created_at` column has microsecond precision
+    let cursor_created_before = cursor_event.created_at - time::Duration::microseconds(1);
+    constraints.created_before = Some(
+        constraints
+            .created_before
+            .map_or(cursor_created_before, |created_before| {
+                created_before.min(cursor_created_before)
+            }),
+    );
+
+    Ok(())
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventInitialDeliveryAttemptList))]
+pub async fn event_list(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let payload: types::StripeEventListConstraints = match qs_config
+        .deserialize_str(req.query_string())
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+
+    let starting_after = payload.starting_after.clone();
+    let constraints = match EventListConstraints::try_from(payload) {
+        Ok(constraints) => constraints,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    // The merchant is only known once the request is authenticated
+    let request_internal = EventListRequestInternal {
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        constraints,
+    };
+
+    let flow = Flow::WebhookEventInitialDeliveryAttemptList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeEventListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        request_internal,
+        |state, auth: auth::AuthenticationData, mut request_internal, _| {
+            let starting_after = starting_after.clone();
+            async move {
+                if let Some(starting_after) = starting_after {
+                    apply_starting_after_cursor(
+                        &state,
+                        &auth,
+                        &mut request_internal.constraints,
+                        &starting_after,
+                    )
+                    .await?;
+                }
+                request_internal.constraints.profile_id = auth
+                    .profile
+                    .as_ref()
+                    .map(|profile| profile.get_id().to_owned());
+
+                webhook_events::list_initial_delivery_attempts(
+                    state,
+                    auth.platform.get_processor().get_account().get_id().clone(),
+                    request_internal.constraints,
+                )
+                .await
+            }
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            allow_connected_scope_operation: true,
+            allow_platform_self_operation: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
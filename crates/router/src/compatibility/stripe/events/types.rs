@@ -0,0 +1,323 @@
+use api_models::{enums::EventClass, webhook_events};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::compatibility::stripe::{errors, webhooks};
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct StripeCreatedRange {
+    pub gt: Option<i64>,
+    pub gte: Option<i64>,
+    pub lt: Option<i64>,
+    pub lte: Option<i64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct StripeEventListConstraints {
+    #[serde(rename = "type")]
+    pub event_type: Option<String>,
+    pub limit: Option<u16>,
+    pub created: Option<StripeCreatedRange>,
+    pub delivery_success: Option<bool>,
+    /// Applied once the merchant is authenticated, as the cursor event has to be looked up
+    pub starting_after: Option<String>,
+    pub ending_before: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeEventObject {
+    pub id: String,
+    pub object: &'static str,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeEventData {
+    pub object: StripeEventObject,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeEvent {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub stype: &'static str,
+    pub data: StripeEventData,
+    pub created: i64,
+    pub pending_webhooks: u8,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeEventListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripeEvent>,
+}
+
+impl TryFrom<StripeEventListConstraints> for webhook_events::EventListConstraints {
+    type Error = error_stack::Report<errors::StripeErrorCode>;
+
+    fn try_from(item: StripeEventListConstraints) -> Result<Self, Self::Error> {
+        // Events are listed from the most recent one, paginating backwards is not supported
+        if item.ending_before.is_some() {
+            return Err(error_stack::report!(
+                errors::StripeErrorCode::ParameterUnknown {
+                    field_name: "ending_before".to_owned(),
+                    expected_format: "`starting_after` to paginate through events".to_owned(),
+                }
+            ));
+        }
+
+        let event_types = item
+            .event_type
+            .map(|stripe_event_type| {
+                let event_types =
+                    webhooks::get_event_types_from_stripe_event_type(&stripe_event_type);
+                if event_types.is_empty() {
+                    Err(error_stack::report!(
+                        errors::StripeErrorCode::ParameterUnknown {
+                            field_name: "type".to_owned(),
+                            expected_format:
+                                "a supported event type such as `payment_intent.succeeded`"
+                                    .to_owned(),
+                        }
+                    ))
+                } else {
+                    Ok(event_types)
+                }
+            })
+            .transpose()?;
+        let created = item.created.unwrap_or_default();
+
+        // The created range is inclusive. Stripe timestamps are truncated to the second, hence
+        // `created > gt` holds from the next second on, while `created < lt` holds up to the
+        // microsecond, the precision of the `created_at` column, before `lt`
+        let created_after = match created.gte {
+            Some(created_gte) => from_timestamp_to_datetime(Some(created_gte))?,
+            None => from_timestamp_to_datetime(created.gt)?
+                .map(|created_gt| created_gt + time::Duration::seconds(1)),
+        };
+        let created_before = match created.lte {
+            Some(created_lte) => from_timestamp_to_datetime(Some(created_lte))?,
+            None => from_timestamp_to_datetime(created.lt)?
+                .map(|created_lt| created_lt - time::Duration::microseconds(1)),
+        };
+
+        Ok(Self {
+            created_after,
+            created_before,
+            limit: item.limit,
+            offset: None,
+            object_id: None,
+            event_id: None,
+            profile_id: None,
+            event_classes: None,
+            event_types,
+            is_delivered: item.delivery_success,
+        })
+    }
+}
+
+#[inline]
+fn from_timestamp_to_datetime(
+    time: Option<i64>,
+) -> Result<Option<PrimitiveDateTime>, error_stack::Report<errors::StripeErrorCode>> {
+    time.map(|time| {
+        time::OffsetDateTime::from_unix_timestamp(time)
+            .map(|time| PrimitiveDateTime::new(time.date(), time.time()))
+            .map_err(|_| {
+                error_stack::report!(errors::StripeErrorCode::ParameterUnknown {
+                    field_name: "created".to_owned(),
+                    expected_format: "a unix timestamp".to_owned(),
+                })
+            })
+    })
+    .transpose()
+}
+
+fn get_stripe_object_name(event_class: EventClass) -> &'static str {
+    match event_class {
+        EventClass::Payments => "payment_intent",
+        EventClass::Refunds => "refund",
+        EventClass::Disputes => "dispute",
+        EventClass::Mandates => "mandate",
+        #[cfg(feature = "payouts")]
+        EventClass::Payouts => "payout",
+        EventClass::Subscriptions => "invoice",
+        EventClass::Routing => "routing_algorithm",
+    }
+}
+
+impl From<webhook_events::EventListItemResponse> for StripeEvent {
+    fn from(item: webhook_events::EventListItemResponse) -> Self {
+        Self {
+            id: item.event_id,
+            object: "event",
+            stype: webhooks::get_stripe_event_type(item.event_type),
+            data: StripeEventData {
+                object: StripeEventObject {
+                    id: item.object_id,
+                    object: get_stripe_object_name(item.event_class),
+                },
+            },
+            created: item.created.assume_utc().unix_timestamp(),
+            pending_webhooks: u8::from(item.is_delivery_successful != Some(true)),
+        }
+    }
+}
+
+impl From<webhook_events::TotalEventsResponse> for StripeEventListResponse {
+    fn from(item: webhook_events::TotalEventsResponse) -> Self {
+        // The total count covers every event matching the constraints, including those past the
+        // `starting_after` cursor
+        let has_more = usize::try_from(item.total_count)
+            .is_ok_and(|total_count| total_count > item.events.len());
+
+        Self {
+            object: "list",
+            url: "/v1/events",
+            has_more,
+            data: item.events.into_iter().map(From::from).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use api_models::enums::EventType;
+
+    use super::*;
+
+    fn get_datetime(timestamp: i64) -> Option<PrimitiveDateTime> {
+        from_timestamp_to_datetime(Some(timestamp)).unwrap()
+    }
+
+    fn get_event_list_item(
+        event_type: EventType,
+        is_delivery_successful: Option<bool>,
+    ) -> webhook_events::EventListItemResponse {
+        webhook_events::EventListItemResponse {
+            event_id: "evt_1".to_string(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            profile_id: common_utils::id_type::ProfileId::try_from(Cow::from("pro_1")).unwrap(),
+            object_id: "pay_1".to_string(),
+            event_type,
+            event_class: EventClass::Payments,
+            is_delivery_successful,
+            initial_attempt_id: "evt_1".to_string(),
+            created: get_datetime(1_700_000_000).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_strict_created_bounds_are_excluded() {
+        let constraints =
+            webhook_events::EventListConstraints::try_from(StripeEventListConstraints {
+                created: Some(StripeCreatedRange {
+                    gt: Some(1_700_000_000),
+                    lt: Some(1_700_000_100),
+                    ..StripeCreatedRange::default()
+                }),
+                ..StripeEventListConstraints::default()
+            })
+            .unwrap();
+
+        assert_eq!(constraints.created_after, get_datetime(1_700_000_001));
+        assert_eq!(
+            constraints.created_before,
+            get_datetime(1_700_000_100)
+                .map(|created_lt| created_lt - time::Duration::microseconds(1))
+        );
+    }
+
+    #[test]
+    fn test_ending_before_is_rejected() {
+        let error = webhook_events::EventListConstraints::try_from(StripeEventListConstraints {
+            ending_before: Some("evt_1".to_string()),
+            ..StripeEventListConstraints::default()
+        })
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::StripeErrorCode::ParameterUnknown { field_name, .. } if field_name == "ending_before"
+        ));
+    }
+
+    #[test]
+    fn test_inclusive_created_bounds_take_precedence() {
+        let constraints =
+            webhook_events::EventListConstraints::try_from(StripeEventListConstraints {
+                created: Some(StripeCreatedRange {
+                    gt: Some(1_700_000_000),
+                    gte: Some(1_700_000_010),
+                    lt: Some(1_700_000_100),
+                    lte: Some(1_700_000_090),
+                }),
+                limit: Some(10),
+                delivery_success: Some(false),
+                ..StripeEventListConstraints::default()
+            })
+            .unwrap();
+
+        assert_eq!(constraints.created_after, get_datetime(1_700_000_010));
+        assert_eq!(constraints.created_before, get_datetime(1_700_000_090));
+        assert_eq!(constraints.limit, Some(10));
+        assert_eq!(constraints.is_delivered, Some(false));
+    }
+
+    #[test]
+    fn test_event_type_filter() {
+        let constraints =
+            webhook_events::EventListConstraints::try_from(StripeEventListConstraints {
+                event_type: Some("payment_intent.canceled".to_string()),
+                ..StripeEventListConstraints::default()
+            })
+            .unwrap();
+        let event_types = constraints.event_types.unwrap();
+        assert!(event_types.contains(&EventType::PaymentCancelled));
+        assert!(event_types.contains(&EventType::PaymentExpired));
+        assert!(!event_types.contains(&EventType::PaymentSucceeded));
+
+        let error = webhook_events::EventListConstraints::try_from(StripeEventListConstraints {
+            event_type: Some("customer.created".to_string()),
+            ..StripeEventListConstraints::default()
+        })
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::StripeErrorCode::ParameterUnknown { field_name, .. } if field_name == "type"
+        ));
+    }
+
+    #[test]
+    fn test_event_conversion() {
+        let event = StripeEvent::from(get_event_list_item(EventType::PaymentSucceeded, Some(true)));
+        assert_eq!(event.stype, "payment_intent.succeeded");
+        assert_eq!(event.data.object.id, "pay_1");
+        assert_eq!(event.data.object.object, "payment_intent");
+        assert_eq!(event.created, 1_700_000_000);
+        assert_eq!(event.pending_webhooks, 0);
+
+        let event = StripeEvent::from(get_event_list_item(EventType::PaymentFailed, None));
+        assert_eq!(event.pending_webhooks, 1);
+    }
+
+    #[test]
+    fn test_event_list_has_more() {
+        let response = StripeEventListResponse::from(webhook_events::TotalEventsResponse::new(
+            100,
+            vec![get_event_list_item(EventType::PaymentSucceeded, Some(true))],
+        ));
+        assert!(response.has_more);
+        assert_eq!(response.data.len(), 1);
+
+        let response = StripeEventListResponse::from(webhook_events::TotalEventsResponse::new(
+            1,
+            vec![get_event_list_item(EventType::PaymentSucceeded, Some(true))],
+        ));
+        assert!(!response.has_more);
+    }
+}
@@ -0,0 +1,343 @@
+pub mod types;
+use ::payment_methods::controller::PaymentMethodsController;
+use actix_web::{web, HttpRequest, HttpResponse};
+use common_utils::fp_utils;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{
+        api_locking,
+        errors::{ApiErrorResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+    },
+    routes::{self, mandates, SessionState},
+    services::{api, authentication as auth},
+    types::{api::payment_methods as payment_method_types, domain, storage},
+};
+
+/// Prefix of the identifiers of mandates, which were detached through this endpoint before it
+/// supported payment methods
+const MANDATE_ID_PREFIX: &str = "man_";
+
+/// Fetches the stored payment method, whose status is not part of the payment method response
+async fn find_storage_payment_method(
+    state: &SessionState,
+    provider: &domain::Provider,
+    payment_method_id: &str,
+) -> RouterResult<domain::PaymentMethod> {
+    state
+        .store
+        .find_payment_method(
+            provider.get_key_store(),
+            payment_method_id,
+            provider.get_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentMethodNotFound)
+}
+
+async fn update_payment_method_status(
+    state: &SessionState,
+    auth: &auth::AuthenticationData,
+    storage_payment_method: domain::PaymentMethod,
+    status: common_enums::PaymentMethodStatus,
+) -> RouterResult<()> {
+    let provider = auth.platform.get_provider();
+    state
+        .store
+        .update_payment_method(
+            provider.get_key_store(),
+            storage_payment_method,
+            storage::PaymentMethodUpdate::StatusUpdate {
+                status: Some(status),
+                last_modified_by: auth
+                    .platform
+                    .get_initiator()
+                    .and_then(|initiator| initiator.to_created_by())
+                    .map(|last_modified_by| last_modified_by.to_string()),
+            },
+            provider.get_account().storage_scheme,
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the status of the payment method")?;
+
+    Ok(())
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsCreate))]
+pub async fn payment_method_create(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripePaymentMethodCreateRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+
+    let create_pm_req = match payment_method_types::PaymentMethodCreate::try_from(payload) {
+        Ok(req) => req,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let flow = Flow::PaymentMethodsCreate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethod,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_pm_req,
+        |state, auth: auth::AuthenticationData, req, _| async move {
+            Box::pin(cards::get_client_secret_or_add_payment_method(
+                &state,
+                req,
+                auth.platform.get_provider(),
+                auth.platform.get_initiator(),
+            ))
+            .await
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            allow_connected_scope_operation: true,
+            allow_platform_self_operation: true,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsRetrieve))]
+pub async fn payment_method_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = payment_method_types::PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+
+    let flow = Flow::PaymentMethodsRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethod,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, pm, _| async move {
+            let provider = auth.platform.get_provider();
+            let mut payment_method = cards::PmCards {
+                state: &state,
+                provider,
+            }
+            .retrieve_payment_method(pm.clone())
+            .await?
+            .get_json_body()
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payment methods core")?;
+
+            // Detached payment methods are deactivated and no longer associated with the customer
+            let storage_payment_method =
+                find_storage_payment_method(&state, provider, &pm.payment_method_id).await?;
+            if storage_payment_method.status == common_enums::PaymentMethodStatus::Inactive {
+                payment_method.customer_id = None;
+            }
+
+            Ok::<_, error_stack::Report<ApiErrorResponse>>(api::ApplicationResponse::Json(
+                payment_method,
+            ))
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            allow_connected_scope_operation: true,
+            allow_platform_self_operation: true,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsUpdate))]
+pub async fn payment_method_attach(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let attach_req: types::StripePaymentMethodAttachRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+    let payload = payment_method_types::PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+
+    let flow = Flow::PaymentMethodsUpdate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethod,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, pm, _| {
+            let customer_id = attach_req.customer.clone();
+            async move {
+                let provider = auth.platform.get_provider();
+                let payment_method = cards::PmCards {
+                    state: &state,
+                    provider,
+                }
+                .retrieve_payment_method(pm.clone())
+                .await?
+                .get_json_body()
+                .change_context(ApiErrorResponse::InternalServerError)
+                .attach_printable("Unexpected response from payment methods core")?;
+
+                // Payment methods are saved against a customer when they are created and cannot be
+                // moved to another customer, hence attaching one is a no-op for that same customer
+                // and the payment method does not exist for any other customer.
+                fp_utils::when(
+                    payment_method.customer_id.as_ref() != Some(&customer_id),
+                    || {
+                        Err(report!(ApiErrorResponse::GenericNotFoundError {
+                            message: format!(
+                                "No such PaymentMethod for customer '{}': payment methods cannot be attached to a different customer",
+                                customer_id.get_string_repr()
+                            ),
+                        }))
+                    },
+                )?;
+
+                // A detached payment method is reactivated when it is attached again
+                let storage_payment_method =
+                    find_storage_payment_method(&state, provider, &pm.payment_method_id).await?;
+                if storage_payment_method.status == common_enums::PaymentMethodStatus::Inactive {
+                    update_payment_method_status(
+                        &state,
+                        &auth,
+                        storage_payment_method,
+                        common_enums::PaymentMethodStatus::Active,
+                    )
+                    .await?;
+                }
+
+                Ok::<_, error_stack::Report<ApiErrorResponse>>(api::ApplicationResponse::Json(
+                    payment_method,
+                ))
+            }
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            allow_connected_scope_operation: true,
+            allow_platform_self_operation: true,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsDelete))]
+pub async fn payment_method_detach(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let id = path.into_inner();
+    if id.starts_with(MANDATE_ID_PREFIX) {
+        return mandates::revoke_mandate(state, req, web::Path::from(id)).await;
+    }
+
+    let payload = payment_method_types::PaymentMethodId {
+        payment_method_id: id,
+    };
+
+    let flow = Flow::PaymentMethodsDelete;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethod,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, pm, _| async move {
+            let provider = auth.platform.get_provider();
+            let mut payment_method = cards::PmCards {
+                state: &state,
+                provider,
+            }
+            .retrieve_payment_method(pm.clone())
+            .await?
+            .get_json_body()
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payment methods core")?;
+
+            // The payment method is deactivated rather than deleted, so that it can no longer be
+            // used while the card stays in the vault
+            let storage_payment_method =
+                find_storage_payment_method(&state, provider, &pm.payment_method_id).await?;
+            update_payment_method_status(
+                &state,
+                &auth,
+                storage_payment_method,
+                common_enums::PaymentMethodStatus::Inactive,
+            )
+            .await?;
+
+            // A detached payment method is no longer associated with the customer
+            payment_method.customer_id = None;
+            Ok::<_, error_stack::Report<ApiErrorResponse>>(api::ApplicationResponse::Json(
+                payment_method,
+            ))
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            allow_connected_scope_operation: true,
+            allow_platform_self_operation: true,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,132 @@
+use api_models::payment_methods as api_types;
+use common_utils::{id_type, pii};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::{
+        customers::types::CardDetails, errors, payment_intents::types::StripeBillingDetails,
+    },
+    types::api::enums as api_enums,
+};
+
+#[derive(Debug, Default, PartialEq, Eq, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum StripePaymentMethodType {
+    #[default]
+    Card,
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize, Clone)]
+pub struct StripePaymentMethodCard {
+    pub number: cards::CardNumber,
+    pub exp_month: hyperswitch_masking::Secret<String>,
+    pub exp_year: hyperswitch_masking::Secret<String>,
+    pub cvc: Option<hyperswitch_masking::Secret<String>>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Deserialize, Clone)]
+pub struct StripePaymentMethodCreateRequest {
+    #[serde(rename = "type")]
+    pub stype: StripePaymentMethodType,
+    pub card: Option<StripePaymentMethodCard>,
+    pub billing_details: Option<StripeBillingDetails>,
+    pub customer: Option<id_type::CustomerId>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize, Clone)]
+pub struct StripePaymentMethodAttachRequest {
+    pub customer: id_type::CustomerId,
+}
+
+#[derive(Serialize, PartialEq, Eq)]
+pub struct StripePaymentMethod {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub stype: Option<api_enums::PaymentMethod>,
+    pub customer: Option<id_type::CustomerId>,
+    pub card: Option<CardDetails>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created: Option<i64>,
+}
+
+impl TryFrom<StripePaymentMethodCreateRequest> for api_types::PaymentMethodCreate {
+    type Error = error_stack::Report<errors::StripeErrorCode>;
+
+    fn try_from(item: StripePaymentMethodCreateRequest) -> Result<Self, Self::Error> {
+        // Payment methods are always saved against a customer, so a payment method cannot be
+        // created first and attached to a customer later.
+        let customer_id = item.customer.ok_or_else(|| {
+            error_stack::report!(errors::StripeErrorCode::ParameterMissing {
+                field_name: "customer".to_owned(),
+                param: "customer".to_owned(),
+            })
+        })?;
+        let card = item.card.ok_or_else(|| {
+            error_stack::report!(errors::StripeErrorCode::ParameterMissing {
+                field_name: "card".to_owned(),
+                param: "card".to_owned(),
+            })
+        })?;
+        let card_holder_name = item
+            .billing_details
+            .as_ref()
+            .and_then(|billing_details| billing_details.name.clone())
+            .map(hyperswitch_masking::Secret::new);
+
+        Ok(Self {
+            payment_method: Some(api_enums::PaymentMethod::from(item.stype)),
+            payment_method_type: None,
+            payment_method_issuer: None,
+            payment_method_issuer_code: None,
+            card: Some(api_types::CardDetail {
+                card_number: card.number,
+                card_exp_month: card.exp_month,
+                card_exp_year: card.exp_year,
+                card_cvc: card.cvc,
+                card_holder_name,
+                nick_name: None,
+                card_issuing_country: None,
+                card_issuing_country_code: None,
+                card_network: None,
+                card_issuer: None,
+                card_type: None,
+            }),
+            metadata: item.metadata,
+            customer_id: Some(customer_id),
+            card_network: None,
+            #[cfg(feature = "payouts")]
+            bank_transfer: None,
+            #[cfg(feature = "payouts")]
+            wallet: None,
+            client_secret: None,
+            payment_method_data: None,
+            billing: item.billing_details.map(From::from),
+            connector_mandate_details: None,
+            network_transaction_id: None,
+        })
+    }
+}
+
+impl From<StripePaymentMethodType> for api_enums::PaymentMethod {
+    fn from(item: StripePaymentMethodType) -> Self {
+        match item {
+            StripePaymentMethodType::Card => Self::Card,
+        }
+    }
+}
+
+impl From<api_types::PaymentMethodResponse> for StripePaymentMethod {
+    fn from(item: api_types::PaymentMethodResponse) -> Self {
+        Self {
+            id: item.payment_method_id,
+            object: "payment_method",
+            stype: item.payment_method,
+            customer: item.customer_id,
+            card: item.card.map(From::from),
+            metadata: item.metadata,
+            created: item.created.map(|t| t.assume_utc().unix_timestamp()),
+        }
+    }
+}
@@ -0,0 +1,289 @@
+pub mod types;
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::webhook_endpoints as api_webhook_endpoints;
+use error_stack::report;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{api_locking, errors::RouterResult, utils as core_utils, webhooks::webhook_endpoints},
+    routes::{self, SessionState},
+    services::{api, authentication as auth},
+};
+
+/// Webhook endpoints are configured per business profile, the profile of the API key is used
+/// when it is scoped to one, the default profile of the merchant otherwise
+async fn get_profile_id(
+    state: &SessionState,
+    auth: &auth::AuthenticationData,
+) -> RouterResult<common_utils::id_type::ProfileId> {
+    core_utils::get_profile_id_from_business_details(
+        None,
+        None,
+        auth.platform.get_processor(),
+        auth.profile.as_ref().map(|profile| profile.get_id()),
+        &*state.store,
+        false,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointCreate))]
+pub async fn webhook_endpoint_create(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripeWebhookEndpointCreateRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+
+    let create_req = match api_webhook_endpoints::WebhookEndpointCreateRequest::try_from(payload) {
+        Ok(req) => req,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let flow = Flow::WebhookEndpointCreate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeWebhookEndpoint,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_req,
+        |state, auth: auth::AuthenticationData, req, _| async move {
+            let profile_id = get_profile_id(&state, &auth).await?;
+            webhook_endpoints::create_webhook_endpoint(
+                state,
+                auth.platform.get_processor().get_account().get_id().clone(),
+                auth.platform.get_processor().get_key_store().clone(),
+                profile_id,
+                req,
+            )
+            .await
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            allow_connected_scope_operation: true,
+            allow_platform_self_operation: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointList))]
+pub async fn webhook_endpoint_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeWebhookEndpointListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| async move {
+            let profile_id = get_profile_id(&state, &auth).await?;
+            webhook_endpoints::list_webhook_endpoints(
+                state,
+                auth.platform.get_processor().get_account().get_id().clone(),
+                auth.platform.get_processor().get_key_store().clone(),
+                profile_id,
+            )
+            .await
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            allow_connected_scope_operation: true,
+            allow_platform_self_operation: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointRetrieve))]
+pub async fn webhook_endpoint_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let endpoint_id = path.into_inner();
+
+    let flow = Flow::WebhookEndpointRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeWebhookEndpoint,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            let endpoint_id = endpoint_id.clone();
+            async move {
+                let profile_id = get_profile_id(&state, &auth).await?;
+                webhook_endpoints::retrieve_webhook_endpoint(
+                    state,
+                    auth.platform.get_processor().get_account().get_id().clone(),
+                    auth.platform.get_processor().get_key_store().clone(),
+                    api_webhook_endpoints::WebhookEndpointRequestInternal {
+                        profile_id,
+                        endpoint_id,
+                    },
+                )
+                .await
+            }
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            allow_connected_scope_operation: true,
+            allow_platform_self_operation: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointUpdate))]
+pub async fn webhook_endpoint_update(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripeWebhookEndpointUpdateRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+
+    let update_req = match api_webhook_endpoints::WebhookEndpointUpdateRequest::try_from(payload) {
+        Ok(req) => req,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+    let endpoint_id = path.into_inner();
+
+    let flow = Flow::WebhookEndpointUpdate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeWebhookEndpoint,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        update_req,
+        |state, auth: auth::AuthenticationData, update_req, _| {
+            let endpoint_id = endpoint_id.clone();
+            async move {
+                let profile_id = get_profile_id(&state, &auth).await?;
+                webhook_endpoints::update_webhook_endpoint(
+                    state,
+                    auth.platform.get_processor().get_account().get_id().clone(),
+                    auth.platform.get_processor().get_key_store().clone(),
+                    api_webhook_endpoints::WebhookEndpointRequestInternal {
+                        profile_id,
+                        endpoint_id,
+                    },
+                    update_req,
+                )
+                .await
+            }
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            allow_connected_scope_operation: true,
+            allow_platform_self_operation: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointDelete))]
+pub async fn webhook_endpoint_delete(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let endpoint_id = path.into_inner();
+
+    let flow = Flow::WebhookEndpointDelete;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeWebhookEndpointDeleteResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            let endpoint_id = endpoint_id.clone();
+            async move {
+                let profile_id = get_profile_id(&state, &auth).await?;
+                webhook_endpoints::delete_webhook_endpoint(
+                    state,
+                    auth.platform.get_processor().get_account().get_id().clone(),
+                    auth.platform.get_processor().get_key_store().clone(),
+                    api_webhook_endpoints::WebhookEndpointRequestInternal {
+                        profile_id,
+                        endpoint_id,
+                    },
+                )
+                .await
+            }
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            allow_connected_scope_operation: true,
+            allow_platform_self_operation: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
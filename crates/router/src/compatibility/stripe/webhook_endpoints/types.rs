@@ -0,0 +1,342 @@
+use std::collections::HashSet;
+
+use api_models::{enums::EventType, webhook_endpoints};
+use serde::{Deserialize, Serialize};
+
+use crate::compatibility::stripe::{errors, webhooks};
+
+/// The stripe event type which subscribes an endpoint to all the event types
+const ALL_EVENT_TYPES: &str = "*";
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct StripeWebhookEndpointCreateRequest {
+    pub url: hyperswitch_masking::Secret<String>,
+    #[serde(default)]
+    pub enabled_events: Vec<String>,
+    pub description: Option<String>,
+    pub api_version: Option<String>,         // not used
+    pub connect: Option<bool>,               // not used
+    pub metadata: Option<serde_json::Value>, // not used
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct StripeWebhookEndpointUpdateRequest {
+    pub url: Option<hyperswitch_masking::Secret<String>>,
+    pub enabled_events: Option<Vec<String>>,
+    pub description: Option<String>,
+    pub disabled: Option<bool>,
+    pub metadata: Option<serde_json::Value>, // not used
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeWebhookEndpoint {
+    pub id: String,
+    pub object: &'static str,
+    pub url: hyperswitch_masking::Secret<String>,
+    pub enabled_events: Vec<&'static str>,
+    pub description: Option<String>,
+    pub status: &'static str,
+    pub created: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<hyperswitch_masking::Secret<String>>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeWebhookEndpointListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripeWebhookEndpoint>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeWebhookEndpointDeleteResponse {
+    pub id: String,
+    pub object: &'static str,
+    pub deleted: bool,
+}
+
+fn get_event_types_from_stripe_event_types(
+    stripe_event_types: Vec<String>,
+) -> Result<Vec<EventType>, error_stack::Report<errors::StripeErrorCode>> {
+    if stripe_event_types
+        .iter()
+        .any(|stripe_event_type| stripe_event_type == ALL_EVENT_TYPES)
+    {
+        return Ok(Vec::new());
+    }
+
+    let mut event_types = HashSet::new();
+    for stripe_event_type in stripe_event_types {
+        let stripe_event_types =
+            webhooks::get_event_types_from_stripe_event_type(&stripe_event_type);
+        if stripe_event_types.is_empty() {
+            return Err(error_stack::report!(
+                errors::StripeErrorCode::ParameterUnknown {
+                    field_name: "enabled_events".to_owned(),
+                    expected_format: format!(
+                        "a list of supported event types such as `payment_intent.succeeded`, or `{ALL_EVENT_TYPES}`"
+                    ),
+                }
+            ));
+        }
+        event_types.extend(stripe_event_types);
+    }
+
+    Ok(event_types.into_iter().collect())
+}
+
+impl TryFrom<StripeWebhookEndpointCreateRequest>
+    for webhook_endpoints::WebhookEndpointCreateRequest
+{
+    type Error = error_stack::Report<errors::StripeErrorCode>;
+
+    fn try_from(item: StripeWebhookEndpointCreateRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            url: item.url,
+            signing_secret: None,
+            enabled_events: get_event_types_from_stripe_event_types(item.enabled_events)?,
+            is_enabled: Some(true),
+            description: item.description,
+        })
+    }
+}
+
+impl TryFrom<StripeWebhookEndpointUpdateRequest>
+    for webhook_endpoints::WebhookEndpointUpdateRequest
+{
+    type Error = error_stack::Report<errors::StripeErrorCode>;
+
+    fn try_from(item: StripeWebhookEndpointUpdateRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            url: item.url,
+            enabled_events: item
+                .enabled_events
+                .map(get_event_types_from_stripe_event_types)
+                .transpose()?,
+            is_enabled: item.disabled.map(|disabled| !disabled),
+            description: item.description,
+        })
+    }
+}
+
+impl From<webhook_endpoints::WebhookEndpointResponse> for StripeWebhookEndpoint {
+    fn from(item: webhook_endpoints::WebhookEndpointResponse) -> Self {
+        let mut enabled_events = item
+            .enabled_events
+            .into_iter()
+            .map(webhooks::get_stripe_event_type)
+            .collect::<Vec<_>>();
+        enabled_events.sort_unstable();
+        enabled_events.dedup();
+        if enabled_events.is_empty() {
+            enabled_events.push(ALL_EVENT_TYPES);
+        }
+
+        Self {
+            id: item.id,
+            object: "webhook_endpoint",
+            url: item.url,
+            enabled_events,
+            description: item.description,
+            status: if item.is_enabled {
+                "enabled"
+            } else {
+                "disabled"
+            },
+            created: item.created_at.assume_utc().unix_timestamp(),
+            secret: None,
+        }
+    }
+}
+
+impl From<webhook_endpoints::WebhookEndpointWithSecretResponse> for StripeWebhookEndpoint {
+    fn from(item: webhook_endpoints::WebhookEndpointWithSecretResponse) -> Self {
+        Self {
+            secret: Some(item.signing_secret),
+            ..Self::from(item.webhook_endpoint)
+        }
+    }
+}
+
+impl From<webhook_endpoints::WebhookEndpointListResponse> for StripeWebhookEndpointListResponse {
+    fn from(item: webhook_endpoints::WebhookEndpointListResponse) -> Self {
+        Self {
+            object: "list",
+            url: "/v1/webhook_endpoints",
+            has_more: false,
+            data: item.data.into_iter().map(From::from).collect(),
+        }
+    }
+}
+
+impl From<webhook_endpoints::WebhookEndpointDeleteResponse>
+    for StripeWebhookEndpointDeleteResponse
+{
+    fn from(item: webhook_endpoints::WebhookEndpointDeleteResponse) -> Self {
+        Self {
+            id: item.id,
+            object: "webhook_endpoint",
+            deleted: item.deleted,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use hyperswitch_masking::{PeekInterface, Secret};
+
+    use super::*;
+
+    fn get_webhook_endpoint(
+        enabled_events: Vec<EventType>,
+        is_enabled: bool,
+    ) -> webhook_endpoints::WebhookEndpointResponse {
+        let now = common_utils::date_time::now();
+        webhook_endpoints::WebhookEndpointResponse {
+            id: "whep_1".to_string(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            profile_id: common_utils::id_type::ProfileId::try_from(Cow::from("pro_1")).unwrap(),
+            url: Secret::new("https://example.com/webhooks".to_string()),
+            enabled_events,
+            is_enabled,
+            description: Some("refunds".to_string()),
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_create_request_conversion() {
+        let create_request = webhook_endpoints::WebhookEndpointCreateRequest::try_from(
+            StripeWebhookEndpointCreateRequest {
+                url: Secret::new("https://example.com/webhooks".to_string()),
+                enabled_events: vec![
+                    "payment_intent.canceled".to_string(),
+                    "refund.succeeded".to_string(),
+                ],
+                ..StripeWebhookEndpointCreateRequest::default()
+            },
+        )
+        .unwrap();
+
+        let enabled_events = create_request
+            .enabled_events
+            .into_iter()
+            .collect::<HashSet<_>>();
+        assert_eq!(
+            enabled_events,
+            HashSet::from([
+                EventType::PaymentCancelled,
+                EventType::PaymentCancelledPostCapture,
+                EventType::PaymentExpired,
+                EventType::RefundSucceeded,
+            ])
+        );
+        assert_eq!(create_request.is_enabled, Some(true));
+        assert!(create_request.signing_secret.is_none());
+    }
+
+    #[test]
+    fn test_all_event_types_subscribe_to_every_event() {
+        let create_request = webhook_endpoints::WebhookEndpointCreateRequest::try_from(
+            StripeWebhookEndpointCreateRequest {
+                enabled_events: vec![
+                    "payment_intent.succeeded".to_string(),
+                    ALL_EVENT_TYPES.to_string(),
+                ],
+                ..StripeWebhookEndpointCreateRequest::default()
+            },
+        )
+        .unwrap();
+
+        assert!(create_request.enabled_events.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_event_type_is_rejected() {
+        let error = webhook_endpoints::WebhookEndpointCreateRequest::try_from(
+            StripeWebhookEndpointCreateRequest {
+                enabled_events: vec!["customer.created".to_string()],
+                ..StripeWebhookEndpointCreateRequest::default()
+            },
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::StripeErrorCode::ParameterUnknown { field_name, .. }
+                if field_name == "enabled_events"
+        ));
+    }
+
+    #[test]
+    fn test_update_request_conversion() {
+        let update_request = webhook_endpoints::WebhookEndpointUpdateRequest::try_from(
+            StripeWebhookEndpointUpdateRequest {
+                disabled: Some(true),
+                ..StripeWebhookEndpointUpdateRequest::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(update_request.is_enabled, Some(false));
+        assert!(update_request.enabled_events.is_none());
+        assert!(update_request.url.is_none());
+    }
+
+    #[test]
+    fn test_webhook_endpoint_conversion() {
+        let webhook_endpoint = StripeWebhookEndpoint::from(get_webhook_endpoint(
+            vec![EventType::PaymentSucceeded, EventType::PaymentCaptured],
+            false,
+        ));
+        assert_eq!(
+            webhook_endpoint.enabled_events,
+            vec!["payment_intent.succeeded"]
+        );
+        assert_eq!(webhook_endpoint.status, "disabled");
+        assert!(webhook_endpoint.secret.is_none());
+
+        let webhook_endpoint =
+            StripeWebhookEndpoint::from(webhook_endpoints::WebhookEndpointWithSecretResponse {
+                webhook_endpoint: get_webhook_endpoint(Vec::new(), true),
+                signing_secret: Secret::new("whsec_1".to_string()),
+            });
+        assert_eq!(webhook_endpoint.enabled_events, vec![ALL_EVENT_TYPES]);
+        assert_eq!(webhook_endpoint.status, "enabled");
+        assert_eq!(
+            webhook_endpoint
+                .secret
+                .as_ref()
+                .map(|secret| secret.peek().as_str()),
+            Some("whsec_1")
+        );
+    }
+
+    #[test]
+    fn test_list_and_delete_response_conversion() {
+        let list_response = StripeWebhookEndpointListResponse::from(
+            webhook_endpoints::WebhookEndpointListResponse {
+                count: 1,
+                data: vec![get_webhook_endpoint(Vec::new(), true)],
+            },
+        );
+        assert!(!list_response.has_more);
+        assert_eq!(list_response.data.len(), 1);
+
+        let delete_response = StripeWebhookEndpointDeleteResponse::from(
+            webhook_endpoints::WebhookEndpointDeleteResponse {
+                id: "whep_1".to_string(),
+                profile_id: common_utils::id_type::ProfileId::try_from(Cow::from("pro_1")).unwrap(),
+                deleted: true,
+            },
+        );
+        assert_eq!(delete_response.id, "whep_1");
+        assert_eq!(delete_response.object, "webhook_endpoint");
+        assert!(delete_response.deleted);
+    }
+}
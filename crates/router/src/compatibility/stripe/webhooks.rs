@@ -1,7 +1,9 @@
+use std::collections::HashSet;
+
 #[cfg(feature = "payouts")]
 use api_models::payouts as payout_models;
 use api_models::{
-    enums::{Currency, DisputeStatus, MandateStatus},
+    enums::{Currency, DisputeStatus, EventClass, MandateStatus},
     webhooks::{self as api},
 };
 use common_utils::{crypto::SignMessage, date_time, ext_traits::Encode};
@@ -42,8 +44,6 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
-        let timestamp = self.created;
-
         let payment_response_hash_key = payment_response_hash_key
             .ok_or(errors::WebhooksFlowError::MerchantConfigNotFound)
             .attach_printable("For stripe compatibility payment_response_hash_key is mandatory")?;
@@ -53,32 +53,86 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
             .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
             .attach_printable("failed encoding outgoing webhook payload")?;
 
-        let new_signature_payload = format!("{timestamp}.{webhook_signature_payload}");
-        let v1 = hex::encode(
-            common_utils::crypto::HmacSha256::sign_message(
-                &common_utils::crypto::HmacSha256,
-                payment_response_hash_key.as_ref(),
-                new_signature_payload.as_bytes(),
-            )
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
-            .attach_printable("Failed to sign the message")?,
-        );
-
-        let t = timestamp;
-        let signature = Some(format!("t={t},v1={v1}"));
+        let signature = get_stripe_webhook_signature(
+            self.created,
+            &webhook_signature_payload,
+            payment_response_hash_key,
+        )?;
 
         Ok(OutgoingWebhookPayloadWithSignature {
             payload: webhook_signature_payload.into(),
-            signature,
+            signature: Some(signature),
         })
     }
 
+    fn get_outgoing_webhook_payload_signature(
+        payload: &str,
+        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+    ) -> errors::CustomResult<Option<String>, errors::WebhooksFlowError> {
+        let payment_response_hash_key = payment_response_hash_key
+            .ok_or(errors::WebhooksFlowError::MerchantConfigNotFound)
+            .attach_printable("For stripe compatibility payment_response_hash_key is mandatory")?;
+
+        // The signature covers the creation time of the event, as it was when first signed
+        let timestamp = serde_json::from_str::<serde_json::Value>(payload)
+            .ok()
+            .and_then(|payload| payload.get("created").and_then(serde_json::Value::as_u64))
+            .ok_or(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+            .attach_printable("Failed to obtain the creation time of the outgoing webhook")?;
+
+        get_stripe_webhook_signature(timestamp, payload, payment_response_hash_key).map(Some)
+    }
+
     fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
         header.push((
             headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE.to_string(),
             signature.into(),
         ))
     }
+
+    /// Stripe carries every signature of the payload in the same header, the signature computed
+    /// with the staged secret is added to it as an additional `v1` entry
+    fn add_staged_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
+        let position = header
+            .iter()
+            .position(|(name, _)| name == headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE);
+
+        match position {
+            Some(position) => {
+                let (name, current_signature) = header.remove(position);
+                let staged_signatures = signature
+                    .split(',')
+                    .filter(|entry| entry.starts_with("v1="))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                header.push((
+                    name,
+                    format!("{},{staged_signatures}", current_signature.into_inner()).into(),
+                ))
+            }
+            None => Self::add_webhook_header(header, signature),
+        }
+    }
+}
+
+fn get_stripe_webhook_signature(
+    timestamp: u64,
+    payload: &str,
+    payment_response_hash_key: impl AsRef<[u8]>,
+) -> errors::CustomResult<String, errors::WebhooksFlowError> {
+    let new_signature_payload = format!("{timestamp}.{payload}");
+    let v1 = hex::encode(
+        common_utils::crypto::HmacSha256::sign_message(
+            &common_utils::crypto::HmacSha256,
+            payment_response_hash_key.as_ref(),
+            new_signature_payload.as_bytes(),
+        )
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the message")?,
+    );
+
+    let t = timestamp;
+    Ok(format!("t={t},v1={v1}"))
 }
 
 #[derive(Serialize, Debug)]
@@ -91,6 +145,7 @@ pub enum StripeWebhookObject {
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
     Subscriptions,
+    Routing,
 }
 
 #[derive(Serialize, Debug)]
@@ -266,7 +321,7 @@ impl From<DisputeStatus> for StripeDisputeStatus {
     }
 }
 
-fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static str {
+pub(super) fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static str {
     match event_type {
         api_models::enums::EventType::PaymentSucceeded => "payment_intent.succeeded",
         api_models::enums::EventType::PaymentFailed => "payment_intent.payment_failed",
@@ -304,9 +359,31 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
         api_models::enums::EventType::InvoicePaid => "invoice.paid",
+        api_models::enums::EventType::RoutingAlgorithmRolledBack => "routing.rolled_back",
     }
 }
 
+/// Returns the event types which are delivered with the specified stripe event type
+pub(super) fn get_event_types_from_stripe_event_type(
+    stripe_event_type: &str,
+) -> HashSet<api_models::enums::EventType> {
+    [
+        EventClass::Payments,
+        EventClass::Refunds,
+        EventClass::Disputes,
+        EventClass::Mandates,
+        #[cfg(feature = "payouts")]
+        EventClass::Payouts,
+        EventClass::Subscriptions,
+        EventClass::Routing,
+    ]
+    .into_iter()
+    .flat_map(EventClass::event_types)
+    .chain([api_models::enums::EventType::PaymentPartiallyAuthorized])
+    .filter(|event_type| get_stripe_event_type(*event_type) == stripe_event_type)
+    .collect()
+}
+
 impl From<api::OutgoingWebhook> for StripeOutgoingWebhook {
     fn from(value: api::OutgoingWebhook) -> Self {
         Self {
@@ -349,6 +426,9 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api_models::webhooks::OutgoingWebhookContent::SubscriptionDetails(_) => {
                 Self::Subscriptions
             }
+            api_models::webhooks::OutgoingWebhookContent::RoutingRollbackDetails(_) => {
+                Self::Routing
+            }
         }
     }
 }
@@ -140,6 +140,7 @@ pub struct Settings<S: SecretState> {
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
     pub lock_settings: LockSettings,
+    pub rate_limit: RateLimitSettings,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub generic_link: GenericLink,
     pub payment_link: PaymentLink,
@@ -1147,6 +1148,7 @@ impl Settings<SecuredSecret> {
                     .list_separator(",")
                     .with_list_parse_key("log.telemetry.route_to_trace")
                     .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("redis.sentinel_urls")
                     .with_list_parse_key("events.kafka.brokers")
                     .with_list_parse_key("connectors.supported.wallets")
                     .with_list_parse_key("connector_request_reference_id_config.merchant_ids_send_payment_id_as_connector_request_id"),
@@ -1218,6 +1220,7 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
+        self.rate_limit.validate()?;
         self.events.validate()?;
 
         #[cfg(feature = "olap")]
@@ -1332,6 +1335,39 @@ impl<'de> Deserialize<'de> for LockSettings {
     }
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    pub enabled: bool,
+    /// Length of the window the limits are counted over, in seconds
+    pub window_in_seconds: u32,
+    /// Requests a merchant may make per window, across all of its API keys and routes
+    pub merchant_limit: Option<u32>,
+    /// Requests which may be made per window with a single API key
+    pub api_key_limit: Option<u32>,
+    /// Requests a merchant may make per window to the flows of a route group, by group name
+    pub route_groups: HashMap<String, RouteGroupRateLimit>,
+}
+
+impl Default for RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_in_seconds: 1,
+            merchant_limit: None,
+            api_key_limit: None,
+            route_groups: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RouteGroupRateLimit {
+    /// Names of the flows in the group, eg: `PaymentsCreate`
+    pub flows: HashSet<String>,
+    pub limit: u32,
+}
+
 #[cfg(feature = "olap")]
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ConnectorOnboarding {
@@ -144,6 +144,32 @@ impl super::settings::ApiKeys {
     }
 }
 
+impl super::settings::RateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.window_in_seconds.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit window_in_seconds must not be 0".into(),
+            ))
+        })?;
+
+        let limits = [self.merchant_limit, self.api_key_limit]
+            .into_iter()
+            .flatten()
+            .chain(self.route_groups.values().map(|group| group.limit));
+        for limit in limits {
+            when(limit == 0, || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "rate limits must not be 0".into(),
+                ))
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
 impl super::settings::LockSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -91,6 +91,9 @@ pub const GUEST_USER_CARD_BLOCKING_CACHE_KEY_PREFIX: &str = "GUEST_USER_CARD_BLO
 
 pub const CUSTOMER_ID_BLOCKING_PREFIX: &str = "CUSTOMER_ID_BLOCKING";
 
+/// Prefix of the redis hash tracking the card testing guard counters of a profile
+pub const CARD_TESTING_GUARD_BLOCKS_PREFIX: &str = "CARD_TESTING_GUARD_BLOCKS";
+
 #[cfg(feature = "olap")]
 pub const VERIFY_CONNECTOR_ID_PREFIX: &str = "conn_verify";
 #[cfg(feature = "olap")]
@@ -31,6 +31,10 @@ pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
 #[cfg(feature = "v1")]
+pub mod kv_consistency;
+#[cfg(feature = "v1")]
+pub mod kv_migration;
+#[cfg(feature = "v1")]
 pub mod locker_migration;
 pub mod mandate;
 pub mod merchant_connector_webhook_management;
@@ -50,7 +54,9 @@ pub mod payout_link;
 pub mod payouts;
 pub mod pm_auth;
 pub mod poll;
+pub mod process_tracker;
 pub mod profile_acquirer;
+pub mod rate_limit;
 #[cfg(feature = "v2")]
 pub mod proxy;
 #[cfg(feature = "recon")]
@@ -34,6 +34,7 @@ use crate::types::transformers::ForeignFrom;
 use crate::{
     consts,
     core::{
+        card_testing_guard::utils as card_testing_guard_utils,
         connector_validation::ConnectorAuthTypeAndMetadataValidation,
         disputes,
         encryption::transfer_encryption_key,
@@ -3162,6 +3163,9 @@ pub async fn kv_for_merchant(
     merchant_id: id_type::MerchantId,
     enable: bool,
 ) -> RouterResponse<api_models::admin::ToggleKVResponse> {
+    #[cfg(feature = "v1")]
+    super::kv_migration::validate_no_kv_migration_in_progress(&state, &merchant_id).await?;
+
     let db = state.store.as_ref();
     let key_store = db
         .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
@@ -3461,6 +3465,12 @@ impl ProfileCreateBridge for api::ProfileCreate {
                 message: "Invalid merchant country code".to_string(),
             })?;
 
+        #[cfg(feature = "frm")]
+        self.native_frm_config
+            .as_ref()
+            .map(crate::core::fraud_check::native::validate_native_frm_config)
+            .transpose()?;
+
         Ok(domain::Profile::from(domain::ProfileSetter {
             profile_id,
             merchant_id: processor.get_account().get_id().clone(),
@@ -3561,6 +3571,8 @@ impl ProfileCreateBridge for api::ProfileCreate {
             merchant_category_code: self.merchant_category_code,
             merchant_country_code: self.merchant_country_code,
             dispute_polling_interval: self.dispute_polling_interval,
+            routing_timezone: self.routing_timezone,
+            native_frm_config: self.native_frm_config,
             is_manual_retry_enabled: self.is_manual_retry_enabled,
             always_enable_overcapture: self.always_enable_overcapture,
             external_vault_details: domain::ExternalVaultDetails::try_from((
@@ -3730,6 +3742,17 @@ impl ProfileCreateBridge for api::ProfileCreate {
     }
 }
 
+/// Validates the card testing guard config of a profile create or update request
+#[cfg(feature = "olap")]
+fn validate_card_testing_guard_config(
+    card_testing_guard_config: Option<&api_models::admin::CardTestingGuardConfig>,
+) -> RouterResult<()> {
+    card_testing_guard_config
+        .map(card_testing_guard_utils::validate_card_testing_guard_allowlist)
+        .transpose()?;
+    Ok(())
+}
+
 #[cfg(feature = "olap")]
 pub async fn create_profile(
     state: SessionState,
@@ -3737,6 +3760,8 @@ pub async fn create_profile(
     processor: domain::Processor,
 ) -> RouterResponse<api_models::admin::ProfileResponse> {
     let db = state.store.as_ref();
+    validate_card_testing_guard_config(request.card_testing_guard_config.as_ref())?;
+
     #[cfg(feature = "v1")]
     let business_profile = request
         .create_domain_model_from_request(&state, &processor)
@@ -4020,6 +4045,12 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 message: "Invalid merchant country code".to_string(),
             })?;
 
+        #[cfg(feature = "frm")]
+        self.native_frm_config
+            .as_ref()
+            .map(crate::core::fraud_check::native::validate_native_frm_config)
+            .transpose()?;
+
         Ok(domain::ProfileUpdate::Update(Box::new(
             domain::ProfileGeneralUpdate {
                 profile_name: self.profile_name,
@@ -4077,6 +4108,8 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 merchant_category_code: self.merchant_category_code,
                 merchant_country_code: self.merchant_country_code,
                 dispute_polling_interval: self.dispute_polling_interval,
+                routing_timezone: self.routing_timezone,
+                native_frm_config: self.native_frm_config,
                 is_manual_retry_enabled: self.is_manual_retry_enabled,
                 always_enable_overcapture: self.always_enable_overcapture,
                 is_external_vault_enabled: self.is_external_vault_enabled,
@@ -4249,6 +4282,8 @@ pub async fn update_profile(
     request: api::ProfileUpdate,
 ) -> RouterResponse<api::ProfileResponse> {
     let db = state.store.as_ref();
+    validate_card_testing_guard_config(request.card_testing_guard_config.as_ref())?;
+
     let business_profile = db
         .find_business_profile_by_merchant_id_profile_id(&key_store, &merchant_id, profile_id)
         .await
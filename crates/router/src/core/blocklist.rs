@@ -65,3 +65,31 @@ pub async fn toggle_blocklist_guard(
     .await
     .map(services::ApplicationResponse::Json)
 }
+
+pub async fn import_blocklist_entries(
+    state: SessionState,
+    platform: domain::Platform,
+    form: utils::BlocklistImportForm,
+) -> RouterResponse<api_blocklist::BlocklistImportResponse> {
+    utils::import_blocklist_entries(
+        &state,
+        platform.get_processor().get_account().get_id(),
+        form,
+    )
+    .await
+    .map(services::ApplicationResponse::Json)
+}
+
+pub async fn export_blocklist_entries(
+    state: SessionState,
+    platform: domain::Platform,
+    query: api_blocklist::ExportBlocklistQuery,
+) -> RouterResponse<Vec<u8>> {
+    utils::export_blocklist_entries(
+        &state,
+        platform.get_processor().get_account().get_id(),
+        query,
+    )
+    .await
+    .map(|csv| services::ApplicationResponse::FileData((csv, mime::TEXT_CSV)))
+}
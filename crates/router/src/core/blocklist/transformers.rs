@@ -20,6 +20,18 @@ impl ForeignFrom<storage::Blocklist> for blocklist::AddToBlocklistResponse {
             fingerprint_id: from.fingerprint_id,
             data_kind: from.data_kind,
             created_at: from.created_at,
+            expires_at: from.expires_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::Blocklist> for blocklist::BlocklistExportRecord {
+    fn foreign_from(from: storage::Blocklist) -> Self {
+        Self {
+            data_kind: from.data_kind,
+            data: from.fingerprint_id,
+            created_at: from.created_at,
+            expires_at: from.expires_at,
         }
     }
 }
@@ -1,9 +1,17 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use actix_multipart::form::{bytes::Bytes, MultipartForm};
 use api_models::blocklist as api_blocklist;
 use common_enums::MerchantDecision;
-use common_utils::errors::CustomResult;
+use common_utils::{
+    crypto::{GenerateDigest, HmacSha256, Sha256, SignMessage},
+    errors::CustomResult,
+    ext_traits::ValueExt,
+};
 use diesel_models::{business_profile::CardBlockingConfig, configs};
 use error_stack::ResultExt;
-use hyperswitch_masking::StrongSecret;
+use hyperswitch_domain_models::payments::payment_intent;
+use hyperswitch_masking::{ExposeInterface, PeekInterface, StrongSecret};
 
 use super::{errors, transformers::generate_fingerprint, SessionState};
 use crate::{
@@ -13,10 +21,29 @@ use crate::{
         payments::PaymentData,
     },
     logger,
-    types::{domain, storage, transformers::ForeignInto},
+    types::{
+        self, domain, storage,
+        transformers::{ForeignFrom, ForeignInto},
+    },
     utils,
 };
 
+/// The rows of an import are added one by one within the request, hence their number is bounded
+const MAX_BLOCKLIST_IMPORT_ROWS: usize = 1000;
+
+#[derive(Debug, MultipartForm)]
+pub struct BlocklistImportForm {
+    #[multipart(limit = "1MB")]
+    pub file: Bytes,
+}
+
+/// The data of a payment which is looked up in the blocklist of the merchant
+pub struct BlocklistCheckData<'a> {
+    pub payment_method_data: Option<&'a domain::PaymentMethodData>,
+    pub email: Option<String>,
+    pub browser_info: Option<types::BrowserInformation>,
+}
+
 pub async fn delete_entry_from_blocklist(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -38,6 +65,38 @@ pub async fn delete_entry_from_blocklist(
             .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
                 message: "no blocklist record for the given fingerprint id was found".to_string(),
             })?,
+
+        api_blocklist::DeleteFromBlocklistRequest::Email(email) => {
+            let merchant_fingerprint_secret =
+                get_merchant_fingerprint_secret(state, merchant_id).await?;
+            delete_blocklist_entry(
+                state,
+                &validate_email(&email, &merchant_fingerprint_secret)?,
+                merchant_id,
+            )
+            .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::IpAddress(ip_address) => {
+            let (network, prefix_length) = validate_ip_network(&ip_address)?;
+            delete_blocklist_entry(state, &format!("{network}/{prefix_length}"), merchant_id)
+                .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::DeviceFingerprint(device_fingerprint) => {
+            delete_blocklist_entry(
+                state,
+                &validate_device_fingerprint(&device_fingerprint)?,
+                merchant_id,
+            )
+            .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::CardBinRange(bin_range) => {
+            let (range_start, range_end) = validate_card_bin_range(&bin_range)?;
+            delete_blocklist_entry(state, &format!("{range_start}-{range_end}"), merchant_id)
+                .await?
+        }
     };
 
     Ok(blocklist_entry.foreign_into())
@@ -147,73 +206,464 @@ fn validate_extended_card_bin(bin: &str) -> RouterResult<()> {
     }
 }
 
+/// The HMAC of the normalized email, an email may also be given as the HMAC itself, such as the
+/// ones returned by the list and the export of the blocklist
+fn validate_email(email: &str, merchant_fingerprint_secret: &str) -> RouterResult<String> {
+    let email = email.trim();
+    if is_email_fingerprint(email) {
+        return Ok(email.to_lowercase());
+    }
+
+    normalize_email(email)
+        .ok_or_else(|| {
+            errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "data".to_string(),
+                expected_format: "an email address".to_string(),
+            }
+            .into()
+        })
+        .and_then(|email| generate_email_fingerprint(&email, merchant_fingerprint_secret))
+}
+
+fn is_email_fingerprint(email: &str) -> bool {
+    email.len() == 64 && email.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn generate_email_fingerprint(
+    normalized_email: &str,
+    merchant_fingerprint_secret: &str,
+) -> RouterResult<String> {
+    HmacSha256
+        .sign_message(
+            merchant_fingerprint_secret.as_bytes(),
+            normalized_email.as_bytes(),
+        )
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to generate email fingerprint")
+}
+
+fn validate_ip_network(ip_network: &str) -> RouterResult<(IpAddr, u8)> {
+    parse_ip_network(ip_network).ok_or_else(|| {
+        errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "data".to_string(),
+            expected_format: "an IP address or a CIDR range".to_string(),
+        }
+        .into()
+    })
+}
+
+fn validate_device_fingerprint(device_fingerprint: &str) -> RouterResult<String> {
+    let device_fingerprint = device_fingerprint.trim();
+    if device_fingerprint.len() == 64 && device_fingerprint.chars().all(|c| c.is_ascii_hexdigit()) {
+        Ok(device_fingerprint.to_lowercase())
+    } else {
+        Err(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "data".to_string(),
+            expected_format: "a 64 character hex encoded SHA-256 digest".to_string(),
+        }
+        .into())
+    }
+}
+
+fn validate_card_bin_range(bin_range: &str) -> RouterResult<(String, String)> {
+    parse_card_bin_range(bin_range).ok_or_else(|| {
+        errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "data".to_string(),
+            expected_format: "two 6 to 8 digit numbers of the same length, such as 400000-400999"
+                .to_string(),
+        }
+        .into()
+    })
+}
+
+/// Lowercases the email and removes the `+` sub-address from its local part, so that
+/// `John.Doe+shop@Example.com` and `john.doe@example.com` are the same entry.
+fn normalize_email(email: &str) -> Option<String> {
+    let email = email.trim().to_lowercase();
+    let (local_part, domain) = email.split_once('@')?;
+    let local_part = local_part
+        .split_once('+')
+        .map_or(local_part, |(local_part, _)| local_part);
+
+    (!local_part.is_empty() && !domain.is_empty() && !domain.contains('@'))
+        .then(|| format!("{local_part}@{domain}"))
+}
+
+/// Parses an IP address as the CIDR range containing only that address, and a CIDR range with
+/// its host bits cleared. A range of IPv4-mapped IPv6 addresses is the IPv4 range of the same
+/// addresses, so that both notations are the same entry.
+fn parse_ip_network(ip_network: &str) -> Option<(IpAddr, u8)> {
+    let ip_network = ip_network.trim();
+    let (ip_address, prefix_length) = match ip_network.split_once('/') {
+        Some((ip_address, prefix_length)) => {
+            let ip_address = ip_address.parse::<IpAddr>().ok()?;
+            (ip_address, prefix_length.parse::<u8>().ok()?)
+        }
+        None => {
+            let ip_address = ip_network.parse::<IpAddr>().ok()?;
+            (ip_address, get_max_prefix_length(ip_address))
+        }
+    };
+    if prefix_length > get_max_prefix_length(ip_address) {
+        return None;
+    }
+
+    let (ip_address, prefix_length) = match ip_address.to_canonical() {
+        IpAddr::V4(ipv4_address) if ip_address.is_ipv6() => prefix_length
+            .checked_sub(IPV4_MAPPED_PREFIX_LENGTH)
+            .map_or((ip_address, prefix_length), |prefix_length| {
+                (IpAddr::V4(ipv4_address), prefix_length)
+            }),
+        _ => (ip_address, prefix_length),
+    };
+
+    let (network, _) = get_ip_range(ip_address, prefix_length);
+    let network = match ip_address {
+        IpAddr::V4(_) => IpAddr::V4(Ipv6Addr::from(network).to_ipv4_mapped()?),
+        IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::from(network)),
+    };
+    Some((network, prefix_length))
+}
+
+/// Stores an IP address or a CIDR range as described in [`parse_ip_network`]
+pub fn normalize_ip_network(ip_network: &str) -> Option<String> {
+    parse_ip_network(ip_network)
+        .map(|(network, prefix_length)| format!("{network}/{prefix_length}"))
+}
+
+fn get_max_prefix_length(ip_address: IpAddr) -> u8 {
+    match ip_address {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+/// IPv4 addresses are compared in the IPv6 space as IPv4-mapped addresses
+const IPV4_MAPPED_PREFIX_LENGTH: u8 = 96;
+
+fn get_ipv6_value(ip_address: IpAddr) -> u128 {
+    match ip_address {
+        IpAddr::V4(ip_address) => u128::from(ip_address.to_ipv6_mapped()),
+        IpAddr::V6(ip_address) => u128::from(ip_address),
+    }
+}
+
+/// The first and the last addresses of a CIDR range, in the IPv6 space
+fn get_ip_range(ip_address: IpAddr, prefix_length: u8) -> (u128, u128) {
+    let prefix_length = match ip_address {
+        IpAddr::V4(_) => prefix_length.saturating_add(IPV4_MAPPED_PREFIX_LENGTH),
+        IpAddr::V6(_) => prefix_length,
+    };
+    let network_mask = u128::MAX
+        .checked_shl(128u32.saturating_sub(u32::from(prefix_length)))
+        .unwrap_or(0);
+    let ip_address = get_ipv6_value(ip_address);
+
+    (ip_address & network_mask, ip_address | !network_mask)
+}
+
+/// An address as stored in the bounds of the IP address entries, a fixed width hex string so
+/// that the addresses are compared in order
+fn get_ip_range_value(ip_address: u128) -> String {
+    format!("{ip_address:032x}")
+}
+
+/// Whether the address falls in the IP address or CIDR range
+pub fn is_ip_address_in_network(ip_address: IpAddr, ip_network: &str) -> bool {
+    parse_ip_network(ip_network).is_some_and(|(network, prefix_length)| {
+        let (range_start, range_end) = get_ip_range(network, prefix_length);
+        (range_start..=range_end).contains(&get_ipv6_value(ip_address))
+    })
+}
+
+/// The bounds of a BIN range over the first 8 digits of the card number, so that the ranges of
+/// every BIN length are compared with the same value
+fn parse_card_bin_range(bin_range: &str) -> Option<(String, String)> {
+    let (start, end) = bin_range.trim().split_once('-')?;
+    let (start, end) = (start.trim(), end.trim());
+    let is_card_bin =
+        |bin: &str| (6..=8).contains(&bin.len()) && bin.chars().all(|c| c.is_ascii_digit());
+
+    (is_card_bin(start) && start.len() == end.len() && start <= end).then(|| {
+        (
+            format!("{start:0<CARD_BIN_RANGE_LENGTH$}"),
+            format!("{end:9<CARD_BIN_RANGE_LENGTH$}"),
+        )
+    })
+}
+
+const CARD_BIN_RANGE_LENGTH: usize = 8;
+
+fn get_card_bin_range_value(card_number: &str) -> Option<String> {
+    card_number
+        .get(..CARD_BIN_RANGE_LENGTH)
+        .filter(|card_bin| card_bin.chars().all(|c| c.is_ascii_digit()))
+        .map(ToOwned::to_owned)
+}
+
+/// Generates the device fingerprint of a payment as documented on
+/// [`api_blocklist::BlocklistRequest::DeviceFingerprint`], the user agent being required.
+pub fn generate_device_fingerprint(browser_info: &types::BrowserInformation) -> Option<String> {
+    let user_agent = browser_info.user_agent.clone()?;
+    let device_attributes = [
+        Some(user_agent),
+        browser_info.accept_language.clone(),
+        browser_info.language.clone(),
+        browser_info.color_depth.map(|value| value.to_string()),
+        browser_info.screen_width.map(|value| value.to_string()),
+        browser_info.screen_height.map(|value| value.to_string()),
+        browser_info.time_zone.map(|value| value.to_string()),
+        browser_info.java_enabled.map(|value| value.to_string()),
+        browser_info
+            .java_script_enabled
+            .map(|value| value.to_string()),
+        browser_info.os_type.clone(),
+        browser_info.os_version.clone(),
+        browser_info.device_model.clone(),
+    ]
+    .map(Option::unwrap_or_default)
+    .join("|");
+
+    Sha256
+        .generate_digest(device_attributes.as_bytes())
+        .map(hex::encode)
+        .inspect_err(|error| logger::error!(?error, "failed to generate device fingerprint"))
+        .ok()
+}
+
+/// The email of the customer of the payment intent
+pub fn get_customer_email(payment_intent: &storage::PaymentIntent) -> Option<String> {
+    payment_intent
+        .customer_details
+        .clone()
+        .and_then(|customer_details| {
+            customer_details
+                .into_inner()
+                .expose()
+                .parse_value::<payment_intent::CustomerData>("CustomerData")
+                .inspect_err(|error| {
+                    logger::warn!(?error, "Failed to parse customer data from payment intent")
+                })
+                .ok()
+        })
+        .and_then(|customer_data| customer_data.email)
+        .map(|email| email.peek().to_owned())
+}
+
 pub async fn insert_entry_into_blocklist(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
     to_block: api_blocklist::AddToBlocklistRequest,
 ) -> RouterResult<api_blocklist::AddToBlocklistResponse> {
-    let blocklist_entry = match &to_block {
-        api_blocklist::AddToBlocklistRequest::CardBin(bin) => {
+    let expires_at = to_block.ttl_in_seconds.map(|ttl_in_seconds| {
+        common_utils::date_time::now()
+            .saturating_add(time::Duration::seconds(i64::from(ttl_in_seconds)))
+    });
+
+    // The bounds of the entries which block a range of values
+    let mut range = None;
+    let (fingerprint_id, data_kind) = match &to_block.data {
+        api_blocklist::BlocklistRequest::CardBin(bin) => {
             validate_card_bin(bin)?;
-            duplicate_check_insert_bin(
-                bin,
-                state,
-                merchant_id,
-                common_enums::BlocklistDataKind::CardBin,
-            )
-            .await?
+            (bin.clone(), common_enums::BlocklistDataKind::CardBin)
         }
 
-        api_blocklist::AddToBlocklistRequest::ExtendedCardBin(bin) => {
+        api_blocklist::BlocklistRequest::ExtendedCardBin(bin) => {
             validate_extended_card_bin(bin)?;
-            duplicate_check_insert_bin(
-                bin,
-                state,
-                merchant_id,
+            (
+                bin.clone(),
                 common_enums::BlocklistDataKind::ExtendedCardBin,
             )
-            .await?
         }
 
-        api_blocklist::AddToBlocklistRequest::Fingerprint(fingerprint_id) => {
-            let blocklist_entry_result = state
-                .store
-                .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
-                .await;
-
-            match blocklist_entry_result {
-                Ok(_) => {
-                    return Err(errors::ApiErrorResponse::PreconditionFailed {
-                        message: "data associated with the given fingerprint is already blocked"
-                            .to_string(),
-                    }
-                    .into());
-                }
+        api_blocklist::BlocklistRequest::Fingerprint(fingerprint_id) => (
+            fingerprint_id.clone(),
+            common_enums::BlocklistDataKind::PaymentMethod,
+        ),
 
-                // if it is a db not found error, we can proceed as normal
-                Err(inner) if inner.current_context().is_db_not_found() => {}
+        api_blocklist::BlocklistRequest::Email(email) => {
+            let merchant_fingerprint_secret =
+                get_merchant_fingerprint_secret(state, merchant_id).await?;
+            (
+                validate_email(email, &merchant_fingerprint_secret)?,
+                common_enums::BlocklistDataKind::Email,
+            )
+        }
 
-                err @ Err(_) => {
-                    err.change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("error fetching blocklist entry from table")?;
-                }
+        api_blocklist::BlocklistRequest::IpAddress(ip_address) => {
+            let (network, prefix_length) = validate_ip_network(ip_address)?;
+            let (range_start, range_end) = get_ip_range(network, prefix_length);
+            range = Some((
+                get_ip_range_value(range_start),
+                get_ip_range_value(range_end),
+            ));
+            (
+                format!("{network}/{prefix_length}"),
+                common_enums::BlocklistDataKind::IpAddress,
+            )
+        }
+
+        api_blocklist::BlocklistRequest::DeviceFingerprint(device_fingerprint) => (
+            validate_device_fingerprint(device_fingerprint)?,
+            common_enums::BlocklistDataKind::DeviceFingerprint,
+        ),
+
+        api_blocklist::BlocklistRequest::CardBinRange(bin_range) => {
+            let (range_start, range_end) = validate_card_bin_range(bin_range)?;
+            let fingerprint_id = format!("{range_start}-{range_end}");
+            range = Some((range_start, range_end));
+            (
+                fingerprint_id,
+                common_enums::BlocklistDataKind::CardBinRange,
+            )
+        }
+    };
+
+    let blocklist_entry = duplicate_check_insert_entry(
+        &fingerprint_id,
+        state,
+        merchant_id,
+        data_kind,
+        expires_at,
+        range,
+    )
+    .await?;
+    Ok(blocklist_entry.foreign_into())
+}
+
+pub async fn import_blocklist_entries(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    form: BlocklistImportForm,
+) -> RouterResult<api_blocklist::BlocklistImportResponse> {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(form.file.data.as_ref());
+    let records = csv_reader
+        .deserialize::<api_blocklist::BlocklistImportRecord>()
+        .take(MAX_BLOCKLIST_IMPORT_ROWS.saturating_add(1))
+        .collect::<Vec<_>>();
+    if records.len() > MAX_BLOCKLIST_IMPORT_ROWS {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "the file must not have more than {MAX_BLOCKLIST_IMPORT_ROWS} rows, split it into several imports"
+            ),
+        }
+        .into());
+    }
+
+    let mut imported_count = 0;
+    let mut failed = Vec::new();
+    // The header is the first line of the file
+    for (line_number, record) in (2..).zip(records) {
+        let record = match record {
+            Ok(record) => record,
+            Err(error) => {
+                failed.push(api_blocklist::BlocklistImportFailure {
+                    line_number,
+                    data: None,
+                    error_message: error.to_string(),
+                });
+                continue;
             }
+        };
 
+        let data = record.data.clone();
+        match insert_entry_into_blocklist(
+            state,
+            merchant_id,
+            api_blocklist::AddToBlocklistRequest {
+                data: get_blocklist_request(record.data_kind, record.data),
+                ttl_in_seconds: record.ttl_in_seconds,
+            },
+        )
+        .await
+        {
+            Ok(_) => imported_count += 1,
+            Err(error) => failed.push(api_blocklist::BlocklistImportFailure {
+                line_number,
+                data: Some(data),
+                error_message: error.current_context().to_string(),
+            }),
+        }
+    }
+
+    Ok(api_blocklist::BlocklistImportResponse {
+        imported_count,
+        failed,
+    })
+}
+
+fn get_blocklist_request(
+    data_kind: common_enums::BlocklistDataKind,
+    data: String,
+) -> api_blocklist::BlocklistRequest {
+    match data_kind {
+        common_enums::BlocklistDataKind::PaymentMethod => {
+            api_blocklist::BlocklistRequest::Fingerprint(data)
+        }
+        common_enums::BlocklistDataKind::CardBin => api_blocklist::BlocklistRequest::CardBin(data),
+        common_enums::BlocklistDataKind::ExtendedCardBin => {
+            api_blocklist::BlocklistRequest::ExtendedCardBin(data)
+        }
+        common_enums::BlocklistDataKind::Email => api_blocklist::BlocklistRequest::Email(data),
+        common_enums::BlocklistDataKind::IpAddress => {
+            api_blocklist::BlocklistRequest::IpAddress(data)
+        }
+        common_enums::BlocklistDataKind::DeviceFingerprint => {
+            api_blocklist::BlocklistRequest::DeviceFingerprint(data)
+        }
+        common_enums::BlocklistDataKind::CardBinRange => {
+            api_blocklist::BlocklistRequest::CardBinRange(data)
+        }
+    }
+}
+
+pub async fn export_blocklist_entries(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    query: api_blocklist::ExportBlocklistQuery,
+) -> RouterResult<Vec<u8>> {
+    let entries = match query.data_kind {
+        Some(data_kind) => {
             state
                 .store
-                .insert_blocklist_entry(storage::BlocklistNew {
-                    merchant_id: merchant_id.to_owned(),
-                    fingerprint_id: fingerprint_id.clone(),
-                    data_kind: api_models::enums::enums::BlocklistDataKind::PaymentMethod,
-                    metadata: None,
-                    created_at: common_utils::date_time::now(),
-                })
+                .list_unexpired_blocklist_entries_by_merchant_id_data_kind(merchant_id, data_kind)
                 .await
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("failed to add fingerprint to blocklist")?
         }
-    };
-    Ok(blocklist_entry.foreign_into())
+        None => state
+            .store
+            .list_blocklist_entries_by_merchant_id(merchant_id)
+            .await
+            .map(|entries| {
+                entries
+                    .into_iter()
+                    .filter(|entry| !is_blocklist_entry_expired(entry))
+                    .collect()
+            }),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("failed to list blocklist entries")?;
+
+    let mut csv_writer = csv::Writer::from_writer(Vec::new());
+    for entry in entries {
+        csv_writer
+            .serialize(api_blocklist::BlocklistExportRecord::foreign_from(entry))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("failed to write blocklist entry to csv")?;
+    }
+
+    csv_writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to write blocklist entries to csv")
+}
+
+fn is_blocklist_entry_expired(entry: &storage::Blocklist) -> bool {
+    entry
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= common_utils::date_time::now())
 }
 
 pub async fn get_merchant_fingerprint_secret(
@@ -250,21 +700,48 @@ pub async fn get_merchant_fingerprint_secret(
     }
 }
 
-async fn duplicate_check_insert_bin(
-    bin: &str,
+async fn duplicate_check_insert_entry(
+    fingerprint_id: &str,
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
     data_kind: common_enums::BlocklistDataKind,
+    expires_at: Option<time::PrimitiveDateTime>,
+    range: Option<(String, String)>,
 ) -> RouterResult<storage::Blocklist> {
     let blocklist_entry_result = state
         .store
-        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
+        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
         .await;
 
     match blocklist_entry_result {
+        // an expired entry no longer blocks payments, and is replaced by the new one
+        Ok(blocklist_entry) if is_blocklist_entry_expired(&blocklist_entry) => {
+            state
+                .store
+                .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("unable to delete expired blocklist entry")?;
+        }
+
         Ok(_) => {
+            let message = match data_kind {
+                common_enums::BlocklistDataKind::CardBin
+                | common_enums::BlocklistDataKind::ExtendedCardBin => {
+                    "provided bin is already blocked"
+                }
+                common_enums::BlocklistDataKind::PaymentMethod => {
+                    "data associated with the given fingerprint is already blocked"
+                }
+                common_enums::BlocklistDataKind::Email
+                | common_enums::BlocklistDataKind::IpAddress
+                | common_enums::BlocklistDataKind::DeviceFingerprint
+                | common_enums::BlocklistDataKind::CardBinRange => {
+                    "provided data is already blocked"
+                }
+            };
             return Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "provided bin is already blocked".to_string(),
+                message: message.to_string(),
             }
             .into());
         }
@@ -282,10 +759,13 @@ async fn duplicate_check_insert_bin(
         .store
         .insert_blocklist_entry(storage::BlocklistNew {
             merchant_id: merchant_id.to_owned(),
-            fingerprint_id: bin.to_string(),
+            fingerprint_id: fingerprint_id.to_string(),
             data_kind,
             metadata: None,
             created_at: common_utils::date_time::now(),
+            expires_at,
+            range_start: range.as_ref().map(|(range_start, _)| range_start.clone()),
+            range_end: range.map(|(_, range_end)| range_end),
         })
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -306,94 +786,124 @@ async fn delete_card_bin_blocklist_entry(
         })
 }
 
+async fn delete_blocklist_entry(
+    state: &SessionState,
+    fingerprint_id: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<storage::Blocklist> {
+    state
+        .store
+        .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "could not find a blocklist entry for the given data".to_string(),
+        })
+}
+
 pub async fn should_payment_be_blocked(
     state: &SessionState,
     processor: &domain::Processor,
-    payment_method_data: &Option<domain::PaymentMethodData>,
+    check_data: &BlocklistCheckData<'_>,
 ) -> CustomResult<bool, errors::ApiErrorResponse> {
     let db = &state.store;
     let merchant_id = processor.get_account().get_id();
     let merchant_fingerprint_secret = get_merchant_fingerprint_secret(state, merchant_id).await?;
 
-    // Hashed Fingerprint to check whether or not this payment should be blocked.
-    let card_number_fingerprint =
-        if let Some(domain::PaymentMethodData::Card(card)) = payment_method_data {
-            generate_fingerprint(
-                state,
-                StrongSecret::new(card.card_number.get_card_no()),
-                StrongSecret::new(merchant_fingerprint_secret.clone()),
-            )
-            .await
-            .attach_printable("error in pm fingerprint creation")
-            .map_or_else(
-                |error| {
-                    logger::error!(?error);
-                    None
-                },
-                Some,
-            )
-            .map(|payload| payload.fingerprint_id)
-        } else {
-            None
-        };
-
-    // Hashed Cardbin to check whether or not this payment should be blocked.
-    let card_bin_fingerprint = payment_method_data
-        .as_ref()
-        .and_then(|pm_data| match pm_data {
-            domain::PaymentMethodData::Card(card) => Some(card.card_number.get_card_isin()),
-            _ => None,
-        });
+    let card = match check_data.payment_method_data {
+        Some(domain::PaymentMethodData::Card(card)) => Some(card),
+        _ => None,
+    };
 
-    // Hashed Extended Cardbin to check whether or not this payment should be blocked.
-    let extended_card_bin_fingerprint =
-        payment_method_data
-            .as_ref()
-            .and_then(|pm_data| match pm_data {
-                domain::PaymentMethodData::Card(card) => {
-                    Some(card.card_number.get_extended_card_bin())
-                }
-                _ => None,
-            });
+    let mut fingerprint_ids = Vec::new();
+    if let Some(card) = card {
+        // Hashed Fingerprint to check whether or not this payment should be blocked.
+        let card_number_fingerprint = generate_fingerprint(
+            state,
+            StrongSecret::new(card.card_number.get_card_no()),
+            StrongSecret::new(merchant_fingerprint_secret.clone()),
+        )
+        .await
+        .attach_printable("error in pm fingerprint creation")
+        .map_or_else(
+            |error| {
+                logger::error!(?error);
+                None
+            },
+            Some,
+        )
+        .map(|payload| payload.fingerprint_id);
 
-    //validating the payment method.
-    let mut blocklist_futures = Vec::new();
-    if let Some(card_number_fingerprint) = card_number_fingerprint.as_ref() {
-        blocklist_futures.push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-            merchant_id,
-            card_number_fingerprint,
-        ));
+        fingerprint_ids.extend(card_number_fingerprint);
+        fingerprint_ids.push(card.card_number.get_card_isin());
+        fingerprint_ids.push(card.card_number.get_extended_card_bin());
     }
 
-    if let Some(card_bin_fingerprint) = card_bin_fingerprint.as_ref() {
-        blocklist_futures.push(
-            db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-                merchant_id,
-                card_bin_fingerprint,
-            ),
+    if let Some(email) = check_data.email.as_deref().and_then(normalize_email) {
+        fingerprint_ids.extend(
+            generate_email_fingerprint(&email, &merchant_fingerprint_secret)
+                .inspect_err(|error| logger::error!(?error))
+                .ok(),
         );
     }
 
-    if let Some(extended_card_bin_fingerprint) = extended_card_bin_fingerprint.as_ref() {
-        blocklist_futures.push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-            merchant_id,
-            extended_card_bin_fingerprint,
-        ));
-    }
-
-    let blocklist_lookups = futures::future::join_all(blocklist_futures).await;
+    let ip_address = check_data
+        .browser_info
+        .as_ref()
+        .and_then(|browser_info| browser_info.ip_address);
+    fingerprint_ids.extend(
+        check_data
+            .browser_info
+            .as_ref()
+            .and_then(generate_device_fingerprint),
+    );
 
     let mut should_payment_be_blocked = false;
-    for lookup in blocklist_lookups {
-        match lookup {
-            Ok(_) => {
-                should_payment_be_blocked = true;
+    if !fingerprint_ids.is_empty() {
+        match db
+            .find_unexpired_blocklist_entries_by_merchant_id_fingerprint_ids(
+                merchant_id,
+                fingerprint_ids,
+            )
+            .await
+        {
+            Ok(entries) => should_payment_be_blocked = !entries.is_empty(),
+            Err(e) => {
+                logger::error!(blocklist_db_error=?e, "failed db operations for blocklist");
             }
+        }
+    }
+
+    // The range entries are looked up by their bounds, one query per kind
+    let range_values = [
+        ip_address.map(|ip_address| {
+            (
+                common_enums::BlocklistDataKind::IpAddress,
+                get_ip_range_value(get_ipv6_value(ip_address)),
+            )
+        }),
+        card.and_then(|card| get_card_bin_range_value(&card.card_number.get_extended_card_bin()))
+            .map(|card_bin| (common_enums::BlocklistDataKind::CardBinRange, card_bin)),
+    ];
+    for (data_kind, value) in range_values.into_iter().flatten() {
+        if should_payment_be_blocked {
+            break;
+        }
+
+        match db
+            .find_unexpired_blocklist_entries_by_merchant_id_data_kind_range_value(
+                merchant_id,
+                data_kind,
+                value,
+            )
+            .await
+        {
+            Ok(entries) => should_payment_be_blocked = !entries.is_empty(),
             Err(e) => {
                 logger::error!(blocklist_db_error=?e, "failed db operations for blocklist");
             }
         }
     }
+
     Ok(should_payment_be_blocked)
 }
 
@@ -407,8 +917,18 @@ where
     F: Send + Clone,
 {
     let db = &state.store;
-    let mut should_block =
-        should_payment_be_blocked(state, processor, &payment_data.payment_method_data).await?;
+    let check_data = BlocklistCheckData {
+        payment_method_data: payment_data.payment_method_data.as_ref(),
+        email: get_customer_email(&payment_data.payment_intent).or_else(|| {
+            payment_data
+                .address
+                .get_payment_method_billing()
+                .and_then(|billing| billing.email.as_ref())
+                .map(|email| email.peek().to_owned())
+        }),
+        browser_info: get_browser_info(&payment_data.payment_attempt),
+    };
+    let mut should_block = should_payment_be_blocked(state, processor, &check_data).await?;
 
     if !should_block {
         should_block = should_payment_be_blocked_by_profile_config(
@@ -455,6 +975,21 @@ where
             "Failed to update status in Payment Attempt to failed, due to it being blocklisted",
         )?;
 
+        #[cfg(feature = "v1")]
+        if let Err(error) = crate::core::fraud_check::review::queue_blocklist_review(
+            state,
+            processor,
+            &payment_data.payment_attempt,
+            "This payment method is blocked",
+        )
+        .await
+        {
+            logger::error!(
+                ?error,
+                "Failed to queue the blocked payment for manual review"
+            );
+        }
+
         Err(errors::ApiErrorResponse::PaymentBlockedError {
             code: 200,
             message: "This payment method is blocked".to_string(),
@@ -473,6 +1008,31 @@ where
     }
 }
 
+#[cfg(feature = "v1")]
+fn get_browser_info(
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<types::BrowserInformation> {
+    payment_attempt
+        .browser_info
+        .clone()
+        .and_then(|browser_info| {
+            browser_info
+                .parse_value::<types::BrowserInformation>("BrowserInformation")
+                .inspect_err(|error| logger::warn!(?error, "Failed to parse browser info"))
+                .ok()
+        })
+}
+
+#[cfg(feature = "v2")]
+fn get_browser_info(
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<types::BrowserInformation> {
+    payment_attempt
+        .browser_info
+        .clone()
+        .map(types::BrowserInformation::from)
+}
+
 pub async fn should_payment_be_blocked_by_profile_config(
     state: &SessionState,
     payment_method_data: &Option<domain::PaymentMethodData>,
@@ -580,3 +1140,189 @@ pub async fn generate_payment_fingerprint(
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_normalize_email() {
+        assert_eq!(
+            normalize_email(" John.Doe+shop@Example.com ").as_deref(),
+            Some("john.doe@example.com")
+        );
+        assert_eq!(
+            normalize_email("john.doe+a+b@example.com").as_deref(),
+            Some("john.doe@example.com")
+        );
+        assert_eq!(normalize_email("+shop@example.com"), None);
+        assert_eq!(normalize_email("john.doe@"), None);
+        assert_eq!(normalize_email("john.doe@a@example.com"), None);
+        assert_eq!(normalize_email("john.doe"), None);
+    }
+
+    #[test]
+    fn test_email_fingerprint() {
+        let fingerprint = validate_email("John.Doe+shop@Example.com", "fs_secret").unwrap();
+
+        assert_eq!(
+            validate_email("john.doe@example.com", "fs_secret").unwrap(),
+            fingerprint
+        );
+        assert_ne!(
+            validate_email("john.doe@example.com", "fs_other_secret").unwrap(),
+            fingerprint
+        );
+        assert!(!fingerprint.contains("john.doe"));
+        // The fingerprints returned by the list and the export are accepted as they are
+        assert_eq!(
+            validate_email(&fingerprint.to_uppercase(), "fs_other_secret").unwrap(),
+            fingerprint
+        );
+        assert!(validate_email("john.doe", "fs_secret").is_err());
+    }
+
+    #[test]
+    fn test_normalize_ip_network() {
+        assert_eq!(
+            normalize_ip_network("203.0.113.7").as_deref(),
+            Some("203.0.113.7/32")
+        );
+        assert_eq!(
+            normalize_ip_network("203.0.113.7/24").as_deref(),
+            Some("203.0.113.0/24")
+        );
+        assert_eq!(
+            normalize_ip_network("2001:db8::1/32").as_deref(),
+            Some("2001:db8::/32")
+        );
+        assert_eq!(
+            normalize_ip_network("0.0.0.0/0").as_deref(),
+            Some("0.0.0.0/0")
+        );
+    }
+
+    #[test]
+    fn test_normalize_ipv4_mapped_ip_network() {
+        assert_eq!(
+            normalize_ip_network("::ffff:203.0.113.7").as_deref(),
+            Some("203.0.113.7/32")
+        );
+        assert_eq!(
+            normalize_ip_network("::ffff:203.0.113.7/120").as_deref(),
+            Some("203.0.113.0/24")
+        );
+        // A range wider than the IPv4-mapped addresses stays an IPv6 range
+        assert_eq!(
+            normalize_ip_network("::ffff:203.0.113.7/80").as_deref(),
+            Some("::/80")
+        );
+    }
+
+    #[test]
+    fn test_normalize_ip_network_with_invalid_prefix_length() {
+        assert_eq!(normalize_ip_network("203.0.113.0/33"), None);
+        assert_eq!(normalize_ip_network("2001:db8::/129"), None);
+        assert_eq!(normalize_ip_network("203.0.113.0/256"), None);
+        assert_eq!(normalize_ip_network("203.0.113.0/"), None);
+        assert_eq!(normalize_ip_network("203.0.113"), None);
+    }
+
+    #[test]
+    fn test_get_ip_range() {
+        let (network, prefix_length) = parse_ip_network("203.0.113.7/24").unwrap();
+        let (range_start, range_end) = get_ip_range(network, prefix_length);
+
+        assert_eq!(
+            get_ip_range_value(range_start),
+            "00000000000000000000ffffcb007100"
+        );
+        assert_eq!(
+            get_ip_range_value(range_end),
+            "00000000000000000000ffffcb0071ff"
+        );
+
+        let (network, prefix_length) = parse_ip_network("2001:db8::/32").unwrap();
+        let (range_start, range_end) = get_ip_range(network, prefix_length);
+        assert_eq!(
+            get_ip_range_value(range_start),
+            "20010db8000000000000000000000000"
+        );
+        assert_eq!(
+            get_ip_range_value(range_end),
+            "20010db8ffffffffffffffffffffffff"
+        );
+    }
+
+    #[test]
+    fn test_is_ip_address_in_network() {
+        let ip_address = |ip_address: &str| ip_address.parse::<IpAddr>().unwrap();
+
+        assert!(is_ip_address_in_network(
+            ip_address("203.0.113.255"),
+            "203.0.113.0/24"
+        ));
+        assert!(!is_ip_address_in_network(
+            ip_address("203.0.114.0"),
+            "203.0.113.0/24"
+        ));
+        assert!(is_ip_address_in_network(
+            ip_address("::ffff:203.0.113.7"),
+            "203.0.113.0/24"
+        ));
+        assert!(is_ip_address_in_network(
+            ip_address("203.0.113.7"),
+            "203.0.113.7"
+        ));
+        assert!(is_ip_address_in_network(
+            ip_address("2001:db8:1::1"),
+            "2001:db8::/32"
+        ));
+        assert!(!is_ip_address_in_network(
+            ip_address("203.0.113.7"),
+            "2001:db8::/32"
+        ));
+        assert!(!is_ip_address_in_network(
+            ip_address("203.0.113.7"),
+            "not an ip"
+        ));
+    }
+
+    #[test]
+    fn test_parse_card_bin_range() {
+        assert_eq!(
+            parse_card_bin_range(" 400000 - 400999 "),
+            Some(("40000000".to_string(), "40099999".to_string()))
+        );
+        assert_eq!(
+            parse_card_bin_range("4000000-4009999"),
+            Some(("40000000".to_string(), "40099999".to_string()))
+        );
+        assert_eq!(
+            parse_card_bin_range("40000000-40000000"),
+            Some(("40000000".to_string(), "40000000".to_string()))
+        );
+        assert_eq!(parse_card_bin_range("400999-400000"), None);
+        assert_eq!(parse_card_bin_range("400000-4009999"), None);
+        assert_eq!(parse_card_bin_range("40000-40099"), None);
+        assert_eq!(parse_card_bin_range("400000-40099a"), None);
+        assert_eq!(parse_card_bin_range("400000"), None);
+    }
+
+    #[test]
+    fn test_card_bin_range_bounds() {
+        let (range_start, range_end) = parse_card_bin_range("400000-400999").unwrap();
+        let is_in_range = |card_number: &str| {
+            get_card_bin_range_value(card_number)
+                .is_some_and(|card_bin| range_start <= card_bin && card_bin <= range_end)
+        };
+
+        assert!(is_in_range("4000000000000000"));
+        assert!(is_in_range("4009999999999999"));
+        assert!(is_in_range("4005551234567890"));
+        assert!(!is_in_range("3999999999999999"));
+        assert!(!is_in_range("4010000000000000"));
+        assert!(!is_in_range("400000"));
+    }
+}
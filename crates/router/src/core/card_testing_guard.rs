@@ -1,3 +1,59 @@
 pub mod utils;
 
-use crate::core::errors;
+use api_models::card_testing_guard as api_card_testing_guard;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::domain,
+};
+
+#[instrument(skip_all)]
+pub async fn list_card_testing_guard_blocks(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<api_card_testing_guard::CardTestingGuardBlockListResponse> {
+    let business_profile =
+        find_business_profile(&state, &merchant_id, &key_store, &profile_id).await?;
+    let data = utils::list_active_blocks(&state, &business_profile).await?;
+
+    Ok(ApplicationResponse::Json(
+        api_card_testing_guard::CardTestingGuardBlockListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn lift_card_testing_guard_block(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    key_store: domain::MerchantKeyStore,
+    request: api_card_testing_guard::CardTestingGuardBlockRequestInternal,
+) -> RouterResponse<api_card_testing_guard::CardTestingGuardBlock> {
+    let business_profile =
+        find_business_profile(&state, &merchant_id, &key_store, &request.profile_id).await?;
+    let block = utils::lift_block(&state, &business_profile, &request.block_id).await?;
+
+    Ok(ApplicationResponse::Json(block))
+}
+
+async fn find_business_profile(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<domain::Profile> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(key_store, merchant_id, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+}
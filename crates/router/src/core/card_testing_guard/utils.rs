@@ -1,13 +1,23 @@
+use std::net::IpAddr;
+
+use api_models::card_testing_guard::{CardTestingGuardBlock, CardTestingGuardBlockType};
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    ext_traits::{Encode, StringExt},
+};
+use diesel_models::business_profile::CardTestingGuardConfig;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::{
     card_testing_guard_data::CardTestingGuardData, router_request_types::BrowserInformation,
 };
 use hyperswitch_masking::{PeekInterface, Secret};
 use router_env::logger;
+use time::PrimitiveDateTime;
 
 use super::errors;
 use crate::{
-    core::{errors::RouterResult, payments::helpers},
+    consts,
+    core::{blocklist::utils as blocklist_utils, errors::RouterResult, payments::helpers},
     routes::SessionState,
     services,
     types::domain,
@@ -24,6 +34,29 @@ pub async fn validate_card_testing_guard_checks(
 ) -> RouterResult<Option<CardTestingGuardData>> {
     match &business_profile.card_testing_guard_config {
         Some(card_testing_guard_config) => {
+            #[cfg(feature = "v1")]
+            let browser_info = browser_info
+                .map(|browser_info| {
+                    serde_json::from_value::<BrowserInformation>(browser_info.clone())
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("could not parse browser_info")
+                })
+                .transpose();
+
+            #[cfg(feature = "v2")]
+            let browser_info: RouterResult<Option<BrowserInformation>> = Ok(browser_info.cloned());
+
+            let ip_address = browser_info
+                .as_ref()
+                .ok()
+                .and_then(Option::as_ref)
+                .and_then(|browser_info| browser_info.ip_address);
+
+            if is_allowlisted(card_testing_guard_config, ip_address, customer_id.as_ref()) {
+                logger::info!("skipping card testing guard checks for allowlisted payment");
+                return Ok(None);
+            }
+
             let fingerprint = generate_fingerprint(card_number, business_profile).await?;
 
             let card_testing_guard_expiry = card_testing_guard_config.card_testing_guard_expiry;
@@ -33,39 +66,17 @@ pub async fn validate_card_testing_guard_checks(
             let mut customer_id_blocking_cache_key = String::new();
 
             if card_testing_guard_config.is_card_ip_blocking_enabled {
-                if let Some(browser_info) = browser_info {
-                    #[cfg(feature = "v1")]
-                    {
-                        let browser_info =
-                            serde_json::from_value::<BrowserInformation>(browser_info.clone())
-                                .change_context(errors::ApiErrorResponse::InternalServerError)
-                                .attach_printable("could not parse browser_info")?;
-
-                        if let Some(browser_info_ip) = browser_info.ip_address {
-                            card_ip_blocking_cache_key =
-                                helpers::validate_card_ip_blocking_for_business_profile(
-                                    state,
-                                    browser_info_ip,
-                                    fingerprint.clone(),
-                                    card_testing_guard_config,
-                                )
-                                .await?;
-                        }
-                    }
-
-                    #[cfg(feature = "v2")]
-                    {
-                        if let Some(browser_info_ip) = browser_info.ip_address {
-                            card_ip_blocking_cache_key =
-                                helpers::validate_card_ip_blocking_for_business_profile(
-                                    state,
-                                    browser_info_ip,
-                                    fingerprint.clone(),
-                                    card_testing_guard_config,
-                                )
-                                .await?;
-                        }
-                    }
+                if let Some(browser_info_ip) =
+                    browser_info?.and_then(|browser_info| browser_info.ip_address)
+                {
+                    card_ip_blocking_cache_key =
+                        helpers::validate_card_ip_blocking_for_business_profile(
+                            state,
+                            browser_info_ip,
+                            fingerprint.clone(),
+                            card_testing_guard_config,
+                        )
+                        .await?;
                 }
             }
 
@@ -96,19 +107,69 @@ pub async fn validate_card_testing_guard_checks(
             Ok(Some(CardTestingGuardData {
                 is_card_ip_blocking_enabled: card_testing_guard_config.is_card_ip_blocking_enabled,
                 card_ip_blocking_cache_key,
+                card_ip_blocking_threshold: card_testing_guard_config.card_ip_blocking_threshold,
                 is_guest_user_card_blocking_enabled: card_testing_guard_config
                     .is_guest_user_card_blocking_enabled,
                 guest_user_card_blocking_cache_key,
+                guest_user_card_blocking_threshold: card_testing_guard_config
+                    .guest_user_card_blocking_threshold,
                 is_customer_id_blocking_enabled: card_testing_guard_config
                     .is_customer_id_blocking_enabled,
                 customer_id_blocking_cache_key,
+                customer_id_blocking_threshold: card_testing_guard_config
+                    .customer_id_blocking_threshold,
                 card_testing_guard_expiry,
+                profile_id: business_profile.get_id().clone(),
+                ip_address,
+                customer_id: customer_id.clone(),
             }))
         }
         None => Ok(None),
     }
 }
 
+/// Whether the IP address or the customer of the payment is exempt from the checks
+fn is_allowlisted(
+    card_testing_guard_config: &CardTestingGuardConfig,
+    ip_address: Option<IpAddr>,
+    customer_id: Option<&common_utils::id_type::CustomerId>,
+) -> bool {
+    let is_ip_address_allowlisted = ip_address
+        .zip(card_testing_guard_config.allowlisted_ips.as_ref())
+        .is_some_and(|(ip_address, allowlisted_ips)| {
+            allowlisted_ips.iter().any(|allowlisted_ip| {
+                blocklist_utils::is_ip_address_in_network(ip_address, allowlisted_ip)
+            })
+        });
+
+    let is_customer_allowlisted = customer_id
+        .zip(card_testing_guard_config.allowlisted_customer_ids.as_ref())
+        .is_some_and(|(customer_id, allowlisted_customer_ids)| {
+            allowlisted_customer_ids.contains(customer_id)
+        });
+
+    is_ip_address_allowlisted || is_customer_allowlisted
+}
+
+pub fn validate_card_testing_guard_allowlist(
+    card_testing_guard_config: &api_models::admin::CardTestingGuardConfig,
+) -> Result<(), errors::ApiErrorResponse> {
+    let invalid_ip = card_testing_guard_config
+        .allowlisted_ips
+        .iter()
+        .flatten()
+        .find(|allowlisted_ip| blocklist_utils::normalize_ip_network(allowlisted_ip).is_none());
+
+    match invalid_ip {
+        Some(invalid_ip) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`{invalid_ip}` in card_testing_guard_config.allowlisted_ips is not a valid IP address or CIDR range"
+            ),
+        }),
+        None => Ok(()),
+    }
+}
+
 pub async fn generate_fingerprint(
     card_number: cards::CardNumber,
     business_profile: &domain::Profile,
@@ -152,12 +213,31 @@ pub async fn increment_blocked_count_in_cache(
                 .card_ip_blocking_cache_key
                 .is_empty()
         {
-            let _ = services::card_testing_guard::increment_blocked_count_in_cache(
+            let blocked_count = services::card_testing_guard::increment_blocked_count_in_cache(
                 state,
                 &card_testing_guard_data.card_ip_blocking_cache_key,
                 card_testing_guard_data.card_testing_guard_expiry.into(),
             )
             .await;
+
+            if blocked_count.is_ok_and(|blocked_count| {
+                blocked_count >= card_testing_guard_data.card_ip_blocking_threshold
+            }) {
+                track_blocked_count(
+                    state,
+                    &card_testing_guard_data,
+                    TrackedCounter {
+                        cache_key: card_testing_guard_data.card_ip_blocking_cache_key.clone(),
+                        block_type: CardTestingGuardBlockType::CardIp,
+                        ip_address: card_testing_guard_data.ip_address,
+                        customer_id: None,
+                        expires_at: get_expires_at(
+                            card_testing_guard_data.card_testing_guard_expiry,
+                        ),
+                    },
+                )
+                .await;
+            }
         }
 
         if card_testing_guard_data.is_guest_user_card_blocking_enabled
@@ -165,12 +245,33 @@ pub async fn increment_blocked_count_in_cache(
                 .guest_user_card_blocking_cache_key
                 .is_empty()
         {
-            let _ = services::card_testing_guard::increment_blocked_count_in_cache(
+            let blocked_count = services::card_testing_guard::increment_blocked_count_in_cache(
                 state,
                 &card_testing_guard_data.guest_user_card_blocking_cache_key,
                 card_testing_guard_data.card_testing_guard_expiry.into(),
             )
             .await;
+
+            if blocked_count.is_ok_and(|blocked_count| {
+                blocked_count >= card_testing_guard_data.guest_user_card_blocking_threshold
+            }) {
+                track_blocked_count(
+                    state,
+                    &card_testing_guard_data,
+                    TrackedCounter {
+                        cache_key: card_testing_guard_data
+                            .guest_user_card_blocking_cache_key
+                            .clone(),
+                        block_type: CardTestingGuardBlockType::GuestUserCard,
+                        ip_address: None,
+                        customer_id: None,
+                        expires_at: get_expires_at(
+                            card_testing_guard_data.card_testing_guard_expiry,
+                        ),
+                    },
+                )
+                .await;
+            }
         }
 
         if card_testing_guard_data.is_customer_id_blocking_enabled
@@ -178,13 +279,420 @@ pub async fn increment_blocked_count_in_cache(
                 .customer_id_blocking_cache_key
                 .is_empty()
         {
-            let _ = services::card_testing_guard::increment_blocked_count_in_cache(
+            let blocked_count = services::card_testing_guard::increment_blocked_count_in_cache(
                 state,
                 &card_testing_guard_data.customer_id_blocking_cache_key,
                 card_testing_guard_data.card_testing_guard_expiry.into(),
             )
             .await;
+
+            if blocked_count.is_ok_and(|blocked_count| {
+                blocked_count >= card_testing_guard_data.customer_id_blocking_threshold
+            }) {
+                track_blocked_count(
+                    state,
+                    &card_testing_guard_data,
+                    TrackedCounter {
+                        cache_key: card_testing_guard_data
+                            .customer_id_blocking_cache_key
+                            .clone(),
+                        block_type: CardTestingGuardBlockType::CustomerId,
+                        ip_address: None,
+                        customer_id: card_testing_guard_data.customer_id.clone(),
+                        expires_at: get_expires_at(
+                            card_testing_guard_data.card_testing_guard_expiry,
+                        ),
+                    },
+                )
+                .await;
+            }
         }
     }
     Ok(())
 }
+
+/// A counter which reached its threshold, tracked in the redis hash of the profile so that the
+/// blocks of the profile can be listed and lifted. The tracked counter is refreshed on every
+/// increment, hence its expiry matches the expiry of the counter.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct TrackedCounter {
+    cache_key: String,
+    block_type: CardTestingGuardBlockType,
+    ip_address: Option<IpAddr>,
+    customer_id: Option<common_utils::id_type::CustomerId>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    expires_at: PrimitiveDateTime,
+}
+
+fn get_expires_at(card_testing_guard_expiry: i32) -> PrimitiveDateTime {
+    common_utils::date_time::now()
+        .saturating_add(time::Duration::seconds(card_testing_guard_expiry.into()))
+}
+
+fn get_tracked_counters_key(profile_id: &common_utils::id_type::ProfileId) -> String {
+    format!(
+        "{}_{}",
+        consts::CARD_TESTING_GUARD_BLOCKS_PREFIX,
+        profile_id.get_string_repr()
+    )
+}
+
+/// The identifier of a counter, which is the SHA-256 digest of its cache key as the key holds
+/// the card fingerprint.
+fn get_block_id(cache_key: &str) -> RouterResult<String> {
+    Sha256
+        .generate_digest(cache_key.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to generate card testing guard block id")
+}
+
+async fn track_blocked_count(
+    state: &SessionState,
+    card_testing_guard_data: &CardTestingGuardData,
+    tracked_counter: TrackedCounter,
+) {
+    let result: RouterResult<()> = async {
+        let block_id = get_block_id(&tracked_counter.cache_key)?;
+        let value = tracked_counter
+            .encode_to_string_of_json()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        services::card_testing_guard::set_tracked_counter_in_cache(
+            state,
+            &get_tracked_counters_key(&card_testing_guard_data.profile_id),
+            block_id,
+            value,
+            card_testing_guard_data.card_testing_guard_expiry.into(),
+        )
+        .await
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, "failed to track card testing guard counter");
+    }
+}
+
+fn get_threshold(
+    card_testing_guard_config: &CardTestingGuardConfig,
+    block_type: CardTestingGuardBlockType,
+) -> i32 {
+    match block_type {
+        CardTestingGuardBlockType::CardIp => card_testing_guard_config.card_ip_blocking_threshold,
+        CardTestingGuardBlockType::GuestUserCard => {
+            card_testing_guard_config.guest_user_card_blocking_threshold
+        }
+        CardTestingGuardBlockType::CustomerId => {
+            card_testing_guard_config.customer_id_blocking_threshold
+        }
+    }
+}
+
+/// Builds the block of a tracked counter from its current count, `None` when the counter
+/// expired.
+fn get_block(
+    card_testing_guard_config: &CardTestingGuardConfig,
+    block_id: String,
+    tracked_counter: TrackedCounter,
+    unsuccessful_payment_count: Option<i32>,
+) -> Option<CardTestingGuardBlock> {
+    unsuccessful_payment_count.map(|unsuccessful_payment_count| CardTestingGuardBlock {
+        block_id,
+        block_type: tracked_counter.block_type,
+        ip_address: tracked_counter.ip_address,
+        customer_id: tracked_counter.customer_id,
+        unsuccessful_payment_count,
+        threshold: get_threshold(card_testing_guard_config, tracked_counter.block_type),
+        expires_at: tracked_counter.expires_at,
+    })
+}
+
+/// Parses the tracked counters of the profile, skipping the ones which cannot be parsed.
+fn parse_tracked_counters(
+    tracked_counters: Vec<(String, String)>,
+) -> Vec<(String, TrackedCounter)> {
+    tracked_counters
+        .into_iter()
+        .filter_map(|(block_id, value)| {
+            value
+                .parse_struct("TrackedCounter")
+                .inspect_err(|error| {
+                    logger::warn!(?error, "failed to parse tracked card testing guard counter")
+                })
+                .ok()
+                .map(|tracked_counter| (block_id, tracked_counter))
+        })
+        .collect()
+}
+
+/// Splits the tracked counters into the blocks which reached their threshold and the
+/// identifiers of the expired counters, given the current count of each counter.
+fn get_active_blocks(
+    card_testing_guard_config: &CardTestingGuardConfig,
+    tracked_counters: Vec<(String, TrackedCounter)>,
+    unsuccessful_payment_counts: Vec<Option<i32>>,
+) -> (Vec<CardTestingGuardBlock>, Vec<String>) {
+    let mut blocks = Vec::new();
+    let mut expired_block_ids = Vec::new();
+
+    for ((block_id, tracked_counter), unsuccessful_payment_count) in tracked_counters
+        .into_iter()
+        .zip(unsuccessful_payment_counts)
+    {
+        match get_block(
+            card_testing_guard_config,
+            block_id.clone(),
+            tracked_counter,
+            unsuccessful_payment_count,
+        ) {
+            Some(block) if block.unsuccessful_payment_count >= block.threshold => {
+                blocks.push(block)
+            }
+            Some(_) => {}
+            None => expired_block_ids.push(block_id),
+        }
+    }
+
+    (blocks, expired_block_ids)
+}
+
+/// Lists the counters of the profile which reached their threshold, removing the expired
+/// counters from the tracking hash. The counts are read with a single `MGET`.
+pub async fn list_active_blocks(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+) -> RouterResult<Vec<CardTestingGuardBlock>> {
+    let Some(card_testing_guard_config) = business_profile.card_testing_guard_config.as_ref()
+    else {
+        return Ok(Vec::new());
+    };
+
+    let tracked_counters_key = get_tracked_counters_key(business_profile.get_id());
+    let tracked_counters = parse_tracked_counters(
+        services::card_testing_guard::get_tracked_counters_from_cache(state, &tracked_counters_key)
+            .await?,
+    );
+
+    let cache_keys = tracked_counters
+        .iter()
+        .map(|(_, tracked_counter)| tracked_counter.cache_key.clone())
+        .collect::<Vec<_>>();
+    let unsuccessful_payment_counts =
+        services::card_testing_guard::get_blocked_counts_from_cache(state, &cache_keys).await?;
+
+    let (blocks, expired_block_ids) = get_active_blocks(
+        card_testing_guard_config,
+        tracked_counters,
+        unsuccessful_payment_counts,
+    );
+
+    for block_id in expired_block_ids {
+        let _ = services::card_testing_guard::delete_tracked_counter_from_cache(
+            state,
+            &tracked_counters_key,
+            &block_id,
+        )
+        .await
+        .inspect_err(|error| {
+            logger::warn!(
+                ?error,
+                "failed to delete expired card testing guard counter"
+            )
+        });
+    }
+
+    Ok(blocks)
+}
+
+/// Deletes the counter of a block, allowing the payments it blocked before the counter expires.
+pub async fn lift_block(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    block_id: &str,
+) -> RouterResult<CardTestingGuardBlock> {
+    let block_not_found = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: "card testing guard block not found".to_string(),
+    };
+    let card_testing_guard_config = business_profile
+        .card_testing_guard_config
+        .as_ref()
+        .ok_or_else(block_not_found)?;
+
+    let tracked_counters_key = get_tracked_counters_key(business_profile.get_id());
+    let tracked_counter: TrackedCounter =
+        services::card_testing_guard::get_tracked_counters_from_cache(state, &tracked_counters_key)
+            .await?
+            .into_iter()
+            .find(|(tracked_block_id, _)| tracked_block_id == block_id)
+            .ok_or_else(block_not_found)?
+            .1
+            .parse_struct("TrackedCounter")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("failed to parse tracked card testing guard counter")?;
+    let cache_key = tracked_counter.cache_key.clone();
+
+    let unsuccessful_payment_count =
+        services::card_testing_guard::get_blocked_count_from_cache(state, &cache_key).await?;
+    let block = get_block(
+        card_testing_guard_config,
+        block_id.to_owned(),
+        tracked_counter,
+        unsuccessful_payment_count,
+    );
+
+    services::card_testing_guard::delete_blocked_count_from_cache(state, &cache_key).await?;
+    services::card_testing_guard::delete_tracked_counter_from_cache(
+        state,
+        &tracked_counters_key,
+        block_id,
+    )
+    .await?;
+
+    block.ok_or_else(|| block_not_found().into())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn get_customer_id(customer_id: &'static str) -> common_utils::id_type::CustomerId {
+        common_utils::id_type::CustomerId::try_from(Cow::from(customer_id)).unwrap()
+    }
+
+    fn get_card_testing_guard_config() -> CardTestingGuardConfig {
+        CardTestingGuardConfig {
+            card_ip_blocking_threshold: 3,
+            customer_id_blocking_threshold: 5,
+            allowlisted_ips: Some(vec!["10.0.0.0/8".to_string(), "192.168.1.1".to_string()]),
+            allowlisted_customer_ids: Some(vec![get_customer_id("cus_allowlisted")]),
+            ..CardTestingGuardConfig::default()
+        }
+    }
+
+    fn get_tracked_counter(block_type: CardTestingGuardBlockType) -> TrackedCounter {
+        TrackedCounter {
+            cache_key: "card_testing_guard_key".to_string(),
+            block_type,
+            ip_address: None,
+            customer_id: None,
+            expires_at: get_expires_at(3600),
+        }
+    }
+
+    #[test]
+    fn test_is_allowlisted() {
+        let card_testing_guard_config = get_card_testing_guard_config();
+        let allowlisted_customer_id = get_customer_id("cus_allowlisted");
+        let customer_id = get_customer_id("cus_other");
+
+        assert!(is_allowlisted(
+            &card_testing_guard_config,
+            Some("10.1.2.3".parse().unwrap()),
+            None
+        ));
+        assert!(is_allowlisted(
+            &card_testing_guard_config,
+            Some("192.168.1.1".parse().unwrap()),
+            Some(&customer_id)
+        ));
+        assert!(is_allowlisted(
+            &card_testing_guard_config,
+            None,
+            Some(&allowlisted_customer_id)
+        ));
+        assert!(!is_allowlisted(
+            &card_testing_guard_config,
+            Some("192.168.1.2".parse().unwrap()),
+            Some(&customer_id)
+        ));
+        assert!(!is_allowlisted(&card_testing_guard_config, None, None));
+        assert!(!is_allowlisted(
+            &CardTestingGuardConfig::default(),
+            Some("10.1.2.3".parse().unwrap()),
+            Some(&allowlisted_customer_id)
+        ));
+    }
+
+    #[test]
+    fn test_get_active_blocks() {
+        let card_testing_guard_config = get_card_testing_guard_config();
+        let tracked_counters = vec![
+            (
+                "active".to_string(),
+                get_tracked_counter(CardTestingGuardBlockType::CardIp),
+            ),
+            (
+                "below_threshold".to_string(),
+                get_tracked_counter(CardTestingGuardBlockType::CustomerId),
+            ),
+            (
+                "expired".to_string(),
+                get_tracked_counter(CardTestingGuardBlockType::CardIp),
+            ),
+        ];
+
+        let (blocks, expired_block_ids) = get_active_blocks(
+            &card_testing_guard_config,
+            tracked_counters,
+            vec![Some(3), Some(4), None],
+        );
+
+        assert_eq!(
+            blocks
+                .iter()
+                .map(|block| (block.block_id.as_str(), block.threshold))
+                .collect::<Vec<_>>(),
+            vec![("active", 3)]
+        );
+        assert_eq!(expired_block_ids, vec!["expired".to_string()]);
+    }
+
+    #[test]
+    fn test_get_block() {
+        let card_testing_guard_config = get_card_testing_guard_config();
+        let tracked_counter = get_tracked_counter(CardTestingGuardBlockType::CustomerId);
+        let expires_at = tracked_counter.expires_at;
+
+        let block = get_block(
+            &card_testing_guard_config,
+            "block".to_string(),
+            tracked_counter,
+            Some(7),
+        )
+        .unwrap();
+        assert_eq!(block.block_type, CardTestingGuardBlockType::CustomerId);
+        assert_eq!(block.unsuccessful_payment_count, 7);
+        assert_eq!(block.threshold, 5);
+        assert_eq!(block.expires_at, expires_at);
+
+        assert!(get_block(
+            &card_testing_guard_config,
+            "block".to_string(),
+            get_tracked_counter(CardTestingGuardBlockType::CustomerId),
+            None,
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_parse_tracked_counters() {
+        let value = get_tracked_counter(CardTestingGuardBlockType::GuestUserCard)
+            .encode_to_string_of_json()
+            .unwrap();
+
+        let tracked_counters = parse_tracked_counters(vec![
+            ("valid".to_string(), value),
+            ("invalid".to_string(), "{".to_string()),
+        ]);
+
+        assert_eq!(tracked_counters.len(), 1);
+        assert!(tracked_counters.iter().all(|(block_id, tracked_counter)| {
+            block_id == "valid"
+                && tracked_counter.block_type == CardTestingGuardBlockType::GuestUserCard
+        }));
+    }
+}
@@ -868,6 +868,7 @@ pub async fn fetch_disputes_from_connector(
                 &connector_name,
                 platform.get_processor().get_account().get_id(),
                 0,
+                None,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -141,6 +141,8 @@ pub enum WebhooksFlowError {
     MerchantWebhookDetailsNotFound,
     #[error("Merchant does not have a webhook URL configured")]
     MerchantWebhookUrlNotConfigured,
+    #[error("Webhook endpoint not found or disabled")]
+    WebhookEndpointNotFound,
     #[error("Webhook event updation failed")]
     WebhookEventUpdationFailed,
     #[error("Outgoing webhook body signing failed")]
@@ -169,6 +171,7 @@ impl WebhooksFlowError {
             Self::MerchantConfigNotFound
             | Self::MerchantWebhookDetailsNotFound
             | Self::MerchantWebhookUrlNotConfigured
+            | Self::WebhookEndpointNotFound
             | Self::OutgoingWebhookResponseEncodingFailed => false,
 
             Self::WebhookEventUpdationFailed
@@ -44,7 +44,11 @@ use crate::{
     utils::ValueExt,
 };
 pub mod flows;
+#[cfg(feature = "v1")]
+pub mod native;
 pub mod operation;
+#[cfg(feature = "v1")]
+pub mod review;
 pub mod types;
 
 #[cfg(feature = "v2")]
@@ -90,15 +94,20 @@ where
     // To construct connector flow specific api
     dyn Connector: services::api::ConnectorIntegration<F, Req, frm_types::FraudCheckResponseData>,
 {
-    let merchant_connector_account = payments::construct_profile_id_and_get_mca(
-        state,
-        platform.get_processor(),
-        payment_data,
-        &frm_data.connector_details.connector_name,
-        None,
-        false,
-    )
-    .await?;
+    let is_native_frm = frm_data.connector_details.connector_name == native::NATIVE_FRM_PROVIDER;
+    let merchant_connector_account = if is_native_frm {
+        native::get_merchant_connector_account()
+    } else {
+        payments::construct_profile_id_and_get_mca(
+            state,
+            platform.get_processor(),
+            payment_data,
+            &frm_data.connector_details.connector_name,
+            None,
+            false,
+        )
+        .await?
+    };
 
     frm_data
         .payment_attempt
@@ -129,6 +138,12 @@ where
         frm_data.fraud_check.last_step = FraudCheckLastStep::TransactionOrRecordRefund
     }
 
+    if is_native_frm {
+        router_data.response =
+            Ok(native::get_frm_response::<D, _>(state, payment_data, frm_data, platform).await?);
+        return Ok(router_data);
+    }
+
     let connector =
         FraudCheckConnectorData::get_connector_by_name(&frm_data.connector_details.connector_name)?;
     let router_data_res = router_data
@@ -146,6 +161,7 @@ where
 #[cfg(feature = "v2")]
 pub async fn should_call_frm<F, D>(
     _platform: &domain::Platform,
+    _business_profile: &domain::Profile,
     _payment_data: &D,
     _state: &SessionState,
 ) -> RouterResult<(
@@ -166,6 +182,7 @@ where
 #[cfg(feature = "v1")]
 pub async fn should_call_frm<F, D>(
     platform: &domain::Platform,
+    business_profile: &domain::Profile,
     payment_data: &D,
     state: &SessionState,
 ) -> RouterResult<(
@@ -181,6 +198,30 @@ where
     use common_utils::ext_traits::OptionExt;
     use hyperswitch_masking::ExposeInterface;
 
+    // The built-in fraud engine of the profile takes precedence over the FRM connector of the
+    // merchant account
+    if let Some(native_frm_config) = business_profile.native_frm_config.as_ref() {
+        let profile_id = business_profile.get_id();
+        let frm_configs_object = native::get_frm_configs(
+            native_frm_config,
+            payment_data.get_payment_attempt().payment_method,
+        );
+        logger::debug!(
+            "native_frm_configs: {:?} {:?}",
+            profile_id,
+            frm_configs_object
+        );
+        return Ok((
+            true,
+            Some(FrmRoutingAlgorithm {
+                data: native::NATIVE_FRM_PROVIDER.to_string(),
+                algorithm_type: "single".to_string(),
+            }),
+            Some(profile_id.clone()),
+            Some(frm_configs_object),
+        ));
+    }
+
     let db = &*state.store;
     match platform
         .get_processor()
@@ -642,6 +683,23 @@ where
                         frm_router_data.to_owned(),
                     )
                     .await?;
+                #[cfg(feature = "v1")]
+                if matches!(
+                    frm_info.suggested_action,
+                    Some(FrmSuggestion::FrmManualReview)
+                ) {
+                    let fraud_check = review::mark_review_pending(
+                        state,
+                        platform.get_processor(),
+                        updated_frm_data.fraud_check.clone(),
+                    )
+                    .await?;
+                    payment_data.set_frm_message(fraud_check.clone());
+                    return Ok(Some(FrmData {
+                        fraud_check,
+                        ..updated_frm_data
+                    }));
+                }
                 return Ok(Some(updated_frm_data));
             }
         }
@@ -656,6 +714,7 @@ where
 pub async fn call_frm_before_connector_call<F, Req, D>(
     operation: &BoxedOperation<'_, F, Req, D>,
     platform: &domain::Platform,
+    business_profile: &domain::Profile,
     payment_data: &mut D,
     state: &SessionState,
     frm_info: &mut Option<FrmInfo<F, D>>,
@@ -671,7 +730,7 @@ where
         + Clone,
 {
     let (is_frm_enabled, frm_routing_algorithm, frm_connector_label, frm_configs) =
-        should_call_frm(platform, payment_data, state).await?;
+        should_call_frm(platform, business_profile, payment_data, state).await?;
     if let Some((frm_routing_algorithm_val, profile_id)) =
         frm_routing_algorithm.zip(frm_connector_label)
     {
@@ -830,6 +889,14 @@ pub async fn make_fulfillment_api_call(
         )
         .await
         .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    if fraud_check.frm_name == native::NATIVE_FRM_PROVIDER {
+        return Err(errors::ApiErrorResponse::NotSupported {
+            message:
+                "Fulfillment is not supported for payments checked by the built-in fraud engine"
+                    .to_string(),
+        }
+        .into());
+    }
     let connector_data = FraudCheckConnectorData::get_connector_by_name(&fraud_check.frm_name)?;
     let connector_integration: services::BoxedFrmConnectorIntegrationInterface<
         Fulfillment,
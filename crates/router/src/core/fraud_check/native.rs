@@ -0,0 +1,645 @@
+use api_models::{enums as api_enums, routing as routing_types};
+use common_types::fraud_rule_engine::{
+    FrmDecisionRule, FrmRuleDecision, NativeFrmConfig, NativeFrmFlowType,
+    MAX_VELOCITY_WINDOW_IN_SECONDS,
+};
+use common_utils::{
+    crypto::{HmacSha256, SignMessage},
+    ext_traits::ValueExt,
+    types::MinorUnit,
+};
+use error_stack::{report, ResultExt};
+use euclid::{
+    backend::{self, inputs as dsl_inputs, EuclidBackend},
+    frontend::dir::enums::CustomerDevicePlatform,
+};
+use hyperswitch_domain_models::payments::payment_intent;
+use hyperswitch_masking::{ExposeInterface, PeekInterface, Secret};
+use redis_interface::RedisKey;
+use router_env::{instrument, logger, tracing};
+
+use super::types::{FrmConfigsObject, FrmData};
+use crate::{
+    connector::utils::PaymentsAttemptData,
+    core::{
+        blocklist::utils as blocklist_utils,
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::{
+            self, helpers,
+            routing::{self as payments_routing, decision_trace},
+        },
+        routing as core_routing,
+    },
+    routes::{metrics, SessionState},
+    types::{
+        domain,
+        fraud_check::FraudCheckResponseData,
+        storage::enums::{FraudCheckLastStep, FraudCheckStatus},
+        BrowserInformation, ResponseId,
+    },
+};
+
+/// Name under which the built-in fraud engine is recorded as the FRM provider of a payment
+pub const NATIVE_FRM_PROVIDER: &str = "hyperswitch_fraud_rules";
+
+pub const FRM_VELOCITY_PREFIX: &str = "FRM_VELOCITY";
+
+/// Records the payment in the sorted set of each identifier and replies with the number and the
+/// total amount of the payments made with it over the window, as a flat array of
+/// `count, amount` pairs in the order of the sorted sets. Only the payments made in the currency
+/// of the evaluated payment are summed up.
+///
+/// The member of a payment is `currency:amount:attempt_id`, so that evaluating an attempt twice
+/// counts it only once. The amounts are summed up per currency in a hash next to each sorted set,
+/// which is kept in step as the payments enter and leave the window, so that only the expired
+/// members are read. The clock of the redis server is used, so that the router instances need not
+/// agree on the time.
+///
+/// KEYS: pairs of the sorted set of an identifier and the hash of its amounts
+/// ARGV[1]: the window in milliseconds
+/// ARGV[2]: the member of the evaluated payment
+/// ARGV[3]: the currency of the evaluated payment
+/// ARGV[4]: the amount of the evaluated payment
+const VELOCITY_SCRIPT: &str = r#"
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local window = tonumber(ARGV[1])
+local counters = {}
+
+for index = 1, #KEYS, 2 do
+    local key = KEYS[index]
+    local amounts_key = KEYS[index + 1]
+    for _, member in ipairs(redis.call('ZRANGEBYSCORE', key, '-inf', now - window)) do
+        local currency, member_amount = string.match(member, '^([^:]+):(%d+):')
+        if currency and redis.call('HINCRBY', amounts_key, currency, -tonumber(member_amount)) <= 0 then
+            redis.call('HDEL', amounts_key, currency)
+        end
+    end
+    redis.call('ZREMRANGEBYSCORE', key, '-inf', now - window)
+    if redis.call('ZADD', key, 'NX', now, ARGV[2]) == 1 then
+        redis.call('HINCRBY', amounts_key, ARGV[3], ARGV[4])
+    end
+    redis.call('PEXPIRE', key, window)
+    redis.call('PEXPIRE', amounts_key, window)
+    table.insert(counters, redis.call('ZCARD', key))
+    table.insert(counters, tonumber(redis.call('HGET', amounts_key, ARGV[3])) or 0)
+end
+
+return counters
+"#;
+
+/// Rejects a configuration whose rules cannot be evaluated or whose velocity window is out of
+/// range
+pub fn validate_native_frm_config(config: &NativeFrmConfig) -> RouterResult<()> {
+    if !(1..=MAX_VELOCITY_WINDOW_IN_SECONDS).contains(&config.velocity_window_in_seconds) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "velocity_window_in_seconds must be between 1 and {MAX_VELOCITY_WINDOW_IN_SECONDS}"
+            ),
+        }));
+    }
+
+    backend::VirInterpreterBackend::<FrmDecisionRule>::with_program(config.rules.clone())
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid native_frm_config rules".to_string(),
+        })
+        .attach_printable("Error initializing DSL interpreter backend for the fraud rules")?;
+
+    Ok(())
+}
+
+/// The built-in fraud engine configuration of the profile the payment is made on, if any
+pub async fn get_native_frm_config(
+    state: &SessionState,
+    platform: &domain::Platform,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<Option<(NativeFrmConfig, domain::Profile)>> {
+    let profile = state
+        .store
+        .find_business_profile_by_profile_id(platform.get_processor().get_key_store(), profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    Ok(profile
+        .native_frm_config
+        .clone()
+        .map(|config| (config, profile)))
+}
+
+pub fn get_frm_configs(
+    config: &NativeFrmConfig,
+    payment_method: Option<api_enums::PaymentMethod>,
+) -> FrmConfigsObject {
+    FrmConfigsObject {
+        frm_enabled_pm: payment_method,
+        frm_enabled_gateway: None,
+        frm_preferred_flow_type: match config.flow {
+            NativeFrmFlowType::Pre => api_enums::FrmPreferredFlowTypes::Pre,
+            NativeFrmFlowType::Post => api_enums::FrmPreferredFlowTypes::Post,
+        },
+    }
+}
+
+/// The built-in fraud engine needs no credentials, this stands in for the merchant connector
+/// account of an FRM connector while constructing the router data
+pub fn get_merchant_connector_account() -> helpers::MerchantConnectorAccountType {
+    helpers::MerchantConnectorAccountType::CacheVal(api_models::admin::MerchantConnectorDetails {
+        connector_account_details: Secret::new(serde_json::json!({ "auth_type": "NoKey" })),
+        metadata: None,
+    })
+}
+
+fn get_fraud_check_status(decision: FrmRuleDecision) -> FraudCheckStatus {
+    match decision {
+        FrmRuleDecision::Accept => FraudCheckStatus::Legit,
+        FrmRuleDecision::Decline => FraudCheckStatus::Fraud,
+        FrmRuleDecision::ManualReview => FraudCheckStatus::ManualReview,
+    }
+}
+
+/// Evaluates the fraud rules of the profile on the first step of the flow, the checkout of the
+/// pre flow or the sale of the post flow. The decision taken then is replayed on the later steps.
+#[instrument(skip_all)]
+pub async fn get_frm_response<F, D>(
+    state: &SessionState,
+    payment_data: &D,
+    frm_data: &FrmData,
+    platform: &domain::Platform,
+) -> RouterResult<FraudCheckResponseData>
+where
+    F: Send + Clone,
+    D: payments::OperationSessionGetters<F> + Send + Sync + Clone,
+{
+    if frm_data.fraud_check.last_step != FraudCheckLastStep::Processing {
+        return Ok(FraudCheckResponseData::TransactionResponse {
+            resource_id: ResponseId::NoResponseId,
+            status: frm_data.fraud_check.frm_status,
+            connector_metadata: frm_data.fraud_check.metadata.clone(),
+            reason: frm_data.fraud_check.frm_reason.clone(),
+            score: frm_data.fraud_check.frm_score,
+        });
+    }
+
+    let (config, profile) =
+        get_native_frm_config(state, platform, &frm_data.connector_details.profile_id)
+            .await?
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "native_frm_config was removed from the profile during the payment",
+            )?;
+
+    let payments_dsl_input = core_routing::PaymentsDslInput::new(
+        payment_data.get_setup_mandate(),
+        payment_data.get_payment_attempt(),
+        payment_data.get_payment_intent(),
+        payment_data.get_payment_method_data(),
+        payment_data.get_address(),
+        payment_data.get_recurring_details(),
+        payment_data.get_currency(),
+    );
+    let mut backend_input = payments_routing::make_dsl_input(&payments_dsl_input)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error constructing the DSL input for the fraud rules")?;
+
+    let browser_info = payment_data.get_payment_attempt().get_browser_info().ok();
+    backend_input.time_data = Some(payments_routing::make_dsl_time_input(
+        profile.routing_timezone,
+        common_utils::date_time::now(),
+    ));
+    backend_input.customer_device_data = browser_info
+        .as_ref()
+        .and_then(get_customer_device_platform)
+        .map(|platform| dsl_inputs::CustomerDeviceDataInput {
+            platform: Some(platform),
+            device_type: None,
+            display_size: None,
+        });
+    backend_input.velocity_data =
+        get_velocity_data(state, payment_data, browser_info.as_ref(), &config).await;
+
+    let interpreter = backend::VirInterpreterBackend::with_program(config.rules)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error initializing DSL interpreter backend for the fraud rules")?;
+    let result = interpreter
+        .execute(backend_input.clone())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error executing the fraud rules")?;
+
+    let trace_key =
+        decision_trace::DecisionTraceKey::from_payment_attempt(payment_data.get_payment_attempt());
+    if decision_trace::is_decision_trace_enabled(state, trace_key.merchant_id).await {
+        decision_trace::record_rule_evaluations(
+            state,
+            trace_key,
+            vec![routing_types::RuleEvaluationTrace::new(
+                routing_types::DecisionTraceType::FraudRule,
+                None,
+                &backend_input,
+                &result,
+            )],
+        )
+        .await;
+    }
+
+    let decision = result.get_output().get_decision();
+    let frm_status = get_fraud_check_status(decision);
+    logger::debug!(?decision, rule_name = ?result.rule_name, "Fraud rules evaluated");
+    metrics::NATIVE_FRM_DECISIONS.add(
+        1,
+        router_env::metric_attributes!(("frm_status", frm_status.to_string())),
+    );
+
+    Ok(FraudCheckResponseData::TransactionResponse {
+        resource_id: ResponseId::NoResponseId,
+        status: frm_status,
+        connector_metadata: backend_input
+            .velocity_data
+            .map(|velocity_data| serde_json::json!({ "velocity": velocity_data })),
+        reason: result.rule_name.map(serde_json::Value::String),
+        score: None,
+    })
+}
+
+fn get_customer_device_platform(
+    browser_info: &BrowserInformation,
+) -> Option<CustomerDevicePlatform> {
+    match browser_info
+        .os_type
+        .as_deref()
+        .map(str::to_lowercase)
+        .as_deref()
+    {
+        Some("android") => Some(CustomerDevicePlatform::Android),
+        Some("ios" | "ipados") => Some(CustomerDevicePlatform::Ios),
+        _ => browser_info
+            .user_agent
+            .as_ref()
+            .map(|_| CustomerDevicePlatform::Web),
+    }
+}
+
+/// The card, email and IP address of the payment, identified by an HMAC of their value keyed by
+/// the fingerprint secret of the merchant, so that none of them can be recovered from redis
+fn get_velocity_identifiers<F, D>(
+    payment_data: &D,
+    browser_info: Option<&BrowserInformation>,
+    fingerprint_secret: &str,
+) -> [Option<String>; 3]
+where
+    F: Send + Clone,
+    D: payments::OperationSessionGetters<F> + Send + Sync + Clone,
+{
+    let card = payment_data
+        .get_payment_attempt()
+        .fingerprint_id
+        .clone()
+        .or_else(|| match payment_data.get_payment_method_data() {
+            Some(domain::PaymentMethodData::Card(card)) => Some(card.card_number.peek().to_owned()),
+            _ => None,
+        });
+
+    let email = payment_data
+        .get_payment_intent()
+        .customer_details
+        .clone()
+        .and_then(|customer_details| {
+            customer_details
+                .into_inner()
+                .expose()
+                .parse_value::<payment_intent::CustomerData>("CustomerData")
+                .inspect_err(|error| {
+                    logger::warn!(?error, "Failed to parse customer data from payment intent")
+                })
+                .ok()
+        })
+        .and_then(|customer_data| customer_data.email)
+        .or_else(|| {
+            payment_data
+                .get_address()
+                .get_payment_method_billing()
+                .and_then(|billing| billing.email.clone())
+        })
+        .map(|email| email.peek().to_lowercase());
+
+    let ip = browser_info
+        .and_then(|browser_info| browser_info.ip_address)
+        .map(|ip_address| ip_address.to_string());
+
+    [card, email, ip].map(|identifier| {
+        identifier.and_then(|identifier| hash_velocity_identifier(fingerprint_secret, &identifier))
+    })
+}
+
+fn hash_velocity_identifier(fingerprint_secret: &str, identifier: &str) -> Option<String> {
+    HmacSha256
+        .sign_message(fingerprint_secret.as_bytes(), identifier.as_bytes())
+        .map(hex::encode)
+        .inspect_err(|error| logger::error!(?error, "Failed to hash the velocity identifier"))
+        .ok()
+}
+
+/// Pairs the `count, amount` counters replied by the velocity script with the identifiers they
+/// were evaluated for, in the order of the identifiers
+fn get_velocity_data_input(
+    identifiers: &[Option<String>; 3],
+    counters: &[u64],
+) -> dsl_inputs::VelocityDataInput {
+    let mut counters = counters
+        .chunks_exact(2)
+        .filter_map(|counter| match counter {
+            [count, amount] => Some(dsl_inputs::VelocityCounter {
+                count: u32::try_from(*count).unwrap_or(u32::MAX),
+                amount: MinorUnit::new(i64::try_from(*amount).unwrap_or(i64::MAX)),
+            }),
+            _ => None,
+        });
+    let [card, email, ip] = identifiers
+        .each_ref()
+        .map(|identifier| identifier.as_ref().and_then(|_| counters.next()));
+
+    dsl_inputs::VelocityDataInput { card, email, ip }
+}
+
+/// Records the payment against its card, email and IP address and returns their velocity over
+/// the window of the configuration.
+///
+/// The counters fail open: the velocity keys are absent from the input of the rules, and hence
+/// match none of them, if redis could not be reached.
+async fn get_velocity_data<F, D>(
+    state: &SessionState,
+    payment_data: &D,
+    browser_info: Option<&BrowserInformation>,
+    config: &NativeFrmConfig,
+) -> Option<dsl_inputs::VelocityDataInput>
+where
+    F: Send + Clone,
+    D: payments::OperationSessionGetters<F> + Send + Sync + Clone,
+{
+    let payment_attempt = payment_data.get_payment_attempt();
+    let fingerprint_secret =
+        blocklist_utils::get_merchant_fingerprint_secret(state, &payment_attempt.merchant_id)
+            .await
+            .inspect_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to get the fingerprint secret for fraud velocity"
+                )
+            })
+            .ok()?;
+
+    let identifiers = get_velocity_identifiers(payment_data, browser_info, &fingerprint_secret);
+    if identifiers.iter().all(Option::is_none) {
+        return None;
+    }
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .inspect_err(|error| {
+            logger::error!(?error, "Failed to get redis connection for fraud velocity")
+        })
+        .ok()?;
+
+    let currency = payment_data.get_currency();
+    let amount = MinorUnit::from(payment_data.get_amount()).get_amount_as_i64();
+    let key_prefix = format!(
+        "{FRM_VELOCITY_PREFIX}_{{{}}}",
+        payment_attempt.merchant_id.get_string_repr()
+    );
+    let keys = ["card", "email", "ip"]
+        .iter()
+        .zip(identifiers.iter())
+        .filter_map(|(kind, identifier)| {
+            identifier
+                .as_ref()
+                .map(|identifier| format!("{key_prefix}_{kind}_{identifier}"))
+        })
+        .flat_map(|key| {
+            let amounts_key = format!("{key}_amounts");
+            [key, amounts_key]
+        })
+        .map(|key| RedisKey::from(key.as_str()).tenant_aware_key(&redis_conn))
+        .collect();
+    let args = vec![
+        (u64::from(config.velocity_window_in_seconds) * 1000).to_string(),
+        format!("{currency}:{amount}:{}", payment_attempt.attempt_id),
+        currency.to_string(),
+        amount.to_string(),
+    ];
+
+    let counters = redis_conn
+        .evaluate_redis_script::<_, Vec<u64>>(VELOCITY_SCRIPT, keys, args)
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to evaluate the fraud velocity"))
+        .ok()?;
+
+    Some(get_velocity_data_input(&identifiers, &counters))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::crypto::{GenerateDigest, Sha256};
+
+    use super::*;
+
+    fn native_frm_config(velocity_window_in_seconds: u32) -> NativeFrmConfig {
+        serde_json::from_value(serde_json::json!({
+            "flow": "pre",
+            "velocity_window_in_seconds": velocity_window_in_seconds,
+            "rules": {
+                "defaultSelection": { "decision": "accept" },
+                "rules": [{
+                    "name": "card_velocity",
+                    "connectorSelection": { "decision": "decline" },
+                    "statements": [{
+                        "condition": [{
+                            "lhs": "card_velocity_count",
+                            "comparison": "greater_than",
+                            "value": { "type": "number", "value": 3 },
+                            "metadata": {}
+                        }],
+                        "nested": null
+                    }]
+                }],
+                "metadata": {}
+            }
+        }))
+        .unwrap()
+    }
+
+    fn backend_input(
+        velocity_data: Option<dsl_inputs::VelocityDataInput>,
+    ) -> dsl_inputs::BackendInput {
+        dsl_inputs::BackendInput {
+            metadata: None,
+            payment: dsl_inputs::PaymentInput {
+                amount: MinorUnit::new(100),
+                transaction_initiator: None,
+                card_bin: None,
+                extended_card_bin: None,
+                currency: common_enums::Currency::USD,
+                authentication_type: None,
+                capture_method: None,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: dsl_inputs::PaymentMethodInput {
+                payment_method: Some(common_enums::PaymentMethod::Card),
+                payment_method_type: None,
+                card_network: None,
+                card_discovery: None,
+            },
+            mandate: dsl_inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            time_data: None,
+            velocity_data,
+        }
+    }
+
+    #[test]
+    fn test_hash_velocity_identifier() {
+        let hash = hash_velocity_identifier("fs_secret", "4242424242424242").unwrap();
+
+        assert_eq!(
+            hash_velocity_identifier("fs_secret", "4242424242424242").as_ref(),
+            Some(&hash)
+        );
+        assert_ne!(
+            hash_velocity_identifier("fs_other_secret", "4242424242424242").as_ref(),
+            Some(&hash)
+        );
+        assert_ne!(
+            hash_velocity_identifier("fs_secret", "4000056655665556").as_ref(),
+            Some(&hash)
+        );
+        assert_ne!(
+            hash,
+            hex::encode(Sha256.generate_digest(b"4242424242424242").unwrap())
+        );
+        assert!(!hash.contains("4242424242424242"));
+    }
+
+    #[test]
+    fn test_get_velocity_data_input_skips_missing_identifiers() {
+        let identifiers = [Some("card".to_string()), None, Some("ip".to_string())];
+        let velocity_data = get_velocity_data_input(&identifiers, &[4, 480, 1, 120]);
+
+        let as_tuple = |counter: Option<dsl_inputs::VelocityCounter>| {
+            counter.map(|counter| (counter.count, counter.amount))
+        };
+        assert_eq!(as_tuple(velocity_data.card), Some((4, MinorUnit::new(480))));
+        assert!(velocity_data.email.is_none());
+        assert_eq!(as_tuple(velocity_data.ip), Some((1, MinorUnit::new(120))));
+    }
+
+    #[test]
+    fn test_get_velocity_data_input_with_short_reply() {
+        let identifiers = [Some("card".to_string()), Some("email".to_string()), None];
+        let velocity_data = get_velocity_data_input(&identifiers, &[2, 200, 1]);
+
+        assert_eq!(velocity_data.card.map(|counter| counter.count), Some(2));
+        assert!(velocity_data.email.is_none());
+        assert!(velocity_data.ip.is_none());
+    }
+
+    #[test]
+    fn test_validate_native_frm_config_window() {
+        assert!(validate_native_frm_config(&native_frm_config(60)).is_ok());
+        assert!(
+            validate_native_frm_config(&native_frm_config(MAX_VELOCITY_WINDOW_IN_SECONDS)).is_ok()
+        );
+        assert!(validate_native_frm_config(&native_frm_config(0)).is_err());
+        assert!(
+            validate_native_frm_config(&native_frm_config(MAX_VELOCITY_WINDOW_IN_SECONDS + 1))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_native_rules_on_velocity() {
+        let config = native_frm_config(60);
+        let interpreter =
+            backend::VirInterpreterBackend::<FrmDecisionRule>::with_program(config.rules).unwrap();
+        let identifiers = [Some("card".to_string()), None, None];
+
+        let fast = interpreter
+            .execute(backend_input(Some(get_velocity_data_input(
+                &identifiers,
+                &[4, 400],
+            ))))
+            .unwrap();
+        assert_eq!(fast.rule_name.as_deref(), Some("card_velocity"));
+        assert_eq!(
+            get_fraud_check_status(fast.get_output().get_decision()),
+            FraudCheckStatus::Fraud
+        );
+
+        let slow = interpreter
+            .execute(backend_input(Some(get_velocity_data_input(
+                &identifiers,
+                &[3, 300],
+            ))))
+            .unwrap();
+        assert_eq!(slow.rule_name, None);
+        assert_eq!(
+            get_fraud_check_status(slow.get_output().get_decision()),
+            FraudCheckStatus::Legit
+        );
+
+        let unavailable = interpreter.execute(backend_input(None)).unwrap();
+        assert_eq!(unavailable.rule_name, None);
+    }
+
+    #[test]
+    fn test_get_fraud_check_status() {
+        assert_eq!(
+            get_fraud_check_status(FrmRuleDecision::Accept),
+            FraudCheckStatus::Legit
+        );
+        assert_eq!(
+            get_fraud_check_status(FrmRuleDecision::Decline),
+            FraudCheckStatus::Fraud
+        );
+        assert_eq!(
+            get_fraud_check_status(FrmRuleDecision::ManualReview),
+            FraudCheckStatus::ManualReview
+        );
+    }
+
+    #[test]
+    fn test_get_customer_device_platform() {
+        let browser_info = |os_type: Option<&str>, user_agent: Option<&str>| BrowserInformation {
+            os_type: os_type.map(str::to_string),
+            user_agent: user_agent.map(str::to_string),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_customer_device_platform(&browser_info(Some("Android"), None)),
+            Some(CustomerDevicePlatform::Android)
+        );
+        assert_eq!(
+            get_customer_device_platform(&browser_info(Some("iPadOS"), Some("Mozilla/5.0"))),
+            Some(CustomerDevicePlatform::Ios)
+        );
+        assert_eq!(
+            get_customer_device_platform(&browser_info(Some("Windows"), Some("Mozilla/5.0"))),
+            Some(CustomerDevicePlatform::Web)
+        );
+        assert_eq!(
+            get_customer_device_platform(&browser_info(None, None)),
+            None
+        );
+    }
+}
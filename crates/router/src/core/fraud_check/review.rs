@@ -0,0 +1,901 @@
+//! Manual review queue of the payments held by a fraud check.
+//!
+//! A payment is queued when the post-authorization fraud check suggests a manual review. The
+//! review is decided by a reviewer through the existing approve and reject payment operations,
+//! or automatically by the `FrmReviewSlaWorkflow` once the SLA configured for the merchant
+//! passes.
+//!
+//! A payment blocked by the blocklist of the merchant is queued as well, so that the blocklist
+//! decisions are reviewed alongside the fraud checks. The payment is failed by then, deciding
+//! such a review only records the verdict of the reviewer on the block.
+
+use api_models::{
+    fraud_check::{self as review_types, FrmReviewSlaAction, FrmReviewSlaConfig},
+    payments as payment_types,
+};
+use common_enums::FrmReviewStatus;
+use common_utils::{ext_traits::StringExt, id_type};
+use diesel_models::{
+    enums::{FraudCheckLastStep, FraudCheckStatus, FraudCheckType, UserRoleVersion, UserStatus},
+    fraud_check::{FraudCheckNew, FrmReviewNote, FrmReviewNotes},
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::HeaderPayload;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments, utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::{app::ReqState, metrics, SessionState},
+    services::{
+        self,
+        authorization::{permissions::Permission, roles},
+    },
+    types::{
+        api, domain,
+        storage::{
+            self,
+            fraud_check::{FraudCheck, FraudCheckUpdate},
+        },
+    },
+};
+
+const FRM_REVIEW_SLA_TASK: &str = "FRM_REVIEW_SLA";
+const FRM_REVIEW_SLA_TAG: &str = "FRM";
+
+/// Name under which the blocklist is recorded as the FRM provider of the payments it blocked
+pub const BLOCKLIST_REVIEW_FRM_NAME: &str = "blocklist";
+
+const DEFAULT_REVIEW_LIST_LIMIT: u32 = 20;
+const MAX_REVIEW_LIST_LIMIT: u32 = 100;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FrmReviewSlaTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub payment_id: id_type::PaymentId,
+    pub action: FrmReviewSlaAction,
+}
+
+/// Outcome of a run of the SLA task of a review
+pub enum FrmReviewSlaOutcome {
+    Decided,
+    /// The review was decided before its deadline
+    AlreadyDecided,
+}
+
+async fn get_review_sla_config(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> Option<FrmReviewSlaConfig> {
+    let key = merchant_id.get_frm_review_sla_config_key();
+
+    db.find_config_by_key(&key)
+        .await
+        .and_then(|config| {
+            config
+                .config
+                .parse_struct("FrmReviewSlaConfig")
+                .change_context(errors::StorageError::DeserializationFailed)
+        })
+        .map_err(|error| {
+            if !error.current_context().is_db_not_found() {
+                logger::error!(?error, "Failed to read manual review SLA config `{key}`");
+            }
+        })
+        .ok()
+}
+
+/// Queues the payment for a manual review and schedules its SLA task when the merchant has one
+/// configured
+#[instrument(skip_all)]
+pub async fn mark_review_pending(
+    state: &SessionState,
+    processor: &domain::Processor,
+    fraud_check: FraudCheck,
+) -> RouterResult<FraudCheck> {
+    let db = &*state.store;
+    let merchant_id = processor.get_account().get_id();
+    let sla = get_review_sla_config(db, merchant_id)
+        .await
+        .map(|sla_config| {
+            (
+                sla_config.action,
+                common_utils::date_time::now()
+                    + time::Duration::seconds(i64::from(sla_config.sla_in_seconds)),
+            )
+        });
+
+    let fraud_check = db
+        .update_fraud_check_response_with_attempt_id(
+            fraud_check,
+            FraudCheckUpdate::ReviewPendingUpdate {
+                review_deadline: sla.map(|(_, review_deadline)| review_deadline),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to queue the payment for manual review")?;
+
+    if let Some((action, review_deadline)) = sla {
+        let tracking_data = FrmReviewSlaTrackingData {
+            merchant_id: fraud_check.merchant_id.clone(),
+            payment_id: fraud_check.payment_id.clone(),
+            action,
+        };
+        add_review_sla_task(
+            db,
+            &tracking_data,
+            review_deadline,
+            state.conf.application_source,
+        )
+        .await?;
+    }
+
+    Ok(fraud_check)
+}
+
+/// Queues a payment blocked by the blocklist of the merchant for a manual review of the block
+#[instrument(skip_all)]
+pub async fn queue_blocklist_review(
+    state: &SessionState,
+    processor: &domain::Processor,
+    payment_attempt: &storage::PaymentAttempt,
+    reason: &str,
+) -> RouterResult<FraudCheck> {
+    let fraud_check = state
+        .store
+        .insert_fraud_check_response(get_blocklist_fraud_check(payment_attempt, reason))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the blocklist decision")?;
+
+    mark_review_pending(state, processor, fraud_check).await
+}
+
+fn get_blocklist_fraud_check(
+    payment_attempt: &storage::PaymentAttempt,
+    reason: &str,
+) -> FraudCheckNew {
+    let now = common_utils::date_time::now();
+    FraudCheckNew {
+        frm_id: uuid::Uuid::new_v4().simple().to_string(),
+        payment_id: payment_attempt.payment_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        created_at: now,
+        frm_name: BLOCKLIST_REVIEW_FRM_NAME.to_string(),
+        frm_transaction_id: None,
+        frm_transaction_type: FraudCheckType::PreFrm,
+        frm_status: FraudCheckStatus::Fraud,
+        frm_score: None,
+        frm_reason: Some(serde_json::Value::String(reason.to_string())),
+        frm_error: None,
+        payment_details: None,
+        metadata: None,
+        modified_at: now,
+        last_step: FraudCheckLastStep::CheckoutOrSale,
+        payment_capture_method: payment_attempt.capture_method,
+    }
+}
+
+fn is_blocklist_review(fraud_check: &FraudCheck) -> bool {
+    fraud_check.frm_name == BLOCKLIST_REVIEW_FRM_NAME
+}
+
+async fn add_review_sla_task(
+    db: &dyn StorageInterface,
+    tracking_data: &FrmReviewSlaTrackingData,
+    schedule_time: time::PrimitiveDateTime,
+    application_source: common_enums::ApplicationSource,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::FrmReviewSlaWorkflow;
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        FRM_REVIEW_SLA_TASK,
+        tracking_data.payment_id.get_string_repr(),
+        &tracking_data.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        FRM_REVIEW_SLA_TASK,
+        runner,
+        [FRM_REVIEW_SLA_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+        application_source,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct manual review SLA process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert manual review SLA task to process_tracker")?;
+    metrics::TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "FrmReviewSla")));
+
+    Ok(())
+}
+
+/// Closes a pending review when its payment is approved or rejected, so that decisions taken
+/// through the payment endpoints leave the queue as well. Failures are logged.
+#[instrument(skip_all)]
+pub async fn close_review(
+    state: &SessionState,
+    fraud_check: FraudCheck,
+    review_status: FrmReviewStatus,
+) -> FraudCheck {
+    if fraud_check.review_status != Some(FrmReviewStatus::Pending) {
+        return fraud_check;
+    }
+
+    state
+        .store
+        .update_fraud_check_response_with_attempt_id(
+            fraud_check.clone(),
+            FraudCheckUpdate::ReviewDecisionUpdate {
+                review_status,
+                reviewed_by: None,
+                reviewed_at: common_utils::date_time::now(),
+                review_notes: None,
+            },
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to close the manual review"))
+        .unwrap_or(fraud_check)
+}
+
+fn get_reviewer_id(platform: &domain::Platform) -> Option<String> {
+    match platform.get_initiator() {
+        Some(domain::Initiator::Jwt { user_id }) => Some(user_id.clone()),
+        _ => None,
+    }
+}
+
+fn get_review_response(fraud_check: FraudCheck) -> RouterResult<review_types::FrmReviewResponse> {
+    let review_status = fraud_check
+        .review_status
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Fraud check is not queued for manual review")?;
+
+    Ok(review_types::FrmReviewResponse {
+        payment_id: fraud_check.payment_id,
+        attempt_id: fraud_check.attempt_id,
+        frm_name: fraud_check.frm_name,
+        frm_status: fraud_check.frm_status,
+        frm_reason: fraud_check.frm_reason,
+        frm_score: fraud_check.frm_score,
+        payment_details: fraud_check.payment_details,
+        review_status,
+        assigned_to: fraud_check.review_assigned_to,
+        assigned_at: fraud_check.review_assigned_at,
+        review_deadline: fraud_check.review_deadline,
+        reviewed_by: fraud_check.reviewed_by,
+        reviewed_at: fraud_check.reviewed_at,
+        notes: fraud_check
+            .review_notes
+            .map(|review_notes| {
+                review_notes
+                    .0
+                    .into_iter()
+                    .map(|review_note| review_types::FrmReviewNote {
+                        author: review_note.author,
+                        note: review_note.note,
+                        created_at: review_note.created_at,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+        created_at: fraud_check.created_at,
+    })
+}
+
+async fn find_review(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<FraudCheck> {
+    db.find_fraud_check_by_payment_id_if_present(payment_id.clone(), merchant_id.clone())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch fraud check")?
+        .filter(|fraud_check| fraud_check.review_status.is_some())
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "Manual review does not exist for the payment".to_string(),
+            })
+        })
+}
+
+/// Finds the review of a payment on behalf of a user scoped to a profile, the reviews of the
+/// payments of the other profiles are not visible to them
+async fn find_review_in_profile(
+    db: &dyn StorageInterface,
+    processor: &domain::Processor,
+    profile_id: Option<&id_type::ProfileId>,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<FraudCheck> {
+    let fraud_check = find_review(db, processor.get_account().get_id(), payment_id).await?;
+
+    if profile_id.is_some() {
+        let payment_intent = find_payment_intent(db, processor, payment_id).await?;
+        core_utils::validate_profile_id_from_auth_layer(profile_id.cloned(), &payment_intent)?;
+    }
+
+    Ok(fraud_check)
+}
+
+async fn find_payment_intent(
+    db: &dyn StorageInterface,
+    processor: &domain::Processor,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<storage::PaymentIntent> {
+    db.find_payment_intent_by_payment_id_processor_merchant_id(
+        payment_id,
+        processor.get_account().get_id(),
+        processor.get_key_store(),
+        processor.get_account().storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+}
+
+fn ensure_review_pending(fraud_check: &FraudCheck) -> RouterResult<()> {
+    if fraud_check.review_status == Some(FrmReviewStatus::Pending) {
+        Ok(())
+    } else {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The manual review of the payment is already decided".to_string(),
+        }))
+    }
+}
+
+fn append_review_note(
+    fraud_check: &FraudCheck,
+    author: Option<String>,
+    note: String,
+) -> FrmReviewNotes {
+    let mut review_notes = fraud_check.review_notes.clone().unwrap_or_default();
+    review_notes.0.push(FrmReviewNote {
+        author,
+        note,
+        created_at: common_utils::date_time::now(),
+    });
+    review_notes
+}
+
+#[instrument(skip_all)]
+pub async fn list_reviews(
+    state: SessionState,
+    platform: domain::Platform,
+    constraints: review_types::FrmReviewListConstraints,
+) -> RouterResponse<review_types::FrmReviewListResponse> {
+    let limit = constraints
+        .limit
+        .unwrap_or(DEFAULT_REVIEW_LIST_LIMIT)
+        .min(MAX_REVIEW_LIST_LIMIT);
+
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let review_status = constraints.status.unwrap_or(FrmReviewStatus::Pending);
+    let fraud_checks = state
+        .store
+        .list_fraud_checks_by_merchant_id_review_status(
+            merchant_id,
+            review_status,
+            constraints.assigned_to.as_deref(),
+            i64::from(limit),
+            i64::from(constraints.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list manual reviews")?;
+    let total_count = state
+        .store
+        .count_fraud_checks_by_merchant_id_review_status(
+            merchant_id,
+            review_status,
+            constraints.assigned_to.as_deref(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count manual reviews")?;
+
+    let data = fraud_checks
+        .into_iter()
+        .map(get_review_response)
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(services::ApplicationResponse::Json(
+        review_types::FrmReviewListResponse {
+            count: data.len(),
+            total_count,
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_review(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<id_type::ProfileId>,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<review_types::FrmReviewResponse> {
+    let fraud_check = find_review_in_profile(
+        &*state.store,
+        platform.get_processor(),
+        profile_id.as_ref(),
+        &payment_id,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(get_review_response(
+        fraud_check,
+    )?))
+}
+
+/// Checks that the reviewer is an active user of the lineage of the payment who is allowed to
+/// approve and reject its payments
+async fn validate_reviewer(
+    state: &SessionState,
+    platform: &domain::Platform,
+    profile_id: &id_type::ProfileId,
+    reviewer_id: &str,
+) -> RouterResult<()> {
+    let merchant_account = platform.get_processor().get_account();
+    let merchant_id = merchant_account.get_id();
+    let org_id = merchant_account.get_org_id();
+    let tenant_id = &state.tenant.tenant_id;
+
+    let user_role = match state
+        .global_store
+        .find_user_role_by_user_id_and_lineage(
+            reviewer_id,
+            tenant_id,
+            org_id,
+            merchant_id,
+            profile_id,
+            UserRoleVersion::V2,
+        )
+        .await
+    {
+        Err(error) if error.current_context().is_db_not_found() => {
+            state
+                .global_store
+                .find_user_role_by_user_id_and_lineage(
+                    reviewer_id,
+                    tenant_id,
+                    org_id,
+                    merchant_id,
+                    profile_id,
+                    UserRoleVersion::V1,
+                )
+                .await
+        }
+        result => result,
+    }
+    .to_not_found_response(errors::ApiErrorResponse::InvalidRequestData {
+        message: "The reviewer is not a user of the merchant".to_string(),
+    })?;
+
+    if user_role.status != UserStatus::Active {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The reviewer is not an active user of the merchant".to_string(),
+        }));
+    }
+
+    let role_info = roles::RoleInfo::from_role_id_in_lineage(
+        state,
+        &user_role.role_id,
+        merchant_id,
+        org_id,
+        profile_id,
+        tenant_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the role of the reviewer")?;
+
+    if !role_info.check_permission_exists(Permission::ProfilePaymentWrite) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The reviewer is not allowed to approve or reject payments".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn assign_review(
+    state: SessionState,
+    platform: domain::Platform,
+    auth_profile_id: Option<id_type::ProfileId>,
+    req: review_types::FrmReviewAssignRequest,
+) -> RouterResponse<review_types::FrmReviewResponse> {
+    let db = &*state.store;
+    let processor = platform.get_processor();
+    let fraud_check = find_review(db, processor.get_account().get_id(), &req.payment_id).await?;
+
+    let payment_intent = find_payment_intent(db, processor, &req.payment_id).await?;
+    core_utils::validate_profile_id_from_auth_layer(auth_profile_id, &payment_intent)?;
+    ensure_review_pending(&fraud_check)?;
+
+    let profile_id = payment_intent
+        .profile_id
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Profile id not found in payment intent")?;
+
+    validate_reviewer(&state, &platform, &profile_id, &req.reviewer_id).await?;
+
+    let fraud_check = db
+        .update_fraud_check_response_with_attempt_id(
+            fraud_check,
+            FraudCheckUpdate::ReviewAssignmentUpdate {
+                assigned_to: req.reviewer_id,
+                assigned_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to assign the manual review")?;
+
+    Ok(services::ApplicationResponse::Json(get_review_response(
+        fraud_check,
+    )?))
+}
+
+#[instrument(skip_all)]
+pub async fn add_review_note(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<id_type::ProfileId>,
+    req: review_types::FrmReviewNoteRequest,
+) -> RouterResponse<review_types::FrmReviewResponse> {
+    if req.note.trim().is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "note must not be empty".to_string(),
+        }));
+    }
+
+    let db = &*state.store;
+    let fraud_check = find_review_in_profile(
+        db,
+        platform.get_processor(),
+        profile_id.as_ref(),
+        &req.payment_id,
+    )
+    .await?;
+    let review_notes = append_review_note(&fraud_check, get_reviewer_id(&platform), req.note);
+
+    let fraud_check = db
+        .update_fraud_check_response_with_attempt_id(
+            fraud_check,
+            FraudCheckUpdate::ReviewNotesUpdate { review_notes },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add the manual review note")?;
+
+    Ok(services::ApplicationResponse::Json(get_review_response(
+        fraud_check,
+    )?))
+}
+
+#[instrument(skip_all)]
+pub async fn approve_review(
+    state: SessionState,
+    req_state: ReqState,
+    platform: domain::Platform,
+    profile_id: Option<id_type::ProfileId>,
+    req: review_types::FrmReviewDecisionRequest,
+) -> RouterResponse<review_types::FrmReviewResponse> {
+    let reviewed_by = get_reviewer_id(&platform);
+    let fraud_check = decide_review(
+        &state,
+        req_state,
+        platform,
+        profile_id,
+        req.payment_id,
+        FrmReviewStatus::Approved,
+        reviewed_by,
+        req.note,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(get_review_response(
+        fraud_check,
+    )?))
+}
+
+#[instrument(skip_all)]
+pub async fn reject_review(
+    state: SessionState,
+    req_state: ReqState,
+    platform: domain::Platform,
+    profile_id: Option<id_type::ProfileId>,
+    req: review_types::FrmReviewDecisionRequest,
+) -> RouterResponse<review_types::FrmReviewResponse> {
+    let reviewed_by = get_reviewer_id(&platform);
+    let fraud_check = decide_review(
+        &state,
+        req_state,
+        platform,
+        profile_id,
+        req.payment_id,
+        FrmReviewStatus::Rejected,
+        reviewed_by,
+        req.note,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(get_review_response(
+        fraud_check,
+    )?))
+}
+
+/// Approves or rejects the payment through the payment operations and records who decided the
+/// review along with the note. The payment of a blocklist review is failed already, only the
+/// decision is recorded for it.
+#[allow(clippy::too_many_arguments)]
+async fn decide_review(
+    state: &SessionState,
+    req_state: ReqState,
+    platform: domain::Platform,
+    profile_id: Option<id_type::ProfileId>,
+    payment_id: id_type::PaymentId,
+    review_status: FrmReviewStatus,
+    reviewed_by: Option<String>,
+    note: Option<String>,
+) -> RouterResult<FraudCheck> {
+    let db = &*state.store;
+    let merchant_id = platform.get_processor().get_account().get_id().clone();
+    let fraud_check = find_review_in_profile(
+        db,
+        platform.get_processor(),
+        profile_id.as_ref(),
+        &payment_id,
+    )
+    .await?;
+    ensure_review_pending(&fraud_check)?;
+
+    match review_status {
+        FrmReviewStatus::Approved | FrmReviewStatus::Rejected
+            if is_blocklist_review(&fraud_check) => {}
+        FrmReviewStatus::Approved => {
+            payments::payments_core::<
+                api::Capture,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api::Capture>,
+            >(
+                state.clone(),
+                req_state,
+                platform,
+                profile_id,
+                payments::PaymentApprove,
+                payment_types::PaymentsCaptureRequest {
+                    payment_id: payment_id.clone(),
+                    ..Default::default()
+                },
+                services::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                None,
+                HeaderPayload::default(),
+            )
+            .await?;
+        }
+        FrmReviewStatus::Rejected => {
+            payments::payments_core::<
+                api::Void,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api::Void>,
+            >(
+                state.clone(),
+                req_state,
+                platform,
+                profile_id,
+                payments::PaymentReject,
+                payment_types::PaymentsCancelRequest {
+                    payment_id: payment_id.clone(),
+                    cancellation_reason: Some("Rejected by merchant".to_string()),
+                    ..Default::default()
+                },
+                services::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                None,
+                HeaderPayload::default(),
+            )
+            .await?;
+        }
+        FrmReviewStatus::Pending => {
+            return Err(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("A manual review cannot be decided as pending");
+        }
+    }
+
+    // The payment operation has closed the review already, the fraud check is fetched again to
+    // record the reviewer on top of it
+    let fraud_check = if is_blocklist_review(&fraud_check) {
+        fraud_check
+    } else {
+        find_review(db, &merchant_id, &payment_id).await?
+    };
+    let review_notes = note.map(|note| append_review_note(&fraud_check, reviewed_by.clone(), note));
+
+    db.update_fraud_check_response_with_attempt_id(
+        fraud_check,
+        FraudCheckUpdate::ReviewDecisionUpdate {
+            review_status,
+            reviewed_by,
+            reviewed_at: common_utils::date_time::now(),
+            review_notes,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to record the manual review decision")
+}
+
+/// Decides a review whose SLA has passed with the action configured for the merchant
+#[instrument(skip_all)]
+pub async fn perform_review_sla_action(
+    state: &SessionState,
+    platform: domain::Platform,
+    tracking_data: FrmReviewSlaTrackingData,
+) -> RouterResult<FrmReviewSlaOutcome> {
+    let fraud_check = find_review(
+        &*state.store,
+        &tracking_data.merchant_id,
+        &tracking_data.payment_id,
+    )
+    .await?;
+    if fraud_check.review_status != Some(FrmReviewStatus::Pending) {
+        return Ok(FrmReviewSlaOutcome::AlreadyDecided);
+    }
+
+    let (review_status, note) = match tracking_data.action {
+        FrmReviewSlaAction::Approve => (
+            FrmReviewStatus::Approved,
+            "Approved automatically as the review SLA passed",
+        ),
+        FrmReviewSlaAction::Reject => (
+            FrmReviewStatus::Rejected,
+            "Rejected automatically as the review SLA passed",
+        ),
+    };
+
+    decide_review(
+        state,
+        state.get_req_state(),
+        platform,
+        None,
+        tracking_data.payment_id,
+        review_status,
+        None,
+        Some(note.to_string()),
+    )
+    .await?;
+
+    Ok(FrmReviewSlaOutcome::Decided)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn fraud_check(frm_name: &str, review_status: Option<FrmReviewStatus>) -> FraudCheck {
+        let now = common_utils::date_time::now();
+        FraudCheck {
+            frm_id: "frm_1".to_string(),
+            payment_id: id_type::PaymentId::try_from(std::borrow::Cow::Borrowed("pay_1")).unwrap(),
+            merchant_id: id_type::MerchantId::try_from(std::borrow::Cow::Borrowed("merchant_1"))
+                .unwrap(),
+            attempt_id: "pay_1_1".to_string(),
+            created_at: now,
+            frm_name: frm_name.to_string(),
+            frm_transaction_id: None,
+            frm_transaction_type: FraudCheckType::PostFrm,
+            frm_status: FraudCheckStatus::ManualReview,
+            frm_score: None,
+            frm_reason: Some(serde_json::Value::String("card_velocity".to_string())),
+            frm_error: None,
+            payment_details: None,
+            metadata: None,
+            modified_at: now,
+            last_step: FraudCheckLastStep::CheckoutOrSale,
+            payment_capture_method: None,
+            review_status,
+            review_assigned_to: None,
+            review_assigned_at: None,
+            review_deadline: None,
+            review_notes: None,
+            reviewed_by: None,
+            reviewed_at: None,
+        }
+    }
+
+    #[test]
+    fn test_ensure_review_pending() {
+        assert!(
+            ensure_review_pending(&fraud_check("signifyd", Some(FrmReviewStatus::Pending))).is_ok()
+        );
+        assert!(
+            ensure_review_pending(&fraud_check("signifyd", Some(FrmReviewStatus::Approved)))
+                .is_err()
+        );
+        assert!(ensure_review_pending(&fraud_check("signifyd", None)).is_err());
+    }
+
+    #[test]
+    fn test_is_blocklist_review() {
+        assert!(is_blocklist_review(&fraud_check(
+            BLOCKLIST_REVIEW_FRM_NAME,
+            Some(FrmReviewStatus::Pending)
+        )));
+        assert!(!is_blocklist_review(&fraud_check(
+            "signifyd",
+            Some(FrmReviewStatus::Pending)
+        )));
+    }
+
+    #[test]
+    fn test_append_review_note_keeps_previous_notes() {
+        let mut fraud_check = fraud_check("signifyd", Some(FrmReviewStatus::Pending));
+        fraud_check.review_notes = Some(append_review_note(
+            &fraud_check,
+            Some("user_1".to_string()),
+            "Shipping address looks fine".to_string(),
+        ));
+
+        let review_notes = append_review_note(&fraud_check, None, "Customer called".to_string());
+
+        let notes = review_notes
+            .0
+            .iter()
+            .map(|review_note| (review_note.author.as_deref(), review_note.note.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            notes,
+            vec![
+                (Some("user_1"), "Shipping address looks fine"),
+                (None, "Customer called"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_review_response() {
+        let mut fraud_check = fraud_check("signifyd", Some(FrmReviewStatus::Pending));
+        fraud_check.review_notes = Some(append_review_note(
+            &fraud_check,
+            Some("user_1".to_string()),
+            "Looks legitimate".to_string(),
+        ));
+
+        let response = get_review_response(fraud_check).unwrap();
+        assert_eq!(response.review_status, FrmReviewStatus::Pending);
+        assert_eq!(response.frm_name, "signifyd");
+        assert_eq!(response.notes.len(), 1);
+        assert_eq!(
+            response.notes.first().map(|note| note.note.as_str()),
+            Some("Looks legitimate")
+        );
+    }
+
+    #[test]
+    fn test_get_review_response_without_review() {
+        assert!(get_review_response(fraud_check("signifyd", None)).is_err());
+    }
+}
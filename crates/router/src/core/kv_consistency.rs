@@ -0,0 +1,598 @@
+//! Reconciliation of the entities written to redis by the KV store with their rows in Postgres.
+//!
+//! The KV partitions of a merchant present in redis are scanned and every payment intent, payment
+//! attempt, refund, address and customer stored in them is compared field by field with its row in
+//! Postgres. Entities modified in redis more recently than the drain grace period are considered
+//! to be pending in the drainer stream rather than divergent.
+//!
+//! Checks are run on demand by the `kv_consistency` binary, or periodically by the
+//! `KvConsistencyCheckWorkflow` process tracker task.
+
+use std::collections::HashMap;
+
+use common_utils::{date_time, ext_traits::Encode, id_type};
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use redis_interface::RedisConnectionPool;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    db::{kv_consistency::KvEntity, StorageInterface},
+    routes::{metrics, SessionState},
+    types::storage,
+};
+
+const KV_CONSISTENCY_CHECK_TASK: &str = "KV_CONSISTENCY_CHECK";
+const KV_CONSISTENCY_CHECK_TAG: &str = "KV_CONSISTENCY";
+
+/// Number of keys requested from redis in every iteration of the scan
+const KV_PARTITION_SCAN_COUNT: u32 = 1000;
+
+/// Entities modified in redis within this duration may still be pending in the drainer stream
+pub const DEFAULT_DRAIN_GRACE_PERIOD_IN_SECONDS: i64 = 300;
+
+/// Field present in every KV entity, recording when it was last modified
+const MODIFIED_AT_FIELD: &str = "modified_at";
+
+/// Deletes the field of a KV partition only if it still holds the value which was compared, so
+/// that a value written to redis after the comparison is not evicted
+///
+/// KEYS[1]: KV partition key
+/// ARGV[1]: field of the entity
+/// ARGV[2]: value of the entity which was compared
+const COMPARE_AND_DELETE_HASH_FIELD_SCRIPT: &str = r#"
+if redis.call('HGET', KEYS[1], ARGV[1]) == ARGV[2] then
+    return redis.call('HDEL', KEYS[1], ARGV[1])
+end
+return 0
+"#;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct KvConsistencyCheckRequest {
+    pub merchant_id: id_type::MerchantId,
+    /// Maximum number of KV partitions to check, all the partitions are checked if not set
+    pub sample_size: Option<usize>,
+    /// Evict the entities from redis for which Postgres holds the later version, and overwrite
+    /// the rows in Postgres for which redis holds the later version
+    #[serde(default)]
+    pub repair: bool,
+    #[serde(default = "default_drain_grace_period_in_seconds")]
+    pub drain_grace_period_in_seconds: i64,
+}
+
+fn default_drain_grace_period_in_seconds() -> i64 {
+    DEFAULT_DRAIN_GRACE_PERIOD_IN_SECONDS
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct KvConsistencyReport {
+    pub merchant_id: id_type::MerchantId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub checked_at: PrimitiveDateTime,
+    pub partitions_checked: usize,
+    pub entities_checked: usize,
+    /// Entities which differ but were modified in redis within the drain grace period
+    pub entities_pending_drain: usize,
+    /// Entities whose value in redis could not be parsed
+    pub entities_skipped: usize,
+    pub divergences: Vec<KvDivergence>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct KvDivergence {
+    pub partition_key: String,
+    /// Field of the entity in the redis hash of the partition, eg: `pi_{payment_id}`
+    pub field: String,
+    pub kind: KvDivergenceKind,
+    /// Names of the fields whose values differ, the values are not reported as they may be
+    /// sensitive
+    pub mismatched_fields: Vec<String>,
+    pub repaired: bool,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum KvDivergenceKind {
+    /// The entity is in redis but was never written to Postgres
+    MissingInPostgres,
+    /// Postgres holds an earlier version of the entity than redis, an update was lost
+    PostgresBehind,
+    /// Redis holds an earlier version of the entity than Postgres, repaired by evicting the
+    /// entity from redis so that it is read from Postgres
+    RedisBehind,
+}
+
+enum EntityComparison {
+    Consistent,
+    PendingDrain,
+    Diverged {
+        kind: KvDivergenceKind,
+        mismatched_fields: Vec<String>,
+        /// Time at which the row in Postgres was last modified, if it exists
+        postgres_modified_at: Option<PrimitiveDateTime>,
+    },
+}
+
+#[instrument(skip_all, fields(merchant_id = ?request.merchant_id))]
+pub async fn check_kv_consistency(
+    state: &SessionState,
+    request: &KvConsistencyCheckRequest,
+) -> RouterResult<KvConsistencyReport> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let partition_keys = scan_kv_partitions(
+        &redis_conn,
+        &request.merchant_id,
+        &["pid", "cust"],
+        request.sample_size,
+    )
+    .await?;
+
+    let mut report = KvConsistencyReport {
+        merchant_id: request.merchant_id.clone(),
+        checked_at: date_time::now(),
+        partitions_checked: partition_keys.len(),
+        entities_checked: 0,
+        entities_pending_drain: 0,
+        entities_skipped: 0,
+        divergences: Vec::new(),
+    };
+
+    for partition_key in partition_keys {
+        let fields = redis_conn
+            .get_hash_fields::<HashMap<String, String>>(&partition_key.as_str().into())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to read the KV partition")?;
+
+        for (field, value) in fields {
+            let Some(entity) = KvEntity::from_field(&field) else {
+                continue;
+            };
+            report.entities_checked = report.entities_checked.saturating_add(1);
+
+            let comparison = match compare_entity(state, request, &entity, &value).await? {
+                Some(comparison) => comparison,
+                None => {
+                    logger::warn!(%partition_key, %field, "Failed to parse the KV entity");
+                    report.entities_skipped = report.entities_skipped.saturating_add(1);
+                    continue;
+                }
+            };
+
+            match comparison {
+                EntityComparison::Consistent => {}
+                EntityComparison::PendingDrain => {
+                    report.entities_pending_drain = report.entities_pending_drain.saturating_add(1);
+                }
+                EntityComparison::Diverged {
+                    kind,
+                    mismatched_fields,
+                    postgres_modified_at,
+                } => {
+                    let repaired = if request.repair {
+                        repair_divergence(
+                            state,
+                            &redis_conn,
+                            &partition_key,
+                            &field,
+                            &entity,
+                            &value,
+                            kind,
+                            postgres_modified_at,
+                        )
+                        .await?
+                    } else {
+                        false
+                    };
+
+                    metrics::KV_CONSISTENCY_DIVERGENCES.add(
+                        1,
+                        router_env::metric_attributes!(("kind", kind.to_string())),
+                    );
+                    logger::error!(
+                        alert = "kv_consistency_divergence",
+                        %partition_key,
+                        %field,
+                        %kind,
+                        ?mismatched_fields,
+                        repaired,
+                        "KV entity diverged from Postgres"
+                    );
+
+                    report.divergences.push(KvDivergence {
+                        partition_key: partition_key.clone(),
+                        field,
+                        kind,
+                        mismatched_fields,
+                        repaired,
+                    });
+                }
+            }
+        }
+    }
+
+    logger::info!(
+        partitions_checked = report.partitions_checked,
+        entities_checked = report.entities_checked,
+        entities_pending_drain = report.entities_pending_drain,
+        entities_skipped = report.entities_skipped,
+        divergences = report.divergences.len(),
+        "KV consistency check completed"
+    );
+
+    Ok(report)
+}
+
+/// Keys of the KV partitions of the merchant of the given kinds, eg: `pid` for the partitions of
+/// payments, without the key prefix of the tenant. The scan stops once `limit` keys are found.
+pub(crate) async fn scan_kv_partitions(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &id_type::MerchantId,
+    partition_kinds: &[&str],
+    limit: Option<usize>,
+) -> RouterResult<Vec<String>> {
+    // The keys returned by the scan include the key prefix of the tenant
+    let key_prefix = redis_conn.add_prefix("");
+    let mut partition_keys = Vec::new();
+    for partition_kind in partition_kinds {
+        let remaining = limit.map_or(usize::MAX, |limit| {
+            limit.saturating_sub(partition_keys.len())
+        });
+        if remaining == 0 {
+            break;
+        }
+
+        let pattern = format!("mid_{}_{partition_kind}_*", merchant_id.get_string_repr());
+        let keys = redis_conn
+            .scan_with_limit(
+                &pattern.as_str().into(),
+                Some(KV_PARTITION_SCAN_COUNT),
+                None,
+                remaining,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to scan the KV partitions of the merchant")?;
+
+        partition_keys.extend(keys.into_iter().map(|key| {
+            key.strip_prefix(key_prefix.as_str())
+                .map(ToOwned::to_owned)
+                .unwrap_or(key)
+        }));
+    }
+
+    Ok(partition_keys)
+}
+
+/// Repairs the divergence, returns whether it was repaired. Neither repair overwrites a value
+/// which was written after the comparison.
+#[allow(clippy::too_many_arguments)]
+async fn repair_divergence(
+    state: &SessionState,
+    redis_conn: &RedisConnectionPool,
+    partition_key: &str,
+    field: &str,
+    entity: &KvEntity,
+    redis_value: &str,
+    kind: KvDivergenceKind,
+    postgres_modified_at: Option<PrimitiveDateTime>,
+) -> RouterResult<bool> {
+    match (kind, postgres_modified_at) {
+        (KvDivergenceKind::RedisBehind, _) => {
+            let deleted_fields = redis_conn
+                .evaluate_redis_script::<_, i64>(
+                    COMPARE_AND_DELETE_HASH_FIELD_SCRIPT,
+                    vec![redis_conn.add_prefix(partition_key)],
+                    vec![field, redis_value],
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to evict the KV entity")?;
+
+            Ok(deleted_fields > 0)
+        }
+        (KvDivergenceKind::PostgresBehind, Some(postgres_modified_at)) => state
+            .store
+            .overwrite_kv_entity_in_postgres(entity, redis_value, postgres_modified_at)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to overwrite the KV entity in Postgres"),
+        // The entities missing in Postgres are only reported, their rows may have been deleted
+        (KvDivergenceKind::PostgresBehind, None) | (KvDivergenceKind::MissingInPostgres, _) => {
+            Ok(false)
+        }
+    }
+}
+
+/// Compares the value of the entity in redis with its row in Postgres, returns `None` if the
+/// value in redis could not be parsed
+async fn compare_entity(
+    state: &SessionState,
+    request: &KvConsistencyCheckRequest,
+    entity: &KvEntity,
+    redis_value: &str,
+) -> RouterResult<Option<EntityComparison>> {
+    let Ok(redis_value) = entity.parse_redis_value(redis_value) else {
+        return Ok(None);
+    };
+
+    let postgres_value = state
+        .store
+        .find_kv_entity_in_postgres(&request.merchant_id, entity)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the KV entity in Postgres")?;
+
+    let redis_modified_at = get_modified_at(&redis_value);
+    let is_pending_drain = redis_modified_at.is_some_and(|modified_at| {
+        date_time::now() - modified_at
+            < time::Duration::seconds(request.drain_grace_period_in_seconds)
+    });
+
+    let Some(postgres_value) = postgres_value else {
+        return Ok(Some(if is_pending_drain {
+            EntityComparison::PendingDrain
+        } else {
+            EntityComparison::Diverged {
+                kind: KvDivergenceKind::MissingInPostgres,
+                mismatched_fields: Vec::new(),
+                postgres_modified_at: None,
+            }
+        }));
+    };
+
+    let mismatched_fields = get_mismatched_fields(&redis_value, &postgres_value);
+    if mismatched_fields.is_empty() {
+        return Ok(Some(EntityComparison::Consistent));
+    }
+
+    let postgres_modified_at = get_modified_at(&postgres_value);
+    let kind = if postgres_modified_at > redis_modified_at {
+        KvDivergenceKind::RedisBehind
+    } else if is_pending_drain {
+        return Ok(Some(EntityComparison::PendingDrain));
+    } else {
+        KvDivergenceKind::PostgresBehind
+    };
+
+    Ok(Some(EntityComparison::Diverged {
+        kind,
+        mismatched_fields,
+        postgres_modified_at,
+    }))
+}
+
+/// Timestamps of the database models are serialized either in the ISO 8601 format or in the
+/// default format of the `time` crate
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum Timestamp {
+    Iso8601(#[serde(with = "common_utils::custom_serde::iso8601")] PrimitiveDateTime),
+    Default(PrimitiveDateTime),
+}
+
+fn parse_timestamp(value: &serde_json::Value) -> Option<PrimitiveDateTime> {
+    match serde_json::from_value(value.clone()).ok()? {
+        Timestamp::Iso8601(timestamp) | Timestamp::Default(timestamp) => Some(timestamp),
+    }
+}
+
+fn get_modified_at(value: &serde_json::Value) -> Option<PrimitiveDateTime> {
+    value.get(MODIFIED_AT_FIELD).and_then(parse_timestamp)
+}
+
+/// Names of the top level fields which differ between the two values. Postgres stores timestamps
+/// with microsecond precision, so they are compared up to microseconds.
+fn get_mismatched_fields(
+    redis_value: &serde_json::Value,
+    postgres_value: &serde_json::Value,
+) -> Vec<String> {
+    let (Some(redis_fields), Some(postgres_fields)) =
+        (redis_value.as_object(), postgres_value.as_object())
+    else {
+        return vec![String::from("*")];
+    };
+
+    let truncate_to_microseconds = |timestamp: PrimitiveDateTime| {
+        timestamp
+            .replace_microsecond(timestamp.microsecond())
+            .unwrap_or(timestamp)
+    };
+
+    let mut mismatched_fields = redis_fields
+        .keys()
+        .chain(postgres_fields.keys())
+        .filter(|field| {
+            let redis_field = redis_fields.get(field.as_str());
+            let postgres_field = postgres_fields.get(field.as_str());
+
+            redis_field != postgres_field
+                && !redis_field
+                    .and_then(parse_timestamp)
+                    .zip(postgres_field.and_then(parse_timestamp))
+                    .is_some_and(|(redis_timestamp, postgres_timestamp)| {
+                        truncate_to_microseconds(redis_timestamp)
+                            == truncate_to_microseconds(postgres_timestamp)
+                    })
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    mismatched_fields.sort();
+    mismatched_fields.dedup();
+
+    mismatched_fields
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct KvConsistencyTrackingData {
+    #[serde(flatten)]
+    pub request: KvConsistencyCheckRequest,
+    /// The next check is scheduled after this interval, the check runs once if not set
+    pub interval_in_seconds: Option<i64>,
+    /// Report of the check performed by the task, recorded when the task is finished
+    pub report: Option<KvConsistencyReport>,
+}
+
+/// Schedules a consistency check for the merchant with the process tracker
+#[instrument(skip_all)]
+pub async fn add_kv_consistency_check_task(
+    db: &dyn StorageInterface,
+    tracking_data: &KvConsistencyTrackingData,
+    schedule_time: PrimitiveDateTime,
+    application_source: common_enums::ApplicationSource,
+) -> RouterResult<storage::ProcessTracker> {
+    let runner = storage::ProcessTrackerRunner::KvConsistencyCheckWorkflow;
+    // Recurring checks insert a task per run, the schedule time keeps the task IDs unique
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        KV_CONSISTENCY_CHECK_TASK,
+        &schedule_time.assume_utc().unix_timestamp().to_string(),
+        &tracking_data.request.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        KV_CONSISTENCY_CHECK_TASK,
+        runner,
+        [KV_CONSISTENCY_CHECK_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+        application_source,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct KV consistency check process tracker task")?;
+
+    let process = db
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert KV consistency check task to process_tracker")?;
+    metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "KvConsistencyCheck")),
+    );
+
+    Ok(process)
+}
+
+/// Records the report of the check in the task, and schedules the next check if it recurs
+#[instrument(skip_all)]
+pub async fn complete_kv_consistency_check_task(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    tracking_data: KvConsistencyTrackingData,
+    report: KvConsistencyReport,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let status = if report.divergences.is_empty() {
+        business_status::KV_CONSISTENT
+    } else {
+        business_status::KV_DIVERGED
+    };
+
+    if let Some(interval_in_seconds) = tracking_data.interval_in_seconds {
+        let next_tracking_data = KvConsistencyTrackingData {
+            request: tracking_data.request.clone(),
+            interval_in_seconds: Some(interval_in_seconds),
+            report: None,
+        };
+        add_kv_consistency_check_task(
+            db,
+            &next_tracking_data,
+            date_time::now().saturating_add(time::Duration::seconds(interval_in_seconds)),
+            state.conf.application_source,
+        )
+        .await?;
+    }
+
+    let tracking_data = KvConsistencyTrackingData {
+        report: Some(report),
+        ..tracking_data
+    }
+    .encode_to_value()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encode KV consistency tracking data")?;
+
+    db.update_process(
+        process,
+        storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: None,
+            tracking_data: Some(tracking_data),
+            business_status: Some(String::from(status)),
+            status: Some(storage::enums::ProcessTrackerStatus::Finish),
+            updated_at: Some(date_time::now()),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to record the KV consistency report")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_mismatched_fields() {
+        let redis_value = serde_json::json!({
+            "status": "succeeded",
+            "amount": 100,
+            "description": null,
+            "connector": "stripe",
+        });
+        let postgres_value = serde_json::json!({
+            "status": "processing",
+            "amount": 100,
+            "connector": "stripe",
+            "description": "payment",
+            "return_url": "https://example.com",
+        });
+
+        assert_eq!(
+            get_mismatched_fields(&redis_value, &postgres_value),
+            vec!["description", "return_url", "status"]
+        );
+        assert!(get_mismatched_fields(&redis_value, &redis_value).is_empty());
+    }
+
+    #[test]
+    fn test_get_mismatched_fields_compares_timestamps_up_to_microseconds() {
+        // Redis holds the timestamp with nanosecond precision, Postgres truncates it
+        let redis_value = serde_json::json!({
+            "modified_at": "2024-01-01T10:00:00.123456789Z",
+            "created_at": "2024-01-01T10:00:00.123456Z",
+        });
+        let postgres_value = serde_json::json!({
+            "modified_at": "2024-01-01T10:00:00.123456Z",
+            "created_at": "2024-01-01T10:00:00.123457Z",
+        });
+
+        assert_eq!(
+            get_mismatched_fields(&redis_value, &postgres_value),
+            vec!["created_at"]
+        );
+    }
+
+    #[test]
+    fn test_get_mismatched_fields_of_values_which_are_not_objects() {
+        assert_eq!(
+            get_mismatched_fields(&serde_json::json!("value"), &serde_json::json!({})),
+            vec!["*"]
+        );
+    }
+}
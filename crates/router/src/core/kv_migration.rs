@@ -0,0 +1,867 @@
+//! Managed migration of a merchant between the KV and the Postgres only storage schemes.
+//!
+//! Flipping the storage scheme while entries of the merchant are still in the drainer streams
+//! would let requests read outdated rows from Postgres, and let the drainer later overwrite the
+//! rows they update. The migration is performed by the `KvMigrationWorkflow` process tracker
+//! task instead, which polls the drainer streams until they have caught up with the merchant
+//! before switching the storage scheme:
+//!
+//! - Disabling KV waits for the entries pushed before the migration started to be drained,
+//!   verifies Postgres against the KV partitions of the merchant, switches the storage scheme and
+//!   then waits for the entries pushed by the requests which were still using KV.
+//! - Enabling KV waits for the entries left behind by an earlier period of KV to be drained, and
+//!   evicts the KV partitions of the merchant before switching the storage scheme, as they would
+//!   otherwise be read in place of the newer rows in Postgres.
+
+use std::collections::HashMap;
+
+use api_models::admin::{KvMigrationPhase, KvMigrationRequest, KvMigrationResponse};
+use common_utils::{date_time, ext_traits::Encode, id_type};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use redis_interface::{errors::RedisError, RedisConnectionPool};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        kv_consistency::{self, KvConsistencyCheckRequest, KvDivergenceKind},
+    },
+    db::AccountsStorageInterface,
+    routes::{metrics, SessionState},
+    services,
+    types::{
+        storage::{self, enums::MerchantStorageScheme},
+        transformers::ForeignFrom,
+    },
+};
+
+const KV_MIGRATION_TASK: &str = "KV_MIGRATION";
+const KV_MIGRATION_TAG: &str = "KV_MIGRATION";
+
+const DEFAULT_KV_MIGRATION_TIMEOUT_IN_SECONDS: u32 = 60 * 60;
+
+/// Interval at which the drainer streams are checked while the migration waits for them
+const KV_MIGRATION_POLL_INTERVAL_IN_SECONDS: i64 = 10;
+
+/// Number of entries read from a drainer stream at once while counting the pending entries
+const STREAM_READ_COUNT: u64 = 1000;
+
+/// Kinds of the KV partitions evicted before enabling KV, eg: `mid_{merchant_id}_pid_{payment_id}`
+const KV_PARTITION_KINDS: [&str; 5] = ["pid", "cust", "po", "poa", "mandate"];
+
+/// Fields of the drainer stream entries which identify the merchant of the entry
+const MERCHANT_ID_FIELD: &str = "merchant_id";
+const GLOBAL_ID_FIELD: &str = "global_id";
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct KvMigrationTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub target_storage_scheme: MerchantStorageScheme,
+    pub phase: KvMigrationPhase,
+    pub timeout_in_seconds: u32,
+    pub verification_sample_size: Option<usize>,
+    pub pending_entries: usize,
+    pub dead_lettered_entries: usize,
+    pub divergences: usize,
+    pub partitions_evicted: Option<usize>,
+    pub failure_reason: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub started_at: PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub switched_at: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_checked_at: Option<PrimitiveDateTime>,
+    /// Progress through each of the drainer streams, keyed by the name of the stream
+    #[serde(default)]
+    pub stream_progress: HashMap<String, DrainerStreamProgress>,
+}
+
+/// Entries of the merchant read from a drainer stream, which have not been drained yet
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct DrainerStreamProgress {
+    /// ID of the last entry read from the stream, later checks only read the entries after it
+    pub last_read_entry_id: Option<String>,
+    pub pending_entry_ids: Vec<String>,
+}
+
+impl ForeignFrom<(String, KvMigrationTrackingData)> for KvMigrationResponse {
+    fn foreign_from(
+        (process_tracker_id, tracking_data): (String, KvMigrationTrackingData),
+    ) -> Self {
+        Self {
+            merchant_id: tracking_data.merchant_id,
+            process_tracker_id,
+            kv_enabled: tracking_data.target_storage_scheme == MerchantStorageScheme::RedisKv,
+            phase: tracking_data.phase,
+            pending_entries: tracking_data.pending_entries,
+            dead_lettered_entries: tracking_data.dead_lettered_entries,
+            divergences: tracking_data.divergences,
+            partitions_evicted: tracking_data.partitions_evicted,
+            failure_reason: tracking_data.failure_reason,
+            started_at: tracking_data.started_at,
+            switched_at: tracking_data.switched_at,
+            last_checked_at: tracking_data.last_checked_at,
+        }
+    }
+}
+
+/// Entries of the merchant in the drainer streams of the tenant
+#[derive(Debug, Default)]
+struct PendingStreamEntries {
+    pending: usize,
+    /// Entries pushed to the stream before the cutoff
+    pending_before_cutoff: usize,
+    dead_lettered: usize,
+}
+
+#[instrument(skip_all)]
+pub async fn start_kv_migration(
+    state: SessionState,
+    request: KvMigrationRequest,
+) -> RouterResponse<KvMigrationResponse> {
+    let timeout_in_seconds = request
+        .timeout_in_seconds
+        .unwrap_or(DEFAULT_KV_MIGRATION_TIMEOUT_IN_SECONDS);
+    if timeout_in_seconds == 0 {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "timeout_in_seconds should be greater than 0".to_string(),
+        }
+        .into());
+    }
+
+    let target_storage_scheme = if request.kv_enabled {
+        if state.conf.as_ref().is_kv_soft_kill_mode() {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Kv cannot be enabled when application is in soft_kill_mode".to_owned(),
+            }
+            .into());
+        }
+        MerchantStorageScheme::RedisKv
+    } else {
+        MerchantStorageScheme::PostgresOnly
+    };
+
+    let merchant_account = find_merchant_account(&state, &request.merchant_id).await?.1;
+    if merchant_account.storage_scheme == target_storage_scheme {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Storage scheme of the merchant is already {target_storage_scheme}"),
+        }
+        .into());
+    }
+
+    let process_tracker_id = get_kv_migration_process_tracker_id(&request.merchant_id);
+    let existing_process = state
+        .store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch KV migration process tracker task")?;
+    if existing_process
+        .as_ref()
+        .is_some_and(|process| process.status != storage::enums::ProcessTrackerStatus::Finish)
+    {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A KV migration is already in progress for the merchant".to_string(),
+        }
+        .into());
+    }
+
+    let now = date_time::now();
+    let tracking_data = KvMigrationTrackingData {
+        merchant_id: request.merchant_id,
+        target_storage_scheme,
+        phase: KvMigrationPhase::DrainingBeforeSwitch,
+        timeout_in_seconds,
+        verification_sample_size: request.verification_sample_size,
+        pending_entries: 0,
+        dead_lettered_entries: 0,
+        divergences: 0,
+        partitions_evicted: None,
+        failure_reason: None,
+        started_at: now,
+        switched_at: None,
+        last_checked_at: None,
+        stream_progress: HashMap::new(),
+    };
+
+    match existing_process {
+        // A finished migration of the merchant is restarted with the new tracking data
+        Some(process) => {
+            let tracking_data_value = encode_tracking_data(&tracking_data)?;
+            state
+                .store
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(0),
+                        schedule_time: Some(now),
+                        tracking_data: Some(tracking_data_value),
+                        business_status: Some(String::from(business_status::PENDING)),
+                        status: Some(storage::enums::ProcessTrackerStatus::New),
+                        updated_at: Some(now),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to restart KV migration process tracker task")?;
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id.clone(),
+                KV_MIGRATION_TASK,
+                storage::ProcessTrackerRunner::KvMigrationWorkflow,
+                [KV_MIGRATION_TAG],
+                &tracking_data,
+                None,
+                now,
+                common_types::consts::API_VERSION,
+                state.conf.application_source,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct KV migration process tracker task")?;
+
+            state
+                .store
+                .insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert KV migration task to process_tracker")?;
+        }
+    }
+    metrics::TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "KvMigration")));
+
+    logger::info!(
+        merchant_id = ?tracking_data.merchant_id,
+        %target_storage_scheme,
+        "KV migration started"
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        KvMigrationResponse::foreign_from((process_tracker_id, tracking_data)),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_kv_migration(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<KvMigrationResponse> {
+    let process_tracker_id = get_kv_migration_process_tracker_id(&merchant_id);
+    let process = state
+        .store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch KV migration process tracker task")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No KV migration found for the merchant".to_string(),
+        })?;
+
+    let tracking_data = parse_tracking_data(process.tracking_data)?;
+
+    Ok(services::ApplicationResponse::Json(
+        KvMigrationResponse::foreign_from((process_tracker_id, tracking_data)),
+    ))
+}
+
+/// The storage scheme must not be toggled directly while a migration of the merchant is
+/// switching it
+pub async fn validate_no_kv_migration_in_progress(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<()> {
+    let process = state
+        .store
+        .find_process_by_id(&get_kv_migration_process_tracker_id(merchant_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch KV migration process tracker task")?;
+
+    match process {
+        Some(process) if process.status != storage::enums::ProcessTrackerStatus::Finish => {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "A KV migration is in progress for the merchant".to_string(),
+            }
+            .into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Advances the migration as far as the drainer streams allow, returns the updated tracking data
+#[instrument(skip_all, fields(merchant_id = ?tracking_data.merchant_id))]
+pub async fn perform_kv_migration_step(
+    state: &SessionState,
+    mut tracking_data: KvMigrationTrackingData,
+) -> RouterResult<KvMigrationTrackingData> {
+    let now = date_time::now();
+    tracking_data.last_checked_at = Some(now);
+
+    match tracking_data.phase {
+        KvMigrationPhase::DrainingBeforeSwitch => {
+            let entries = get_pending_stream_entries(
+                state,
+                &tracking_data.merchant_id,
+                &mut tracking_data.stream_progress,
+                tracking_data.started_at,
+            )
+            .await?;
+            tracking_data.pending_entries = entries.pending_before_cutoff;
+            tracking_data.dead_lettered_entries = entries.dead_lettered;
+            if entries.pending_before_cutoff > 0 || entries.dead_lettered > 0 {
+                return Ok(fail_if_timed_out(tracking_data, now));
+            }
+
+            match tracking_data.target_storage_scheme {
+                MerchantStorageScheme::PostgresOnly => {
+                    let report = kv_consistency::check_kv_consistency(
+                        state,
+                        &KvConsistencyCheckRequest {
+                            merchant_id: tracking_data.merchant_id.clone(),
+                            sample_size: tracking_data.verification_sample_size,
+                            repair: false,
+                            drain_grace_period_in_seconds:
+                                kv_consistency::DEFAULT_DRAIN_GRACE_PERIOD_IN_SECONDS,
+                        },
+                    )
+                    .await?;
+
+                    // Entities for which Postgres holds the later version are not read anymore
+                    tracking_data.divergences = report
+                        .divergences
+                        .iter()
+                        .filter(|divergence| divergence.kind != KvDivergenceKind::RedisBehind)
+                        .count();
+                    if tracking_data.divergences > 0 {
+                        tracking_data.phase = KvMigrationPhase::Failed;
+                        tracking_data.failure_reason = Some(format!(
+                            "{} KV entities are missing or outdated in Postgres, the storage \
+                             scheme was not switched",
+                            tracking_data.divergences
+                        ));
+                        return Ok(tracking_data);
+                    }
+                }
+                MerchantStorageScheme::RedisKv => {
+                    tracking_data.partitions_evicted =
+                        Some(evict_kv_partitions(state, &tracking_data.merchant_id).await?);
+                }
+            }
+
+            switch_storage_scheme(
+                state,
+                &tracking_data.merchant_id,
+                tracking_data.target_storage_scheme,
+            )
+            .await?;
+            tracking_data.switched_at = Some(now);
+            tracking_data.pending_entries = 0;
+            tracking_data.phase = match tracking_data.target_storage_scheme {
+                MerchantStorageScheme::PostgresOnly => KvMigrationPhase::DrainingAfterSwitch,
+                MerchantStorageScheme::RedisKv => KvMigrationPhase::Completed,
+            };
+        }
+        KvMigrationPhase::DrainingAfterSwitch => {
+            let entries = get_pending_stream_entries(
+                state,
+                &tracking_data.merchant_id,
+                &mut tracking_data.stream_progress,
+                now,
+            )
+            .await?;
+            tracking_data.pending_entries = entries.pending;
+            tracking_data.dead_lettered_entries = entries.dead_lettered;
+            if entries.pending > 0 || entries.dead_lettered > 0 {
+                return Ok(fail_if_timed_out(tracking_data, now));
+            }
+
+            tracking_data.phase = KvMigrationPhase::Completed;
+        }
+        KvMigrationPhase::Completed | KvMigrationPhase::Failed => {}
+    }
+
+    Ok(tracking_data)
+}
+
+/// Finishes the task once the migration has completed or failed, and schedules the next check of
+/// the drainer streams otherwise
+#[instrument(skip_all)]
+pub async fn update_kv_migration_task(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    tracking_data: KvMigrationTrackingData,
+) -> RouterResult<()> {
+    let now = date_time::now();
+    let (status, process_business_status, schedule_time) = match tracking_data.phase {
+        KvMigrationPhase::DrainingBeforeSwitch => (
+            storage::enums::ProcessTrackerStatus::Pending,
+            business_status::KV_MIGRATION_DRAINING,
+            Some(get_next_poll_time(now)),
+        ),
+        KvMigrationPhase::DrainingAfterSwitch => (
+            storage::enums::ProcessTrackerStatus::Pending,
+            business_status::KV_MIGRATION_SWITCHED,
+            Some(get_next_poll_time(now)),
+        ),
+        KvMigrationPhase::Completed => (
+            storage::enums::ProcessTrackerStatus::Finish,
+            business_status::KV_MIGRATION_COMPLETED,
+            None,
+        ),
+        KvMigrationPhase::Failed => {
+            logger::error!(
+                alert = "kv_migration_failed",
+                merchant_id = ?tracking_data.merchant_id,
+                failure_reason = ?tracking_data.failure_reason,
+                "KV migration failed"
+            );
+            (
+                storage::enums::ProcessTrackerStatus::Finish,
+                business_status::KV_MIGRATION_FAILED,
+                None,
+            )
+        }
+    };
+
+    state
+        .store
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time,
+                tracking_data: Some(encode_tracking_data(&tracking_data)?),
+                business_status: Some(String::from(process_business_status)),
+                status: Some(status),
+                updated_at: Some(now),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update KV migration process tracker task")?;
+
+    Ok(())
+}
+
+pub fn get_next_poll_time(now: PrimitiveDateTime) -> PrimitiveDateTime {
+    now.saturating_add(time::Duration::seconds(
+        KV_MIGRATION_POLL_INTERVAL_IN_SECONDS,
+    ))
+}
+
+pub fn parse_tracking_data(value: serde_json::Value) -> RouterResult<KvMigrationTrackingData> {
+    serde_json::from_value(value)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse KV migration tracking data")
+}
+
+fn encode_tracking_data(
+    tracking_data: &KvMigrationTrackingData,
+) -> RouterResult<serde_json::Value> {
+    tracking_data
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode KV migration tracking data")
+}
+
+/// A single task is used per merchant, so that concurrent migrations of a merchant are rejected
+fn get_kv_migration_process_tracker_id(merchant_id: &id_type::MerchantId) -> String {
+    scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::KvMigrationWorkflow,
+        KV_MIGRATION_TASK,
+        "",
+        merchant_id,
+    )
+}
+
+fn fail_if_timed_out(
+    mut tracking_data: KvMigrationTrackingData,
+    now: PrimitiveDateTime,
+) -> KvMigrationTrackingData {
+    let deadline = tracking_data
+        .started_at
+        .saturating_add(time::Duration::seconds(i64::from(
+            tracking_data.timeout_in_seconds,
+        )));
+    if now < deadline {
+        return tracking_data;
+    }
+
+    tracking_data.phase = KvMigrationPhase::Failed;
+    tracking_data.failure_reason = Some(format!(
+        "Timed out waiting for {} pending and {} dead-lettered drainer entries of the merchant, {}",
+        tracking_data.pending_entries,
+        tracking_data.dead_lettered_entries,
+        if tracking_data.switched_at.is_some() {
+            "the storage scheme was already switched"
+        } else {
+            "the storage scheme was not switched"
+        }
+    ));
+    tracking_data
+}
+
+async fn find_merchant_account(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<(
+    crate::types::domain::MerchantKeyStore,
+    crate::types::domain::MerchantAccount,
+)> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    Ok((key_store, merchant_account))
+}
+
+async fn switch_storage_scheme(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    storage_scheme: MerchantStorageScheme,
+) -> RouterResult<()> {
+    if storage_scheme == MerchantStorageScheme::RedisKv
+        && state.conf.as_ref().is_kv_soft_kill_mode()
+    {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Kv cannot be enabled when application is in soft_kill_mode");
+    }
+
+    let (key_store, merchant_account) = find_merchant_account(state, merchant_id).await?;
+    state
+        .store
+        .update_merchant(
+            merchant_account,
+            storage::MerchantAccountUpdate::StorageSchemeUpdate { storage_scheme },
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to switch merchant_storage_scheme")?;
+
+    logger::info!(merchant_id = ?merchant_id, %storage_scheme, "Switched merchant storage scheme");
+
+    Ok(())
+}
+
+/// Entries of the merchant in the drainer streams and their dead-letter streams.
+///
+/// Only the entries pushed since the previous check are read from the drainer streams, the
+/// entries of the merchant read earlier are tracked in `stream_progress` until the drainer trims
+/// them. The dead-letter streams are read in full, as they only hold the entries which failed.
+async fn get_pending_stream_entries(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    stream_progress: &mut HashMap<String, DrainerStreamProgress>,
+    cutoff: PrimitiveDateTime,
+) -> RouterResult<PendingStreamEntries> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let merchant_id = merchant_id.get_string_repr();
+    // Stream entry IDs are prefixed with the time at which they were pushed in milliseconds
+    let cutoff_in_millis = cutoff.assume_utc().unix_timestamp_nanos() / 1_000_000;
+
+    let mut entries = PendingStreamEntries::default();
+    for shard in 0..state.conf.drainer.num_partitions {
+        let stream_name = format!("{{shard_{shard}}}_{}", state.conf.drainer.stream_name);
+        let progress = stream_progress.entry(stream_name.clone()).or_default();
+
+        // The drainer trims the stream up to the last entry it has processed
+        let first_entry_id = read_stream_entries(&redis_conn, &stream_name, "0-0", Some(1))
+            .await?
+            .into_iter()
+            .next()
+            .map(|(entry_id, _)| entry_id);
+        progress.remove_drained_entries(first_entry_id.as_deref());
+
+        let last_read_entry_id = progress
+            .last_read_entry_id
+            .clone()
+            .unwrap_or_else(|| String::from("0-0"));
+        for (entry_id, fields) in
+            read_stream_entries(&redis_conn, &stream_name, &last_read_entry_id, None).await?
+        {
+            if is_merchant_entry(&fields, merchant_id) {
+                progress.pending_entry_ids.push(entry_id.clone());
+            }
+            progress.last_read_entry_id = Some(entry_id);
+        }
+
+        entries.pending = entries
+            .pending
+            .saturating_add(progress.pending_entry_ids.len());
+        entries.pending_before_cutoff = entries
+            .pending_before_cutoff
+            .saturating_add(progress.count_entries_before(cutoff_in_millis));
+
+        let dead_letter_stream_name = format!("{stream_name}_dead_letter");
+        entries.dead_lettered = entries.dead_lettered.saturating_add(
+            read_stream_entries(&redis_conn, &dead_letter_stream_name, "0-0", None)
+                .await?
+                .iter()
+                .filter(|(_, fields)| is_merchant_entry(fields, merchant_id))
+                .count(),
+        );
+    }
+
+    Ok(entries)
+}
+
+/// Whether the stream entry belongs to the merchant. Entries pushed before the merchant ID was
+/// recorded in them are matched by the partition key in their `global_id`, whose merchant ID has
+/// to be followed by the kind of a partition, eg: `mid_{merchant_id}_pid_{payment_id}`.
+fn is_merchant_entry(fields: &HashMap<String, String>, merchant_id: &str) -> bool {
+    if let Some(entry_merchant_id) = fields.get(MERCHANT_ID_FIELD) {
+        return entry_merchant_id == merchant_id;
+    }
+
+    fields
+        .get(GLOBAL_ID_FIELD)
+        .and_then(|global_id| global_id.strip_prefix("mid_"))
+        .and_then(|partition_key| partition_key.strip_prefix(merchant_id))
+        .and_then(|partition_key| partition_key.strip_prefix('_'))
+        .and_then(|partition_key| partition_key.split_once('_'))
+        .is_some_and(|(kind, _)| KV_PARTITION_KINDS.contains(&kind))
+}
+
+/// Parses a stream entry ID of the form `{millis}-{sequence}`
+fn parse_entry_id(entry_id: &str) -> Option<(u64, u64)> {
+    let (millis, sequence) = entry_id.split_once('-')?;
+    Some((millis.parse().ok()?, sequence.parse().ok()?))
+}
+
+impl DrainerStreamProgress {
+    /// Removes the entries which precede the first entry left in the stream, or all of them once
+    /// the stream is empty
+    fn remove_drained_entries(&mut self, first_entry_id: Option<&str>) {
+        let first_entry_id = first_entry_id.and_then(parse_entry_id);
+        self.pending_entry_ids.retain(|entry_id| {
+            first_entry_id.is_some_and(|first_entry_id| {
+                parse_entry_id(entry_id).is_none_or(|entry_id| entry_id >= first_entry_id)
+            })
+        });
+    }
+
+    /// Number of pending entries pushed to the stream before the cutoff
+    fn count_entries_before(&self, cutoff_in_millis: i128) -> usize {
+        self.pending_entry_ids
+            .iter()
+            .filter(|entry_id| {
+                parse_entry_id(entry_id)
+                    .is_none_or(|(millis, _)| i128::from(millis) < cutoff_in_millis)
+            })
+            .count()
+    }
+}
+
+/// Entries of the stream after the given entry ID, up to `count` entries or all of them
+async fn read_stream_entries(
+    redis_conn: &RedisConnectionPool,
+    stream_name: &str,
+    after_entry_id: &str,
+    count: Option<u64>,
+) -> RouterResult<Vec<(String, HashMap<String, String>)>> {
+    let mut stream_entries = Vec::new();
+    let mut last_read_id = after_entry_id.to_owned();
+
+    loop {
+        let read_count = count.map_or(STREAM_READ_COUNT, |count| {
+            count
+                .saturating_sub(u64::try_from(stream_entries.len()).unwrap_or(u64::MAX))
+                .min(STREAM_READ_COUNT)
+        });
+        if read_count == 0 {
+            break;
+        }
+
+        let entries = match redis_conn
+            .stream_read_entries(stream_name, last_read_id.as_str(), Some(read_count))
+            .await
+        {
+            Ok(read_result) => read_result.into_values().flatten().collect::<Vec<_>>(),
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    RedisError::StreamEmptyOrNotAvailable
+                ) =>
+            {
+                Vec::new()
+            }
+            Err(error) => {
+                return Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to read the drainer stream")
+            }
+        };
+        let Some((last_id, _)) = entries.last() else {
+            break;
+        };
+        last_read_id = last_id.clone();
+        stream_entries.extend(entries);
+    }
+
+    Ok(stream_entries)
+}
+
+/// Deletes the KV partitions of the merchant, returns the number of partitions deleted
+async fn evict_kv_partitions(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<usize> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let partition_keys =
+        kv_consistency::scan_kv_partitions(&redis_conn, merchant_id, &KV_PARTITION_KINDS, None)
+            .await?;
+    for partition_key in &partition_keys {
+        redis_conn
+            .delete_key(&partition_key.as_str().into())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to evict the KV partition")?;
+    }
+
+    logger::info!(
+        merchant_id = ?merchant_id,
+        partitions_evicted = partition_keys.len(),
+        "Evicted KV partitions left behind by an earlier period of KV"
+    );
+
+    Ok(partition_keys.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_entry_fields(fields: &[(&str, &str)]) -> HashMap<String, String> {
+        fields
+            .iter()
+            .map(|(field, value)| ((*field).to_owned(), (*value).to_owned()))
+            .collect()
+    }
+
+    fn get_progress(pending_entry_ids: &[&str]) -> DrainerStreamProgress {
+        DrainerStreamProgress {
+            last_read_entry_id: pending_entry_ids
+                .last()
+                .map(|entry_id| (*entry_id).to_owned()),
+            pending_entry_ids: pending_entry_ids
+                .iter()
+                .map(|entry_id| (*entry_id).to_owned())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_is_merchant_entry_by_merchant_id_field() {
+        let fields = get_entry_fields(&[
+            (MERCHANT_ID_FIELD, "merchant_1"),
+            (GLOBAL_ID_FIELD, "mid_merchant_1_pid_pay_1"),
+        ]);
+
+        assert!(is_merchant_entry(&fields, "merchant_1"));
+        assert!(!is_merchant_entry(&fields, "merchant"));
+    }
+
+    #[test]
+    fn test_is_merchant_entry_by_global_id() {
+        for global_id in [
+            "mid_merchant_pid_pay_1",
+            "mid_merchant_cust_cus_1",
+            "mid_merchant_po_payout_1",
+            "mid_merchant_poa_merchant_payout_1_1",
+            "mid_merchant_mandate_man_1",
+        ] {
+            let fields = get_entry_fields(&[(GLOBAL_ID_FIELD, global_id)]);
+            assert!(is_merchant_entry(&fields, "merchant"), "{global_id}");
+        }
+    }
+
+    #[test]
+    fn test_is_merchant_entry_of_merchant_whose_id_extends_the_merchant_id() {
+        let fields = get_entry_fields(&[(GLOBAL_ID_FIELD, "mid_merchant_eu_pid_pay_1")]);
+        assert!(!is_merchant_entry(&fields, "merchant"));
+        assert!(is_merchant_entry(&fields, "merchant_eu"));
+
+        let fields = get_entry_fields(&[(GLOBAL_ID_FIELD, "mid_merchant-eu_pid_pay_1")]);
+        assert!(!is_merchant_entry(&fields, "merchant"));
+
+        let fields = get_entry_fields(&[(GLOBAL_ID_FIELD, "global_payment_pay_1")]);
+        assert!(!is_merchant_entry(&fields, "merchant"));
+    }
+
+    #[test]
+    fn test_parse_entry_id() {
+        assert_eq!(
+            parse_entry_id("1700000000000-1"),
+            Some((1_700_000_000_000, 1))
+        );
+        assert_eq!(parse_entry_id("1700000000000"), None);
+        assert_eq!(parse_entry_id("entry-1"), None);
+    }
+
+    #[test]
+    fn test_remove_drained_entries() {
+        let mut progress = get_progress(&["1000-0", "1000-1", "2000-0", "10000-0"]);
+
+        // Entry IDs are compared numerically, `10000-0` follows `2000-0`
+        progress.remove_drained_entries(Some("1000-1"));
+        assert_eq!(progress.pending_entry_ids, ["1000-1", "2000-0", "10000-0"]);
+
+        progress.remove_drained_entries(Some("3000-0"));
+        assert_eq!(progress.pending_entry_ids, ["10000-0"]);
+
+        progress.remove_drained_entries(None);
+        assert!(progress.pending_entry_ids.is_empty());
+        assert_eq!(progress.last_read_entry_id.as_deref(), Some("10000-0"));
+    }
+
+    #[test]
+    fn test_count_entries_before() {
+        let progress = get_progress(&["1000-0", "1999-5", "2000-0", "3000-0"]);
+
+        assert_eq!(progress.count_entries_before(2000), 2);
+        assert_eq!(progress.count_entries_before(1000), 0);
+        assert_eq!(progress.count_entries_before(5000), 4);
+    }
+
+    #[test]
+    fn test_tracking_data_without_stream_progress() {
+        let tracking_data = parse_tracking_data(serde_json::json!({
+            "merchant_id": "merchant_1",
+            "target_storage_scheme": "postgres_only",
+            "phase": "draining_before_switch",
+            "timeout_in_seconds": 3600,
+            "verification_sample_size": null,
+            "pending_entries": 1,
+            "dead_lettered_entries": 0,
+            "divergences": 0,
+            "partitions_evicted": null,
+            "failure_reason": null,
+            "started_at": "2024-01-01T10:00:00.000Z",
+        }))
+        .expect("Failed to parse the tracking data");
+
+        assert!(tracking_data.stream_progress.is_empty());
+    }
+}
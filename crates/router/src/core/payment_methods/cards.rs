@@ -41,10 +41,7 @@ use common_utils::{
 };
 use diesel_models::payment_method;
 use error_stack::{report, ResultExt};
-use euclid::{
-    dssa::graph::{AnalysisContext, CgraphExt},
-    frontend::dir,
-};
+use euclid::{dssa::graph::AnalysisContext, frontend::dir};
 use hyperswitch_constraint_graph as cgraph;
 #[cfg(feature = "v1")]
 use hyperswitch_domain_models::customer::CustomerUpdate;
@@ -52,7 +49,7 @@ use hyperswitch_domain_models::mandates::CommonMandateReference;
 use hyperswitch_interfaces::secrets_interface::secret_state::RawSecret;
 use hyperswitch_masking::Secret;
 #[cfg(feature = "v1")]
-use kgraph_utils::transformers::IntoDirValue;
+use kgraph_utils::{eligibility as kgraph_eligibility, transformers::IntoDirValue};
 use router_env::{instrument, tracing};
 use scheduler::errors as sch_errors;
 use strum::IntoEnumIterator;
@@ -4363,11 +4360,10 @@ pub async fn filter_payment_methods(
                     logger::info!("Context created for List Payment method is {:?}", context);
 
                     let domain_ident: &[String] = &[mca_id.clone().get_string_repr().to_string()];
-                    let result = graph.key_value_analysis(
+                    let result = kgraph_eligibility::check_payment_method_eligibility(
+                        graph,
                         pm_dir_value.clone(),
                         &context,
-                        &mut cgraph::Memoization::new(),
-                        &mut cgraph::CycleCheck::new(),
                         Some(domain_ident),
                     );
                     if let Err(ref e) = result {
@@ -4376,10 +4372,17 @@ pub async fn filter_payment_methods(
                             for list payment methods {:?}",
                             e
                         );
+                    } else if let Ok(kgraph_eligibility::Eligibility::Ineligible(explanation)) =
+                        &result
+                    {
+                        logger::info!(
+                            connector = %connector,
+                            payment_method_type = ?payment_method_object.payment_method_type,
+                            "Payment method type is not eligible for the payment:\n{explanation}"
+                        );
                     } else if filter_pm_based_on_allowed_types
                         && filter_pm_card_network_based
                         && saved_payment_methods_filter
-                        && matches!(result, Ok(()))
                     {
                         let response_pm_type = ResponsePaymentMethodIntermediate::new(
                             payment_method_object,
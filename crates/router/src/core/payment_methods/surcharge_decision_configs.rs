@@ -19,6 +19,8 @@ use router_env::{instrument, logger, tracing};
 use serde::{Deserialize, Serialize};
 use storage_impl::redis::cache::{self, SURCHARGE_CACHE};
 
+#[cfg(feature = "v1")]
+use crate::core::payments::routing::decision_trace;
 use crate::{
     core::{
         errors::{self, ConditionalConfigError as ConfigError},
@@ -56,6 +58,37 @@ impl TryFrom<SurchargeDecisionManagerRecord> for VirInterpreterBackendCacheWrapp
     }
 }
 
+/// Collects the surcharge rule evaluations of an attempt for its decision trace
+struct SurchargeEvaluationTrace {
+    algorithm_id: Option<String>,
+    evaluations: Vec<routing::RuleEvaluationTrace>,
+}
+
+#[cfg(feature = "v1")]
+impl SurchargeEvaluationTrace {
+    async fn new_if_enabled(
+        state: &SessionState,
+        payment_attempt: &storage::PaymentAttempt,
+        algorithm_id: Option<String>,
+    ) -> Option<Self> {
+        decision_trace::is_decision_trace_enabled(state, &payment_attempt.merchant_id)
+            .await
+            .then(|| Self {
+                algorithm_id,
+                evaluations: Vec::new(),
+            })
+    }
+
+    async fn record(self, state: &SessionState, payment_attempt: &storage::PaymentAttempt) {
+        decision_trace::record_rule_evaluations(
+            state,
+            decision_trace::DecisionTraceKey::from_payment_attempt(payment_attempt),
+            self.evaluations,
+        )
+        .await
+    }
+}
+
 enum SurchargeSource {
     /// Surcharge will be generated through the surcharge rules
     Generate(VirInterpreterBackendCacheWrapper),
@@ -69,14 +102,26 @@ impl SurchargeSource {
         backend_input: &backend::BackendInput,
         payment_attempt: &storage::PaymentAttempt,
         surcharge_metadata_and_key: (&mut types::SurchargeMetadata, types::SurchargeKey),
+        evaluation_trace: Option<&mut SurchargeEvaluationTrace>,
     ) -> ConditionalConfigResult<Option<types::SurchargeDetails>> {
         match self {
             Self::Generate(interpreter) => {
-                let surcharge_output = execute_dsl_and_get_conditional_config(
-                    backend_input.clone(),
-                    &interpreter.cached_algorithm,
-                )?;
-                Ok(surcharge_output
+                let output = interpreter
+                    .cached_algorithm
+                    .execute(backend_input.clone())
+                    .change_context(ConfigError::DslExecutionError)?;
+                if let Some(evaluation_trace) = evaluation_trace {
+                    evaluation_trace
+                        .evaluations
+                        .push(routing::RuleEvaluationTrace::new(
+                            routing::DecisionTraceType::Surcharge,
+                            evaluation_trace.algorithm_id.clone(),
+                            backend_input,
+                            &output,
+                        ));
+                }
+                Ok(output
+                    .connector_selection
                     .surcharge_details
                     .map(|surcharge_details| {
                         get_surcharge_details_from_surcharge_output(
@@ -126,6 +171,12 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
     surcharge_decision_configs::MerchantSurchargeConfigs,
 )> {
     let mut surcharge_metadata = types::SurchargeMetadata::new(payment_attempt.attempt_id.clone());
+    let mut evaluation_trace = SurchargeEvaluationTrace::new_if_enabled(
+        state,
+        payment_attempt,
+        algorithm_ref.surcharge_config_algo_id.clone(),
+    )
+    .await;
 
     let (surcharge_source, merchant_surcharge_configs) = match (
         payment_attempt.get_surcharge_details(),
@@ -191,6 +242,7 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
                                     Some(card_network_type.card_network.clone()),
                                 ),
                             ),
+                            evaluation_trace.as_mut(),
                         )?;
                     card_network_type.surcharge_details = surcharge_details
                         .map(|surcharge_details| {
@@ -216,6 +268,7 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
                                 None,
                             ),
                         ),
+                        evaluation_trace.as_mut(),
                     )?;
                 payment_method_type_response.surcharge_details = surcharge_details
                     .map(|surcharge_details| {
@@ -230,6 +283,9 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
             }
         }
     }
+    if let Some(evaluation_trace) = evaluation_trace {
+        evaluation_trace.record(state, payment_attempt).await;
+    }
     Ok((surcharge_metadata, merchant_surcharge_configs))
 }
 
@@ -243,6 +299,12 @@ pub async fn perform_surcharge_decision_management_for_session_flow(
     payment_method_type_list: &Vec<common_enums::PaymentMethodType>,
 ) -> ConditionalConfigResult<types::SurchargeMetadata> {
     let mut surcharge_metadata = types::SurchargeMetadata::new(payment_attempt.attempt_id.clone());
+    let mut evaluation_trace = SurchargeEvaluationTrace::new_if_enabled(
+        state,
+        payment_attempt,
+        algorithm_ref.surcharge_config_algo_id.clone(),
+    )
+    .await;
     let surcharge_source = match (
         payment_attempt.get_surcharge_details(),
         algorithm_ref.surcharge_config_algo_id,
@@ -280,8 +342,12 @@ pub async fn perform_surcharge_decision_management_for_session_flow(
                     None,
                 ),
             ),
+            evaluation_trace.as_mut(),
         )?;
     }
+    if let Some(evaluation_trace) = evaluation_trace {
+        evaluation_trace.record(state, payment_attempt).await;
+    }
     Ok(surcharge_metadata)
 }
 
@@ -294,6 +360,12 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
     customer_payment_method_list: &mut [api_models::payment_methods::CustomerPaymentMethod],
 ) -> ConditionalConfigResult<types::SurchargeMetadata> {
     let mut surcharge_metadata = types::SurchargeMetadata::new(payment_attempt.attempt_id.clone());
+    let mut evaluation_trace = SurchargeEvaluationTrace::new_if_enabled(
+        state,
+        payment_attempt,
+        algorithm_ref.surcharge_config_algo_id.clone(),
+    )
+    .await;
     let surcharge_source = match (
         payment_attempt.get_surcharge_details(),
         algorithm_ref.surcharge_config_algo_id,
@@ -350,6 +422,7 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
                     &mut surcharge_metadata,
                     types::SurchargeKey::Token(payment_token),
                 ),
+                evaluation_trace.as_mut(),
             )?;
         customer_payment_method.surcharge_details = surcharge_details
             .map(|surcharge_details| {
@@ -358,6 +431,9 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
             })
             .transpose()?;
     }
+    if let Some(evaluation_trace) = evaluation_trace {
+        evaluation_trace.record(state, payment_attempt).await;
+    }
     Ok(surcharge_metadata)
 }
 
@@ -813,6 +813,7 @@ where
             match Box::pin(frm_core::call_frm_before_connector_call(
                 &operation,
                 platform,
+                &business_profile,
                 &mut payment_data,
                 state,
                 &mut frm_info,
@@ -961,6 +962,7 @@ where
                             connector.connector_data.connector.id(),
                             platform.get_processor().get_account().get_id(),
                             0,
+                            None,
                         )
                         .await
                         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -1134,6 +1136,7 @@ where
                             connector_data.connector.id(),
                             platform.get_processor().get_account().get_id(),
                             0,
+                            None,
                         )
                         .await
                         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -1568,6 +1571,7 @@ where
             connector.connector.id(),
             platform.get_processor().get_account().get_id(),
             0,
+            None,
         )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -9947,29 +9951,51 @@ where
     let (connectors, routing_approach, requires_eligibility) =
         routing_future.await.unwrap_or(fallback_outcome);
 
-    let final_connectors = if requires_eligibility {
-        routing::perform_eligibility_analysis_with_fallback(
+    let is_decision_trace_enabled = routing::decision_trace::is_decision_trace_enabled(
+        &state,
+        processor.get_account().get_id(),
+    )
+    .await;
+
+    let (final_connectors, eligibility_trace) = if requires_eligibility {
+        routing::perform_eligibility_analysis_with_fallback_and_trace(
             &state,
             processor.get_key_store(),
             connectors.clone(),
             &txn,
             eligible_connectors.clone(),
             business_profile,
+            is_decision_trace_enabled,
         )
         .await
+        .map(|(final_connectors, eligibility_trace)| (final_connectors, Some(eligibility_trace)))
         .inspect_err(|err| {
             logger::error!(
                 error = ?err,
                 "euclid: eligibility analysis failed, using fallback connectors"
             );
         })
-        .unwrap_or_else(|_| fallback_config.clone())
+        .unwrap_or_else(|_| (fallback_config.clone(), None))
     } else {
-        connectors
+        (connectors.clone(), None)
     };
 
     core_routing::log_connectors("eligibility", &final_connectors);
 
+    if is_decision_trace_enabled {
+        routing::decision_trace::record_routing_outcome(
+            &state,
+            routing::decision_trace::DecisionTraceKey::from_payment_attempt(
+                transaction_data.payment_attempt,
+            ),
+            connectors,
+            routing_approach.clone(),
+            eligibility_trace,
+            final_connectors.first().cloned(),
+        )
+        .await;
+    }
+
     let connector_data = final_connectors
         .into_iter()
         .map(|conn| {
@@ -11538,10 +11564,25 @@ impl EligibilityCheck for BlockListCheck {
         payment_elgibility_data: &PaymentEligibilityData,
         _business_profile: &domain::Profile,
     ) -> CustomResult<CheckResult, errors::ApiErrorResponse> {
+        let check_data = blocklist_utils::BlocklistCheckData {
+            payment_method_data: payment_elgibility_data.payment_method_data.as_ref(),
+            email: blocklist_utils::get_customer_email(&payment_elgibility_data.payment_intent),
+            browser_info: payment_elgibility_data
+                .browser_info
+                .as_ref()
+                .and_then(|browser_info| {
+                    browser_info
+                        .peek()
+                        .clone()
+                        .parse_value::<BrowserInformation>("BrowserInformation")
+                        .inspect_err(|error| logger::warn!(?error, "Failed to parse browser info"))
+                        .ok()
+                }),
+        };
         let should_payment_be_blocked = blocklist_utils::should_payment_be_blocked(
             state,
             platform.get_processor(),
-            &payment_elgibility_data.payment_method_data,
+            &check_data,
         )
         .await?;
         if should_payment_be_blocked {
@@ -5,6 +5,8 @@ use euclid::backend::{self, inputs as dsl_inputs, EuclidBackend};
 use router_env::{instrument, tracing};
 use storage_impl::redis::cache::{self, DECISION_MANAGER_CACHE};
 
+#[cfg(feature = "v1")]
+use super::routing::decision_trace;
 use super::routing::make_dsl_input;
 #[cfg(feature = "v2")]
 use crate::{core::errors::RouterResult, types::domain};
@@ -57,7 +59,23 @@ pub async fn perform_decision_management(
     let backend_input =
         make_dsl_input(payment_data).change_context(ConfigError::InputConstructionError)?;
 
-    execute_dsl_and_get_conditional_config(backend_input, &interpreter)
+    let output = execute_dsl(backend_input.clone(), &interpreter)?;
+
+    if decision_trace::is_decision_trace_enabled(state, merchant_id).await {
+        decision_trace::record_rule_evaluations(
+            state,
+            decision_trace::DecisionTraceKey::from_payment_attempt(payment_data.payment_attempt),
+            vec![routing::RuleEvaluationTrace::new(
+                routing::DecisionTraceType::ThreeDs,
+                Some(algorithm_id.clone()),
+                &backend_input,
+                &output,
+            )],
+        )
+        .await;
+    }
+
+    Ok(output.connector_selection)
 }
 
 #[cfg(feature = "v2")]
@@ -81,9 +99,14 @@ pub fn execute_dsl_and_get_conditional_config(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<common_types::payments::ConditionalConfigs>,
 ) -> ConditionalConfigResult<common_types::payments::ConditionalConfigs> {
-    let routing_output = interpreter
+    execute_dsl(backend_input, interpreter).map(|out| out.connector_selection)
+}
+
+fn execute_dsl(
+    backend_input: dsl_inputs::BackendInput,
+    interpreter: &backend::VirInterpreterBackend<common_types::payments::ConditionalConfigs>,
+) -> ConditionalConfigResult<backend::BackendOutput<common_types::payments::ConditionalConfigs>> {
+    interpreter
         .execute(backend_input)
-        .map(|out| out.connector_selection)
-        .change_context(ConfigError::DslExecutionError)?;
-    Ok(routing_output)
+        .change_context(ConfigError::DslExecutionError)
 }
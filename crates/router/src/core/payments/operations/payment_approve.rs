@@ -268,6 +268,17 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsCaptureReque
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        #[cfg(feature = "frm")]
+        if let Some(fraud_check) = payment_data.frm_message.take() {
+            payment_data.frm_message = Some(
+                crate::core::fraud_check::review::close_review(
+                    state,
+                    fraud_check,
+                    common_enums::FrmReviewStatus::Approved,
+                )
+                .await,
+            );
+        }
         req_state
             .event_context
             .event(AuditEvent::new(AuditEventType::PaymentApprove))
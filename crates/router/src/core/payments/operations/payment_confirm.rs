@@ -1607,6 +1607,11 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
                         }
                     }),
                 },
+                Some(
+                    payments::routing::decision_trace::DecisionTraceKey::from_payment_attempt(
+                        &payment_data.payment_attempt,
+                    ),
+                ),
             )
             .await
             .inspect_err(|err| {
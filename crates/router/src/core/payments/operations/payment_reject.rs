@@ -276,6 +276,17 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, PaymentsCancelRequest> fo
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        #[cfg(feature = "frm")]
+        if let Some(fraud_check) = payment_data.frm_message.take() {
+            payment_data.frm_message = Some(
+                crate::core::fraud_check::review::close_review(
+                    state,
+                    fraud_check,
+                    common_enums::FrmReviewStatus::Rejected,
+                )
+                .await,
+            );
+        }
         let error_code = payment_data.payment_attempt.error_code.clone();
         let error_message = payment_data.payment_attempt.error_message.clone();
         req_state
@@ -1,3 +1,4 @@
+pub mod decision_trace;
 mod transformers;
 pub mod utils;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
@@ -21,7 +22,7 @@ use diesel_models::enums as storage_enums;
 use error_stack::ResultExt;
 use euclid::{
     backend::{self, inputs as dsl_inputs, EuclidBackend},
-    dssa::graph::{self as euclid_graph, CgraphExt},
+    dssa::graph as euclid_graph,
     enums as euclid_enums,
     frontend::{ast, dir as euclid_dir},
 };
@@ -39,7 +40,7 @@ use hyperswitch_domain_models::{
 use hyperswitch_interfaces::events::routing_api_logs::{ApiMethod, RoutingEngine};
 use hyperswitch_masking::{PeekInterface, Secret};
 use kgraph_utils::{
-    mca as mca_graph,
+    eligibility as kgraph_eligibility, mca as mca_graph,
     transformers::{IntoContext, IntoDirValue},
     types::CountryCurrencyFilter,
 };
@@ -85,6 +86,29 @@ pub enum CachedAlgorithm {
     Advanced(backend::VirInterpreterBackend<ConnectorSelection>),
 }
 
+impl CachedAlgorithm {
+    pub fn try_from_static_algorithm(
+        algorithm: routing_types::StaticRoutingAlgorithm,
+    ) -> RoutingResult<Self> {
+        Ok(match algorithm {
+            routing_types::StaticRoutingAlgorithm::Single(conn) => Self::Single(conn),
+            routing_types::StaticRoutingAlgorithm::Priority(plist) => Self::Priority(plist),
+            routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => Self::VolumeSplit(splits),
+            routing_types::StaticRoutingAlgorithm::Advanced(program) => {
+                let interpreter = backend::VirInterpreterBackend::with_program(program)
+                    .change_context(errors::RoutingError::DslBackendInitError)
+                    .attach_printable("Error initializing DSL interpreter backend")?;
+
+                Self::Advanced(interpreter)
+            }
+            routing_types::StaticRoutingAlgorithm::ThreeDsDecisionRule(_program) => {
+                Err(errors::RoutingError::InvalidRoutingAlgorithmStructure)
+                    .attach_printable("Unsupported algorithm received")?
+            }
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 pub struct SessionFlowRoutingInput<'a> {
     pub state: &'a SessionState,
@@ -216,6 +240,8 @@ pub fn make_dsl_input_for_payouts(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        time_data: None,
+        velocity_data: None,
     })
 }
 
@@ -342,6 +368,8 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        time_data: None,
+        velocity_data: None,
     })
 }
 
@@ -656,9 +684,43 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data,
+        time_data: None,
+        velocity_data: None,
     })
 }
 
+/// Builds the time input for the routing DSL from the given UTC timestamp, converted to the
+/// routing timezone configured on the profile
+pub fn make_dsl_time_input(
+    routing_timezone: Option<common_types::primitive_wrappers::RoutingTimezone>,
+    timestamp: time::PrimitiveDateTime,
+) -> dsl_inputs::TimeDataInput {
+    let utc_time = timestamp.assume_utc();
+    let offset = routing_timezone
+        .map(|timezone| timezone.get_utc_offset_at(utc_time))
+        .unwrap_or(time::UtcOffset::UTC);
+    let local_time = utc_time.to_offset(offset);
+
+    let day_of_week = match local_time.weekday() {
+        time::Weekday::Monday => euclid_dir::enums::DayOfWeek::Monday,
+        time::Weekday::Tuesday => euclid_dir::enums::DayOfWeek::Tuesday,
+        time::Weekday::Wednesday => euclid_dir::enums::DayOfWeek::Wednesday,
+        time::Weekday::Thursday => euclid_dir::enums::DayOfWeek::Thursday,
+        time::Weekday::Friday => euclid_dir::enums::DayOfWeek::Friday,
+        time::Weekday::Saturday => euclid_dir::enums::DayOfWeek::Saturday,
+        time::Weekday::Sunday => euclid_dir::enums::DayOfWeek::Sunday,
+    };
+    let date = local_time.year() * 10000
+        + i32::from(u8::from(local_time.month())) * 100
+        + i32::from(local_time.day());
+
+    dsl_inputs::TimeDataInput {
+        time_of_day: u16::from(local_time.hour()) * 100 + u16::from(local_time.minute()),
+        day_of_week,
+        date: u32::try_from(date).unwrap_or_default(),
+    }
+}
+
 type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
 pub trait RoutingStage: Send + Sync {
     type Input<'a>
@@ -824,6 +886,9 @@ pub async fn perform_static_routing_locally(
         .change_context(errors::ApiErrorResponse::InternalServerError)?
         .unwrap_or_default()
         .algorithm_id;
+    let traced_algorithm_id = routing_algorithm_id
+        .as_ref()
+        .map(|algorithm_id| algorithm_id.get_string_repr().to_string());
 
     let cached_algorithm = routing_algorithm_id
         .async_and_then(|routing_algorithm_id| async move {
@@ -838,6 +903,17 @@ pub async fn perform_static_routing_locally(
         })
         .await;
 
+    if let Some(CachedAlgorithm::Advanced(interpreter)) = cached_algorithm.as_deref() {
+        trace_static_routing_rule(
+            state,
+            payment_dsl_input.payment_attempt,
+            traced_algorithm_id,
+            interpreter,
+            backend_input,
+        )
+        .await;
+    }
+
     let static_input = StaticRoutingInput { backend_input };
 
     let static_stage = cached_algorithm.map(|algo| StaticRoutingStage {
@@ -878,6 +954,40 @@ pub async fn perform_static_routing_locally(
     Ok((static_connectors, static_approach))
 }
 
+/// Evaluates the advanced routing program once more to capture the rule which matched, when the
+/// decision trace is enabled for the merchant
+#[cfg(feature = "v1")]
+async fn trace_static_routing_rule(
+    state: &SessionState,
+    payment_attempt: &oss_storage::PaymentAttempt,
+    algorithm_id: Option<String>,
+    interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+    backend_input: &backend::BackendInput,
+) {
+    if !decision_trace::is_decision_trace_enabled(state, &payment_attempt.merchant_id).await {
+        return;
+    }
+
+    match interpreter.execute(backend_input.clone()) {
+        Ok(output) => {
+            decision_trace::record_rule_evaluations(
+                state,
+                decision_trace::DecisionTraceKey::from_payment_attempt(payment_attempt),
+                vec![routing_types::RuleEvaluationTrace::new(
+                    routing_types::DecisionTraceType::Routing,
+                    algorithm_id,
+                    backend_input,
+                    &output,
+                )],
+            )
+            .await
+        }
+        Err(error) => {
+            logger::error!(?error, "euclid: unable to evaluate routing rule for trace")
+        }
+    }
+}
+
 pub struct SessionRoutingInput<'a> {
     pub state: &'a SessionState,
     pub business_profile: &'a domain::Profile,
@@ -1688,6 +1798,14 @@ pub async fn perform_static_routing_v1(
         #[cfg(feature = "payouts")]
         routing::TransactionData::Payout(payout_data) => make_dsl_input_for_payouts(payout_data)?,
     };
+    #[cfg(feature = "v1")]
+    let backend_input = dsl_inputs::BackendInput {
+        time_data: Some(make_dsl_time_input(
+            business_profile.routing_timezone,
+            common_utils::date_time::now(),
+        )),
+        ..backend_input
+    };
 
     let payment_id = match transaction_data {
         routing::TransactionData::Payment(payment_data) => payment_data
@@ -1724,22 +1842,8 @@ pub async fn perform_static_routing_v1(
         .unwrap_or_default()
     };
 
-    let (routable_connectors, routing_approach) = match cached_algorithm.as_ref() {
-        CachedAlgorithm::Single(conn) => (
-            vec![(**conn).clone()],
-            Some(common_enums::RoutingApproach::StraightThroughRouting),
-        ),
-        CachedAlgorithm::Priority(plist) => (plist.clone(), None),
-        CachedAlgorithm::VolumeSplit(splits) => (
-            perform_volume_split(splits.to_vec())
-                .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
-            Some(common_enums::RoutingApproach::VolumeBasedRouting),
-        ),
-        CachedAlgorithm::Advanced(interpreter) => (
-            execute_dsl_and_get_connector_v1(backend_input, interpreter)?,
-            Some(common_enums::RoutingApproach::RuleBasedRouting),
-        ),
-    };
+    let (routable_connectors, routing_approach) =
+        execute_cached_algorithm(cached_algorithm.as_ref(), backend_input)?;
 
     // Results are logged for diff(between legacy and decision_engine's euclid) and have parameters as:
     // is_equal: verifies all output are matching in order,
@@ -1765,6 +1869,32 @@ pub async fn perform_static_routing_v1(
     ))
 }
 
+/// Evaluates an algorithm against the DSL input, returning the connectors in order of preference
+pub fn execute_cached_algorithm(
+    cached_algorithm: &CachedAlgorithm,
+    backend_input: dsl_inputs::BackendInput,
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    Option<common_enums::RoutingApproach>,
+)> {
+    Ok(match cached_algorithm {
+        CachedAlgorithm::Single(conn) => (
+            vec![(**conn).clone()],
+            Some(common_enums::RoutingApproach::StraightThroughRouting),
+        ),
+        CachedAlgorithm::Priority(plist) => (plist.clone(), None),
+        CachedAlgorithm::VolumeSplit(splits) => (
+            perform_volume_split(splits.to_vec())
+                .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+            Some(common_enums::RoutingApproach::VolumeBasedRouting),
+        ),
+        CachedAlgorithm::Advanced(interpreter) => (
+            execute_dsl_and_get_connector_v1(backend_input, interpreter)?,
+            Some(common_enums::RoutingApproach::RuleBasedRouting),
+        ),
+    })
+}
+
 pub async fn ensure_algorithm_cached_v1(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -1910,26 +2040,7 @@ pub async fn refresh_routing_cache_v1(
         algorithm
     };
 
-    let cached_algorithm = match algorithm {
-        routing_types::StaticRoutingAlgorithm::Single(conn) => CachedAlgorithm::Single(conn),
-        routing_types::StaticRoutingAlgorithm::Priority(plist) => CachedAlgorithm::Priority(plist),
-        routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => {
-            CachedAlgorithm::VolumeSplit(splits)
-        }
-        routing_types::StaticRoutingAlgorithm::Advanced(program) => {
-            let interpreter = backend::VirInterpreterBackend::with_program(program)
-                .change_context(errors::RoutingError::DslBackendInitError)
-                .attach_printable("Error initializing DSL interpreter backend")?;
-
-            CachedAlgorithm::Advanced(interpreter)
-        }
-        api_models::routing::StaticRoutingAlgorithm::ThreeDsDecisionRule(_program) => {
-            Err(errors::RoutingError::InvalidRoutingAlgorithmStructure)
-                .attach_printable("Unsupported algorithm received")?
-        }
-    };
-
-    let arc_cached_algorithm = Arc::new(cached_algorithm);
+    let arc_cached_algorithm = Arc::new(CachedAlgorithm::try_from_static_algorithm(algorithm)?);
 
     ROUTING_CACHE
         .push(
@@ -2163,6 +2274,39 @@ pub async fn perform_cgraph_filtering(
     transaction_type: &api_enums::TransactionType,
     active_mca_ids: &std::collections::HashSet<common_utils::id_type::MerchantConnectorAccountId>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    perform_cgraph_filtering_with_removals(
+        state,
+        key_store,
+        chosen,
+        backend_input,
+        eligible_connectors,
+        profile_id,
+        transaction_type,
+        active_mca_ids,
+        false,
+    )
+    .await
+    .map(|(final_selection, _)| final_selection)
+}
+
+/// Filters the chosen connectors in the same way as [`perform_cgraph_filtering`], additionally
+/// returning the connectors which were removed along with the reasons for their removal. The
+/// constraint graph is asked to explain a rejection only when `explain_removals` is set.
+#[allow(clippy::too_many_arguments)]
+pub async fn perform_cgraph_filtering_with_removals(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    chosen: Vec<routing_types::RoutableConnectorChoice>,
+    backend_input: dsl_inputs::BackendInput,
+    eligible_connectors: Option<&Vec<api_enums::RoutableConnectors>>,
+    profile_id: &common_utils::id_type::ProfileId,
+    transaction_type: &api_enums::TransactionType,
+    active_mca_ids: &std::collections::HashSet<common_utils::id_type::MerchantConnectorAccountId>,
+    explain_removals: bool,
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    Vec<routing_types::EligibilityFilterRemoval>,
+)> {
     let context = euclid_graph::AnalysisContext::from_dir_values(
         backend_input
             .into_context()
@@ -2172,6 +2316,7 @@ pub async fn perform_cgraph_filtering(
     let cached_cgraph = get_merchant_cgraph(state, key_store, profile_id, transaction_type).await?;
 
     let mut final_selection = Vec::new();
+    let mut removals = Vec::new();
 
     for choice in chosen {
         let routable_connector = choice.connector;
@@ -2181,15 +2326,24 @@ pub async fn perform_cgraph_filtering(
             .into_dir_value()
             .change_context(errors::RoutingError::KgraphAnalysisError)?;
 
-        let cgraph_eligible = cached_cgraph
-            .check_value_validity(
+        let (cgraph_eligible, constraint_graph_explanation) = if explain_removals {
+            let cgraph_eligibility = kgraph_eligibility::check_connector_eligibility(
+                &cached_cgraph,
                 dir_val,
                 &context,
-                &mut hyperswitch_constraint_graph::Memoization::new(),
-                &mut hyperswitch_constraint_graph::CycleCheck::new(),
                 None,
             )
             .change_context(errors::RoutingError::KgraphAnalysisError)?;
+            (
+                cgraph_eligibility.is_eligible(),
+                cgraph_eligibility.explanation().map(ToString::to_string),
+            )
+        } else {
+            let cgraph_eligible =
+                kgraph_eligibility::is_connector_eligible(&cached_cgraph, dir_val, &context, None)
+                    .change_context(errors::RoutingError::KgraphAnalysisError)?;
+            (cgraph_eligible, None)
+        };
 
         let filter_eligible =
             eligible_connectors.is_none_or(|list| list.contains(&routable_connector));
@@ -2202,10 +2356,29 @@ pub async fn perform_cgraph_filtering(
 
         if cgraph_eligible && filter_eligible && mca_active {
             final_selection.push(choice);
+        } else {
+            let reasons = [
+                (!cgraph_eligible)
+                    .then_some(routing_types::EligibilityFilterReason::ConstraintGraph),
+                (!filter_eligible)
+                    .then_some(routing_types::EligibilityFilterReason::NotInEligibleConnectors),
+                (!mca_active).then_some(
+                    routing_types::EligibilityFilterReason::InactiveMerchantConnectorAccount,
+                ),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+
+            removals.push(routing_types::EligibilityFilterRemoval {
+                connector: choice,
+                reasons,
+                constraint_graph_explanation,
+            });
         }
     }
 
-    Ok(final_selection)
+    Ok((final_selection, removals))
 }
 
 #[cfg(feature = "v1")]
@@ -2388,19 +2561,57 @@ pub async fn perform_eligibility_analysis_with_fallback(
     eligible_connectors: Option<Vec<api_enums::RoutableConnectors>>,
     business_profile: &domain::Profile,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    perform_eligibility_analysis_with_fallback_and_trace(
+        state,
+        key_store,
+        chosen,
+        transaction_data,
+        eligible_connectors,
+        business_profile,
+        false,
+    )
+    .await
+    .map(|(final_selection, _)| final_selection)
+}
+
+/// Performs the eligibility analysis with fallback, additionally returning the connectors which
+/// were removed and the fallback connectors which were appended to the selection. The removals
+/// carry the explanation of the constraint graph only when `explain_removals` is set.
+pub async fn perform_eligibility_analysis_with_fallback_and_trace(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    chosen: Vec<routing_types::RoutableConnectorChoice>,
+    transaction_data: &routing::TransactionData<'_>,
+    eligible_connectors: Option<Vec<api_enums::RoutableConnectors>>,
+    business_profile: &domain::Profile,
+    explain_removals: bool,
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    routing_types::EligibilityTrace,
+)> {
     logger::debug!("euclid_routing: performing eligibility");
 
     #[cfg(feature = "v1")]
     let eligible_connectors =
         update_eligible_connectors_for_installments(state, transaction_data, eligible_connectors);
 
-    let mut final_selection = perform_eligibility_analysis(
+    let backend_input = match transaction_data {
+        routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data)?,
+        #[cfg(feature = "payouts")]
+        routing::TransactionData::Payout(payout_data) => make_dsl_input_for_payouts(payout_data)?,
+    };
+
+    let active_mca_ids = get_active_mca_ids(state, key_store).await?;
+    let (mut final_selection, removed) = perform_cgraph_filtering_with_removals(
         state,
         key_store,
         chosen,
-        transaction_data,
+        backend_input,
         eligible_connectors.as_ref(),
         business_profile.get_id(),
+        &api_enums::TransactionType::from(transaction_data),
+        &active_mca_ids,
+        explain_removals,
     )
     .await?;
 
@@ -2413,16 +2624,12 @@ pub async fn perform_eligibility_analysis_with_fallback(
     )
     .await;
 
-    final_selection.append(
-        &mut fallback_selection
-            .unwrap_or_default()
-            .iter()
-            .filter(|&routable_connector_choice| {
-                !final_selection.contains(routable_connector_choice)
-            })
-            .cloned()
-            .collect::<Vec<_>>(),
-    );
+    let fallback_connectors = fallback_selection
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|routable_connector_choice| !final_selection.contains(routable_connector_choice))
+        .collect::<Vec<_>>();
+    final_selection.extend(fallback_connectors.iter().cloned());
 
     let final_selected_connectors = final_selection
         .iter()
@@ -2430,7 +2637,13 @@ pub async fn perform_eligibility_analysis_with_fallback(
         .collect::<Vec<_>>();
     logger::debug!(final_selected_connectors_for_routing=?final_selected_connectors, "euclid_routing: List of final selected connectors for routing");
 
-    Ok(final_selection)
+    Ok((
+        final_selection,
+        routing_types::EligibilityTrace {
+            removed,
+            fallback_connectors,
+        },
+    ))
 }
 
 #[cfg(feature = "v2")]
@@ -2499,6 +2712,8 @@ pub async fn perform_session_flow_routing<'a>(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        time_data: None,
+        velocity_data: None,
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -2656,6 +2871,10 @@ pub async fn perform_session_flow_routing(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        time_data: Some(make_dsl_time_input(
+            business_profile.routing_timezone,
+            common_utils::date_time::now(),
+        )),
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -2994,6 +3213,8 @@ pub fn make_dsl_input_for_surcharge(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        time_data: None,
+        velocity_data: None,
     };
     Ok(backend_input)
 }
@@ -3856,13 +4077,13 @@ where
             "performing contract_based_routing for profile {}",
             profile_id.get_string_repr()
         );
-        let client = &state
+        let client = state
             .grpc_client
             .dynamic_routing
             .as_ref()
+            .and_then(|dynamic_routing| dynamic_routing.contract_based_client.as_ref())
             .ok_or(errors::RoutingError::ContractRoutingClientInitializationError)
-            .attach_printable("dynamic routing gRPC client not found")?
-            .contract_based_client;
+            .attach_printable("contract based routing gRPC client not found")?;
 
         let contract_based_routing_configs = routing::helpers::fetch_dynamic_routing_configs::<
             api_routing::ContractBasedRoutingConfig,
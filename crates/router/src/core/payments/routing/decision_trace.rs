@@ -0,0 +1,543 @@
+//! Opt-in trace of the decisions made for a payment attempt by evaluating the routing, surcharge
+//! and 3DS rule programs, along with the connectors removed by the eligibility analysis.
+//!
+//! Tracing is enabled per merchant through the `routing_decision_trace_enabled_{merchant_id}`
+//! config. Traces are stored in redis for a week and are also emitted as routing events, with the
+//! values of the DSL input which could identify the customer or the card redacted.
+
+use api_models::routing as routing_types;
+use common_utils::{errors::CustomResult, ext_traits::Encode, id_type};
+use error_stack::ResultExt;
+use hyperswitch_interfaces::events::routing_api_logs::{ApiMethod, RoutingEngine, RoutingEvent};
+use redis_interface::errors::RedisError;
+use router_env::logger;
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        utils as core_utils,
+    },
+    routes::app::SessionStateInfo,
+    services,
+    types::{domain, storage},
+    SessionState,
+};
+
+/// Time for which the trace of a payment is retained in redis
+const DECISION_TRACE_TTL_IN_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+const DECISION_TRACE_EVENT_FLOW: &str = "Euclid: DecisionTrace";
+
+/// Identifies the payment attempt whose decisions are traced
+#[derive(Clone, Copy)]
+pub struct DecisionTraceKey<'a> {
+    pub merchant_id: &'a id_type::MerchantId,
+    pub profile_id: &'a id_type::ProfileId,
+    pub payment_id: &'a id_type::PaymentId,
+    pub attempt_id: &'a str,
+}
+
+impl<'a> DecisionTraceKey<'a> {
+    #[cfg(feature = "v1")]
+    pub fn from_payment_attempt(payment_attempt: &'a storage::PaymentAttempt) -> Self {
+        Self {
+            merchant_id: &payment_attempt.merchant_id,
+            profile_id: &payment_attempt.profile_id,
+            payment_id: &payment_attempt.payment_id,
+            attempt_id: &payment_attempt.attempt_id,
+        }
+    }
+}
+
+fn get_decision_trace_redis_key(
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> String {
+    format!(
+        "routing_decision_trace_{}_{}",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
+pub async fn is_decision_trace_enabled(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> bool {
+    let config_key = merchant_id.get_routing_decision_trace_enabled_key();
+    state
+        .store
+        .find_config_by_key_unwrap_or(&config_key, Some("false".to_string()))
+        .await
+        .inspect_err(|error| {
+            logger::error!(?error, "Failed to fetch `{config_key}` config from DB");
+        })
+        .ok()
+        .and_then(|config| config.config.parse::<bool>().ok())
+        .unwrap_or(false)
+}
+
+/// A part of the trace of an attempt. Every part is stored in a hash field of its own, so that
+/// the parts recorded concurrently for a payment never overwrite each other.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct DecisionTraceEntry {
+    attempt_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    recorded_at: time::PrimitiveDateTime,
+    #[serde(flatten)]
+    kind: DecisionTraceEntryKind,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "entry_type", rename_all = "snake_case")]
+enum DecisionTraceEntryKind {
+    RuleEvaluations {
+        evaluations: Vec<routing_types::RuleEvaluationTrace>,
+    },
+    RoutingOutcome {
+        routed_connectors: Vec<routing_types::RoutableConnectorChoice>,
+        routing_approach: common_enums::RoutingApproach,
+        eligibility: Option<routing_types::EligibilityTrace>,
+        final_connector: Option<routing_types::RoutableConnectorChoice>,
+    },
+}
+
+impl DecisionTraceEntry {
+    fn new(attempt_id: &str, kind: DecisionTraceEntryKind) -> Self {
+        Self {
+            attempt_id: attempt_id.to_string(),
+            recorded_at: common_utils::date_time::now(),
+            kind,
+        }
+    }
+
+    /// Rule evaluations are appended under a unique field, while the routing outcome of an
+    /// attempt replaces the previously recorded one
+    fn get_field_name(&self) -> String {
+        match self.kind {
+            DecisionTraceEntryKind::RuleEvaluations { .. } => format!(
+                "{}_{}",
+                self.attempt_id,
+                common_utils::generate_time_ordered_id("rule_evaluations")
+            ),
+            DecisionTraceEntryKind::RoutingOutcome { .. } => {
+                format!("{}_routing_outcome", self.attempt_id)
+            }
+        }
+    }
+}
+
+impl DecisionTraceEntryKind {
+    fn apply(self, trace: &mut routing_types::RoutingDecisionTrace) {
+        match self {
+            Self::RuleEvaluations { evaluations } => trace.rule_evaluations.extend(evaluations),
+            Self::RoutingOutcome {
+                routed_connectors,
+                routing_approach,
+                eligibility,
+                final_connector,
+            } => {
+                trace.routed_connectors = routed_connectors;
+                trace.routing_approach = Some(routing_approach);
+                trace.eligibility = eligibility;
+                trace.final_connector = final_connector;
+            }
+        }
+    }
+}
+
+/// Assembles the traces of the attempts from their parts, ordered by creation time
+fn build_decision_traces(
+    mut entries: Vec<DecisionTraceEntry>,
+) -> Vec<routing_types::RoutingDecisionTrace> {
+    entries.sort_by_key(|entry| entry.recorded_at);
+
+    let mut traces: Vec<routing_types::RoutingDecisionTrace> = Vec::new();
+    for entry in entries {
+        match traces
+            .iter_mut()
+            .find(|trace| trace.attempt_id == entry.attempt_id)
+        {
+            Some(trace) => entry.kind.apply(trace),
+            None => {
+                let mut trace = routing_types::RoutingDecisionTrace::new(entry.attempt_id);
+                trace.created_at = entry.recorded_at;
+                entry.kind.apply(&mut trace);
+                traces.push(trace);
+            }
+        }
+    }
+
+    traces
+}
+
+async fn store_decision_trace_entry(
+    state: &SessionState,
+    key: DecisionTraceKey<'_>,
+    entry: DecisionTraceEntry,
+) -> CustomResult<(), RedisError> {
+    let redis_conn = state.store.get_redis_conn()?;
+    let redis_key = get_decision_trace_redis_key(key.merchant_id, key.payment_id);
+
+    let encoded_entry = entry
+        .encode_to_string_of_json()
+        .change_context(RedisError::JsonSerializationFailed)?;
+    redis_conn
+        .set_hash_fields(
+            &redis_key.as_str().into(),
+            vec![(entry.get_field_name(), encoded_entry)],
+            Some(DECISION_TRACE_TTL_IN_SECONDS),
+        )
+        .await
+}
+
+async fn get_decision_trace_entries(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> CustomResult<Vec<DecisionTraceEntry>, RedisError> {
+    let redis_conn = state.store.get_redis_conn()?;
+    let redis_key = get_decision_trace_redis_key(merchant_id, payment_id);
+
+    redis_conn
+        .get_hash_fields::<Vec<(String, String)>>(&redis_key.as_str().into())
+        .await?
+        .into_iter()
+        .map(|(_, value)| {
+            serde_json::from_str::<DecisionTraceEntry>(&value)
+                .change_context(RedisError::JsonDeserializationFailed)
+                .attach_printable("Failed to parse DecisionTraceEntry")
+        })
+        .collect()
+}
+
+/// Appends rule evaluations to the trace of the attempt. Failures are logged and never affect
+/// the payment.
+pub async fn record_rule_evaluations(
+    state: &SessionState,
+    key: DecisionTraceKey<'_>,
+    evaluations: Vec<routing_types::RuleEvaluationTrace>,
+) {
+    if evaluations.is_empty() {
+        return;
+    }
+
+    let entry = DecisionTraceEntry::new(
+        key.attempt_id,
+        DecisionTraceEntryKind::RuleEvaluations { evaluations },
+    );
+    let _ = store_decision_trace_entry(state, key, entry)
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to record rule evaluations in trace"));
+}
+
+/// Records the outcome of routing in the trace of the attempt, and emits the complete trace as a
+/// routing event
+pub async fn record_routing_outcome(
+    state: &SessionState,
+    key: DecisionTraceKey<'_>,
+    routed_connectors: Vec<routing_types::RoutableConnectorChoice>,
+    routing_approach: common_enums::RoutingApproach,
+    eligibility: Option<routing_types::EligibilityTrace>,
+    final_connector: Option<routing_types::RoutableConnectorChoice>,
+) {
+    let entry = DecisionTraceEntry::new(
+        key.attempt_id,
+        DecisionTraceEntryKind::RoutingOutcome {
+            routed_connectors,
+            routing_approach,
+            eligibility,
+            final_connector,
+        },
+    );
+    if let Err(error) = store_decision_trace_entry(state, key, entry).await {
+        logger::error!(?error, "Failed to record routing outcome in trace");
+        return;
+    }
+
+    let trace = match get_decision_trace_entries(state, key.merchant_id, key.payment_id)
+        .await
+        .map(build_decision_traces)
+        .map(|traces| {
+            traces
+                .into_iter()
+                .find(|trace| trace.attempt_id == key.attempt_id)
+        }) {
+        Ok(Some(trace)) => trace,
+        Ok(None) => return,
+        Err(error) => {
+            logger::error!(?error, "Failed to read trace for the routing event");
+            return;
+        }
+    };
+    let dsl_input = trace
+        .rule_evaluations
+        .iter()
+        .rev()
+        .find(|evaluation| evaluation.decision_type == routing_types::DecisionTraceType::Routing)
+        .map(|evaluation| evaluation.dsl_input.clone())
+        .unwrap_or_default();
+
+    let mut routing_event = RoutingEvent::new(
+        state.tenant.tenant_id.clone(),
+        String::new(),
+        DECISION_TRACE_EVENT_FLOW,
+        dsl_input,
+        DECISION_TRACE_EVENT_FLOW.to_string(),
+        ApiMethod::Internal,
+        key.payment_id.get_string_repr().to_string(),
+        key.profile_id.clone(),
+        key.merchant_id.clone(),
+        state.request_id.clone(),
+        RoutingEngine::Euclid,
+    );
+    routing_event.set_routable_connectors(trace.routed_connectors.clone());
+    if let Some(final_connector) = trace.final_connector.clone() {
+        routing_event.set_payment_connector(final_connector);
+    }
+    if let Some(routing_approach) = &trace.routing_approach {
+        routing_event.set_routing_approach(routing_approach.to_string());
+    }
+    routing_event.set_response_body(&trace);
+    routing_event.set_status_code(200);
+    state.event_handler().log_event(&routing_event);
+}
+
+/// Retrieves the decision traces recorded for the attempts of a payment
+pub async fn retrieve_decision_traces(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<id_type::ProfileId>,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<routing_types::RoutingDecisionTraceResponse> {
+    let processor = platform.get_processor();
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_processor_merchant_id(
+            &payment_id,
+            processor.get_account().get_id(),
+            processor.get_key_store(),
+            processor.get_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    let traces = build_decision_traces(
+        get_decision_trace_entries(&state, processor.get_account().get_id(), &payment_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to read routing decision traces from redis")?,
+    );
+
+    if traces.is_empty() {
+        return Err(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No routing decision trace found for the payment, tracing may be disabled or the trace may have expired".to_string(),
+        }
+        .into());
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        routing_types::RoutingDecisionTraceResponse { payment_id, traces },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use euclid::{backend, backend::inputs as dsl_inputs, enums};
+
+    use super::*;
+
+    fn make_entry(
+        attempt_id: &str,
+        recorded_at: time::PrimitiveDateTime,
+        kind: DecisionTraceEntryKind,
+    ) -> DecisionTraceEntry {
+        DecisionTraceEntry {
+            attempt_id: attempt_id.to_string(),
+            recorded_at,
+            kind,
+        }
+    }
+
+    fn make_evaluation(algorithm_id: &str) -> routing_types::RuleEvaluationTrace {
+        routing_types::RuleEvaluationTrace {
+            decision_type: routing_types::DecisionTraceType::Routing,
+            algorithm_id: Some(algorithm_id.to_string()),
+            dsl_input: serde_json::Value::Null,
+            matched_rule: None,
+            output: serde_json::Value::Null,
+        }
+    }
+
+    #[test]
+    fn test_build_decision_traces_groups_entries_by_attempt() {
+        let now = common_utils::date_time::now();
+        let entries = vec![
+            make_entry(
+                "attempt_2",
+                now + time::Duration::seconds(3),
+                DecisionTraceEntryKind::RuleEvaluations {
+                    evaluations: vec![make_evaluation("algorithm_3")],
+                },
+            ),
+            make_entry(
+                "attempt_1",
+                now + time::Duration::seconds(2),
+                DecisionTraceEntryKind::RoutingOutcome {
+                    routed_connectors: Vec::new(),
+                    routing_approach: common_enums::RoutingApproach::RuleBasedRouting,
+                    eligibility: None,
+                    final_connector: None,
+                },
+            ),
+            make_entry(
+                "attempt_1",
+                now + time::Duration::seconds(1),
+                DecisionTraceEntryKind::RuleEvaluations {
+                    evaluations: vec![make_evaluation("algorithm_2")],
+                },
+            ),
+            make_entry(
+                "attempt_1",
+                now,
+                DecisionTraceEntryKind::RuleEvaluations {
+                    evaluations: vec![make_evaluation("algorithm_1")],
+                },
+            ),
+        ];
+
+        let traces = build_decision_traces(entries);
+
+        assert_eq!(
+            traces
+                .iter()
+                .map(|trace| trace.attempt_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["attempt_1", "attempt_2"]
+        );
+        let first_trace = traces.first().unwrap();
+        assert_eq!(first_trace.created_at, now);
+        assert_eq!(
+            first_trace
+                .rule_evaluations
+                .iter()
+                .filter_map(|evaluation| evaluation.algorithm_id.as_deref())
+                .collect::<Vec<_>>(),
+            vec!["algorithm_1", "algorithm_2"]
+        );
+        assert_eq!(
+            first_trace.routing_approach,
+            Some(common_enums::RoutingApproach::RuleBasedRouting)
+        );
+        assert!(traces.last().unwrap().routing_approach.is_none());
+    }
+
+    #[test]
+    fn test_decision_trace_entry_field_names() {
+        let now = common_utils::date_time::now();
+        let evaluations_entry = || {
+            make_entry(
+                "attempt_1",
+                now,
+                DecisionTraceEntryKind::RuleEvaluations {
+                    evaluations: Vec::new(),
+                },
+            )
+        };
+        let outcome_entry = || {
+            make_entry(
+                "attempt_1",
+                now,
+                DecisionTraceEntryKind::RoutingOutcome {
+                    routed_connectors: Vec::new(),
+                    routing_approach: common_enums::RoutingApproach::DefaultFallback,
+                    eligibility: None,
+                    final_connector: None,
+                },
+            )
+        };
+
+        // Evaluations recorded concurrently must not overwrite each other
+        assert_ne!(
+            evaluations_entry().get_field_name(),
+            evaluations_entry().get_field_name()
+        );
+        assert_eq!(
+            outcome_entry().get_field_name(),
+            "attempt_1_routing_outcome"
+        );
+    }
+
+    #[test]
+    fn test_rule_evaluation_trace_redacts_dsl_input() {
+        let backend_input = dsl_inputs::BackendInput {
+            metadata: Some(
+                [("customer_email".to_string(), "john@example.com".to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+            payment: dsl_inputs::PaymentInput {
+                amount: common_utils::types::MinorUnit::new(100),
+                transaction_initiator: None,
+                card_bin: Some("424242".to_string()),
+                extended_card_bin: Some("42424242".to_string()),
+                currency: enums::Currency::USD,
+                authentication_type: None,
+                capture_method: None,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: dsl_inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: None,
+                card_network: None,
+                card_discovery: None,
+            },
+            mandate: dsl_inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            time_data: None,
+            velocity_data: None,
+        };
+        let output = backend::BackendOutput {
+            rule_name: Some("card_rule".to_string()),
+            connector_selection: serde_json::json!(["stripe"]),
+        };
+
+        let evaluation = routing_types::RuleEvaluationTrace::new(
+            routing_types::DecisionTraceType::Routing,
+            None,
+            &backend_input,
+            &output,
+        );
+
+        let redacted = serde_json::Value::String(common_utils::pii::REDACTED.to_string());
+        assert_eq!(
+            evaluation.dsl_input.pointer("/metadata/customer_email"),
+            Some(&redacted)
+        );
+        assert_eq!(
+            evaluation.dsl_input.pointer("/payment/card_bin"),
+            Some(&redacted)
+        );
+        assert_eq!(
+            evaluation.dsl_input.pointer("/payment/extended_card_bin"),
+            Some(&redacted)
+        );
+        assert_eq!(
+            evaluation.dsl_input.pointer("/payment/amount"),
+            Some(&serde_json::json!(100))
+        );
+        assert_eq!(evaluation.matched_rule.as_deref(), Some("card_rule"));
+    }
+}
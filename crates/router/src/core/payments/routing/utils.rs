@@ -1121,6 +1121,22 @@ pub fn convert_backend_input_to_routing_eval(
         );
     }
 
+    // Time
+    if let Some(time) = input.time_data {
+        params.insert(
+            "time_of_day".to_string(),
+            Some(ValueType::Number(u64::from(time.time_of_day))),
+        );
+        params.insert(
+            "day_of_week".to_string(),
+            Some(ValueType::EnumVariant(time.day_of_week.to_string())),
+        );
+        params.insert(
+            "date".to_string(),
+            Some(ValueType::Number(u64::from(time.date))),
+        );
+    }
+
     // Metadata
     if let Some(meta) = input.metadata {
         for (k, v) in meta.into_iter() {
@@ -0,0 +1,414 @@
+use api_models::process_tracker::admin as process_tracker_api;
+use common_utils::ext_traits::OptionExt;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    services,
+    types::{storage, transformers::ForeignFrom},
+    SessionState,
+};
+
+impl ForeignFrom<storage::ProcessTracker> for process_tracker_api::ProcessTrackerResponse {
+    fn foreign_from(process: storage::ProcessTracker) -> Self {
+        Self {
+            id: process.id,
+            name: process.name,
+            runner: process.runner,
+            tag: process.tag,
+            status: process.status,
+            business_status: process.business_status,
+            schedule_time: process.schedule_time,
+            tracking_data: process.tracking_data,
+            retry_history: process_tracker_api::ProcessTrackerRetryHistory {
+                retry_count: process.retry_count,
+                events: process.event,
+            },
+            created_at: process.created_at,
+            updated_at: process.updated_at,
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn list_processes(
+    state: SessionState,
+    request: process_tracker_api::ProcessTrackerListRequest,
+) -> RouterResponse<process_tracker_api::ProcessTrackerListResponse> {
+    let limit = validate_list_request(&request)?;
+
+    let processes = state
+        .store
+        .list_processes_by_constraints(
+            request.runner,
+            request.status,
+            request.merchant_id.as_ref(),
+            request.schedule_time_start,
+            request.schedule_time_end,
+            i64::from(limit),
+            i64::from(request.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list process tracker entries")?;
+
+    let data = processes
+        .into_iter()
+        .map(process_tracker_api::ProcessTrackerResponse::foreign_from)
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        process_tracker_api::ProcessTrackerListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_process(
+    state: SessionState,
+    process_tracker_id: String,
+) -> RouterResponse<process_tracker_api::ProcessTrackerResponse> {
+    let process = find_process(&state, &process_tracker_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        process_tracker_api::ProcessTrackerResponse::foreign_from(process),
+    ))
+}
+
+/// Requeues the task to be picked up by the producer in its next run
+#[instrument(skip_all)]
+pub async fn run_process_now(
+    state: SessionState,
+    process_tracker_id: String,
+    request: process_tracker_api::ProcessTrackerRunRequest,
+) -> RouterResponse<process_tracker_api::ProcessTrackerResponse> {
+    let process = find_process(&state, &process_tracker_id).await?;
+    validate_process_not_in_flight(&process, request.force)?;
+
+    let schedule_time = common_utils::date_time::now();
+    let event = get_events_with_manual_action(
+        &process,
+        &format!("requeued to run now from status {}", process.status),
+    )?;
+
+    update_process(
+        &state,
+        process,
+        storage::ProcessTrackerUpdate::ManualUpdate {
+            status: storage::enums::ProcessTrackerStatus::Pending,
+            business_status: None,
+            schedule_time: Some(schedule_time),
+            event,
+        },
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn reschedule_process(
+    state: SessionState,
+    process_tracker_id: String,
+    request: process_tracker_api::ProcessTrackerRescheduleRequest,
+) -> RouterResponse<process_tracker_api::ProcessTrackerResponse> {
+    // The producer only picks up tasks scheduled around the time it runs
+    if request.schedule_time < common_utils::date_time::now() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "schedule_time should be in the future, run the task to execute it now"
+                .to_string(),
+        }
+        .into());
+    }
+
+    let process = find_process(&state, &process_tracker_id).await?;
+    validate_process_not_in_flight(&process, request.force)?;
+
+    let event = get_events_with_manual_action(
+        &process,
+        &format!(
+            "rescheduled to {} from status {}",
+            request.schedule_time, process.status
+        ),
+    )?;
+
+    update_process(
+        &state,
+        process,
+        storage::ProcessTrackerUpdate::ManualUpdate {
+            status: storage::enums::ProcessTrackerStatus::Pending,
+            business_status: None,
+            schedule_time: Some(request.schedule_time),
+            event,
+        },
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn finish_process(
+    state: SessionState,
+    process_tracker_id: String,
+    request: process_tracker_api::ProcessTrackerFinishRequest,
+) -> RouterResponse<process_tracker_api::ProcessTrackerResponse> {
+    if request.business_status.trim().is_empty() || request.business_status.len() > 255 {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "business_status should be between 1 and 255 characters".to_string(),
+        }
+        .into());
+    }
+
+    let process = find_process(&state, &process_tracker_id).await?;
+    validate_process_not_in_flight(&process, request.force)?;
+
+    let event = get_events_with_manual_action(
+        &process,
+        &format!(
+            "finished from status {} with business status {}{}",
+            process.status,
+            request.business_status,
+            request
+                .reason
+                .as_ref()
+                .map(|reason| format!(", reason: {reason}"))
+                .unwrap_or_default()
+        ),
+    )?;
+
+    update_process(
+        &state,
+        process,
+        storage::ProcessTrackerUpdate::ManualUpdate {
+            status: storage::enums::ProcessTrackerStatus::Finish,
+            business_status: Some(request.business_status),
+            schedule_time: None,
+            event,
+        },
+    )
+    .await
+}
+
+/// Returns the number of tasks to be listed
+fn validate_list_request(
+    request: &process_tracker_api::ProcessTrackerListRequest,
+) -> RouterResult<u32> {
+    let limit = request
+        .limit
+        .unwrap_or(process_tracker_api::DEFAULT_PROCESS_TRACKER_LIST_LIMIT);
+    if limit == 0 || limit > process_tracker_api::MAX_PROCESS_TRACKER_LIST_LIMIT {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "limit should be between 1 and {}",
+                process_tracker_api::MAX_PROCESS_TRACKER_LIST_LIMIT
+            ),
+        }
+        .into());
+    }
+
+    if let Some((start, end)) = request.schedule_time_start.zip(request.schedule_time_end) {
+        if start > end {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "schedule_time_start should not be later than schedule_time_end"
+                    .to_string(),
+            }
+            .into());
+        }
+    }
+
+    Ok(limit)
+}
+
+async fn find_process(
+    state: &SessionState,
+    process_tracker_id: &str,
+) -> RouterResult<storage::ProcessTracker> {
+    state
+        .store
+        .find_process_by_id(process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch process tracker entry")?
+        .get_required_value("process_tracker")
+        .change_context(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Process tracker entry with id {process_tracker_id} not found"),
+        })
+}
+
+async fn update_process(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    process_tracker_update: storage::ProcessTrackerUpdate,
+) -> RouterResponse<process_tracker_api::ProcessTrackerResponse> {
+    let process_tracker_id = process.id.clone();
+    let updated_process = state
+        .store
+        .update_process(process, process_tracker_update)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Process tracker entry with id {process_tracker_id} not found"),
+        })
+        .attach_printable("Failed to update process tracker entry")?;
+
+    logger::info!(
+        process_tracker_id = %process_tracker_id,
+        status = %updated_process.status,
+        "Process tracker entry updated manually"
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        process_tracker_api::ProcessTrackerResponse::foreign_from(updated_process),
+    ))
+}
+
+/// Tasks picked up by the producer may be executed by a consumer at any moment, updating them
+/// could lead to the task being executed twice or its update being overwritten by the consumer
+fn validate_process_not_in_flight(
+    process: &storage::ProcessTracker,
+    force: bool,
+) -> RouterResult<()> {
+    match process.status {
+        storage::enums::ProcessTrackerStatus::Processing
+        | storage::enums::ProcessTrackerStatus::ProcessStarted
+            if !force =>
+        {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Process tracker entry is in {} status, set force to update it anyway",
+                    process.status
+                ),
+            }
+            .into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The events of a task after appending the description of an operator action
+fn get_events_with_manual_action(
+    process: &storage::ProcessTracker,
+    description: &str,
+) -> RouterResult<Vec<String>> {
+    let timestamp = common_utils::date_time::date_as_yyyymmddthhmmssmmmz()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to format the current time")?;
+
+    let mut events = process.event.clone();
+    events.push(format!("{timestamp} manual: {description}"));
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_list_request() -> process_tracker_api::ProcessTrackerListRequest {
+        process_tracker_api::ProcessTrackerListRequest {
+            runner: None,
+            status: None,
+            merchant_id: None,
+            schedule_time_start: None,
+            schedule_time_end: None,
+            limit: None,
+            offset: None,
+        }
+    }
+
+    fn get_process(status: storage::enums::ProcessTrackerStatus) -> storage::ProcessTracker {
+        let now = common_utils::date_time::now();
+        storage::ProcessTracker {
+            id: "pt_test".to_string(),
+            name: Some("TEST".to_string()),
+            tag: vec!["TEST".to_string()],
+            runner: Some("PAYMENTS_SYNC_WORKFLOW".to_string()),
+            retry_count: 0,
+            schedule_time: Some(now),
+            rule: String::new(),
+            tracking_data: serde_json::json!({}),
+            business_status: "Pending".to_string(),
+            status,
+            event: vec!["previous event".to_string()],
+            created_at: now,
+            updated_at: now,
+            version: common_enums::ApiVersion::V1,
+            application_source: None,
+        }
+    }
+
+    #[test]
+    fn test_list_request_limit() {
+        assert_eq!(
+            validate_list_request(&get_list_request()).ok(),
+            Some(process_tracker_api::DEFAULT_PROCESS_TRACKER_LIST_LIMIT)
+        );
+
+        for (limit, is_valid) in [
+            (0, false),
+            (1, true),
+            (process_tracker_api::MAX_PROCESS_TRACKER_LIST_LIMIT, true),
+            (
+                process_tracker_api::MAX_PROCESS_TRACKER_LIST_LIMIT + 1,
+                false,
+            ),
+        ] {
+            let request = process_tracker_api::ProcessTrackerListRequest {
+                limit: Some(limit),
+                ..get_list_request()
+            };
+            assert_eq!(validate_list_request(&request).is_ok(), is_valid, "{limit}");
+        }
+    }
+
+    #[test]
+    fn test_list_request_schedule_time_range() {
+        let now = common_utils::date_time::now();
+        let later = now.saturating_add(time::Duration::hours(1));
+
+        let request = process_tracker_api::ProcessTrackerListRequest {
+            schedule_time_start: Some(now),
+            schedule_time_end: Some(later),
+            ..get_list_request()
+        };
+        assert!(validate_list_request(&request).is_ok());
+
+        let request = process_tracker_api::ProcessTrackerListRequest {
+            schedule_time_start: Some(later),
+            schedule_time_end: Some(now),
+            ..get_list_request()
+        };
+        assert!(validate_list_request(&request).is_err());
+    }
+
+    #[test]
+    fn test_process_in_flight_requires_force() {
+        for status in [
+            storage::enums::ProcessTrackerStatus::Processing,
+            storage::enums::ProcessTrackerStatus::ProcessStarted,
+        ] {
+            let process = get_process(status);
+            assert!(validate_process_not_in_flight(&process, false).is_err());
+            assert!(validate_process_not_in_flight(&process, true).is_ok());
+        }
+
+        for status in [
+            storage::enums::ProcessTrackerStatus::New,
+            storage::enums::ProcessTrackerStatus::Pending,
+            storage::enums::ProcessTrackerStatus::Finish,
+        ] {
+            assert!(validate_process_not_in_flight(&get_process(status), false).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_manual_action_is_appended_to_events() {
+        let process = get_process(storage::enums::ProcessTrackerStatus::Pending);
+        let events = get_events_with_manual_action(&process, "requeued to run now")
+            .expect("Failed to append the manual action");
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events.first().map(String::as_str), Some("previous event"));
+        assert!(events
+            .last()
+            .is_some_and(|event| event.ends_with(" manual: requeued to run now")));
+    }
+}
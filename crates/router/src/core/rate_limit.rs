@@ -0,0 +1,378 @@
+use std::collections::HashMap;
+
+use common_utils::ext_traits::StringExt;
+use error_stack::report;
+use redis_interface::RedisKey;
+use router_env::{instrument, logger, tracing};
+
+use super::errors::{self, RouterResult};
+use crate::{
+    configs::settings::RateLimitSettings,
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services::authentication::AuthenticationType,
+};
+
+pub const RATE_LIMIT_PREFIX: &str = "RATE_LIMIT";
+
+/// Refills the token bucket of each key, and takes a token from all of them only if none is
+/// empty, so that a rejected request does not count against any of the limits. Replies with the
+/// milliseconds after which the request may be retried, `0` if it is allowed.
+///
+/// The clock of the redis server is used, so that the router instances need not agree on the time.
+///
+/// KEYS: the buckets
+/// ARGV[1]: the window in milliseconds, over which an empty bucket is refilled
+/// ARGV[2..]: the capacity of each bucket
+const TOKEN_BUCKET_SCRIPT: &str = r#"
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local window = tonumber(ARGV[1])
+local retry_after = 0
+local tokens = {}
+
+for index, key in ipairs(KEYS) do
+    local capacity = tonumber(ARGV[index + 1])
+    local bucket = redis.call('HMGET', key, 'tokens', 'updated_at')
+    local available = tonumber(bucket[1]) or capacity
+    local updated_at = tonumber(bucket[2]) or now
+    available = math.min(capacity, available + math.max(0, now - updated_at) * capacity / window)
+    if available < 1 then
+        retry_after = math.max(retry_after, math.ceil((1 - available) * window / capacity))
+    end
+    tokens[index] = available
+end
+
+if retry_after == 0 then
+    for index, key in ipairs(KEYS) do
+        redis.call('HSET', key, 'tokens', tokens[index] - 1, 'updated_at', now)
+        redis.call('PEXPIRE', key, window)
+    end
+end
+
+return retry_after
+"#;
+
+#[derive(Debug, PartialEq)]
+struct Bucket {
+    key: String,
+    capacity: u32,
+}
+
+/// Limits of a merchant replacing the ones of the `rate_limit` settings, kept in the
+/// `rate_limits_{merchant_id}` config so that they can be changed without a deployment
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(default)]
+struct MerchantRateLimits {
+    merchant_limit: Option<u32>,
+    api_key_limit: Option<u32>,
+    /// Limits of the route groups, by group name
+    route_groups: HashMap<String, u32>,
+}
+
+impl MerchantRateLimits {
+    fn has_zero_limit(&self) -> bool {
+        [self.merchant_limit, self.api_key_limit]
+            .into_iter()
+            .flatten()
+            .chain(self.route_groups.values().copied())
+            .any(|limit| limit == 0)
+    }
+}
+
+/// Limits configured for the merchant, the settings apply if there are none or they could not be
+/// read. A missing config is cached like an empty one, so that it is not looked up in the
+/// database on every request.
+async fn get_merchant_rate_limits(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> Option<MerchantRateLimits> {
+    let key = merchant_id.get_rate_limits_config_key();
+
+    let merchant_limits: MerchantRateLimits = db
+        .find_config_by_key_unwrap_or(&key, Some(String::from("{}")))
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to read rate limits config `{key}`"))
+        .ok()?
+        .config
+        .parse_struct("MerchantRateLimits")
+        .map_err(|error| logger::error!(?error, "Failed to parse rate limits config `{key}`"))
+        .ok()?;
+
+    if merchant_limits.has_zero_limit() {
+        logger::error!("Rate limits config `{key}` is ignored, the limits must not be 0");
+        return None;
+    }
+
+    Some(merchant_limits)
+}
+
+/// Buckets of the limits applying to the request, the keys of a merchant share a hash tag so
+/// that the script can be run on them in cluster mode
+fn get_buckets(
+    settings: &RateLimitSettings,
+    merchant_limits: Option<&MerchantRateLimits>,
+    merchant_id: &common_utils::id_type::MerchantId,
+    key_id: Option<&common_utils::id_type::ApiKeyId>,
+    flow: &str,
+) -> Vec<Bucket> {
+    let key_prefix = format!("{RATE_LIMIT_PREFIX}_{{{}}}", merchant_id.get_string_repr());
+
+    let merchant_bucket = merchant_limits
+        .and_then(|limits| limits.merchant_limit)
+        .or(settings.merchant_limit)
+        .map(|capacity| Bucket {
+            key: format!("{key_prefix}_merchant"),
+            capacity,
+        });
+
+    let api_key_bucket = key_id.and_then(|key_id| {
+        merchant_limits
+            .and_then(|limits| limits.api_key_limit)
+            .or(settings.api_key_limit)
+            .map(|capacity| Bucket {
+                key: format!("{key_prefix}_api_key_{}", key_id.get_string_repr()),
+                capacity,
+            })
+    });
+
+    let route_group_buckets = settings
+        .route_groups
+        .iter()
+        .filter(|(_, group)| group.flows.contains(flow))
+        .map(|(name, group)| Bucket {
+            key: format!("{key_prefix}_route_group_{name}"),
+            capacity: merchant_limits
+                .and_then(|limits| limits.route_groups.get(name).copied())
+                .unwrap_or(group.limit),
+        });
+
+    merchant_bucket
+        .into_iter()
+        .chain(api_key_bucket)
+        .chain(route_group_buckets)
+        .collect()
+}
+
+/// Rejects the request with a `429` if the merchant, the API key or the route group it was made
+/// with is over its limit. Requests not made on behalf of a merchant are not limited.
+///
+/// The limiter fails open: the request is allowed if redis could not be reached.
+#[instrument(skip_all)]
+pub async fn check_rate_limits(
+    state: &SessionState,
+    flow: &str,
+    auth_type: &AuthenticationType,
+) -> RouterResult<()> {
+    let settings = &state.conf.rate_limit;
+    if !settings.enabled {
+        return Ok(());
+    }
+
+    let Some(merchant_id) = auth_type.get_merchant_id() else {
+        return Ok(());
+    };
+    let key_id = match auth_type {
+        AuthenticationType::ApiKey { key_id, .. } => Some(key_id),
+        _ => None,
+    };
+
+    let merchant_limits = get_merchant_rate_limits(&*state.store, merchant_id).await;
+    let buckets = get_buckets(
+        settings,
+        merchant_limits.as_ref(),
+        merchant_id,
+        key_id,
+        flow,
+    );
+    if buckets.is_empty() {
+        return Ok(());
+    }
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection for rate limiting");
+            return Ok(());
+        }
+    };
+
+    let keys = buckets
+        .iter()
+        .map(|bucket| RedisKey::from(bucket.key.as_str()).tenant_aware_key(&redis_conn))
+        .collect();
+    let args = std::iter::once(u64::from(settings.window_in_seconds) * 1000)
+        .chain(buckets.iter().map(|bucket| u64::from(bucket.capacity)))
+        .collect::<Vec<_>>();
+
+    let retry_after_milliseconds = match redis_conn
+        .evaluate_redis_script::<_, u64>(TOKEN_BUCKET_SCRIPT, keys, args)
+        .await
+    {
+        Ok(retry_after_milliseconds) => retry_after_milliseconds,
+        Err(error) => {
+            logger::error!(?error, "Failed to evaluate the rate limits of the request");
+            return Ok(());
+        }
+    };
+
+    if retry_after_milliseconds == 0 {
+        return Ok(());
+    }
+
+    logger::warn!(
+        ?buckets,
+        flow,
+        "Request rejected for exceeding a rate limit"
+    );
+    metrics::REQUESTS_RATE_LIMITED
+        .add(1, router_env::metric_attributes!(("flow", flow.to_owned())));
+
+    Err(report!(errors::ApiErrorResponse::TooManyRequests {
+        retry_after_seconds: retry_after_milliseconds.div_ceil(1000),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, collections::HashSet};
+
+    use common_utils::id_type;
+
+    use super::*;
+    use crate::configs::settings::RouteGroupRateLimit;
+
+    fn get_settings() -> RateLimitSettings {
+        RateLimitSettings {
+            enabled: true,
+            window_in_seconds: 1,
+            merchant_limit: Some(200),
+            api_key_limit: Some(100),
+            route_groups: HashMap::from([(
+                "payment_creates".to_string(),
+                RouteGroupRateLimit {
+                    flows: HashSet::from(["PaymentsCreate".to_string()]),
+                    limit: 50,
+                },
+            )]),
+        }
+    }
+
+    fn get_ids() -> (id_type::MerchantId, id_type::ApiKeyId) {
+        (
+            id_type::MerchantId::try_from(Cow::from("merchant_1")).expect("invalid merchant id"),
+            id_type::ApiKeyId::try_from(Cow::from("key_1")).expect("invalid key id"),
+        )
+    }
+
+    #[test]
+    fn test_get_buckets() {
+        let (merchant_id, key_id) = get_ids();
+
+        let buckets = get_buckets(
+            &get_settings(),
+            None,
+            &merchant_id,
+            Some(&key_id),
+            "PaymentsCreate",
+        );
+
+        assert_eq!(
+            buckets,
+            vec![
+                Bucket {
+                    key: "RATE_LIMIT_{merchant_1}_merchant".to_string(),
+                    capacity: 200,
+                },
+                Bucket {
+                    key: "RATE_LIMIT_{merchant_1}_api_key_key_1".to_string(),
+                    capacity: 100,
+                },
+                Bucket {
+                    key: "RATE_LIMIT_{merchant_1}_route_group_payment_creates".to_string(),
+                    capacity: 50,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_buckets_of_flows_outside_route_groups_and_without_api_key() {
+        let (merchant_id, _) = get_ids();
+
+        let buckets = get_buckets(
+            &get_settings(),
+            None,
+            &merchant_id,
+            None,
+            "PaymentsRetrieve",
+        );
+
+        assert_eq!(
+            buckets,
+            vec![Bucket {
+                key: "RATE_LIMIT_{merchant_1}_merchant".to_string(),
+                capacity: 200,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_get_buckets_with_merchant_rate_limits() {
+        let (merchant_id, key_id) = get_ids();
+        let merchant_limits: MerchantRateLimits =
+            r#"{"merchant_limit": 1000, "route_groups": {"payment_creates": 250, "refunds": 10}}"#
+                .to_string()
+                .parse_struct("MerchantRateLimits")
+                .expect("invalid merchant rate limits");
+
+        let buckets = get_buckets(
+            &get_settings(),
+            Some(&merchant_limits),
+            &merchant_id,
+            Some(&key_id),
+            "PaymentsCreate",
+        );
+
+        // The limits missing from the merchant rate limits are those of the settings, and route
+        // groups which are not configured are ignored
+        assert_eq!(
+            buckets
+                .iter()
+                .map(|bucket| bucket.capacity)
+                .collect::<Vec<_>>(),
+            vec![1000, 100, 250]
+        );
+    }
+
+    #[test]
+    fn test_get_buckets_without_limits() {
+        let (merchant_id, key_id) = get_ids();
+        let settings = RateLimitSettings {
+            merchant_limit: None,
+            api_key_limit: None,
+            route_groups: HashMap::new(),
+            ..get_settings()
+        };
+
+        assert!(get_buckets(
+            &settings,
+            None,
+            &merchant_id,
+            Some(&key_id),
+            "PaymentsCreate"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_merchant_rate_limits_with_zero_limit() {
+        let merchant_limits: MerchantRateLimits = r#"{"route_groups": {"payment_creates": 0}}"#
+            .to_string()
+            .parse_struct("MerchantRateLimits")
+            .expect("invalid merchant rate limits");
+        assert!(merchant_limits.has_zero_limit());
+
+        assert!(!MerchantRateLimits::default().has_zero_limit());
+    }
+}
@@ -2091,9 +2091,14 @@ pub async fn retry_refund_sync_task(
     merchant_id: common_utils::id_type::MerchantId,
     pt: storage::ProcessTracker,
 ) -> Result<bool, sch_errors::ProcessTrackerError> {
-    let schedule_time =
-        get_refund_sync_process_schedule_time(db, &connector, &merchant_id, pt.retry_count + 1)
-            .await?;
+    let schedule_time = get_refund_sync_process_schedule_time(
+        db,
+        &connector,
+        &merchant_id,
+        pt.retry_count + 1,
+        Some(&pt.id),
+    )
+    .await?;
 
     match schedule_time {
         Some(s_time) => {
@@ -2269,7 +2274,7 @@ pub async fn add_refund_sync_task(
     let task = "SYNC_REFUND";
     let process_tracker_id = format!("{runner}_{task}_{}", refund.internal_reference_id);
     let schedule_time =
-        get_refund_sync_process_schedule_time(db, &refund.connector, &refund.merchant_id, 0)
+        get_refund_sync_process_schedule_time(db, &refund.connector, &refund.merchant_id, 0, None)
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to fetch schedule time for refund sync process")?
@@ -2349,7 +2354,23 @@ pub async fn get_refund_sync_process_schedule_time(
     connector: &str,
     merchant_id: &common_utils::id_type::MerchantId,
     retry_count: i32,
+    process_tracker_id: Option<&str>,
 ) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
+    if let Some(policy) = process_tracker_utils::get_runner_retry_policy(
+        db,
+        storage::ProcessTrackerRunner::RefundWorkflowRouter,
+    )
+    .await
+    {
+        let time_delta = process_tracker_utils::get_runner_retry_schedule_time(
+            policy,
+            merchant_id,
+            retry_count,
+            process_tracker_id,
+        );
+        return Ok(process_tracker_utils::get_time_from_delta(time_delta));
+    }
+
     let mapping: common_utils::errors::CustomResult<
         process_data::ConnectorPTMapping,
         errors::StorageError,
@@ -1,4 +1,8 @@
 pub mod helpers;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod scheduled_activation;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod simulation;
 pub mod transformers;
 use std::collections::HashSet;
 
@@ -322,7 +326,18 @@ pub async fn create_routing_algorithm_under_profile(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let new_record = record.foreign_into();
+    let mut new_record: routing_types::RoutingDictionaryRecord = record.foreign_into();
+
+    if let routing_types::StaticRoutingAlgorithm::Advanced(program) = &request.algorithm {
+        new_record.warnings = helpers::get_routing_program_warnings(
+            &state,
+            platform.get_processor().get_key_store(),
+            business_profile.get_id(),
+            &transaction_type,
+            program,
+        )
+        .await;
+    }
 
     metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(1, &[]);
     Ok(service_api::ApplicationResponse::Json(new_record))
@@ -496,7 +511,18 @@ pub async fn create_routing_algorithm_under_profile(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let new_record = record.foreign_into();
+    let mut new_record: routing_types::RoutingDictionaryRecord = record.foreign_into();
+
+    if let EuclidAlgorithm::Advanced(program) = &algorithm {
+        new_record.warnings = helpers::get_routing_program_warnings(
+            &state,
+            platform.get_processor().get_key_store(),
+            business_profile.get_id(),
+            &transaction_type,
+            program,
+        )
+        .await;
+    }
 
     metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(1, &[]);
     Ok(service_api::ApplicationResponse::Json(new_record))
@@ -566,10 +592,19 @@ pub async fn link_routing_config_under_profile(
         )
         .await?;
 
+    let mut record: routing_types::RoutingDictionaryRecord =
+        routing_algorithm.0.clone().foreign_into();
+    record.warnings = helpers::get_routing_algorithm_warnings(
+        &state,
+        platform.get_processor().get_key_store(),
+        &profile_id,
+        transaction_type,
+        &routing_algorithm.0.algorithm_data,
+    )
+    .await;
+
     metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
-    Ok(service_api::ApplicationResponse::Json(
-        routing_algorithm.0.foreign_into(),
-    ))
+    Ok(service_api::ApplicationResponse::Json(record))
 }
 
 #[cfg(feature = "v1")]
@@ -897,10 +932,26 @@ pub async fn link_routing_config(
     )
     .await?;
 
+    // Connectors may have been disabled since the algorithm was created, so the program is
+    // analysed again on activation
+    let warnings = if routing_algorithm.kind == diesel_models::enums::RoutingAlgorithmKind::Advanced
+    {
+        helpers::get_routing_algorithm_warnings(
+            &state,
+            platform.get_processor().get_key_store(),
+            business_profile.get_id(),
+            &transaction_type,
+            &routing_algorithm.algorithm_data,
+        )
+        .await
+    } else {
+        Vec::new()
+    };
+    let mut record: routing_types::RoutingDictionaryRecord = routing_algorithm.foreign_into();
+    record.warnings = warnings;
+
     metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
-    Ok(service_api::ApplicationResponse::Json(
-        routing_algorithm.foreign_into(),
-    ))
+    Ok(service_api::ApplicationResponse::Json(record))
 }
 
 #[cfg(feature = "v2")]
@@ -975,6 +1026,90 @@ pub async fn retrieve_routing_algorithm_from_algorithm_id(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+#[cfg(all(feature = "v1", feature = "routing_text"))]
+pub async fn create_routing_algorithm_from_text(
+    state: SessionState,
+    platform: domain::Platform,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingTextConfigRequest,
+    transaction_type: enums::TransactionType,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    let request = request.try_into_config_request().map_err(|err| {
+        errors::ApiErrorResponse::InvalidRoutingProgram {
+            message: err.message,
+            line: err.line,
+            column: err.column,
+        }
+    })?;
+
+    create_routing_algorithm_under_profile(
+        state,
+        platform,
+        authentication_profile_id,
+        request,
+        transaction_type,
+    )
+    .await
+}
+
+/// Renders an advanced routing algorithm in the text form of the routing DSL
+#[cfg(feature = "v1")]
+pub async fn retrieve_routing_algorithm_as_text(
+    state: SessionState,
+    platform: domain::Platform,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<routing_types::RoutingTextConfigResponse> {
+    metrics::ROUTING_RETRIEVE_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            platform.get_processor().get_account().get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        platform.get_processor(),
+        Some(&routing_algorithm.profile_id),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let record = routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to parse routing algorithm")?;
+
+    let routing_types::RoutingAlgorithmWrapper::Static(
+        routing_types::StaticRoutingAlgorithm::Advanced(program),
+    ) = &record.algorithm
+    else {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Only advanced routing algorithms have a text form".to_string(),
+        }
+        .into());
+    };
+
+    let response = routing_types::RoutingTextConfigResponse {
+        program: routing_types::ast::formatter::format_program(program),
+        id: record.id,
+        profile_id: record.profile_id,
+        name: record.name,
+        description: record.description,
+        created_at: record.created_at,
+        modified_at: record.modified_at,
+    };
+
+    metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
 #[cfg(feature = "v2")]
 pub async fn unlink_routing_config_under_profile(
     state: SessionState,
@@ -2331,9 +2466,9 @@ pub async fn contract_based_routing_update_configs(
         .grpc_client
         .dynamic_routing
         .as_ref()
-        .async_map(|dr_client| async {
-            dr_client
-                .contract_based_client
+        .and_then(|dr_client| dr_client.contract_based_client.as_ref())
+        .async_map(|contract_based_client| async {
+            contract_based_client
                 .invalidate_contracts(
                     profile_id.get_string_repr().into(),
                     state.get_grpc_headers(),
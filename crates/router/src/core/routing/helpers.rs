@@ -574,6 +574,68 @@ pub async fn validate_connectors_in_routing_config(
     Ok(())
 }
 
+/// Analyses an advanced routing program for rules that can never be selected, rules that
+/// overlap with earlier rules and connectors that the merchant's constraint graph rejects.
+///
+/// The analysis is best effort, failures are logged and produce no warnings so that they never
+/// block the creation of a routing configuration.
+pub async fn get_routing_program_warnings(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    transaction_type: &api_models::enums::TransactionType,
+    program: &euclid::frontend::ast::Program<routing_types::ConnectorSelection>,
+) -> Vec<routing_types::RoutingAnalysisWarning> {
+    let dir_program = match euclid::frontend::ast::lowering::lower_program(program.clone()) {
+        Ok(dir_program) => dir_program,
+        Err(error) => {
+            router_env::logger::warn!(?error, "Failed to lower routing program for analysis");
+            return Vec::new();
+        }
+    };
+
+    let knowledge_graph = crate::core::payments::routing::get_merchant_cgraph(
+        state,
+        key_store,
+        profile_id,
+        transaction_type,
+    )
+    .await
+    .inspect_err(|error| {
+        router_env::logger::warn!(?error, "Failed to fetch constraint graph for analysis")
+    })
+    .ok();
+
+    euclid::dssa::analyzer::analyze_warnings(&dir_program, knowledge_graph.as_deref())
+        .map(|warnings| warnings.into_iter().map(From::from).collect())
+        .unwrap_or_else(|error| {
+            router_env::logger::warn!(?error, "Failed to analyse routing program");
+            Vec::new()
+        })
+}
+
+/// Analyses the routing algorithm stored in a routing configuration, only advanced algorithms
+/// can produce warnings
+pub async fn get_routing_algorithm_warnings(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    transaction_type: &api_models::enums::TransactionType,
+    algorithm_data: &serde_json::Value,
+) -> Vec<routing_types::RoutingAnalysisWarning> {
+    match serde_json::from_value::<routing_types::StaticRoutingAlgorithm>(algorithm_data.clone()) {
+        Ok(routing_types::StaticRoutingAlgorithm::Advanced(program)) => {
+            get_routing_program_warnings(state, key_store, profile_id, transaction_type, &program)
+                .await
+        }
+        Ok(_) => Vec::new(),
+        Err(error) => {
+            router_env::logger::warn!(?error, "Failed to parse routing algorithm for analysis");
+            Vec::new()
+        }
+    }
+}
+
 /// Provides the identifier for the specific merchant's routing_dictionary_key
 #[inline(always)]
 pub fn get_routing_dictionary_key(merchant_id: &str) -> String {
@@ -1389,14 +1451,14 @@ pub async fn push_metrics_with_update_window_for_contract_based_routing(
     if let Some(contract_routing_algo_ref) = dynamic_routing_algo_ref.contract_based_routing {
         if contract_routing_algo_ref.enabled_feature != routing_types::DynamicRoutingFeatures::None
         {
-            let client = &state
+            let client = state
                 .grpc_client
                 .dynamic_routing
                 .as_ref()
+                .and_then(|dynamic_routing| dynamic_routing.contract_based_client.as_ref())
                 .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
                     message: "dynamic routing gRPC client not found".to_string(),
-                })?
-                .contract_based_client;
+                })?;
 
             let payment_connector = &payment_attempt.connector.clone().ok_or(
                 errors::ApiErrorResponse::GenericNotFoundError {
@@ -0,0 +1,886 @@
+//! Activation of static routing algorithms at a scheduled time, optionally followed by a
+//! guard-rail which reverts to the previously active algorithm when the authorization rate of the
+//! payments routed by the static algorithm of the profile drops beyond the tolerated threshold.
+//!
+//! Both the activation and the monitoring of the guard-rail are performed by the
+//! `RoutingActivationWorkflow` process tracker task, whose ID identifies the schedule.
+
+use api_models::{
+    enums,
+    routing::{
+        self as routing_types, RoutingActivationDetails, RoutingActivationScheduleResponse,
+        RoutingActivationScheduleStatus, RoutingActivationScheduleWrapper, RoutingRollbackDetails,
+        RoutingRollbackGuardrail,
+    },
+    webhooks,
+};
+use common_utils::{date_time, ext_traits::Encode, fp_utils, id_type, types::TimeRange};
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use super::helpers;
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils, webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services::api as service_api,
+    types::{domain, storage},
+    utils::{OptionExt, ValueExt},
+};
+
+const ROUTING_ACTIVATION_TASK: &str = "ROUTING_ACTIVATION";
+const ROUTING_ACTIVATION_TAG: &str = "ROUTING";
+
+/// Interval at which the guard-rail of an activated algorithm is evaluated
+const GUARDRAIL_CHECK_INTERVAL_IN_MINUTES: i64 = 15;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingActivationTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub algorithm_id: id_type::RoutingId,
+    pub transaction_type: enums::TransactionType,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub activate_at: time::PrimitiveDateTime,
+    pub guardrail: Option<RoutingRollbackGuardrail>,
+    /// Present once the algorithm has been activated
+    pub activation: Option<RoutingActivationDetails>,
+}
+
+/// Result of a single run of the routing activation task
+pub enum RoutingActivationOutcome {
+    /// The guard-rail has to be evaluated again after the check interval
+    Monitoring(Box<RoutingActivationTrackingData>),
+    Completed,
+    RolledBack,
+    /// The algorithm active for the profile is not the one activated by the schedule
+    Superseded,
+}
+
+pub(crate) fn get_next_guardrail_check_time() -> time::PrimitiveDateTime {
+    date_time::now().saturating_add(time::Duration::minutes(GUARDRAIL_CHECK_INTERVAL_IN_MINUTES))
+}
+
+/// Authorization rate of the payment attempts of a profile which were routed by its static
+/// routing algorithm and reached a final status in a time range
+#[derive(Debug, PartialEq)]
+struct AuthRate {
+    authorized: u64,
+    attempts: u64,
+}
+
+impl AuthRate {
+    /// Attempts which are still in progress or were declined before reaching the connector are
+    /// left out
+    fn from_status_counts(
+        status_counts: impl IntoIterator<Item = (enums::AttemptStatus, i64)>,
+    ) -> Self {
+        status_counts.into_iter().fold(
+            Self {
+                authorized: 0,
+                attempts: 0,
+            },
+            |mut auth_rate, (status, count)| {
+                let count = u64::try_from(count).unwrap_or_default();
+                match status {
+                    enums::AttemptStatus::Authorized
+                    | enums::AttemptStatus::Charged
+                    | enums::AttemptStatus::PartialCharged
+                    | enums::AttemptStatus::PartialChargedAndChargeable
+                    | enums::AttemptStatus::PartiallyAuthorized
+                    | enums::AttemptStatus::CaptureInitiated
+                    | enums::AttemptStatus::CaptureFailed
+                    | enums::AttemptStatus::Voided
+                    | enums::AttemptStatus::VoidedPostCharge
+                    | enums::AttemptStatus::VoidInitiated
+                    | enums::AttemptStatus::VoidFailed
+                    | enums::AttemptStatus::AutoRefunded => {
+                        auth_rate.authorized += count;
+                        auth_rate.attempts += count;
+                    }
+                    enums::AttemptStatus::AuthorizationFailed | enums::AttemptStatus::Failure => {
+                        auth_rate.attempts += count
+                    }
+                    enums::AttemptStatus::Started
+                    | enums::AttemptStatus::AuthenticationFailed
+                    | enums::AttemptStatus::RouterDeclined
+                    | enums::AttemptStatus::AuthenticationPending
+                    | enums::AttemptStatus::AuthenticationSuccessful
+                    | enums::AttemptStatus::Authorizing
+                    | enums::AttemptStatus::CodInitiated
+                    | enums::AttemptStatus::Pending
+                    | enums::AttemptStatus::Unresolved
+                    | enums::AttemptStatus::PaymentMethodAwaited
+                    | enums::AttemptStatus::ConfirmationAwaited
+                    | enums::AttemptStatus::DeviceDataCollectionPending
+                    | enums::AttemptStatus::IntegrityFailure
+                    | enums::AttemptStatus::Expired => {}
+                }
+                auth_rate
+            },
+        )
+    }
+
+    #[allow(clippy::as_conversions)]
+    fn get_percentage(&self) -> Option<f64> {
+        (self.attempts > 0).then(|| (self.authorized as f64 * 100.0) / self.attempts as f64)
+    }
+}
+
+/// Routing approaches recorded on the attempts routed by a static routing algorithm
+fn get_static_routing_approaches() -> Vec<enums::RoutingApproach> {
+    vec![
+        enums::RoutingApproach::RuleBasedRouting,
+        enums::RoutingApproach::VolumeBasedRouting,
+    ]
+}
+
+/// Returns the baseline and observed authorization rates when the observed rate dropped below the
+/// baseline by more than the guard-rail tolerates
+fn find_guardrail_breach(
+    guardrail: &RoutingRollbackGuardrail,
+    baseline_auth_rate: Option<f64>,
+    observed: &AuthRate,
+) -> Option<(f64, f64)> {
+    baseline_auth_rate.zip(observed.get_percentage()).filter(
+        |(baseline_auth_rate, observed_auth_rate)| {
+            observed.attempts >= u64::from(guardrail.min_attempts)
+                && baseline_auth_rate - observed_auth_rate > guardrail.max_auth_rate_drop
+        },
+    )
+}
+
+#[instrument(skip_all)]
+pub async fn schedule_routing_activation(
+    state: SessionState,
+    platform: domain::Platform,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    request: RoutingActivationScheduleWrapper,
+) -> RouterResponse<RoutingActivationScheduleResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let RoutingActivationScheduleWrapper {
+        algorithm_id,
+        request,
+    } = request;
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(&algorithm_id, merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        platform.get_processor(),
+        Some(&routing_algorithm.profile_id),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: routing_algorithm.profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    fp_utils::when(
+        routing_algorithm.kind == diesel_models::enums::RoutingAlgorithmKind::Dynamic,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Only static routing algorithms can be scheduled for activation"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    fp_utils::when(request.activate_at <= date_time::now(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`activate_at` must be in the future".to_string(),
+        })
+    })?;
+
+    if let Some(guardrail) = &request.guardrail {
+        guardrail
+            .validate()
+            .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+                message: error.to_string(),
+            })?;
+
+        // The guard-rail is evaluated on the authorization rate of payments
+        fp_utils::when(
+            routing_algorithm.algorithm_for != enums::TransactionType::Payment,
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Guard-rails are only supported for payment routing algorithms"
+                        .to_string(),
+                })
+            },
+        )?;
+    }
+
+    let tracking_data = RoutingActivationTrackingData {
+        merchant_id: merchant_id.clone(),
+        profile_id: routing_algorithm.profile_id.clone(),
+        algorithm_id,
+        transaction_type: routing_algorithm.algorithm_for,
+        activate_at: request.activate_at,
+        guardrail: request.guardrail,
+        activation: None,
+    };
+
+    let process =
+        add_routing_activation_task(db, &tracking_data, state.conf.application_source).await?;
+
+    let warnings = if routing_algorithm.kind == diesel_models::enums::RoutingAlgorithmKind::Advanced
+    {
+        helpers::get_routing_algorithm_warnings(
+            &state,
+            platform.get_processor().get_key_store(),
+            business_profile.get_id(),
+            &routing_algorithm.algorithm_for,
+            &routing_algorithm.algorithm_data,
+        )
+        .await
+    } else {
+        Vec::new()
+    };
+
+    Ok(service_api::ApplicationResponse::Json(
+        RoutingActivationScheduleResponse {
+            warnings,
+            ..get_schedule_response(&process, tracking_data)
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_routing_activation_schedule(
+    state: SessionState,
+    platform: domain::Platform,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    schedule_id: String,
+) -> RouterResponse<RoutingActivationScheduleResponse> {
+    let (process, tracking_data) = find_routing_activation_schedule(
+        &state,
+        &platform,
+        authentication_profile_id,
+        &schedule_id,
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        get_schedule_response(&process, tracking_data),
+    ))
+}
+
+/// Cancels a schedule which has not yet completed. An algorithm which has already been activated
+/// remains active, only the monitoring of its guard-rail is stopped.
+#[instrument(skip_all)]
+pub async fn cancel_routing_activation_schedule(
+    state: SessionState,
+    platform: domain::Platform,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    schedule_id: String,
+) -> RouterResponse<RoutingActivationScheduleResponse> {
+    let (process, tracking_data) = find_routing_activation_schedule(
+        &state,
+        &platform,
+        authentication_profile_id,
+        &schedule_id,
+    )
+    .await?;
+
+    fp_utils::when(
+        process.status == storage::enums::ProcessTrackerStatus::Finish,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The scheduled routing activation has already completed".to_string(),
+            })
+        },
+    )?;
+
+    // The task is only finished if the scheduler has not picked it up in the meantime, a running
+    // task would otherwise set it back to pending once it is done
+    let is_cancelled = state
+        .store
+        .as_scheduler()
+        .finish_process_if_status_in(
+            &process.id,
+            vec![
+                storage::enums::ProcessTrackerStatus::New,
+                storage::enums::ProcessTrackerStatus::Pending,
+            ],
+            business_status::ROUTING_ACTIVATION_CANCELLED,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cancel the routing activation task")?;
+
+    fp_utils::when(!is_cancelled, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The scheduled routing activation is being processed or has already \
+                      completed, please retry shortly"
+                .to_string(),
+        })
+    })?;
+
+    let mut response = get_schedule_response(&process, tracking_data);
+    response.status = RoutingActivationScheduleStatus::Cancelled;
+
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+/// Activates the algorithm on the first run of the task, and evaluates its guard-rail on every
+/// subsequent run until the guard-rail is breached or the monitoring window ends.
+#[instrument(skip_all)]
+pub(crate) async fn perform_routing_activation(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+    mut tracking_data: RoutingActivationTrackingData,
+) -> RouterResult<RoutingActivationOutcome> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let business_profile = db
+        .find_business_profile_by_merchant_id_profile_id(
+            &key_store,
+            &tracking_data.merchant_id,
+            &tracking_data.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: tracking_data.profile_id.get_string_repr().to_owned(),
+        })?;
+    let platform = domain::Platform::new(
+        merchant_account.clone(),
+        key_store.clone(),
+        merchant_account,
+        key_store,
+        None,
+    );
+
+    let active_algorithm_id =
+        get_active_algorithm_id(&business_profile, &tracking_data.transaction_type)?;
+
+    let Some(activation) = tracking_data.activation.clone() else {
+        if active_algorithm_id.as_ref() == Some(&tracking_data.algorithm_id) {
+            logger::info!("Scheduled routing algorithm is already active for the profile");
+            return Ok(RoutingActivationOutcome::Superseded);
+        }
+
+        let activated_at = date_time::now();
+        let baseline = match &tracking_data.guardrail {
+            Some(guardrail) => Some(
+                get_auth_rate(
+                    state,
+                    &tracking_data.merchant_id,
+                    &tracking_data.profile_id,
+                    TimeRange {
+                        start_time: activated_at.saturating_sub(guardrail.get_monitoring_window()),
+                        end_time: Some(activated_at),
+                    },
+                )
+                .await?,
+            ),
+            None => None,
+        };
+
+        let Some((guardrail, baseline)) = tracking_data.guardrail.as_ref().zip(baseline) else {
+            Box::pin(super::link_routing_config(
+                state.clone(),
+                platform,
+                None,
+                tracking_data.algorithm_id.clone(),
+                tracking_data.transaction_type,
+            ))
+            .await?;
+            return Ok(RoutingActivationOutcome::Completed);
+        };
+
+        tracking_data.activation = Some(RoutingActivationDetails {
+            activated_at,
+            previous_algorithm_id: active_algorithm_id,
+            // A baseline over too few payments would make the guard-rail trip on noise
+            baseline_auth_rate: (baseline.attempts >= u64::from(guardrail.min_attempts))
+                .then(|| baseline.get_percentage())
+                .flatten(),
+            baseline_attempts: baseline.attempts,
+        });
+
+        // The activation is recorded before the algorithm is linked, so that a retry after a
+        // failure to start the monitoring does not take the activated algorithm for one
+        // activated by the merchant, and still knows the algorithm to roll back to
+        state
+            .store
+            .as_scheduler()
+            .update_process(
+                process.clone(),
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: None,
+                    tracking_data: Some(encode_tracking_data(&tracking_data)?),
+                    business_status: None,
+                    status: None,
+                    updated_at: Some(date_time::now()),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record the routing activation")?;
+
+        Box::pin(super::link_routing_config(
+            state.clone(),
+            platform,
+            None,
+            tracking_data.algorithm_id.clone(),
+            tracking_data.transaction_type,
+        ))
+        .await?;
+
+        return Ok(RoutingActivationOutcome::Monitoring(Box::new(
+            tracking_data,
+        )));
+    };
+
+    // The run which recorded the activation failed before the monitoring started, the algorithm
+    // is linked again unless that run already linked it
+    if process.business_status != business_status::ROUTING_GUARDRAIL_MONITORING
+        && active_algorithm_id.as_ref() != Some(&tracking_data.algorithm_id)
+    {
+        logger::info!("Resuming scheduled routing activation which did not start monitoring");
+        Box::pin(super::link_routing_config(
+            state.clone(),
+            platform,
+            None,
+            tracking_data.algorithm_id.clone(),
+            tracking_data.transaction_type,
+        ))
+        .await?;
+
+        tracking_data.activation = Some(RoutingActivationDetails {
+            activated_at: date_time::now(),
+            ..activation
+        });
+        return Ok(RoutingActivationOutcome::Monitoring(Box::new(
+            tracking_data,
+        )));
+    }
+
+    if active_algorithm_id.as_ref() != Some(&tracking_data.algorithm_id) {
+        logger::info!("Scheduled routing algorithm was replaced while it was being monitored");
+        return Ok(RoutingActivationOutcome::Superseded);
+    }
+
+    let guardrail = tracking_data
+        .guardrail
+        .clone()
+        .get_required_value("guardrail")?;
+    let now = date_time::now();
+    let observed = get_auth_rate(
+        state,
+        &tracking_data.merchant_id,
+        &tracking_data.profile_id,
+        TimeRange {
+            start_time: activation.activated_at,
+            end_time: Some(now),
+        },
+    )
+    .await?;
+
+    if let Some((baseline_auth_rate, observed_auth_rate)) =
+        find_guardrail_breach(&guardrail, activation.baseline_auth_rate, &observed)
+    {
+        logger::info!(
+            baseline_auth_rate,
+            observed_auth_rate,
+            observed_attempts = observed.attempts,
+            "Routing guard-rail breached, restoring the previously active algorithm"
+        );
+
+        restore_previous_algorithm(state, &platform, &tracking_data, &activation).await?;
+
+        let rollback_details = RoutingRollbackDetails {
+            schedule_id: process.id.clone(),
+            profile_id: tracking_data.profile_id.clone(),
+            transaction_type: tracking_data.transaction_type,
+            rolled_back_algorithm_id: tracking_data.algorithm_id.clone(),
+            restored_algorithm_id: activation.previous_algorithm_id.clone(),
+            guardrail,
+            baseline_auth_rate,
+            observed_auth_rate,
+            observed_attempts: observed.attempts,
+            activated_at: activation.activated_at,
+            rolled_back_at: now,
+        };
+        trigger_rollback_webhook(state, &platform, business_profile, rollback_details);
+
+        return Ok(RoutingActivationOutcome::RolledBack);
+    }
+
+    if now
+        >= activation
+            .activated_at
+            .saturating_add(guardrail.get_monitoring_window())
+    {
+        return Ok(RoutingActivationOutcome::Completed);
+    }
+
+    Ok(RoutingActivationOutcome::Monitoring(Box::new(
+        tracking_data,
+    )))
+}
+
+async fn restore_previous_algorithm(
+    state: &SessionState,
+    platform: &domain::Platform,
+    tracking_data: &RoutingActivationTrackingData,
+    activation: &RoutingActivationDetails,
+) -> RouterResult<()> {
+    match &activation.previous_algorithm_id {
+        Some(previous_algorithm_id) => {
+            Box::pin(super::link_routing_config(
+                state.clone(),
+                platform.clone(),
+                None,
+                previous_algorithm_id.clone(),
+                tracking_data.transaction_type,
+            ))
+            .await?;
+        }
+        None => {
+            let request = routing_types::RoutingConfigRequest {
+                name: None,
+                description: None,
+                algorithm: None,
+                profile_id: Some(tracking_data.profile_id.clone()),
+                transaction_type: Some(tracking_data.transaction_type),
+            };
+            Box::pin(super::unlink_routing_config(
+                state.clone(),
+                platform.clone(),
+                request,
+                None,
+                tracking_data.transaction_type,
+            ))
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+fn trigger_rollback_webhook(
+    state: &SessionState,
+    platform: &domain::Platform,
+    business_profile: domain::Profile,
+    rollback_details: RoutingRollbackDetails,
+) {
+    let cloned_state = state.clone();
+    let processor = platform.get_processor().clone();
+    let schedule_id = rollback_details.schedule_id.clone();
+    let activated_at = rollback_details.activated_at;
+
+    tokio::spawn(async move {
+        Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+            cloned_state,
+            processor,
+            business_profile,
+            enums::EventType::RoutingAlgorithmRolledBack,
+            enums::EventClass::Routing,
+            schedule_id,
+            enums::EventObjectType::RoutingRollbackDetails,
+            webhooks::OutgoingWebhookContent::RoutingRollbackDetails(Box::new(rollback_details)),
+            Some(activated_at),
+        ))
+        .await
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to trigger routing rollback outgoing webhook"
+            );
+        })
+    });
+}
+
+/// Counts the payment attempts of the profile created in the time range which were routed by its
+/// static routing algorithm. Activating any other algorithm supersedes the schedule, so these
+/// attempts are routed by the algorithm which was active for the profile during the time range.
+async fn get_auth_rate(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    time_range: TimeRange,
+) -> RouterResult<AuthRate> {
+    let status_counts = state
+        .store
+        .get_attempt_status_with_count(
+            merchant_id,
+            profile_id,
+            get_static_routing_approaches(),
+            &time_range,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment attempt status counts of the profile")?;
+
+    Ok(AuthRate::from_status_counts(status_counts))
+}
+
+fn get_active_algorithm_id(
+    business_profile: &domain::Profile,
+    transaction_type: &enums::TransactionType,
+) -> RouterResult<Option<id_type::RoutingId>> {
+    let algorithm_ref = match transaction_type {
+        enums::TransactionType::Payment => business_profile.routing_algorithm.clone(),
+        #[cfg(feature = "payouts")]
+        enums::TransactionType::Payout => business_profile.payout_routing_algorithm.clone(),
+        enums::TransactionType::ThreeDsAuthentication => {
+            business_profile.three_ds_decision_rule_algorithm.clone()
+        }
+    }
+    .map(|val| val.parse_value::<routing_types::RoutingAlgorithmRef>("RoutingAlgorithmRef"))
+    .transpose()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("unable to deserialize routing algorithm ref from business profile")?;
+
+    Ok(algorithm_ref.and_then(|algorithm_ref| algorithm_ref.algorithm_id))
+}
+
+async fn find_routing_activation_schedule(
+    state: &SessionState,
+    platform: &domain::Platform,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    schedule_id: &str,
+) -> RouterResult<(storage::ProcessTracker, RoutingActivationTrackingData)> {
+    let db = state.store.as_ref();
+    let not_found = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Scheduled routing activation not found".to_string(),
+    };
+
+    let process = db
+        .find_process_by_id(schedule_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the routing activation task")?
+        .filter(|process| {
+            process.runner.as_deref()
+                == Some(
+                    storage::ProcessTrackerRunner::RoutingActivationWorkflow
+                        .to_string()
+                        .as_str(),
+                )
+        })
+        .ok_or_else(not_found)?;
+
+    let tracking_data: RoutingActivationTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("RoutingActivationTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    fp_utils::when(
+        &tracking_data.merchant_id != platform.get_processor().get_account().get_id(),
+        || Err(not_found()),
+    )?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        platform.get_processor(),
+        Some(&tracking_data.profile_id),
+    )
+    .await?
+    .get_required_value("Profile")?;
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    Ok((process, tracking_data))
+}
+
+fn get_schedule_response(
+    process: &storage::ProcessTracker,
+    tracking_data: RoutingActivationTrackingData,
+) -> RoutingActivationScheduleResponse {
+    let status = match process.status {
+        storage::enums::ProcessTrackerStatus::Finish => match process.business_status.as_str() {
+            business_status::COMPLETED_BY_PT => RoutingActivationScheduleStatus::Completed,
+            business_status::ROUTING_ROLLED_BACK => RoutingActivationScheduleStatus::RolledBack,
+            business_status::RESOURCE_STATUS_MISMATCH => {
+                RoutingActivationScheduleStatus::Superseded
+            }
+            business_status::ROUTING_ACTIVATION_CANCELLED => {
+                RoutingActivationScheduleStatus::Cancelled
+            }
+            _ => RoutingActivationScheduleStatus::Failed,
+        },
+        _ if tracking_data.activation.is_some() => RoutingActivationScheduleStatus::Monitoring,
+        _ => RoutingActivationScheduleStatus::Scheduled,
+    };
+
+    RoutingActivationScheduleResponse {
+        schedule_id: process.id.clone(),
+        algorithm_id: tracking_data.algorithm_id,
+        profile_id: tracking_data.profile_id,
+        transaction_type: tracking_data.transaction_type,
+        activate_at: tracking_data.activate_at,
+        guardrail: tracking_data.guardrail,
+        status,
+        activation: tracking_data.activation,
+        warnings: Vec::new(),
+    }
+}
+
+async fn add_routing_activation_task(
+    db: &dyn StorageInterface,
+    tracking_data: &RoutingActivationTrackingData,
+    application_source: common_enums::ApplicationSource,
+) -> RouterResult<storage::ProcessTracker> {
+    let runner = storage::ProcessTrackerRunner::RoutingActivationWorkflow;
+    // An algorithm may be scheduled several times, the schedule time keeps the task IDs unique
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        ROUTING_ACTIVATION_TASK,
+        &format!(
+            "{}_{}",
+            tracking_data.algorithm_id.get_string_repr(),
+            tracking_data.activate_at.assume_utc().unix_timestamp()
+        ),
+        &tracking_data.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        ROUTING_ACTIVATION_TASK,
+        runner,
+        [ROUTING_ACTIVATION_TAG],
+        tracking_data,
+        None,
+        tracking_data.activate_at,
+        common_types::consts::API_VERSION,
+        application_source,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct routing activation process tracker task")?;
+
+    let process = db
+        .insert_process(process_tracker_entry)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The algorithm is already scheduled for activation at this time".to_string(),
+        })
+        .attach_printable("Failed to insert routing activation task to process_tracker")?;
+    metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "RoutingActivation")),
+    );
+
+    Ok(process)
+}
+
+/// Tracking data of a task which has to be run again to evaluate the guard-rail
+pub(crate) fn encode_tracking_data(
+    tracking_data: &RoutingActivationTrackingData,
+) -> RouterResult<serde_json::Value> {
+    tracking_data
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode routing activation tracking data")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_guardrail() -> RoutingRollbackGuardrail {
+        RoutingRollbackGuardrail {
+            max_auth_rate_drop: 5.0,
+            min_attempts: 100,
+            monitoring_window_in_minutes: None,
+        }
+    }
+
+    #[test]
+    fn test_auth_rate_from_status_counts() {
+        let auth_rate = AuthRate::from_status_counts([
+            (enums::AttemptStatus::Charged, 70),
+            (enums::AttemptStatus::Authorized, 10),
+            (enums::AttemptStatus::Voided, 5),
+            (enums::AttemptStatus::Failure, 10),
+            (enums::AttemptStatus::AuthorizationFailed, 5),
+            (enums::AttemptStatus::Pending, 40),
+            (enums::AttemptStatus::RouterDeclined, 3),
+        ]);
+
+        assert_eq!(
+            auth_rate,
+            AuthRate {
+                authorized: 85,
+                attempts: 100,
+            }
+        );
+        assert_eq!(auth_rate.get_percentage(), Some(85.0));
+        assert_eq!(AuthRate::from_status_counts([]).get_percentage(), None);
+    }
+
+    #[test]
+    fn test_guardrail_breached_beyond_tolerated_drop() {
+        let observed = AuthRate {
+            authorized: 80,
+            attempts: 100,
+        };
+
+        assert_eq!(
+            find_guardrail_breach(&get_guardrail(), Some(90.0), &observed),
+            Some((90.0, 80.0))
+        );
+    }
+
+    #[test]
+    fn test_guardrail_not_breached_within_tolerated_drop() {
+        let observed = AuthRate {
+            authorized: 86,
+            attempts: 100,
+        };
+
+        assert_eq!(
+            find_guardrail_breach(&get_guardrail(), Some(90.0), &observed),
+            None
+        );
+    }
+
+    #[test]
+    fn test_guardrail_not_breached_below_min_attempts() {
+        let observed = AuthRate {
+            authorized: 10,
+            attempts: 99,
+        };
+
+        assert_eq!(
+            find_guardrail_breach(&get_guardrail(), Some(90.0), &observed),
+            None
+        );
+    }
+
+    #[test]
+    fn test_guardrail_not_breached_without_baseline() {
+        let observed = AuthRate {
+            authorized: 10,
+            attempts: 100,
+        };
+
+        assert_eq!(
+            find_guardrail_breach(&get_guardrail(), None, &observed),
+            None
+        );
+    }
+}
@@ -0,0 +1,552 @@
+use std::collections::{BTreeMap, HashSet};
+
+use api_models::routing::{
+    self as routing_types, RoutingSimulationBreakdown, RoutingSimulationConnectorCount,
+    RoutingSimulationError, RoutingSimulationRequest, RoutingSimulationResponse,
+};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    payment_address,
+    payments::payment_intent::{PaymentIntentFetchConstraints, PaymentIntentListParams},
+};
+use router_env::logger;
+
+use super::{helpers, PaymentsDslInput};
+use crate::{
+    core::{
+        errors::{self, CustomResult, RouterResponse},
+        payments::{helpers as payments_helpers, routing as payments_routing},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::api as service_api,
+    types::{domain, storage},
+    utils::OptionExt,
+};
+
+/// Number of payments fetched from the database in a single query while replaying
+const SIMULATION_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of failed payments listed in the response, the rest are only counted
+const SIMULATION_MAX_REPORTED_ERRORS: usize = 10;
+
+#[derive(Debug, thiserror::Error)]
+enum SimulationError {
+    #[error("Failed to fetch the addresses of the payment")]
+    AddressFetchFailed,
+    #[error("Failed to construct the routing input from the payment")]
+    DslInputConstructionFailed,
+    #[error("Failed to execute the routing algorithm")]
+    AlgorithmExecutionFailed,
+    #[error("Failed to filter the connectors through the constraint graph")]
+    ConstraintGraphFilteringFailed,
+}
+
+/// Replays the payments of a profile in the given time range through a candidate routing
+/// algorithm, and compares the connector it would have picked with the one that was used.
+///
+/// Volume splits are sampled afresh for every payment, so the distribution they produce is an
+/// estimate of what the split would have done rather than a deterministic replay.
+pub async fn simulate_routing_algorithm(
+    state: SessionState,
+    platform: domain::Platform,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: RoutingSimulationRequest,
+) -> RouterResponse<RoutingSimulationResponse> {
+    let db = state.store.as_ref();
+    let processor = platform.get_processor();
+    let key_store = processor.get_key_store();
+    let merchant_id = processor.get_account().get_id();
+    let storage_scheme = processor.get_account().storage_scheme;
+
+    let business_profile =
+        core_utils::validate_and_get_business_profile(db, processor, Some(&request.profile_id))
+            .await?
+            .get_required_value("Profile")?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    if request
+        .algorithm
+        .should_validate_connectors_in_routing_config()
+    {
+        helpers::validate_connectors_in_routing_config(
+            &state,
+            key_store,
+            merchant_id,
+            &request.profile_id,
+            &request.algorithm,
+        )
+        .await?;
+    }
+
+    let limit = request.validated_limit();
+    let cached_algorithm =
+        payments_routing::CachedAlgorithm::try_from_static_algorithm(request.algorithm)
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Unable to initialize the routing algorithm".to_string(),
+            })?;
+
+    let active_mca_ids = payments_routing::get_active_mca_ids(&state, key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the active merchant connector accounts")?;
+
+    let mut accumulator = SimulationAccumulator::default();
+    let mut offset = 0;
+
+    while offset < limit {
+        let page_size = (limit - offset).min(SIMULATION_PAGE_SIZE);
+        let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+            offset,
+            starting_at: Some(request.time_range.start_time),
+            ending_at: request.time_range.end_time,
+            amount_filter: None,
+            connector: None,
+            currency: None,
+            status: None,
+            payment_method: None,
+            payment_method_type: None,
+            authentication_type: None,
+            merchant_connector_id: None,
+            profile_id: Some(vec![request.profile_id.clone()]),
+            customer_id: None,
+            starting_after_id: None,
+            ending_before_id: None,
+            limit: Some(page_size),
+            order: Default::default(),
+            card_network: None,
+            card_discovery: None,
+            merchant_order_reference_id: None,
+            customer_email: None,
+        }));
+
+        let payments = db
+            .get_filtered_payment_intents_attempt(
+                merchant_id,
+                &constraints,
+                key_store,
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payments to replay")?;
+
+        let fetched_count = payments.len();
+
+        for (payment_intent, payment_attempt) in payments {
+            let simulated_connector = simulate_payment(
+                &state,
+                processor,
+                &business_profile,
+                &cached_algorithm,
+                &active_mca_ids,
+                &payment_intent,
+                &payment_attempt,
+            )
+            .await;
+
+            match simulated_connector {
+                Ok(simulated_connector) => {
+                    accumulator.record(ReplayedPayment::from(&payment_attempt), simulated_connector)
+                }
+                Err(err) => {
+                    logger::warn!(
+                        error=?err,
+                        payment_id=?payment_attempt.payment_id,
+                        "routing_simulation: unable to replay payment"
+                    );
+                    accumulator.record_error(
+                        payment_attempt.payment_id,
+                        err.current_context().to_string(),
+                    );
+                }
+            }
+        }
+
+        if fetched_count < usize::try_from(page_size).unwrap_or(usize::MAX) {
+            break;
+        }
+        offset += page_size;
+    }
+
+    Ok(service_api::ApplicationResponse::Json(
+        accumulator.into_response(),
+    ))
+}
+
+/// Runs a single stored payment through the candidate algorithm and the constraint graph,
+/// returning the connector that would have been tried first
+async fn simulate_payment(
+    state: &SessionState,
+    processor: &domain::Processor,
+    business_profile: &domain::Profile,
+    cached_algorithm: &payments_routing::CachedAlgorithm,
+    active_mca_ids: &HashSet<common_utils::id_type::MerchantConnectorAccountId>,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> CustomResult<Option<routing_types::RoutableConnectorChoice>, SimulationError> {
+    let key_store = processor.get_key_store();
+    let merchant_id = processor.get_account().get_id();
+    let storage_scheme = processor.get_account().storage_scheme;
+
+    let Some(currency) = payment_attempt.currency.or(payment_intent.currency) else {
+        return Ok(None);
+    };
+
+    let billing_address = payments_helpers::get_address_by_id(
+        state,
+        payment_intent.billing_address_id.clone(),
+        key_store,
+        &payment_intent.payment_id,
+        merchant_id,
+        storage_scheme,
+    )
+    .await
+    .change_context(SimulationError::AddressFetchFailed)?;
+    let payment_method_billing_address = payments_helpers::get_address_by_id(
+        state,
+        payment_attempt.payment_method_billing_address_id.clone(),
+        key_store,
+        &payment_intent.payment_id,
+        merchant_id,
+        storage_scheme,
+    )
+    .await
+    .change_context(SimulationError::AddressFetchFailed)?;
+    let address = payment_address::PaymentAddress::new(
+        None,
+        billing_address.as_ref().map(From::from),
+        payment_method_billing_address.as_ref().map(From::from),
+        business_profile.use_billing_as_payment_method_billing,
+    );
+
+    let payments_dsl_input = PaymentsDslInput::new(
+        None,
+        payment_attempt,
+        payment_intent,
+        None,
+        &address,
+        None,
+        currency,
+    );
+
+    // The raw payment method data is not stored, the card details saved on the attempt are
+    // used in its place. Time based rules are evaluated against the creation time of the payment
+    let mut backend_input = payments_routing::make_dsl_input(&payments_dsl_input)
+        .change_context(SimulationError::DslInputConstructionFailed)?;
+    backend_input.payment_method.card_network = backend_input
+        .payment_method
+        .card_network
+        .or_else(|| payment_attempt.extract_card_network());
+    backend_input.time_data = Some(payments_routing::make_dsl_time_input(
+        business_profile.routing_timezone,
+        payment_intent.created_at,
+    ));
+
+    let (routable_connectors, _) =
+        payments_routing::execute_cached_algorithm(cached_algorithm, backend_input.clone())
+            .change_context(SimulationError::AlgorithmExecutionFailed)?;
+
+    let eligible_connectors = payments_routing::perform_cgraph_filtering(
+        state,
+        key_store,
+        routable_connectors,
+        backend_input,
+        None,
+        business_profile.get_id(),
+        &common_enums::TransactionType::Payment,
+        active_mca_ids,
+    )
+    .await
+    .change_context(SimulationError::ConstraintGraphFilteringFailed)?;
+
+    Ok(eligible_connectors.into_iter().next())
+}
+
+#[derive(Default)]
+struct DistributionAccumulator {
+    simulated_count: usize,
+    matched_count: usize,
+    connectors: BTreeMap<String, (usize, usize)>,
+}
+
+impl DistributionAccumulator {
+    fn record(&mut self, simulated_connector: &str, actual_connector: &str, is_match: bool) {
+        self.simulated_count += 1;
+        if is_match {
+            self.matched_count += 1;
+        }
+        self.connectors
+            .entry(simulated_connector.to_string())
+            .or_default()
+            .0 += 1;
+        self.connectors
+            .entry(actual_connector.to_string())
+            .or_default()
+            .1 += 1;
+    }
+
+    fn into_distribution(self) -> Vec<RoutingSimulationConnectorCount> {
+        self.connectors
+            .into_iter()
+            .map(
+                |(connector, (simulated_count, actual_count))| RoutingSimulationConnectorCount {
+                    connector,
+                    simulated_count,
+                    actual_count,
+                },
+            )
+            .collect()
+    }
+
+    fn into_breakdown(self, value: String) -> RoutingSimulationBreakdown {
+        RoutingSimulationBreakdown {
+            value,
+            simulated_count: self.simulated_count,
+            matched_count: self.matched_count,
+            distribution: self.into_distribution(),
+        }
+    }
+}
+
+/// The details of a stored payment the simulated connector is compared against
+struct ReplayedPayment<'a> {
+    connector: Option<&'a str>,
+    merchant_connector_id: Option<&'a common_utils::id_type::MerchantConnectorAccountId>,
+    payment_method: Option<common_enums::PaymentMethod>,
+    currency: Option<common_enums::Currency>,
+    card_network: Option<common_enums::CardNetwork>,
+}
+
+impl<'a> From<&'a storage::PaymentAttempt> for ReplayedPayment<'a> {
+    fn from(payment_attempt: &'a storage::PaymentAttempt) -> Self {
+        Self {
+            connector: payment_attempt.connector.as_deref(),
+            merchant_connector_id: payment_attempt.merchant_connector_id.as_ref(),
+            payment_method: payment_attempt.payment_method,
+            currency: payment_attempt.currency,
+            card_network: payment_attempt.extract_card_network(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct SimulationAccumulator {
+    total_count: usize,
+    unrouted_count: usize,
+    no_connector_count: usize,
+    error_count: usize,
+    errors: Vec<RoutingSimulationError>,
+    overall: DistributionAccumulator,
+    by_payment_method: BTreeMap<String, DistributionAccumulator>,
+    by_currency: BTreeMap<String, DistributionAccumulator>,
+    by_card_network: BTreeMap<String, DistributionAccumulator>,
+}
+
+impl SimulationAccumulator {
+    fn record(
+        &mut self,
+        payment: ReplayedPayment<'_>,
+        simulated_connector: Option<routing_types::RoutableConnectorChoice>,
+    ) {
+        self.total_count += 1;
+
+        // A payment the candidate algorithm does not route points at the algorithm, one which
+        // never reached a connector at the payment history
+        let Some(simulated_connector) = simulated_connector else {
+            self.unrouted_count += 1;
+            return;
+        };
+        let Some(actual_connector) = payment.connector else {
+            self.no_connector_count += 1;
+            return;
+        };
+
+        let simulated_name = simulated_connector.connector.to_string();
+        let is_match = simulated_name == actual_connector
+            && match (
+                simulated_connector.merchant_connector_id.as_ref(),
+                payment.merchant_connector_id,
+            ) {
+                (Some(simulated_mca_id), Some(actual_mca_id)) => simulated_mca_id == actual_mca_id,
+                _ => true,
+            };
+
+        self.overall
+            .record(&simulated_name, actual_connector, is_match);
+
+        let payment_method = payment
+            .payment_method
+            .map(|payment_method| payment_method.to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        self.by_payment_method
+            .entry(payment_method)
+            .or_default()
+            .record(&simulated_name, actual_connector, is_match);
+
+        if let Some(currency) = payment.currency {
+            self.by_currency
+                .entry(currency.to_string())
+                .or_default()
+                .record(&simulated_name, actual_connector, is_match);
+        }
+
+        if let Some(card_network) = payment.card_network {
+            self.by_card_network
+                .entry(card_network.to_string())
+                .or_default()
+                .record(&simulated_name, actual_connector, is_match);
+        }
+    }
+
+    fn record_error(&mut self, payment_id: common_utils::id_type::PaymentId, message: String) {
+        self.total_count += 1;
+        self.error_count += 1;
+        if self.errors.len() < SIMULATION_MAX_REPORTED_ERRORS {
+            self.errors.push(RoutingSimulationError {
+                payment_id,
+                message,
+            });
+        }
+    }
+
+    fn into_response(self) -> RoutingSimulationResponse {
+        let into_breakdowns = |dimension: BTreeMap<String, DistributionAccumulator>| {
+            dimension
+                .into_iter()
+                .map(|(value, accumulator)| accumulator.into_breakdown(value))
+                .collect()
+        };
+
+        RoutingSimulationResponse {
+            total_count: self.total_count,
+            simulated_count: self.overall.simulated_count,
+            unrouted_count: self.unrouted_count,
+            no_connector_count: self.no_connector_count,
+            error_count: self.error_count,
+            errors: self.errors,
+            matched_count: self.overall.matched_count,
+            by_payment_method: into_breakdowns(self.by_payment_method),
+            by_currency: into_breakdowns(self.by_currency),
+            by_card_network: into_breakdowns(self.by_card_network),
+            distribution: self.overall.into_distribution(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn stripe_choice() -> routing_types::RoutableConnectorChoice {
+        routing_types::RoutableConnectorChoice {
+            choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+            connector: api_models::enums::RoutableConnectors::Stripe,
+            merchant_connector_id: None,
+        }
+    }
+
+    fn card_payment(connector: Option<&str>) -> ReplayedPayment<'_> {
+        ReplayedPayment {
+            connector,
+            merchant_connector_id: None,
+            payment_method: Some(common_enums::PaymentMethod::Card),
+            currency: Some(common_enums::Currency::USD),
+            card_network: Some(common_enums::CardNetwork::Visa),
+        }
+    }
+
+    #[test]
+    fn test_simulation_accumulator_counts_matches_and_skips() {
+        let mut accumulator = SimulationAccumulator::default();
+        accumulator.record(card_payment(Some("stripe")), Some(stripe_choice()));
+        accumulator.record(card_payment(Some("adyen")), Some(stripe_choice()));
+        accumulator.record(card_payment(None), Some(stripe_choice()));
+        accumulator.record(card_payment(Some("adyen")), None);
+
+        let response = accumulator.into_response();
+        assert_eq!(response.total_count, 4);
+        assert_eq!(response.simulated_count, 2);
+        assert_eq!(response.matched_count, 1);
+        assert_eq!(response.unrouted_count, 1);
+        assert_eq!(response.no_connector_count, 1);
+        assert_eq!(response.error_count, 0);
+
+        let distribution = response
+            .distribution
+            .iter()
+            .map(|count| {
+                (
+                    count.connector.as_str(),
+                    count.simulated_count,
+                    count.actual_count,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(distribution, vec![("adyen", 0, 1), ("stripe", 2, 1)]);
+
+        assert_eq!(response.by_payment_method.len(), 1);
+        assert_eq!(response.by_currency.len(), 1);
+        let card_network_breakdown = response
+            .by_card_network
+            .iter()
+            .map(|breakdown| {
+                (
+                    breakdown.value.as_str(),
+                    breakdown.simulated_count,
+                    breakdown.matched_count,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(card_network_breakdown, vec![("Visa", 2, 1)]);
+    }
+
+    #[test]
+    fn test_simulation_accumulator_matches_on_merchant_connector_id() {
+        let simulated_mca_id =
+            common_utils::id_type::MerchantConnectorAccountId::wrap("mca_1".to_string()).unwrap();
+        let actual_mca_id =
+            common_utils::id_type::MerchantConnectorAccountId::wrap("mca_2".to_string()).unwrap();
+        let mut accumulator = SimulationAccumulator::default();
+        accumulator.record(
+            ReplayedPayment {
+                merchant_connector_id: Some(&actual_mca_id),
+                ..card_payment(Some("stripe"))
+            },
+            Some(routing_types::RoutableConnectorChoice {
+                merchant_connector_id: Some(simulated_mca_id),
+                ..stripe_choice()
+            }),
+        );
+
+        let response = accumulator.into_response();
+        assert_eq!(response.simulated_count, 1);
+        assert_eq!(response.matched_count, 0);
+    }
+
+    #[test]
+    fn test_simulation_accumulator_reports_errors() {
+        let mut accumulator = SimulationAccumulator::default();
+        for index in 0..SIMULATION_MAX_REPORTED_ERRORS + 5 {
+            let payment_id = common_utils::id_type::PaymentId::try_from(std::borrow::Cow::Owned(
+                format!("pay_{index}"),
+            ))
+            .unwrap();
+            accumulator.record_error(
+                payment_id,
+                SimulationError::AlgorithmExecutionFailed.to_string(),
+            );
+        }
+        accumulator.record(card_payment(Some("stripe")), Some(stripe_choice()));
+
+        let response = accumulator.into_response();
+        assert_eq!(response.total_count, SIMULATION_MAX_REPORTED_ERRORS + 6);
+        assert_eq!(response.error_count, SIMULATION_MAX_REPORTED_ERRORS + 5);
+        assert_eq!(response.errors.len(), SIMULATION_MAX_REPORTED_ERRORS);
+        assert_eq!(response.unrouted_count, 0);
+        assert_eq!(response.no_connector_count, 0);
+        assert_eq!(response.simulated_count, 1);
+    }
+}
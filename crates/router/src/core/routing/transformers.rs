@@ -34,6 +34,7 @@ impl ForeignFrom<RoutingProfileMetadata> for RoutingDictionaryRecord {
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
             algorithm_for: Some(value.algorithm_for),
             decision_engine_routing_id: None,
+            warnings: Vec::new(),
         }
     }
 }
@@ -51,6 +52,7 @@ impl ForeignFrom<RoutingAlgorithm> for RoutingDictionaryRecord {
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
             algorithm_for: Some(value.algorithm_for),
             decision_engine_routing_id: value.decision_engine_routing_id,
+            warnings: Vec::new(),
         }
     }
 }
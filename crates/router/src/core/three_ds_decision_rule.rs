@@ -14,6 +14,7 @@ use crate::{
     core::{
         errors,
         errors::{RouterResponse, StorageErrorExt},
+        payments::routing::decision_trace,
     },
     services,
     types::transformers::ForeignFrom,
@@ -30,6 +31,7 @@ pub async fn execute_three_ds_decision_rule(
         &state,
         platform.get_processor().get_account().get_id(),
         request.clone(),
+        None,
     )
     .await?;
     // Construct response
@@ -38,10 +40,13 @@ pub async fn execute_three_ds_decision_rule(
     Ok(services::ApplicationResponse::Json(response))
 }
 
+/// Evaluates the 3DS decision rule, recording the evaluation in the decision trace of the
+/// payment attempt when one is given and tracing is enabled for the merchant
 pub async fn get_three_ds_decision_rule_output(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
     request: api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest,
+    trace_key: Option<decision_trace::DecisionTraceKey<'_>>,
 ) -> errors::RouterResult<common_types::three_ds_decision_rule_engine::ThreeDSDecision> {
     let db = state.store.as_ref();
     // Retrieve the rule from database
@@ -67,9 +72,24 @@ pub async fn get_three_ds_decision_rule_output(
         .attach_printable("Error initializing DSL interpreter backend")?;
     // Execute the rule
     let result = interpreter
-        .execute(backend_input)
+        .execute(backend_input.clone())
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Error executing 3DS decision rule")?;
+    if let Some(trace_key) = trace_key {
+        if decision_trace::is_decision_trace_enabled(state, merchant_id).await {
+            decision_trace::record_rule_evaluations(
+                state,
+                trace_key,
+                vec![api_models::routing::RuleEvaluationTrace::new(
+                    api_models::routing::DecisionTraceType::ThreeDsDecisionRule,
+                    Some(request.routing_id.get_string_repr().to_string()),
+                    &backend_input,
+                    &result,
+                )],
+            )
+            .await;
+        }
+    }
     // Apply PSD2 validations to the decision
     let final_decision =
         utils::apply_psd2_validations_during_execute(result.get_output().get_decision(), &request);
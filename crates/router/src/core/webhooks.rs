@@ -12,8 +12,12 @@ mod outgoing_v2;
 pub mod recovery_incoming;
 pub mod types;
 pub mod utils;
+#[cfg(feature = "v1")]
+pub mod webhook_endpoints;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
+#[cfg(feature = "v1")]
+pub mod webhook_secret_rotation;
 
 #[cfg(feature = "v1")]
 pub(crate) use self::{
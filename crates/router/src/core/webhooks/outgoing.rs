@@ -20,7 +20,7 @@ use router_env::{
     tracing::{self, Instrument},
 };
 
-use super::{types, utils, MERCHANT_ID};
+use super::{types, utils, webhook_endpoints, MERCHANT_ID};
 #[cfg(feature = "stripe")]
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
@@ -32,9 +32,9 @@ use crate::{
     events::outgoing_webhook_logs::{
         OutgoingWebhookEvent, OutgoingWebhookEventContent, OutgoingWebhookEventMetric,
     },
-    logger,
+    headers, logger,
     routes::{app::SessionStateInfo, SessionState},
-    services,
+    services::{self, request::Maskable},
     types::{
         api,
         domain::{self},
@@ -45,6 +45,14 @@ use crate::{
     workflows::outgoing_webhook_retry,
 };
 
+/// Headers carrying the signatures of outgoing webhooks, which are replaced when a webhook is
+/// signed again
+const WEBHOOK_SIGNATURE_HEADERS: [&str; 3] = [
+    headers::X_WEBHOOK_SIGNATURE,
+    headers::X_WEBHOOK_SIGNATURE_NEXT,
+    headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE,
+];
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 pub(crate) async fn create_event_and_trigger_outgoing_webhook(
@@ -58,26 +66,123 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to generate idempotent event ID")?;
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
+    if !state.conf.webhooks.outgoing_enabled {
+        logger::debug!(
+            business_profile_id=?business_profile.get_id(),
+            %primary_object_id,
+            "Outgoing webhooks are disabled in application configuration; skipping outgoing \
+             webhooks for event"
+        );
+        return Ok(());
+    }
 
-    if !state.conf.webhooks.outgoing_enabled
-        || webhook_url_result.is_err()
-        || webhook_url_result.as_ref().is_ok_and(String::is_empty)
-    {
+    // Failing to read the webhook endpoints must not prevent delivery to the webhook URL
+    // configured in the business profile.
+    let webhook_endpoints = webhook_endpoints::get_webhook_endpoints_for_event(
+        &state,
+        business_profile.get_id(),
+        event_type,
+    )
+    .await
+    .inspect_err(|error| {
+        logger::error!(
+            ?error,
+            "Failed to obtain webhook endpoints for business profile"
+        );
+    })
+    .unwrap_or_default();
+
+    let is_profile_webhook_url_configured =
+        get_webhook_url_from_business_profile(&business_profile)
+            .is_ok_and(|webhook_url| !webhook_url.is_empty());
+
+    if !is_profile_webhook_url_configured && webhook_endpoints.is_empty() {
         logger::debug!(
             business_profile_id=?business_profile.get_id(),
-            %idempotent_event_id,
-            "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
-             could not be obtained; skipping outgoing webhooks for event"
+            %primary_object_id,
+            "Merchant webhook URL could not be obtained and no webhook endpoints are subscribed \
+             to the event; skipping outgoing webhooks for event"
         );
         return Ok(());
     }
 
+    let profile_webhook_result = if is_profile_webhook_url_configured {
+        Box::pin(create_event_and_trigger_outgoing_webhook_for_destination(
+            state.clone(),
+            processor.clone(),
+            business_profile.clone(),
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+            None,
+        ))
+        .await
+    } else {
+        Ok(())
+    };
+
+    // Every subscribed webhook endpoint gets its own event and retry task, so that a failure to
+    // deliver to one endpoint does not affect the others.
+    for webhook_endpoint in webhook_endpoints {
+        let webhook_endpoint_id = webhook_endpoint.id.clone();
+        let _ = Box::pin(create_event_and_trigger_outgoing_webhook_for_destination(
+            state.clone(),
+            processor.clone(),
+            business_profile.clone(),
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+            Some(webhook_endpoint),
+        ))
+        .await
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                %webhook_endpoint_id,
+                "Failed to create outgoing webhook for webhook endpoint"
+            );
+        });
+    }
+
+    profile_webhook_result
+}
+
+/// Creates an event for delivering the webhook either to the webhook URL configured in the
+/// business profile (when `webhook_endpoint` is `None`) or to the specified webhook endpoint, and
+/// triggers the delivery.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn create_event_and_trigger_outgoing_webhook_for_destination(
+    state: SessionState,
+    processor: domain::Processor,
+    business_profile: domain::Profile,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    webhook_endpoint: Option<storage::WebhookEndpoint>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
+    let webhook_endpoint_id = webhook_endpoint
+        .as_ref()
+        .map(|webhook_endpoint| webhook_endpoint.id.clone());
+    let idempotent_event_id = utils::get_idempotent_event_id(
+        &primary_object_id,
+        event_type,
+        delivery_attempt,
+        webhook_endpoint_id.as_deref(),
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to generate idempotent event ID")?;
+
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -90,10 +195,25 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         timestamp: now,
     };
 
-    let request_content =
-        get_outgoing_webhook_request(&processor, outgoing_webhook, &business_profile)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to construct outgoing webhook request content")?;
+    let webhook_endpoint_signing_secret = match webhook_endpoint.as_ref() {
+        Some(webhook_endpoint) => Some(
+            webhook_endpoints::get_webhook_endpoint_signing_secret(
+                &state,
+                processor.get_key_store(),
+                webhook_endpoint,
+            )
+            .await?,
+        ),
+        None => None,
+    };
+    let request_content = get_outgoing_webhook_request(
+        &processor,
+        outgoing_webhook,
+        &business_profile,
+        webhook_endpoint_signing_secret.as_ref(),
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to construct outgoing webhook request content")?;
 
     let event_metadata = storage::EventMetadata::foreign_from(&content);
     let key_manager_state = &(&state).into();
@@ -133,6 +253,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
         is_overall_delivery_successful: Some(false),
+        webhook_endpoint_id,
     };
 
     let lock_value = utils::perform_redis_lock(
@@ -281,11 +402,11 @@ async fn trigger_webhook_to_merchant(
     delivery_attempt: enums::WebhookDeliveryAttempt,
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+    let (webhook_url, webhook_endpoint) = match (
+        get_webhook_destination_for_event(&state, &business_profile, &event).await,
         process_tracker.clone(),
     ) {
-        (Ok(webhook_url), _) => Ok(webhook_url),
+        (Ok(webhook_destination), _) => Ok(webhook_destination),
         (Err(error), Some(process_tracker)) => {
             if !error
                 .current_context()
@@ -306,6 +427,43 @@ async fn trigger_webhook_to_merchant(
         (Err(error), None) => Err(error),
     }?;
 
+    // Retried deliveries are signed again, as the signing secrets may have been rotated since the
+    // request was first signed
+    let request_content = match delivery_attempt {
+        enums::WebhookDeliveryAttempt::InitialAttempt => request_content,
+        enums::WebhookDeliveryAttempt::AutomaticRetry
+        | enums::WebhookDeliveryAttempt::ManualRetry => {
+            let merchant_account = state
+                .store
+                .find_merchant_account_by_merchant_id(
+                    &business_profile.merchant_id,
+                    merchant_key_store,
+                )
+                .await
+                .change_context(errors::WebhooksFlowError::MerchantConfigNotFound)
+                .attach_printable("Failed to fetch merchant account")?;
+            let webhook_endpoint_signing_secret = match webhook_endpoint.as_ref() {
+                Some(webhook_endpoint) => Some(
+                    webhook_endpoints::get_webhook_endpoint_signing_secret(
+                        &state,
+                        merchant_key_store,
+                        webhook_endpoint,
+                    )
+                    .await
+                    .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)?,
+                ),
+                None => None,
+            };
+
+            resign_outgoing_webhook_request(
+                &merchant_account,
+                request_content,
+                &business_profile,
+                webhook_endpoint_signing_secret.as_ref(),
+            )?
+        }
+    };
+
     let event_id = event.event_id;
 
     let headers = request_content
@@ -570,6 +728,7 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
         db,
         &business_profile.merchant_id,
         0,
+        None,
     )
     .await
     .ok_or(errors::StorageError::ValueNotFound(
@@ -585,6 +744,7 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
         primary_object_id: event.primary_object_id.clone(),
         primary_object_type: event.primary_object_type,
         initial_attempt_id: event.initial_attempt_id.clone(),
+        webhook_endpoint_id: event.webhook_endpoint_id.clone(),
     };
 
     let runner = storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow;
@@ -638,15 +798,68 @@ fn get_webhook_url_from_business_profile(
         .map(ExposeInterface::expose)
 }
 
+/// Obtains the URL to which the webhook for the event must be delivered: the URL of the webhook
+/// endpoint the event was created for, or the webhook URL configured in the business profile.
+async fn get_webhook_destination_for_event(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    event: &domain::Event,
+) -> CustomResult<(String, Option<storage::WebhookEndpoint>), errors::WebhooksFlowError> {
+    match event.webhook_endpoint_id.as_deref() {
+        Some(webhook_endpoint_id) => {
+            let webhook_endpoint = state
+                .store
+                .find_webhook_endpoint_by_profile_id_endpoint_id(
+                    business_profile.get_id(),
+                    webhook_endpoint_id,
+                )
+                .await
+                .change_context(errors::WebhooksFlowError::WebhookEndpointNotFound)
+                .attach_printable_lazy(|| {
+                    format!("Failed to find webhook endpoint `{webhook_endpoint_id}`")
+                })?;
+
+            if !webhook_endpoint.is_enabled {
+                return Err(report!(errors::WebhooksFlowError::WebhookEndpointNotFound))
+                    .attach_printable_lazy(|| {
+                        format!("Webhook endpoint `{webhook_endpoint_id}` is disabled")
+                    });
+            }
+
+            Ok((webhook_endpoint.url.clone(), Some(webhook_endpoint)))
+        }
+        None => get_webhook_url_from_business_profile(business_profile)
+            .map(|webhook_url| (webhook_url, None)),
+    }
+}
+
+/// Returns the key outgoing webhooks are signed with, along with the staged key of a signing
+/// secret rotation within its grace period.
+/// Webhooks delivered to a webhook endpoint are signed with the decrypted secret of the endpoint.
+fn get_webhook_signing_keys(
+    business_profile: &domain::Profile,
+    webhook_endpoint_signing_secret: Option<&Secret<String>>,
+) -> (Option<String>, Option<Secret<String>>) {
+    match webhook_endpoint_signing_secret {
+        Some(signing_secret) => (Some(signing_secret.clone().expose()), None),
+        None => {
+            let (current_key, staged_key) = business_profile.get_webhook_signing_keys();
+            (current_key.map(ExposeInterface::expose), staged_key)
+        }
+    }
+}
+
 pub(crate) fn get_outgoing_webhook_request(
     processor: &domain::Processor,
     outgoing_webhook: api::OutgoingWebhook,
     business_profile: &domain::Profile,
+    webhook_endpoint_signing_secret: Option<&Secret<String>>,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         business_profile: &domain::Profile,
+        webhook_endpoint_signing_secret: Option<&Secret<String>>,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![
             (
@@ -660,7 +873,8 @@ pub(crate) fn get_outgoing_webhook_request(
         ];
 
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
-        let payment_response_hash_key = business_profile.payment_response_hash_key.clone();
+        let (payment_response_hash_key, staged_payment_response_hash_key) =
+            get_webhook_signing_keys(business_profile, webhook_endpoint_signing_secret);
         let custom_headers = business_profile
             .outgoing_webhook_custom_http_headers
             .clone()
@@ -686,6 +900,17 @@ pub(crate) fn get_outgoing_webhook_request(
             WebhookType::add_webhook_header(&mut headers, signature)
         }
 
+        // While a signing secret rotation is in its grace period, the payload is additionally
+        // signed with the staged secret
+        if let Some(staged_payment_response_hash_key) = staged_payment_response_hash_key {
+            let staged_signature = transformed_outgoing_webhook
+                .get_outgoing_webhooks_signature(Some(staged_payment_response_hash_key.expose()))?;
+
+            if let Some(signature) = staged_signature.signature {
+                WebhookType::add_staged_webhook_header(&mut headers, signature)
+            }
+        }
+
         Ok(OutgoingWebhookRequestContent {
             body: outgoing_webhooks_signature.payload,
             headers: headers
@@ -697,12 +922,87 @@ pub(crate) fn get_outgoing_webhook_request(
 
     match processor.get_account().get_compatible_connector() {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(outgoing_webhook, business_profile),
+        Some(api_models::enums::Connector::Stripe) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                business_profile,
+                webhook_endpoint_signing_secret,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
+            webhook_endpoint_signing_secret,
+        ),
+    }
+}
+
+/// Signs the stored request of an outgoing webhook again with the current signing secrets of the
+/// business profile or of the webhook endpoint.
+pub(crate) fn resign_outgoing_webhook_request(
+    merchant_account: &domain::MerchantAccount,
+    request_content: OutgoingWebhookRequestContent,
+    business_profile: &domain::Profile,
+    webhook_endpoint_signing_secret: Option<&Secret<String>>,
+) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
+    #[inline]
+    fn resign_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
+        request_content: OutgoingWebhookRequestContent,
+        business_profile: &domain::Profile,
+        webhook_endpoint_signing_secret: Option<&Secret<String>>,
+    ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
+        let mut headers: Vec<(String, Maskable<String>)> = request_content
+            .headers
+            .into_iter()
+            .filter(|(name, _)| {
+                !WEBHOOK_SIGNATURE_HEADERS
+                    .iter()
+                    .any(|signature_header| name.eq_ignore_ascii_case(signature_header))
+            })
+            .map(|(name, value)| (name, value.into_masked()))
+            .collect();
+
+        let (payment_response_hash_key, staged_payment_response_hash_key) =
+            get_webhook_signing_keys(business_profile, webhook_endpoint_signing_secret);
+        let payload = request_content.body.peek();
+
+        if let Some(signature) =
+            WebhookType::get_outgoing_webhook_payload_signature(payload, payment_response_hash_key)?
+        {
+            WebhookType::add_webhook_header(&mut headers, signature)
+        }
+
+        if let Some(staged_payment_response_hash_key) = staged_payment_response_hash_key {
+            if let Some(signature) = WebhookType::get_outgoing_webhook_payload_signature(
+                payload,
+                Some(staged_payment_response_hash_key.expose()),
+            )? {
+                WebhookType::add_staged_webhook_header(&mut headers, signature)
+            }
+        }
+
+        Ok(OutgoingWebhookRequestContent {
+            body: request_content.body,
+            headers: headers
+                .into_iter()
+                .map(|(name, value)| (name, Secret::new(value.into_inner())))
+                .collect(),
+        })
+    }
+
+    match merchant_account.get_compatible_connector() {
+        #[cfg(feature = "stripe")]
+        Some(api_models::enums::Connector::Stripe) => {
+            resign_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                request_content,
+                business_profile,
+                webhook_endpoint_signing_secret,
+            )
+        }
+        _ => resign_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
+            request_content,
+            business_profile,
+            webhook_endpoint_signing_secret,
         ),
     }
 }
@@ -1008,6 +1308,13 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
                     payment_id: subscription.get_optional_payment_id(),
                 }
             }
+            webhooks::OutgoingWebhookContent::RoutingRollbackDetails(rollback_details) => {
+                Self::RoutingRollback {
+                    schedule_id: rollback_details.schedule_id.clone(),
+                    profile_id: rollback_details.profile_id.clone(),
+                    algorithm_id: rollback_details.rolled_back_algorithm_id.clone(),
+                }
+            }
         }
     }
 }
@@ -1062,5 +1369,15 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             payment_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::RoutingRollback {
+            schedule_id,
+            profile_id,
+            algorithm_id,
+        } => OutgoingWebhookEventContent::RoutingRollback {
+            schedule_id,
+            profile_id,
+            algorithm_id,
+            content: serde_json::Value::Null,
+        },
     })
 }
@@ -47,7 +47,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
     let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt)
+        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt, None)
             .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
             .attach_printable("Failed to generate idempotent event ID")?;
     let webhook_url_result = business_profile
@@ -116,6 +116,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
         is_overall_delivery_successful: Some(false),
+        webhook_endpoint_id: None,
     };
 
     let event_insert_result = state
@@ -666,6 +667,16 @@ impl ForeignFrom<storage::EventMetadata> for outgoing_webhook_logs::OutgoingWebh
                 payment_id,
                 content: serde_json::Value::Null,
             },
+            diesel_models::EventMetadata::RoutingRollback {
+                schedule_id,
+                profile_id,
+                algorithm_id,
+            } => Self::RoutingRollback {
+                schedule_id,
+                profile_id,
+                algorithm_id,
+                content: serde_json::Value::Null,
+            },
         }
     }
 }
@@ -30,7 +30,23 @@ pub trait OutgoingWebhookType:
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError>;
 
+    /// Signs an already encoded payload, used to sign the stored payload again when the delivery
+    /// of a webhook is retried
+    fn get_outgoing_webhook_payload_signature(
+        payload: &str,
+        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+    ) -> errors::CustomResult<Option<String>, errors::WebhooksFlowError>;
+
     fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String);
+
+    /// Adds the signature computed with the staged secret of an in-progress signing secret
+    /// rotation, so that consumers can switch over to the new secret during the grace period
+    fn add_staged_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
+        header.push((
+            headers::X_WEBHOOK_SIGNATURE_NEXT.to_string(),
+            signature.into(),
+        ))
+    }
 }
 
 impl OutgoingWebhookType for webhooks::OutgoingWebhook {
@@ -43,23 +59,33 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
             .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
             .attach_printable("failed encoding outgoing webhook payload")?;
 
-        let signature = payment_response_hash_key
+        let signature = Self::get_outgoing_webhook_payload_signature(
+            &webhook_signature_payload,
+            payment_response_hash_key,
+        )?;
+
+        Ok(OutgoingWebhookPayloadWithSignature {
+            payload: webhook_signature_payload.into(),
+            signature,
+        })
+    }
+
+    fn get_outgoing_webhook_payload_signature(
+        payload: &str,
+        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+    ) -> errors::CustomResult<Option<String>, errors::WebhooksFlowError> {
+        payment_response_hash_key
             .map(|key| {
                 common_utils::crypto::HmacSha512::sign_message(
                     &common_utils::crypto::HmacSha512,
                     key.as_ref(),
-                    webhook_signature_payload.as_bytes(),
+                    payload.as_bytes(),
                 )
             })
             .transpose()
             .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
-            .attach_printable("Failed to sign the message")?
-            .map(hex::encode);
-
-        Ok(OutgoingWebhookPayloadWithSignature {
-            payload: webhook_signature_payload.into(),
-            signature,
-        })
+            .attach_printable("Failed to sign the message")
+            .map(|signature| signature.map(hex::encode))
     }
 
     fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
@@ -76,6 +102,15 @@ pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) primary_object_id: String,
     pub(crate) primary_object_type: enums::EventObjectType,
     pub(crate) initial_attempt_id: Option<String>,
+    #[serde(default)]
+    pub(crate) webhook_endpoint_id: Option<String>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct WebhookSecretRotationTrackingData {
+    pub(crate) merchant_id: common_utils::id_type::MerchantId,
+    pub(crate) business_profile_id: common_utils::id_type::ProfileId,
 }
 
 pub struct WebhookResponse {
@@ -0,0 +1,423 @@
+use api_models::webhook_endpoints as api_webhook_endpoints;
+use common_utils::{crypto::Encryptable, fp_utils, type_name, types::keymanager};
+use error_stack::ResultExt;
+use hyperswitch_masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        api_locking,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    routes::{lock_utils, SessionState},
+    services::ApplicationResponse,
+    types::{domain, domain::types as domain_types, storage, transformers::ForeignFrom},
+};
+
+const MAX_WEBHOOK_ENDPOINTS_PER_PROFILE: usize = 16;
+const WEBHOOK_ENDPOINT_ID_PREFIX: &str = "whep";
+const WEBHOOK_ENDPOINT_SIGNING_SECRET_LENGTH: usize = 64;
+/// Upper bound on the length of merchant provided signing secrets
+const WEBHOOK_ENDPOINT_SIGNING_SECRET_MAX_LENGTH: usize = 255;
+/// Length of the `description` column of the `webhook_endpoint` table
+const WEBHOOK_ENDPOINT_DESCRIPTION_MAX_LENGTH: usize = 255;
+
+#[instrument(skip_all)]
+pub async fn create_webhook_endpoint(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+    request: api_webhook_endpoints::WebhookEndpointCreateRequest,
+) -> RouterResponse<api_webhook_endpoints::WebhookEndpointWithSecretResponse> {
+    validate_profile(&state, &merchant_id, &key_store, &profile_id).await?;
+    validate_webhook_endpoint_url(request.url.peek())?;
+    request
+        .description
+        .as_deref()
+        .map(validate_webhook_endpoint_description)
+        .transpose()?;
+
+    let signing_secret = request
+        .signing_secret
+        .map(ExposeInterface::expose)
+        .unwrap_or_else(|| {
+            common_utils::crypto::generate_cryptographically_secure_random_string(
+                WEBHOOK_ENDPOINT_SIGNING_SECRET_LENGTH,
+            )
+        });
+    validate_webhook_endpoint_signing_secret(&signing_secret)?;
+    let encrypted_signing_secret = domain_types::crypto_operation(
+        &(&state).into(),
+        type_name!(storage::WebhookEndpoint),
+        domain_types::CryptoOperation::Encrypt(Secret::new(signing_secret.clone())),
+        keymanager::Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the webhook endpoint signing secret")?;
+
+    let now = common_utils::date_time::now();
+    let webhook_endpoint_new = storage::WebhookEndpointNew {
+        id: common_utils::generate_time_ordered_id(WEBHOOK_ENDPOINT_ID_PREFIX),
+        merchant_id: merchant_id.clone(),
+        profile_id: profile_id.clone(),
+        url: request.url.expose(),
+        signing_secret: encrypted_signing_secret.into(),
+        enabled_events: request.enabled_events,
+        is_enabled: request.is_enabled.unwrap_or(true),
+        description: request.description,
+        created_at: now,
+        modified_at: now,
+    };
+
+    // Creates of the same profile are serialized so that concurrent requests cannot exceed the
+    // maximum number of webhook endpoints
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: format!("{}_webhook_endpoints", profile_id.get_string_repr()),
+            api_identifier: lock_utils::ApiIdentifier::Profile,
+            override_lock_retries: None,
+        },
+    };
+    lock_action
+        .clone()
+        .perform_locking_action(&state, merchant_id.clone())
+        .await?;
+
+    let insert_result = insert_webhook_endpoint_within_limit(&state, webhook_endpoint_new).await;
+
+    lock_action.free_lock_action(&state, merchant_id).await?;
+    let webhook_endpoint = insert_result?;
+
+    Ok(ApplicationResponse::Json(
+        api_webhook_endpoints::WebhookEndpointWithSecretResponse {
+            webhook_endpoint: api_webhook_endpoints::WebhookEndpointResponse::foreign_from(
+                webhook_endpoint,
+            ),
+            signing_secret: Secret::new(signing_secret),
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_webhook_endpoints(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<api_webhook_endpoints::WebhookEndpointListResponse> {
+    validate_profile(&state, &merchant_id, &key_store, &profile_id).await?;
+
+    let data = state
+        .store
+        .list_webhook_endpoints_by_profile_id(&profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints for business profile")?
+        .into_iter()
+        .map(api_webhook_endpoints::WebhookEndpointResponse::foreign_from)
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        api_webhook_endpoints::WebhookEndpointListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_webhook_endpoint(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    key_store: domain::MerchantKeyStore,
+    request: api_webhook_endpoints::WebhookEndpointRequestInternal,
+) -> RouterResponse<api_webhook_endpoints::WebhookEndpointResponse> {
+    validate_profile(&state, &merchant_id, &key_store, &request.profile_id).await?;
+
+    let webhook_endpoint = state
+        .store
+        .find_webhook_endpoint_by_profile_id_endpoint_id(&request.profile_id, &request.endpoint_id)
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(&request.endpoint_id))?;
+
+    Ok(ApplicationResponse::Json(
+        api_webhook_endpoints::WebhookEndpointResponse::foreign_from(webhook_endpoint),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn update_webhook_endpoint(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    key_store: domain::MerchantKeyStore,
+    request: api_webhook_endpoints::WebhookEndpointRequestInternal,
+    update: api_webhook_endpoints::WebhookEndpointUpdateRequest,
+) -> RouterResponse<api_webhook_endpoints::WebhookEndpointResponse> {
+    validate_profile(&state, &merchant_id, &key_store, &request.profile_id).await?;
+    update
+        .url
+        .as_ref()
+        .map(|url| validate_webhook_endpoint_url(url.peek()))
+        .transpose()?;
+    update
+        .description
+        .as_deref()
+        .map(validate_webhook_endpoint_description)
+        .transpose()?;
+
+    let webhook_endpoint_update = storage::WebhookEndpointUpdate::Update {
+        url: update.url.map(ExposeInterface::expose),
+        enabled_events: update.enabled_events,
+        is_enabled: update.is_enabled,
+        // An empty description clears the description of the endpoint
+        description: update
+            .description
+            .map(|description| Some(description).filter(|description| !description.is_empty())),
+    };
+
+    let webhook_endpoint = state
+        .store
+        .update_webhook_endpoint_by_profile_id_endpoint_id(
+            &request.profile_id,
+            &request.endpoint_id,
+            webhook_endpoint_update,
+        )
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(&request.endpoint_id))?;
+
+    Ok(ApplicationResponse::Json(
+        api_webhook_endpoints::WebhookEndpointResponse::foreign_from(webhook_endpoint),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_webhook_endpoint(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    key_store: domain::MerchantKeyStore,
+    request: api_webhook_endpoints::WebhookEndpointRequestInternal,
+) -> RouterResponse<api_webhook_endpoints::WebhookEndpointDeleteResponse> {
+    validate_profile(&state, &merchant_id, &key_store, &request.profile_id).await?;
+
+    let webhook_endpoint = state
+        .store
+        .delete_webhook_endpoint_by_profile_id_endpoint_id(
+            &request.profile_id,
+            &request.endpoint_id,
+        )
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(&request.endpoint_id))?;
+
+    Ok(ApplicationResponse::Json(
+        api_webhook_endpoints::WebhookEndpointDeleteResponse {
+            id: webhook_endpoint.id,
+            profile_id: webhook_endpoint.profile_id,
+            deleted: true,
+        },
+    ))
+}
+
+/// Returns the enabled webhook endpoints of the business profile which are subscribed to the
+/// specified event type.
+pub(crate) async fn get_webhook_endpoints_for_event(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+    event_type: storage::enums::EventType,
+) -> RouterResult<Vec<storage::WebhookEndpoint>> {
+    Ok(state
+        .store
+        .list_webhook_endpoints_by_profile_id(profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints for business profile")?
+        .into_iter()
+        .filter(|webhook_endpoint| webhook_endpoint.is_subscribed_to(event_type))
+        .collect())
+}
+
+/// Decrypts the signing secret of the webhook endpoint.
+pub(crate) async fn get_webhook_endpoint_signing_secret(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    webhook_endpoint: &storage::WebhookEndpoint,
+) -> RouterResult<Secret<String>> {
+    domain_types::crypto_operation::<String, hyperswitch_masking::WithType>(
+        &state.into(),
+        type_name!(storage::WebhookEndpoint),
+        domain_types::CryptoOperation::Decrypt(webhook_endpoint.signing_secret.clone()),
+        keymanager::Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt the webhook endpoint signing secret")
+    .map(Encryptable::into_inner)
+}
+
+/// Inserts the webhook endpoint unless the business profile already has the maximum number of
+/// webhook endpoints. Must be called with the webhook endpoints lock of the profile held.
+async fn insert_webhook_endpoint_within_limit(
+    state: &SessionState,
+    webhook_endpoint_new: storage::WebhookEndpointNew,
+) -> RouterResult<storage::WebhookEndpoint> {
+    let db = state.store.as_ref();
+    let existing_endpoints = db
+        .list_webhook_endpoints_by_profile_id(&webhook_endpoint_new.profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints for business profile")?;
+    fp_utils::when(
+        existing_endpoints.len() >= MAX_WEBHOOK_ENDPOINTS_PER_PROFILE,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "A business profile can have at most {MAX_WEBHOOK_ENDPOINTS_PER_PROFILE} webhook endpoints"
+                ),
+            })
+        },
+    )?;
+
+    db.insert_webhook_endpoint(webhook_endpoint_new)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Webhook endpoint with the same ID already exists".to_string(),
+        })
+}
+
+async fn validate_profile(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<()> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(key_store, merchant_id, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+        .map(|_| ())
+}
+
+fn validate_webhook_endpoint_url(url: &str) -> RouterResult<()> {
+    let parsed_url =
+        url::Url::parse(url).change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`url` must be a valid absolute URL".to_string(),
+        })?;
+
+    fp_utils::when(!matches!(parsed_url.scheme(), "http" | "https"), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`url` must use the `http` or `https` scheme".to_string(),
+        })
+    })?;
+
+    Ok(())
+}
+
+fn validate_webhook_endpoint_signing_secret(signing_secret: &str) -> RouterResult<()> {
+    fp_utils::when(signing_secret.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`signing_secret` must not be empty".to_string(),
+        })
+    })?;
+
+    fp_utils::when(
+        signing_secret.len() > WEBHOOK_ENDPOINT_SIGNING_SECRET_MAX_LENGTH,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`signing_secret` must be at most {WEBHOOK_ENDPOINT_SIGNING_SECRET_MAX_LENGTH} characters long"
+                ),
+            })
+        },
+    )?;
+
+    Ok(())
+}
+
+fn validate_webhook_endpoint_description(description: &str) -> RouterResult<()> {
+    fp_utils::when(
+        description.len() > WEBHOOK_ENDPOINT_DESCRIPTION_MAX_LENGTH,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`description` must be at most {WEBHOOK_ENDPOINT_DESCRIPTION_MAX_LENGTH} characters long"
+                ),
+            })
+        },
+    )?;
+
+    Ok(())
+}
+
+fn webhook_endpoint_not_found(endpoint_id: &str) -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Webhook endpoint `{endpoint_id}` does not exist"),
+    }
+}
+
+impl ForeignFrom<storage::WebhookEndpoint> for api_webhook_endpoints::WebhookEndpointResponse {
+    fn foreign_from(webhook_endpoint: storage::WebhookEndpoint) -> Self {
+        Self {
+            id: webhook_endpoint.id,
+            merchant_id: webhook_endpoint.merchant_id,
+            profile_id: webhook_endpoint.profile_id,
+            url: Secret::new(webhook_endpoint.url),
+            enabled_events: webhook_endpoint.enabled_events,
+            is_enabled: webhook_endpoint.is_enabled,
+            description: webhook_endpoint.description,
+            created_at: webhook_endpoint.created_at,
+            modified_at: webhook_endpoint.modified_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_webhook_endpoint_url() {
+        assert!(validate_webhook_endpoint_url("https://example.com/webhooks").is_ok());
+        assert!(validate_webhook_endpoint_url("http://localhost:8080").is_ok());
+        assert!(validate_webhook_endpoint_url("ftp://example.com").is_err());
+        assert!(validate_webhook_endpoint_url("/webhooks").is_err());
+    }
+
+    #[test]
+    fn test_validate_webhook_endpoint_signing_secret() {
+        let generated_secret =
+            common_utils::crypto::generate_cryptographically_secure_random_string(
+                WEBHOOK_ENDPOINT_SIGNING_SECRET_LENGTH,
+            );
+        assert!(validate_webhook_endpoint_signing_secret(&generated_secret).is_ok());
+        assert!(validate_webhook_endpoint_signing_secret(
+            &"a".repeat(WEBHOOK_ENDPOINT_SIGNING_SECRET_MAX_LENGTH)
+        )
+        .is_ok());
+        assert!(validate_webhook_endpoint_signing_secret(
+            &"a".repeat(WEBHOOK_ENDPOINT_SIGNING_SECRET_MAX_LENGTH + 1)
+        )
+        .is_err());
+        assert!(validate_webhook_endpoint_signing_secret("").is_err());
+    }
+
+    #[test]
+    fn test_validate_webhook_endpoint_description() {
+        assert!(validate_webhook_endpoint_description("").is_ok());
+        assert!(validate_webhook_endpoint_description(
+            &"a".repeat(WEBHOOK_ENDPOINT_DESCRIPTION_MAX_LENGTH)
+        )
+        .is_ok());
+        assert!(validate_webhook_endpoint_description(
+            &"a".repeat(WEBHOOK_ENDPOINT_DESCRIPTION_MAX_LENGTH + 1)
+        )
+        .is_err());
+    }
+}
@@ -299,6 +299,7 @@ pub async fn retry_delivery_attempt(
         &event_to_retry.primary_object_id,
         event_to_retry.event_type,
         delivery_attempt,
+        event_to_retry.webhook_endpoint_id.as_deref(),
     )
     .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
     .attach_printable("Failed to generate idempotent event ID")?;
@@ -322,6 +323,7 @@ pub async fn retry_delivery_attempt(
         delivery_attempt: Some(delivery_attempt),
         metadata: event_to_retry.metadata,
         is_overall_delivery_successful: Some(false),
+        webhook_endpoint_id: event_to_retry.webhook_endpoint_id,
     };
 
     let event = store
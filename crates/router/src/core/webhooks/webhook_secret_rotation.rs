@@ -0,0 +1,240 @@
+use api_models::admin::{WebhookSecretRotationRequest, WebhookSecretRotationResponse};
+use common_utils::{date_time, fp_utils, type_name, types::keymanager};
+use error_stack::ResultExt;
+use hyperswitch_masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use super::types;
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{domain, domain::types as domain_types, storage},
+};
+
+const DEFAULT_GRACE_PERIOD_IN_SECONDS: u32 = 24 * 60 * 60;
+const MAX_GRACE_PERIOD_IN_SECONDS: u32 = 30 * 24 * 60 * 60;
+const WEBHOOK_SIGNING_SECRET_LENGTH: usize = 64;
+const WEBHOOK_SECRET_ROTATION_TASK: &str = "WEBHOOK_SECRET_ROTATION";
+const WEBHOOK_SECRET_ROTATION_TAG: &str = "WEBHOOK_SECRET_ROTATION";
+
+/// Stages a new webhook signing secret for the profile.
+/// During the grace period, outgoing webhooks carry signatures computed with both the current and
+/// the staged secret, after which the scheduler retires the current secret.
+#[instrument(skip_all)]
+pub async fn rotate_webhook_signing_secret(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+    request: WebhookSecretRotationRequest,
+) -> RouterResponse<WebhookSecretRotationResponse> {
+    let db = state.store.as_ref();
+    let grace_period_in_seconds = request
+        .grace_period_in_seconds
+        .unwrap_or(DEFAULT_GRACE_PERIOD_IN_SECONDS);
+    fp_utils::when(
+        grace_period_in_seconds == 0 || grace_period_in_seconds > MAX_GRACE_PERIOD_IN_SECONDS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`grace_period_in_seconds` must be between 1 and {MAX_GRACE_PERIOD_IN_SECONDS}"
+                ),
+            })
+        },
+    )?;
+
+    let business_profile = db
+        .find_business_profile_by_merchant_id_profile_id(&key_store, &merchant_id, &profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    fp_utils::when(
+        business_profile
+            .webhook_secret_rotation
+            .as_ref()
+            .is_some_and(|rotation| rotation.is_grace_period_active()),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message:
+                    "A webhook signing secret rotation is already in progress for this profile"
+                        .to_string(),
+            })
+        },
+    )?;
+
+    // A rotation whose grace period has ended but which has not yet been completed by the
+    // scheduler is completed here, so that the staged secret is not lost
+    let payment_response_hash_key =
+        business_profile
+            .webhook_secret_rotation
+            .as_ref()
+            .map(|rotation| {
+                rotation
+                    .staged_payment_response_hash_key
+                    .clone()
+                    .into_inner()
+                    .expose()
+            });
+
+    let grace_period_ends_at = date_time::now()
+        .saturating_add(time::Duration::seconds(i64::from(grace_period_in_seconds)));
+    let staged_payment_response_hash_key = domain_types::crypto_operation(
+        &(&state).into(),
+        type_name!(domain::Profile),
+        domain_types::CryptoOperation::Encrypt(Secret::new(
+            common_utils::crypto::generate_cryptographically_secure_random_string(
+                WEBHOOK_SIGNING_SECRET_LENGTH,
+            ),
+        )),
+        keymanager::Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the staged webhook signing secret")?;
+    let webhook_secret_rotation = domain::WebhookSecretRotation {
+        staged_payment_response_hash_key,
+        grace_period_ends_at,
+    };
+
+    // The task is added before the rotation is persisted, so that a failure to add it does not
+    // leave behind a rotation whose staged secret was never returned. A task without a rotation
+    // to complete is a no-op.
+    add_webhook_secret_rotation_task(
+        db,
+        &merchant_id,
+        &profile_id,
+        grace_period_ends_at,
+        state.conf.application_source,
+    )
+    .await?;
+
+    let profile_update = domain::ProfileUpdate::WebhookSecretRotationUpdate {
+        payment_response_hash_key,
+        webhook_secret_rotation: Some(webhook_secret_rotation.clone()),
+    };
+    db.update_profile_by_profile_id(&key_store, business_profile, profile_update)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    Ok(ApplicationResponse::Json(WebhookSecretRotationResponse {
+        profile_id,
+        staged_payment_response_hash_key: webhook_secret_rotation
+            .staged_payment_response_hash_key
+            .into_inner(),
+        grace_period_ends_at,
+    }))
+}
+
+/// Replaces the current webhook signing secret of the profile with the staged one, if the grace
+/// period of the rotation has ended.
+/// Rotations that are still within their grace period were started after the task was scheduled,
+/// and are left untouched.
+#[instrument(skip_all)]
+pub(crate) async fn complete_webhook_secret_rotation(
+    state: &SessionState,
+    tracking_data: &types::WebhookSecretRotationTrackingData,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let business_profile = db
+        .find_business_profile_by_merchant_id_profile_id(
+            &key_store,
+            &tracking_data.merchant_id,
+            &tracking_data.business_profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: tracking_data
+                .business_profile_id
+                .get_string_repr()
+                .to_owned(),
+        })?;
+
+    let staged_payment_response_hash_key = match &business_profile.webhook_secret_rotation {
+        Some(rotation) if !rotation.is_grace_period_active() => rotation
+            .staged_payment_response_hash_key
+            .clone()
+            .into_inner(),
+        Some(_) | None => {
+            logger::info!(
+                "No webhook signing secret rotation pending completion for business profile"
+            );
+            return Ok(());
+        }
+    };
+
+    let profile_update = domain::ProfileUpdate::WebhookSecretRotationUpdate {
+        payment_response_hash_key: Some(staged_payment_response_hash_key.expose()),
+        webhook_secret_rotation: None,
+    };
+    db.update_profile_by_profile_id(&key_store, business_profile, profile_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retire the previous webhook signing secret")?;
+
+    Ok(())
+}
+
+async fn add_webhook_secret_rotation_task(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: &common_utils::id_type::ProfileId,
+    schedule_time: time::PrimitiveDateTime,
+    application_source: common_enums::ApplicationSource,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::WebhookSecretRotationWorkflow;
+    let tracking_data = types::WebhookSecretRotationTrackingData {
+        merchant_id: merchant_id.clone(),
+        business_profile_id: profile_id.clone(),
+    };
+    // A profile may be rotated several times, the schedule time keeps the task IDs unique
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        WEBHOOK_SECRET_ROTATION_TASK,
+        &format!(
+            "{}_{}",
+            profile_id.get_string_repr(),
+            schedule_time.assume_utc().unix_timestamp()
+        ),
+        merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        WEBHOOK_SECRET_ROTATION_TASK,
+        runner,
+        [WEBHOOK_SECRET_ROTATION_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+        application_source,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct webhook secret rotation process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert webhook secret rotation task to process_tracker")?;
+    metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "WebhookSecretRotation")),
+    );
+
+    Ok(())
+}
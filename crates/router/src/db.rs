@@ -23,6 +23,7 @@ pub mod gsm;
 pub mod health_check;
 pub mod hyperswitch_ai_interaction;
 pub mod kafka_store;
+pub mod kv_consistency;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -41,6 +42,7 @@ pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use ::payment_methods::state::PaymentMethodsStorageInterface;
 use common_utils::{id_type, types::keymanager::KeyManagerState};
@@ -139,6 +141,7 @@ pub trait StorageInterface:
     + authorization::AuthorizationInterface
     + user::sample_data::BatchSampleDataInterface
     + health_check::HealthCheckDbInterface
+    + kv_consistency::KvConsistencyInterface
     + user_authentication_method::UserAuthenticationMethodInterface
     + hyperswitch_ai_interaction::HyperswitchAiInteractionInterface
     + authentication::AuthenticationInterface
@@ -148,6 +151,7 @@ pub trait StorageInterface:
     + payment_method_session::PaymentMethodsSessionInterface
     + tokenization::TokenizationInterface
     + callback_mapper::CallbackMapperInterface
+    + webhook_endpoint::WebhookEndpointInterface
     + storage_impl::subscription::SubscriptionInterface<Error = StorageError>
     + storage_impl::invoice::InvoiceInterface<Error = StorageError>
     + 'static
@@ -410,6 +414,38 @@ impl FraudCheckInterface for KafkaStore {
         }
         Ok(frm)
     }
+    async fn list_fraud_checks_by_merchant_id_review_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        review_status: common_enums::FrmReviewStatus,
+        assigned_to: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<FraudCheck>, StorageError> {
+        self.diesel_store
+            .list_fraud_checks_by_merchant_id_review_status(
+                merchant_id,
+                review_status,
+                assigned_to,
+                limit,
+                offset,
+            )
+            .await
+    }
+    async fn count_fraud_checks_by_merchant_id_review_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        review_status: common_enums::FrmReviewStatus,
+        assigned_to: Option<&str>,
+    ) -> CustomResult<usize, StorageError> {
+        self.diesel_store
+            .count_fraud_checks_by_merchant_id_review_status(
+                merchant_id,
+                review_status,
+                assigned_to,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
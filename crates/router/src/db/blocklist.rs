@@ -47,6 +47,25 @@ pub trait BlocklistInterface {
         merchant_id: &common_utils::id_type::MerchantId,
         data_kind: common_enums::BlocklistDataKind,
     ) -> CustomResult<usize, errors::StorageError>;
+
+    async fn list_unexpired_blocklist_entries_by_merchant_id_data_kind(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kind: common_enums::BlocklistDataKind,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
+
+    async fn find_unexpired_blocklist_entries_by_merchant_id_fingerprint_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
+
+    async fn find_unexpired_blocklist_entries_by_merchant_id_data_kind_range_value(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kind: common_enums::BlocklistDataKind,
+        value: String,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -133,6 +152,52 @@ impl BlocklistInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn list_unexpired_blocklist_entries_by_merchant_id_data_kind(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kind: common_enums::BlocklistDataKind,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Blocklist::list_unexpired_by_merchant_id_data_kind(&conn, merchant_id, data_kind)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_unexpired_blocklist_entries_by_merchant_id_fingerprint_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Blocklist::find_unexpired_by_merchant_id_fingerprint_ids(
+            &conn,
+            merchant_id,
+            fingerprint_ids,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_unexpired_blocklist_entries_by_merchant_id_data_kind_range_value(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kind: common_enums::BlocklistDataKind,
+        value: String,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Blocklist::find_unexpired_by_merchant_id_data_kind_range_value(
+            &conn,
+            merchant_id,
+            data_kind,
+            value,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -185,6 +250,30 @@ impl BlocklistInterface for MockDb {
     ) -> CustomResult<storage::Blocklist, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
+    async fn list_unexpired_blocklist_entries_by_merchant_id_data_kind(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _data_kind: common_enums::BlocklistDataKind,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_unexpired_blocklist_entries_by_merchant_id_fingerprint_ids(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _fingerprint_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_unexpired_blocklist_entries_by_merchant_id_data_kind_range_value(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _data_kind: common_enums::BlocklistDataKind,
+        _value: String,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
 
 #[async_trait::async_trait]
@@ -252,4 +341,45 @@ impl BlocklistInterface for KafkaStore {
             .list_blocklist_entries_by_merchant_id(merchant_id)
             .await
     }
+
+    #[instrument(skip_all)]
+    async fn list_unexpired_blocklist_entries_by_merchant_id_data_kind(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kind: common_enums::BlocklistDataKind,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        self.diesel_store
+            .list_unexpired_blocklist_entries_by_merchant_id_data_kind(merchant_id, data_kind)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_unexpired_blocklist_entries_by_merchant_id_fingerprint_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        self.diesel_store
+            .find_unexpired_blocklist_entries_by_merchant_id_fingerprint_ids(
+                merchant_id,
+                fingerprint_ids,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_unexpired_blocklist_entries_by_merchant_id_data_kind_range_value(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kind: common_enums::BlocklistDataKind,
+        value: String,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        self.diesel_store
+            .find_unexpired_blocklist_entries_by_merchant_id_data_kind_range_value(
+                merchant_id,
+                data_kind,
+                value,
+            )
+            .await
+    }
 }
@@ -1080,6 +1080,7 @@ mod tests {
                         .unwrap(),
                     }),
                     is_overall_delivery_successful: Some(false),
+                    webhook_endpoint_id: None,
                 },
                 &merchant_key_store,
             )
@@ -1193,6 +1194,7 @@ mod tests {
                         .unwrap(),
                     }),
                     is_overall_delivery_successful: Some(false),
+                    webhook_endpoint_id: None,
                 },
                 &merchant_key_store,
             )
@@ -1399,6 +1401,8 @@ mod tests {
             is_pre_network_tokenization_enabled: false,
             merchant_category_code: None,
             dispute_polling_interval: None,
+            routing_timezone: None,
+            native_frm_config: None,
             is_manual_retry_enabled: None,
             always_enable_overcapture: None,
             external_vault_details: domain::ExternalVaultDetails::Skip,
@@ -33,6 +33,22 @@ pub trait FraudCheckInterface {
         payment_id: common_utils::id_type::PaymentId,
         merchant_id: common_utils::id_type::MerchantId,
     ) -> CustomResult<Option<FraudCheck>, errors::StorageError>;
+
+    async fn list_fraud_checks_by_merchant_id_review_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_status: common_enums::FrmReviewStatus,
+        assigned_to: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<FraudCheck>, errors::StorageError>;
+
+    async fn count_fraud_checks_by_merchant_id_review_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_status: common_enums::FrmReviewStatus,
+        assigned_to: Option<&str>,
+    ) -> CustomResult<usize, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -83,6 +99,46 @@ impl FraudCheckInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn list_fraud_checks_by_merchant_id_review_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_status: common_enums::FrmReviewStatus,
+        assigned_to: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<FraudCheck>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        FraudCheck::list_by_merchant_id_review_status(
+            &conn,
+            merchant_id,
+            review_status,
+            assigned_to,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn count_fraud_checks_by_merchant_id_review_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_status: common_enums::FrmReviewStatus,
+        assigned_to: Option<&str>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        FraudCheck::count_by_merchant_id_review_status(
+            &conn,
+            merchant_id,
+            review_status,
+            assigned_to,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -115,4 +171,24 @@ impl FraudCheckInterface for MockDb {
     ) -> CustomResult<Option<FraudCheck>, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn list_fraud_checks_by_merchant_id_review_status(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _review_status: common_enums::FrmReviewStatus,
+        _assigned_to: Option<&str>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<FraudCheck>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn count_fraud_checks_by_merchant_id_review_status(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _review_status: common_enums::FrmReviewStatus,
+        _assigned_to: Option<&str>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
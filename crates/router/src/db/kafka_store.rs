@@ -80,6 +80,7 @@ use crate::{
         generic_link::GenericLinkInterface,
         gsm::GsmInterface,
         health_check::HealthCheckDbInterface,
+        kv_consistency::{KvConsistencyInterface, KvEntity},
         locker_mock_up::LockerMockUpInterface,
         mandate::MandateInterface,
         merchant_account::MerchantAccountInterface,
@@ -1818,6 +1819,19 @@ impl PaymentAttemptInterface for KafkaStore {
             .await
     }
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn get_attempt_status_with_count(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        routing_approaches: Vec<common_enums::RoutingApproach>,
+        time_range: &common_utils::types::TimeRange,
+    ) -> CustomResult<Vec<(common_enums::AttemptStatus, i64)>, errors::StorageError> {
+        self.diesel_store
+            .get_attempt_status_with_count(merchant_id, profile_id, routing_approaches, time_range)
+            .await
+    }
+
     #[cfg(feature = "v1")]
     async fn find_attempts_by_processor_merchant_id_payment_id(
         &self,
@@ -2672,6 +2686,17 @@ impl ProcessTrackerInterface for KafkaStore {
             .finish_process_with_business_status(this, business_status)
             .await
     }
+
+    async fn finish_process_if_status_in(
+        &self,
+        id: &str,
+        statuses: Vec<ProcessTrackerStatus>,
+        business_status: &'static str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .finish_process_if_status_in(id, statuses, business_status)
+            .await
+    }
     async fn find_processes_by_time_status(
         &self,
         time_lower_limit: PrimitiveDateTime,
@@ -2683,6 +2708,29 @@ impl ProcessTrackerInterface for KafkaStore {
             .find_processes_by_time_status(time_lower_limit, time_upper_limit, status, limit)
             .await
     }
+
+    async fn list_processes_by_constraints(
+        &self,
+        runner: Option<storage::ProcessTrackerRunner>,
+        status: Option<ProcessTrackerStatus>,
+        merchant_id: Option<&id_type::MerchantId>,
+        schedule_time_lower_limit: Option<PrimitiveDateTime>,
+        schedule_time_upper_limit: Option<PrimitiveDateTime>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .list_processes_by_constraints(
+                runner,
+                status,
+                merchant_id,
+                schedule_time_lower_limit,
+                schedule_time_upper_limit,
+                limit,
+                offset,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -3968,6 +4016,30 @@ impl HealthCheckDbInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl KvConsistencyInterface for KafkaStore {
+    async fn find_kv_entity_in_postgres(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        entity: &KvEntity,
+    ) -> CustomResult<Option<serde_json::Value>, errors::StorageError> {
+        self.diesel_store
+            .find_kv_entity_in_postgres(merchant_id, entity)
+            .await
+    }
+
+    async fn overwrite_kv_entity_in_postgres(
+        &self,
+        entity: &KvEntity,
+        redis_value: &str,
+        modified_at: PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .overwrite_kv_entity_in_postgres(entity, redis_value, modified_at)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl RoleInterface for KafkaStore {
     async fn insert_role(
@@ -0,0 +1,289 @@
+use common_utils::{ext_traits::StringExt, id_type};
+use diesel_models as storage;
+use error_stack::{report, ResultExt};
+#[cfg(feature = "v1")]
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use super::{MockDb, Store};
+#[cfg(feature = "v1")]
+use crate::connection;
+use crate::core::errors::{self, CustomResult};
+
+/// An entity stored in the redis hash of a KV partition, identified by its field in the hash
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "entity", content = "id", rename_all = "snake_case")]
+pub enum KvEntity {
+    PaymentIntent(String),
+    PaymentAttempt(String),
+    Refund(String),
+    Address(String),
+    Customer(String),
+}
+
+impl KvEntity {
+    /// Parses the field of a KV partition, eg: `pi_{payment_id}`, `pa_{attempt_id}_ref_{refund_id}`
+    pub fn from_field(field: &str) -> Option<Self> {
+        if let Some(payment_id) = field.strip_prefix("pi_") {
+            Some(Self::PaymentIntent(payment_id.to_owned()))
+        } else if let Some(attempt_field) = field.strip_prefix("pa_") {
+            Some(match attempt_field.split_once("_ref_") {
+                Some((_, refund_id)) => Self::Refund(refund_id.to_owned()),
+                None => Self::PaymentAttempt(attempt_field.to_owned()),
+            })
+        } else if let Some(address_id) = field.strip_prefix("add_") {
+            Some(Self::Address(address_id.to_owned()))
+        } else {
+            field
+                .strip_prefix("cust_")
+                .map(|customer_id| Self::Customer(customer_id.to_owned()))
+        }
+    }
+
+    /// Deserializes the value stored in redis into the database model of the entity, so that it
+    /// can be compared with the row in Postgres
+    pub fn parse_redis_value(
+        &self,
+        value: &str,
+    ) -> CustomResult<serde_json::Value, errors::StorageError> {
+        match self {
+            Self::PaymentIntent(_) => {
+                to_json_value(value.parse_struct::<storage::PaymentIntent>("PaymentIntent"))
+            }
+            Self::PaymentAttempt(_) => {
+                to_json_value(value.parse_struct::<storage::PaymentAttempt>("PaymentAttempt"))
+            }
+            Self::Refund(_) => to_json_value(value.parse_struct::<storage::Refund>("Refund")),
+            Self::Address(_) => to_json_value(value.parse_struct::<storage::Address>("Address")),
+            Self::Customer(_) => to_json_value(value.parse_struct::<storage::Customer>("Customer")),
+        }
+    }
+}
+
+fn to_json_value<T: serde::Serialize>(
+    value: CustomResult<T, common_utils::errors::ParsingError>,
+) -> CustomResult<serde_json::Value, errors::StorageError> {
+    value
+        .change_context(errors::StorageError::DeserializationFailed)
+        .and_then(|value| {
+            serde_json::to_value(value).change_context(errors::StorageError::SerializationFailed)
+        })
+}
+
+#[async_trait::async_trait]
+pub trait KvConsistencyInterface {
+    /// Row of the entity in Postgres, serialized the same way as the value stored in redis
+    async fn find_kv_entity_in_postgres(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        entity: &KvEntity,
+    ) -> CustomResult<Option<serde_json::Value>, errors::StorageError>;
+
+    /// Overwrites the row of the entity in Postgres with its value in redis, if the row was not
+    /// modified after `modified_at`. Returns whether the row was overwritten.
+    async fn overwrite_kv_entity_in_postgres(
+        &self,
+        entity: &KvEntity,
+        redis_value: &str,
+        modified_at: PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl KvConsistencyInterface for Store {
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_kv_entity_in_postgres(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        entity: &KvEntity,
+    ) -> CustomResult<Option<serde_json::Value>, errors::StorageError> {
+        // The replica may lag behind the drainer, which would be reported as a divergence
+        let conn = connection::pg_connection_write(self).await?;
+
+        match entity {
+            KvEntity::PaymentIntent(payment_id) => {
+                let payment_id = id_type::PaymentId::wrap(payment_id.clone())
+                    .change_context(errors::StorageError::DeserializationFailed)?;
+                find_optional(
+                    storage::PaymentIntent::find_optional_by_payment_id_processor_merchant_id(
+                        &conn,
+                        &payment_id,
+                        merchant_id,
+                    )
+                    .await,
+                )
+            }
+            KvEntity::PaymentAttempt(attempt_id) => find_optional(
+                storage::PaymentAttempt::find_by_processor_merchant_id_attempt_id(
+                    &conn,
+                    merchant_id,
+                    attempt_id,
+                )
+                .await
+                .map(Some),
+            ),
+            KvEntity::Refund(refund_id) => find_optional(
+                storage::Refund::find_by_merchant_id_refund_id(&conn, merchant_id, refund_id)
+                    .await
+                    .map(Some),
+            ),
+            KvEntity::Address(address_id) => find_optional(
+                storage::Address::find_optional_by_address_id(&conn, address_id).await,
+            ),
+            KvEntity::Customer(customer_id) => {
+                let customer_id =
+                    id_type::CustomerId::try_from(std::borrow::Cow::from(customer_id.clone()))
+                        .change_context(errors::StorageError::DeserializationFailed)?;
+                find_optional(
+                    storage::Customer::find_optional_by_customer_id_merchant_id(
+                        &conn,
+                        &customer_id,
+                        merchant_id,
+                    )
+                    .await,
+                )
+            }
+        }
+    }
+
+    #[cfg(feature = "v2")]
+    async fn find_kv_entity_in_postgres(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+        _entity: &KvEntity,
+    ) -> CustomResult<Option<serde_json::Value>, errors::StorageError> {
+        Err(report!(errors::StorageError::KVError))
+            .attach_printable("KV consistency checks are not supported for v2 entities")
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn overwrite_kv_entity_in_postgres(
+        &self,
+        entity: &KvEntity,
+        redis_value: &str,
+        modified_at: PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+
+        let result = match entity {
+            KvEntity::PaymentIntent(_) => {
+                redis_value
+                    .parse_struct::<storage::PaymentIntent>("PaymentIntent")
+                    .change_context(errors::StorageError::DeserializationFailed)?
+                    .overwrite_if_unmodified(&conn, modified_at)
+                    .await
+            }
+            KvEntity::PaymentAttempt(_) => {
+                redis_value
+                    .parse_struct::<storage::PaymentAttempt>("PaymentAttempt")
+                    .change_context(errors::StorageError::DeserializationFailed)?
+                    .overwrite_if_unmodified(&conn, modified_at)
+                    .await
+            }
+            KvEntity::Refund(_) => {
+                redis_value
+                    .parse_struct::<storage::Refund>("Refund")
+                    .change_context(errors::StorageError::DeserializationFailed)?
+                    .overwrite_if_unmodified(&conn, modified_at)
+                    .await
+            }
+            KvEntity::Address(_) => {
+                redis_value
+                    .parse_struct::<storage::Address>("Address")
+                    .change_context(errors::StorageError::DeserializationFailed)?
+                    .overwrite_if_unmodified(&conn, modified_at)
+                    .await
+            }
+            KvEntity::Customer(_) => {
+                redis_value
+                    .parse_struct::<storage::Customer>("Customer")
+                    .change_context(errors::StorageError::DeserializationFailed)?
+                    .overwrite_if_unmodified(&conn, modified_at)
+                    .await
+            }
+        };
+
+        result.map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v2")]
+    async fn overwrite_kv_entity_in_postgres(
+        &self,
+        _entity: &KvEntity,
+        _redis_value: &str,
+        _modified_at: PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(report!(errors::StorageError::KVError))
+            .attach_printable("KV consistency checks are not supported for v2 entities")
+    }
+}
+
+#[cfg(feature = "v1")]
+fn find_optional<T: serde::Serialize>(
+    result: diesel_models::StorageResult<Option<T>>,
+) -> CustomResult<Option<serde_json::Value>, errors::StorageError> {
+    match result.map_err(|error| report!(errors::StorageError::from(error))) {
+        Ok(row) => row
+            .map(|row| {
+                serde_json::to_value(row).change_context(errors::StorageError::SerializationFailed)
+            })
+            .transpose(),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+#[async_trait::async_trait]
+impl KvConsistencyInterface for MockDb {
+    async fn find_kv_entity_in_postgres(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+        _entity: &KvEntity,
+    ) -> CustomResult<Option<serde_json::Value>, errors::StorageError> {
+        // The mock database does not have a KV store to be consistent with
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn overwrite_kv_entity_in_postgres(
+        &self,
+        _entity: &KvEntity,
+        _redis_value: &str,
+        _modified_at: PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // The mock database does not have a KV store to be consistent with
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KvEntity;
+
+    #[test]
+    fn test_kv_entity_from_field() {
+        assert_eq!(
+            KvEntity::from_field("pi_pay_123"),
+            Some(KvEntity::PaymentIntent("pay_123".to_owned()))
+        );
+        assert_eq!(
+            KvEntity::from_field("pa_pay_123_1"),
+            Some(KvEntity::PaymentAttempt("pay_123_1".to_owned()))
+        );
+        assert_eq!(
+            KvEntity::from_field("pa_pay_123_1_ref_ref_456"),
+            Some(KvEntity::Refund("ref_456".to_owned()))
+        );
+        assert_eq!(
+            KvEntity::from_field("add_add_789"),
+            Some(KvEntity::Address("add_789".to_owned()))
+        );
+        assert_eq!(
+            KvEntity::from_field("cust_cus_123"),
+            Some(KvEntity::Customer("cus_123".to_owned()))
+        );
+        // Fields of other entities, such as reverse lookups, are not compared
+        assert_eq!(KvEntity::from_field("rf_ref_456"), None);
+    }
+}
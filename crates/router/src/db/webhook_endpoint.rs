@@ -0,0 +1,217 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait WebhookEndpointInterface {
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn find_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn list_webhook_endpoints_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError>;
+
+    async fn update_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+        webhook_endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn delete_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        webhook_endpoint
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::find_by_profile_id_endpoint_id(&conn, profile_id, endpoint_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_endpoints_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::list_by_profile_id(&conn, profile_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+        webhook_endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::update_by_profile_id_endpoint_id(
+            &conn,
+            profile_id,
+            endpoint_id,
+            webhook_endpoint_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::delete_by_profile_id_endpoint_id(&conn, profile_id, endpoint_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for MockDb {
+    #[instrument(skip_all)]
+    async fn insert_webhook_endpoint(
+        &self,
+        _webhook_endpoint: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_webhook_endpoints_by_profile_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError> {
+        // No endpoints are configured in the mock store, outgoing webhooks fall back to the
+        // business profile webhook URL.
+        Ok(Vec::new())
+    }
+
+    async fn update_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _endpoint_id: &str,
+        _webhook_endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .insert_webhook_endpoint(webhook_endpoint)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .find_webhook_endpoint_by_profile_id_endpoint_id(profile_id, endpoint_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_endpoints_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError> {
+        self.diesel_store
+            .list_webhook_endpoints_by_profile_id(profile_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+        webhook_endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .update_webhook_endpoint_by_profile_id_endpoint_id(
+                profile_id,
+                endpoint_id,
+                webhook_endpoint_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .delete_webhook_endpoint_by_profile_id_endpoint_id(profile_id, endpoint_id)
+            .await
+    }
+}
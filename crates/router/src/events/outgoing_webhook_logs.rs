@@ -78,6 +78,12 @@ pub enum OutgoingWebhookEventContent {
         payment_id: Option<common_utils::id_type::PaymentId>,
         content: Value,
     },
+    RoutingRollback {
+        schedule_id: String,
+        profile_id: common_utils::id_type::ProfileId,
+        algorithm_id: common_utils::id_type::RoutingId,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_content(&self) -> Option<OutgoingWebhookEventContent>;
@@ -126,6 +132,15 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                         .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
                 })
             }
+            Self::RoutingRollbackDetails(rollback_details) => {
+                Some(OutgoingWebhookEventContent::RoutingRollback {
+                    schedule_id: rollback_details.schedule_id.clone(),
+                    profile_id: rollback_details.profile_id.clone(),
+                    algorithm_id: rollback_details.rolled_back_algorithm_id.clone(),
+                    content: hyperswitch_masking::masked_serialize(&rollback_details)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
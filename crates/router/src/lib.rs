@@ -76,6 +76,7 @@ pub mod headers {
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_SIGNATURE_NEXT: &str = "X-Webhook-Signature-512-Next";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
     pub const X_PROFILE_ID: &str = "X-Profile-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
@@ -226,9 +227,15 @@ pub fn mk_app(
                 .service(routes::Analytics::server(state.clone()))
                 .service(routes::WebhookEvents::server(state.clone()))
                 .service(routes::FeatureMatrix::server(state.clone()))
+                .service(routes::ProcessTracker::server(state.clone()))
                 .service(routes::Embedded::server(state.clone()));
         }
 
+        #[cfg(all(feature = "frm", feature = "v1"))]
+        {
+            server_app = server_app.service(routes::FrmReviews::server(state.clone()));
+        }
+
         #[cfg(feature = "v2")]
         {
             server_app = server_app
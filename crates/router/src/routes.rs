@@ -8,6 +8,8 @@ pub mod authentication;
 pub mod blocklist;
 pub mod cache;
 pub mod card_issuer;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod card_testing_guard;
 pub mod cards_info;
 pub mod configs;
 #[cfg(feature = "olap")]
@@ -70,6 +72,8 @@ pub mod verification;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
 #[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_endpoints;
+#[cfg(all(feature = "olap", feature = "v1"))]
 pub mod webhook_events;
 pub mod webhooks;
 
@@ -88,6 +92,8 @@ pub mod chat;
 
 #[cfg(feature = "dummy_connector")]
 pub use self::app::DummyConnector;
+#[cfg(all(feature = "olap", feature = "frm"))]
+pub use self::app::FrmReviews;
 #[cfg(feature = "v2")]
 pub use self::app::PaymentMethodSession;
 #[cfg(all(feature = "oltp", feature = "v2"))]
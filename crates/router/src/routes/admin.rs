@@ -2,6 +2,8 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(feature = "v1")]
+use crate::core::kv_migration;
 use crate::{
     core::{admin::*, api_locking, errors, merchant_connector_webhook_management::*},
     services::{api, authentication as auth, authorization::permissions::Permission},
@@ -1092,6 +1094,58 @@ pub async fn merchant_account_kv_status(
     )
     .await
 }
+
+/// Merchant Account - Start KV Migration
+///
+/// Migrate the Merchant Account to or from KV once the drainer has caught up with it
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKvMigrationStart))]
+pub async fn merchant_account_kv_migration_start(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<admin::KvMigrationRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKvMigrationStart;
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| kv_migration::start_kv_migration(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Retrieve KV Migration
+///
+/// Retrieve the progress of the latest KV migration of the Merchant Account
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKvMigrationRetrieve))]
+pub async fn merchant_account_kv_migration_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKvMigrationRetrieve;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| kv_migration::retrieve_kv_migration(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Merchant Account - KV Status
 ///
 /// Toggle KV mode for the Merchant Account
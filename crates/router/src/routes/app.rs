@@ -32,7 +32,10 @@ use hyperswitch_interfaces::{
 };
 use router_env::RequestId;
 use scheduler::SchedulerInterface;
-use storage_impl::{redis::RedisStore, MockDb};
+use storage_impl::{
+    redis::{kv_store::RedisConnInterface, RedisStore},
+    MockDb,
+};
 use tokio::sync::oneshot;
 
 use self::settings::Tenant;
@@ -92,12 +95,16 @@ use crate::routes::cards_info::{
 };
 #[cfg(all(feature = "olap", feature = "v1"))]
 use crate::routes::feature_matrix;
-#[cfg(all(feature = "frm", feature = "oltp"))]
+#[cfg(all(feature = "frm", any(feature = "olap", feature = "oltp")))]
 use crate::routes::fraud_check as frm_routes;
 #[cfg(all(feature = "olap", feature = "v1"))]
 use crate::routes::profile_acquirer;
 #[cfg(all(feature = "recon", feature = "olap"))]
 use crate::routes::recon as recon_routes;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::routes::card_testing_guard;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::routes::webhook_endpoints;
 pub use crate::{
     configs::settings,
     db::{
@@ -503,6 +510,12 @@ impl AppState {
             let theme_storage_client = conf.theme.storage.get_file_storage_client().await;
             let crm_client = conf.crm.get_crm_client().await;
 
+            #[cfg(feature = "dynamic_routing")]
+            let grpc_client = conf
+                .grpc_client
+                .get_grpc_client_interface(cache_store.get_redis_conn().ok())
+                .await;
+            #[cfg(not(feature = "dynamic_routing"))]
             let grpc_client = conf.grpc_client.get_grpc_client_interface().await;
             let infra_component_values = Self::process_env_mappings(conf.infra_values.clone());
             let enhancement = conf.enhancement.clone();
@@ -955,6 +968,10 @@ impl Payments {
                     web::resource("/{payment_id}/manual-update")
                         .route(web::put().to(payments::payments_manual_update)),
                 )
+                .service(
+                    web::resource("/{payment_id}/routing_trace")
+                        .route(web::get().to(payments::payments_routing_decision_trace)),
+                )
         }
         #[cfg(feature = "oltp")]
         {
@@ -1136,6 +1153,17 @@ impl Routing {
                     routing::migrate_routing_rules_for_profile(state, req, query)
                 },
             )))
+            .service(
+                web::resource("/simulate").route(web::post().to(routing::routing_simulate_config)),
+            )
+            .service(
+                web::resource("/activation_schedules/{schedule_id}")
+                    .route(web::get().to(routing::routing_retrieve_activation_schedule)),
+            )
+            .service(
+                web::resource("/activation_schedules/{schedule_id}/cancel")
+                    .route(web::post().to(routing::routing_cancel_activation_schedule)),
+            )
             .service(
                 web::resource("/deactivate").route(web::post().to(|state, req, payload| {
                     routing::routing_unlink_config(state, req, payload, None)
@@ -1172,6 +1200,13 @@ impl Routing {
                 })),
             );
 
+        #[cfg(feature = "routing_text")]
+        {
+            route = route.service(
+                web::resource("/text").route(web::post().to(routing::routing_create_text_config)),
+            );
+        }
+
         #[cfg(feature = "dynamic_routing")]
         {
             route = route
@@ -1299,6 +1334,10 @@ impl Routing {
                 web::resource("/{algorithm_id}")
                     .route(web::get().to(routing::routing_retrieve_config)),
             )
+            .service(
+                web::resource("/{algorithm_id}/text")
+                    .route(web::get().to(routing::routing_retrieve_text_config)),
+            )
             .service(
                 web::resource("/{algorithm_id}/activate").route(web::post().to(
                     |state, req, payload, path| {
@@ -1306,6 +1345,10 @@ impl Routing {
                     },
                 )),
             )
+            .service(
+                web::resource("/{algorithm_id}/schedule_activation")
+                    .route(web::post().to(routing::routing_schedule_activation)),
+            )
             .service(
                 web::resource("/rule/evaluate")
                     .route(web::post().to(routing::evaluate_routing_rule)),
@@ -1884,6 +1927,44 @@ impl Blocklist {
             .service(
                 web::resource("/toggle").route(web::post().to(blocklist::toggle_blocklist_guard)),
             )
+            .service(
+                web::resource("/import").route(web::post().to(blocklist::import_blocklist_entries)),
+            )
+            .service(
+                web::resource("/export").route(web::get().to(blocklist::export_blocklist_entries)),
+            )
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "frm"))]
+pub struct FrmReviews;
+
+#[cfg(all(feature = "olap", feature = "frm", feature = "v1"))]
+impl FrmReviews {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/frm/reviews")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(frm_routes::frm_review_list)))
+            .service(
+                web::resource("/{payment_id}")
+                    .route(web::get().to(frm_routes::frm_review_retrieve)),
+            )
+            .service(
+                web::resource("/{payment_id}/assign")
+                    .route(web::post().to(frm_routes::frm_review_assign)),
+            )
+            .service(
+                web::resource("/{payment_id}/notes")
+                    .route(web::post().to(frm_routes::frm_review_add_note)),
+            )
+            .service(
+                web::resource("/{payment_id}/approve")
+                    .route(web::post().to(frm_routes::frm_review_approve)),
+            )
+            .service(
+                web::resource("/{payment_id}/reject")
+                    .route(web::post().to(frm_routes::frm_review_reject)),
+            )
     }
 }
 
@@ -1986,6 +2067,11 @@ impl MerchantAccount {
                     .route(web::post().to(admin::merchant_account_toggle_kv))
                     .route(web::get().to(admin::merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/kv/migration")
+                    .route(web::post().to(admin::merchant_account_kv_migration_start))
+                    .route(web::get().to(admin::merchant_account_kv_migration_retrieve)),
+            )
             .service(
                 web::resource("/transfer")
                     .route(web::post().to(admin::merchant_account_transfer_keys)),
@@ -2580,6 +2666,35 @@ impl Profile {
                 .service(
                     web::resource("/toggle_connector_agnostic_mit")
                         .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
+                )
+                .service(
+                    web::resource("/webhook_secret/rotate")
+                        .route(web::post().to(profiles::rotate_webhook_signing_secret)),
+                )
+                .service(
+                    web::scope("/card_testing_guard/blocks")
+                        .service(web::resource("").route(
+                            web::get().to(card_testing_guard::list_card_testing_guard_blocks),
+                        ))
+                        .service(web::resource("/{block_id}").route(
+                            web::delete().to(card_testing_guard::lift_card_testing_guard_block),
+                        )),
+                )
+                .service(
+                    web::scope("/webhook_endpoints")
+                        .service(
+                            web::resource("")
+                                .route(web::post().to(webhook_endpoints::create_webhook_endpoint))
+                                .route(web::get().to(webhook_endpoints::list_webhook_endpoints)),
+                        )
+                        .service(
+                            web::resource("/{endpoint_id}")
+                                .route(web::get().to(webhook_endpoints::retrieve_webhook_endpoint))
+                                .route(web::post().to(webhook_endpoints::update_webhook_endpoint))
+                                .route(
+                                    web::delete().to(webhook_endpoints::delete_webhook_endpoint),
+                                ),
+                        ),
                 ),
         );
 
@@ -3249,6 +3364,33 @@ impl ProcessTrackerDeprecated {
 #[cfg(feature = "olap")]
 pub struct ProcessTracker;
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl ProcessTracker {
+    pub fn server(state: AppState) -> Scope {
+        use super::process_tracker::admin;
+        web::scope("/process_tracker")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/list").route(web::get().to(admin::process_tracker_list)))
+            .service(
+                web::scope("/{process_tracker_id}")
+                    .service(
+                        web::resource("").route(web::get().to(admin::process_tracker_retrieve)),
+                    )
+                    .service(
+                        web::resource("/run").route(web::post().to(admin::process_tracker_run)),
+                    )
+                    .service(
+                        web::resource("/reschedule")
+                            .route(web::post().to(admin::process_tracker_reschedule)),
+                    )
+                    .service(
+                        web::resource("/finish")
+                            .route(web::post().to(admin::process_tracker_finish)),
+                    ),
+            )
+    }
+}
+
 #[cfg(all(feature = "olap", feature = "v2"))]
 impl ProcessTracker {
     pub fn server(state: AppState) -> Scope {
@@ -0,0 +1,97 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::card_testing_guard as api_card_testing_guard;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, card_testing_guard},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::CardTestingGuardBlockList))]
+pub async fn list_card_testing_guard_blocks(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+) -> impl Responder {
+    let flow = Flow::CardTestingGuardBlockList;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth: auth::AuthenticationData, profile_id, _| {
+            card_testing_guard::list_card_testing_guard_blocks(
+                state,
+                auth.platform.get_processor().get_account().get_id().clone(),
+                auth.platform.get_processor().get_key_store().clone(),
+                profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id,
+                required_permission: Permission::ProfileAccountRead,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CardTestingGuardBlockLift))]
+pub async fn lift_card_testing_guard_block(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+) -> impl Responder {
+    let flow = Flow::CardTestingGuardBlockLift;
+    let (merchant_id, profile_id, block_id) = path.into_inner();
+
+    let request_internal = api_card_testing_guard::CardTestingGuardBlockRequestInternal {
+        profile_id: profile_id.clone(),
+        block_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth: auth::AuthenticationData, request_internal, _| {
+            card_testing_guard::lift_card_testing_guard_block(
+                state,
+                auth.platform.get_processor().get_account().get_id().clone(),
+                auth.platform.get_processor().get_key_store().clone(),
+                request_internal,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id,
+                required_permission: Permission::ProfileAccountWrite,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
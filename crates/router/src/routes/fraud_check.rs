@@ -1,6 +1,14 @@
 use actix_web::{web, HttpRequest, HttpResponse};
+#[cfg(all(feature = "olap", feature = "v1"))]
+use api_models::fraud_check as review_types;
 use router_env::Flow;
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::{
+    core::fraud_check::review,
+    routes::lock_utils,
+    services::{authentication as auth, authorization::permissions::Permission},
+};
 use crate::{
     core::{api_locking, fraud_check as frm_core},
     services::{self, api},
@@ -30,3 +38,256 @@ pub async fn frm_fulfillment(
     ))
     .await
 }
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+fn get_review_locking_action(
+    flow: Flow,
+    payment_id: &common_utils::id_type::PaymentId,
+) -> api_locking::LockAction {
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payment_id.get_string_repr().to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow),
+            override_lock_retries: None,
+        },
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn frm_review_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<review_types::FrmReviewListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            review::list_reviews(state, auth.platform, constraints)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: true,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentRead,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn frm_review_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            review::retrieve_review(
+                state,
+                auth.platform,
+                auth.profile.map(|profile| profile.get_id().clone()),
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: true,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn frm_review_assign(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+    json_payload: web::Json<review_types::FrmReviewAssignRequest>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewAssign;
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = path.into_inner();
+    let locking_action = get_review_locking_action(flow.clone(), &payload.payment_id);
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            review::assign_review(
+                state,
+                auth.platform,
+                auth.profile.map(|profile| profile.get_id().clone()),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: true,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn frm_review_add_note(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+    json_payload: web::Json<review_types::FrmReviewNoteRequest>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewAddNote;
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = path.into_inner();
+    let locking_action = get_review_locking_action(flow.clone(), &payload.payment_id);
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            review::add_review_note(
+                state,
+                auth.platform,
+                auth.profile.map(|profile| profile.get_id().clone()),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: true,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn frm_review_approve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+    json_payload: Option<web::Json<review_types::FrmReviewDecisionRequest>>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewApprove;
+    let mut payload = json_payload
+        .map(|json_payload| json_payload.into_inner())
+        .unwrap_or_default();
+    payload.payment_id = path.into_inner();
+    let locking_action = get_review_locking_action(flow.clone(), &payload.payment_id);
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            review::approve_review(
+                state,
+                req_state,
+                auth.platform,
+                auth.profile.map(|profile| profile.get_id().clone()),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: true,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn frm_review_reject(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+    json_payload: Option<web::Json<review_types::FrmReviewDecisionRequest>>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewReject;
+    let mut payload = json_payload
+        .map(|json_payload| json_payload.into_inner())
+        .unwrap_or_default();
+    payload.payment_id = path.into_inner();
+    let locking_action = get_review_locking_action(flow.clone(), &payload.payment_id);
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            review::reject_review(
+                state,
+                req_state,
+                auth.platform,
+                auth.profile.map(|profile| profile.get_id().clone()),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: true,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
@@ -64,6 +64,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
             | Flow::MerchantTransferKey
+            | Flow::MerchantKvMigrationStart
+            | Flow::MerchantKvMigrationRetrieve
             | Flow::MerchantAccountList
             | Flow::EnablePlatformAccount => Self::MerchantAccount,
             Flow::OrganizationCreate
@@ -87,6 +89,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::UpdateDynamicRoutingConfigs
             | Flow::DecisionManagerUpsertConfig
             | Flow::RoutingEvaluateRule
+            | Flow::RoutingSimulate
+            | Flow::RoutingCreateTextConfig
+            | Flow::RoutingRetrieveTextConfig
+            | Flow::RoutingScheduleActivation
+            | Flow::RoutingRetrieveActivationSchedule
+            | Flow::RoutingCancelActivationSchedule
             | Flow::DecisionEngineRuleMigration
             | Flow::VolumeSplitOnRoutingType
             | Flow::DecisionEngineDecideGatewayCall
@@ -106,6 +114,8 @@ impl From<Flow> for ApiIdentifier {
             Flow::DeleteFromBlocklist => Self::Blocklist,
             Flow::ListBlocklist => Self::Blocklist,
             Flow::ToggleBlocklistGuard => Self::Blocklist,
+            Flow::ImportBlocklist => Self::Blocklist,
+            Flow::ExportBlocklist => Self::Blocklist,
             Flow::MerchantConnectorsCreate
             | Flow::MerchantConnectorsRetrieve
             | Flow::MerchantConnectorsUpdate
@@ -166,6 +176,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsExternalAuthentication
             | Flow::PaymentsAuthorize
             | Flow::GetExtendedCardInfo
+            | Flow::PaymentsRoutingDecisionTrace
             | Flow::PaymentsCompleteAuthorize
             | Flow::PaymentsManualUpdate
             | Flow::SessionUpdateTaxCalculation
@@ -183,7 +194,13 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsRetrieveUsingMerchantReferenceId
             | Flow::PaymentAttemptsList
             | Flow::RecoveryPaymentsCreate
-            | Flow::PaymentsSubmitEligibility => Self::Payments,
+            | Flow::PaymentsSubmitEligibility
+            | Flow::FrmReviewList
+            | Flow::FrmReviewRetrieve
+            | Flow::FrmReviewAssign
+            | Flow::FrmReviewAddNote
+            | Flow::FrmReviewApprove
+            | Flow::FrmReviewReject => Self::Payments,
             Flow::PayoutsCreate
             | Flow::PayoutsRetrieve
             | Flow::PayoutsUpdate
@@ -238,7 +255,15 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ProfileDelete
             | Flow::ProfileList
             | Flow::ToggleExtendedCardInfo
-            | Flow::ToggleConnectorAgnosticMit => Self::Profile,
+            | Flow::ToggleConnectorAgnosticMit
+            | Flow::WebhookEndpointCreate
+            | Flow::WebhookEndpointList
+            | Flow::WebhookEndpointRetrieve
+            | Flow::WebhookEndpointUpdate
+            | Flow::WebhookEndpointDelete
+            | Flow::WebhookSecretRotate
+            | Flow::CardTestingGuardBlockList
+            | Flow::CardTestingGuardBlockLift => Self::Profile,
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
             | Flow::PaymentSecureLinkInitiate
@@ -367,7 +392,13 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentMethodSessionUpdateSavedPaymentMethod
             | Flow::PaymentMethodSessionDeleteSavedPaymentMethod
             | Flow::PaymentMethodSessionUpdate => Self::PaymentMethodSession,
-            Flow::RevenueRecoveryRetrieve | Flow::RevenueRecoveryResume => Self::ProcessTracker,
+            Flow::RevenueRecoveryRetrieve
+            | Flow::RevenueRecoveryResume
+            | Flow::ProcessTrackerList
+            | Flow::ProcessTrackerRetrieve
+            | Flow::ProcessTrackerRun
+            | Flow::ProcessTrackerReschedule
+            | Flow::ProcessTrackerFinish => Self::ProcessTracker,
             Flow::AuthenticationCreate
             | Flow::AuthenticationEligibility
             | Flow::AuthenticationSync
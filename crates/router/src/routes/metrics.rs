@@ -11,6 +11,7 @@ counter_metric!(KV_MISS, GLOBAL_METER); // No. of KV misses
 // API Level Metrics
 counter_metric!(REQUESTS_RECEIVED, GLOBAL_METER);
 histogram_metric_f64!(REQUEST_TIME, GLOBAL_METER);
+counter_metric!(REQUESTS_RATE_LIMITED, GLOBAL_METER); // No. of requests rejected for exceeding a rate limit
 
 // Operation Level Metrics
 counter_metric!(PAYMENT_OPS_COUNT, GLOBAL_METER);
@@ -123,6 +124,9 @@ counter_metric!(TASKS_ADDED_COUNT, GLOBAL_METER); // Tasks added to process trac
 counter_metric!(TASK_ADDITION_FAILURES_COUNT, GLOBAL_METER); // Failures in task addition to process tracker
 counter_metric!(TASKS_RESET_COUNT, GLOBAL_METER); // Tasks reset in process tracker for requeue flow
 
+// A counter to indicate the divergences found between redis and Postgres by the KV consistency check
+counter_metric!(KV_CONSISTENCY_DIVERGENCES, GLOBAL_METER);
+
 // Access token metrics
 //
 // A counter to indicate the number of new access tokens created
@@ -139,6 +143,7 @@ counter_metric!(INTEGRITY_CHECK_FAILED, GLOBAL_METER);
 
 // FRM (Fraud Risk Management) metrics
 counter_metric!(FRM_FAILURE, GLOBAL_METER);
+counter_metric!(NATIVE_FRM_DECISIONS, GLOBAL_METER); // No. of fraud decisions taken by the built-in fraud engine
 
 // Network Tokenization metrics
 histogram_metric_f64!(GENERATE_NETWORK_TOKEN_TIME, GLOBAL_METER);
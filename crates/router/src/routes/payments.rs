@@ -2700,6 +2700,48 @@ pub async fn retrieve_extended_card_info(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+/// Retrieve the routing, surcharge and 3DS decisions traced for the attempts of a payment
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRoutingDecisionTrace, payment_id))]
+pub async fn payments_routing_decision_trace(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsRoutingDecisionTrace;
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            payments::routing::decision_trace::retrieve_decision_traces(
+                state,
+                auth.platform,
+                auth.profile.map(|profile| profile.get_id().clone()),
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: true,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "oltp", feature = "v1"))]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsSubmitEligibility, payment_id))]
 pub async fn payments_submit_eligibility(
@@ -1,2 +1,4 @@
+#[cfg(feature = "v1")]
+pub mod admin;
 #[cfg(feature = "v2")]
 pub mod revenue_recovery;
@@ -0,0 +1,133 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::process_tracker::admin as process_tracker_api;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, process_tracker},
+    routes::AppState,
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerList))]
+pub async fn process_tracker_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<process_tracker_api::ProcessTrackerListRequest>,
+) -> HttpResponse {
+    let flow = Flow::ProcessTrackerList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, request, _| process_tracker::list_processes(state, request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRetrieve))]
+pub async fn process_tracker_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ProcessTrackerRetrieve;
+    let payload = process_tracker_api::ProcessTrackerId {
+        process_tracker_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| process_tracker::retrieve_process(state, payload.process_tracker_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRun))]
+pub async fn process_tracker_run(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<process_tracker_api::ProcessTrackerRunRequest>,
+) -> HttpResponse {
+    let flow = Flow::ProcessTrackerRun;
+    let payload = process_tracker_api::ProcessTrackerActionWrapper {
+        process_tracker_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| {
+            process_tracker::run_process_now(state, payload.process_tracker_id, payload.request)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerReschedule))]
+pub async fn process_tracker_reschedule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<process_tracker_api::ProcessTrackerRescheduleRequest>,
+) -> HttpResponse {
+    let flow = Flow::ProcessTrackerReschedule;
+    let payload = process_tracker_api::ProcessTrackerActionWrapper {
+        process_tracker_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| {
+            process_tracker::reschedule_process(state, payload.process_tracker_id, payload.request)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerFinish))]
+pub async fn process_tracker_finish(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<process_tracker_api::ProcessTrackerFinishRequest>,
+) -> HttpResponse {
+    let flow = Flow::ProcessTrackerFinish;
+    let payload = process_tracker_api::ProcessTrackerActionWrapper {
+        process_tracker_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| {
+            process_tracker::finish_process(state, payload.process_tracker_id, payload.request)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
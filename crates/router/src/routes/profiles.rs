@@ -2,6 +2,8 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::{core::webhooks::webhook_secret_rotation, routes::lock_utils};
 use crate::{
     core::{admin::*, api_locking, errors},
     services::{api, authentication as auth, authorization::permissions},
@@ -288,6 +290,63 @@ pub async fn profile_update(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSecretRotate))]
+pub async fn rotate_webhook_signing_secret(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+    json_payload: web::Json<api_models::admin::WebhookSecretRotationRequest>,
+) -> HttpResponse {
+    let flow = Flow::WebhookSecretRotate;
+    let (merchant_id, profile_id) = path.into_inner();
+    // Concurrent rotations of the same profile would overwrite each other's staged secret
+    let locking_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: format!("{}_webhook_secret_rotation", profile_id.get_string_repr()),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data: auth::AuthenticationData, req, _| {
+            webhook_secret_rotation::rotate_webhook_signing_secret(
+                state,
+                auth_data
+                    .platform
+                    .get_processor()
+                    .get_account()
+                    .get_id()
+                    .clone(),
+                auth_data.platform.get_processor().get_key_store().clone(),
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
 #[cfg(feature = "v2")]
 #[instrument(skip_all, fields(flow = ?Flow::ProfileUpdate))]
 pub async fn profile_update(
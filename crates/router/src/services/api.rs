@@ -61,6 +61,7 @@ use crate::{
     core::{
         api_locking,
         errors::{self, CustomResult},
+        rate_limit,
     },
     events::api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
     headers, logger,
@@ -265,6 +266,10 @@ where
 
     tracing::Span::current().record("merchant_id", merchant_id.get_string_repr().to_owned());
 
+    rate_limit::check_rate_limits(&session_state, &flow.to_string(), &auth_type)
+        .await
+        .switch()?;
+
     let output = {
         lock_action
             .clone()
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use error_stack::ResultExt;
-use redis_interface::RedisConnectionPool;
+use redis_interface::{RedisConnectionPool, RedisKey};
 
 use crate::{
     core::errors::{ApiErrorResponse, RouterResult},
@@ -54,7 +54,7 @@ pub async fn increment_blocked_count_in_cache<A>(
     state: &A,
     cache_key: &str,
     expiry: i64,
-) -> RouterResult<()>
+) -> RouterResult<i32>
 where
     A: SessionStateInfo + Sync,
 {
@@ -74,5 +74,90 @@ where
     redis_conn
         .set_key_with_expiry(&cache_key.into(), incremented_blocked_count, expiry)
         .await
+        .change_context(ApiErrorResponse::InternalServerError)?;
+
+    Ok(incremented_blocked_count)
+}
+
+pub async fn delete_blocked_count_from_cache<A>(state: &A, cache_key: &str) -> RouterResult<()>
+where
+    A: SessionStateInfo + Sync,
+{
+    let redis_conn = get_redis_connection(state)?;
+
+    redis_conn
+        .delete_key(&cache_key.into())
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .map(|_| ())
+}
+
+pub async fn get_blocked_counts_from_cache<A>(
+    state: &A,
+    cache_keys: &[String],
+) -> RouterResult<Vec<Option<i32>>>
+where
+    A: SessionStateInfo + Sync,
+{
+    let redis_conn = get_redis_connection(state)?;
+
+    let cache_keys = cache_keys
+        .iter()
+        .map(|cache_key| cache_key.as_str().into())
+        .collect::<Vec<RedisKey>>();
+
+    redis_conn
+        .get_multiple_keys(&cache_keys)
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+}
+
+pub async fn set_tracked_counter_in_cache<A>(
+    state: &A,
+    index_key: &str,
+    counter_id: String,
+    value: String,
+    expiry: i64,
+) -> RouterResult<()>
+where
+    A: SessionStateInfo + Sync,
+{
+    let redis_conn = get_redis_connection(state)?;
+
+    redis_conn
+        .set_hash_fields(&index_key.into(), vec![(counter_id, value)], Some(expiry))
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+}
+
+pub async fn get_tracked_counters_from_cache<A>(
+    state: &A,
+    index_key: &str,
+) -> RouterResult<Vec<(String, String)>>
+where
+    A: SessionStateInfo + Sync,
+{
+    let redis_conn = get_redis_connection(state)?;
+
+    redis_conn
+        .get_hash_fields(&index_key.into())
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+}
+
+pub async fn delete_tracked_counter_from_cache<A>(
+    state: &A,
+    index_key: &str,
+    counter_id: &str,
+) -> RouterResult<()>
+where
+    A: SessionStateInfo + Sync,
+{
+    let redis_conn = get_redis_connection(state)?;
+
+    redis_conn
+        .delete_hash_field(&index_key.into(), counter_id)
+        .await
         .change_context(ApiErrorResponse::InternalServerError)
+        .map(|_| ())
 }
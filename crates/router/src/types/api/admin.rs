@@ -4,12 +4,13 @@ use std::collections::HashMap;
 pub use api_models::admin;
 pub use api_models::{
     admin::{
-        MaskedHeaders, MerchantAccountCreate, MerchantAccountDeleteResponse,
-        MerchantAccountResponse, MerchantAccountUpdate, MerchantConnectorCreate,
-        MerchantConnectorDeleteResponse, MerchantConnectorDetails, MerchantConnectorDetailsWrap,
-        MerchantConnectorId, MerchantConnectorResponse, MerchantDetails, MerchantId,
-        PaymentMethodsEnabled, ProfileCreate, ProfileResponse, ProfileUpdate, ToggleAllKVRequest,
-        ToggleAllKVResponse, ToggleKVRequest, ToggleKVResponse, WebhookDetails,
+        KvMigrationRequest, KvMigrationResponse, MaskedHeaders, MerchantAccountCreate,
+        MerchantAccountDeleteResponse, MerchantAccountResponse, MerchantAccountUpdate,
+        MerchantConnectorCreate, MerchantConnectorDeleteResponse, MerchantConnectorDetails,
+        MerchantConnectorDetailsWrap, MerchantConnectorId, MerchantConnectorResponse,
+        MerchantDetails, MerchantId, PaymentMethodsEnabled, ProfileCreate, ProfileResponse,
+        ProfileUpdate, ToggleAllKVRequest, ToggleAllKVResponse, ToggleKVRequest, ToggleKVResponse,
+        WebhookDetails,
     },
     organization::{
         ConvertOrganizationToPlatformRequest, ConvertOrganizationToPlatformResponse,
@@ -258,6 +259,8 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             merchant_category_code: item.merchant_category_code,
             merchant_country_code: item.merchant_country_code,
             dispute_polling_interval: item.dispute_polling_interval,
+            routing_timezone: item.routing_timezone,
+            native_frm_config: item.native_frm_config,
             is_manual_retry_enabled: item.is_manual_retry_enabled,
             always_enable_overcapture: item.always_enable_overcapture,
             is_external_vault_enabled,
@@ -527,6 +530,8 @@ pub async fn create_profile_from_merchant_account(
         merchant_category_code: request.merchant_category_code,
         merchant_country_code: request.merchant_country_code,
         dispute_polling_interval: request.dispute_polling_interval,
+        routing_timezone: request.routing_timezone,
+        native_frm_config: request.native_frm_config,
         is_manual_retry_enabled: request.is_manual_retry_enabled,
         always_enable_overcapture: request.always_enable_overcapture,
         external_vault_details: domain::ExternalVaultDetails::try_from((
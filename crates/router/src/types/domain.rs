@@ -21,6 +21,7 @@ mod business_profile {
 mod business_profile {
     pub use hyperswitch_domain_models::business_profile::{
         ExternalVaultDetails, Profile, ProfileGeneralUpdate, ProfileSetter, ProfileUpdate,
+        WebhookSecretRotation,
     };
 }
 
@@ -47,6 +47,7 @@ pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 pub use diesel_models::{
     process_tracker::business_status, ProcessTracker, ProcessTrackerNew, ProcessTrackerRunner,
@@ -78,5 +79,5 @@ pub use self::{
     merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
     payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
     routing_algorithm::*, unified_translations::*, user::*, user_authentication_method::*,
-    user_role::*,
+    user_role::*, webhook_endpoint::*,
 };
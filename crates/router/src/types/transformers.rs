@@ -2198,6 +2198,8 @@ impl ForeignFrom<api_models::admin::CardTestingGuardConfig>
             },
             customer_id_blocking_threshold: item.customer_id_blocking_threshold,
             card_testing_guard_expiry: item.card_testing_guard_expiry,
+            allowlisted_ips: item.allowlisted_ips,
+            allowlisted_customer_ids: item.allowlisted_customer_ids,
         }
     }
 }
@@ -2223,6 +2225,8 @@ impl ForeignFrom<diesel_models::business_profile::CardTestingGuardConfig>
             },
             customer_id_blocking_threshold: item.customer_id_blocking_threshold,
             card_testing_guard_expiry: item.card_testing_guard_expiry,
+            allowlisted_ips: item.allowlisted_ips,
+            allowlisted_customer_ids: item.allowlisted_customer_ids,
         }
     }
 }
@@ -20,3 +20,18 @@ pub mod invoice_sync;
 
 #[cfg(feature = "payouts")]
 pub mod payout_sync;
+
+#[cfg(feature = "v1")]
+pub mod webhook_secret_rotation;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod routing_activation;
+
+#[cfg(feature = "v1")]
+pub mod kv_consistency;
+
+#[cfg(feature = "v1")]
+pub mod kv_migration;
+
+#[cfg(all(feature = "frm", feature = "v1"))]
+pub mod frm_review_sla;
@@ -0,0 +1,70 @@
+use common_utils::{errors::CustomResult, ext_traits::ValueExt};
+use diesel_models::process_tracker::business_status;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::fraud_check::review::{self, FrmReviewSlaOutcome, FrmReviewSlaTrackingData},
+    db::StorageInterface,
+    logger,
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+pub struct FrmReviewSlaWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for FrmReviewSlaWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: FrmReviewSlaTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("FrmReviewSlaTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+        let platform = domain::Platform::new(
+            merchant_account.clone(),
+            key_store.clone(),
+            merchant_account,
+            key_store,
+            None,
+        );
+
+        let outcome = review::perform_review_sla_action(state, platform, tracking_data).await?;
+
+        let business_status = match outcome {
+            FrmReviewSlaOutcome::Decided => business_status::COMPLETED_BY_PT,
+            FrmReviewSlaOutcome::AlreadyDecided => business_status::RESOURCE_STATUS_MISMATCH,
+        };
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(?error, "Failed to decide manual review after its SLA");
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,45 @@
+use common_utils::{errors::CustomResult, ext_traits::ValueExt};
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::kv_consistency::{self, KvConsistencyTrackingData},
+    logger,
+    routes::SessionState,
+    types::storage,
+};
+
+pub struct KvConsistencyCheckWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for KvConsistencyCheckWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: KvConsistencyTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("KvConsistencyTrackingData")?;
+
+        let report = kv_consistency::check_kv_consistency(state, &tracking_data.request).await?;
+
+        kv_consistency::complete_kv_consistency_check_task(state, process, tracking_data, report)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(?error, "Failed to perform KV consistency check");
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,56 @@
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{core::kv_migration, logger, routes::SessionState, types::storage};
+
+/// Number of times a failed check of the drainer streams is retried before the migration is
+/// abandoned
+const MAX_KV_MIGRATION_RETRIES: i32 = 5;
+
+pub struct KvMigrationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for KvMigrationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data = kv_migration::parse_tracking_data(process.tracking_data.clone())?;
+
+        let tracking_data = kv_migration::perform_kv_migration_step(state, tracking_data).await?;
+
+        kv_migration::update_kv_migration_task(state, process, tracking_data).await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(?error, "Failed to perform KV migration step");
+
+        // The storage scheme may already have been switched, a transient failure must not leave
+        // the migration unfinished
+        if process.retry_count < MAX_KV_MIGRATION_RETRIES {
+            return state
+                .store
+                .as_scheduler()
+                .retry_process(
+                    process,
+                    kv_migration::get_next_poll_time(common_utils::date_time::now()),
+                )
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed);
+        }
+
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
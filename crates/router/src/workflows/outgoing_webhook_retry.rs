@@ -68,6 +68,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             &tracking_data.primary_object_id,
             tracking_data.event_type,
             delivery_attempt,
+            tracking_data.webhook_endpoint_id.as_deref(),
         )
         .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
         .attach_printable("Failed to generate idempotent event ID")?;
@@ -116,6 +117,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             delivery_attempt: Some(delivery_attempt),
             metadata: initial_event.metadata,
             is_overall_delivery_successful: Some(false),
+            webhook_endpoint_id: initial_event.webhook_endpoint_id,
         };
 
         let event = db
@@ -184,6 +186,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             platform.get_processor(),
                             outgoing_webhook,
                             &business_profile,
+                            None,
                         )
                         .map_err(|error| {
                             logger::error!(
@@ -282,7 +285,23 @@ pub(crate) async fn get_webhook_delivery_retry_schedule_time(
     db: &dyn StorageInterface,
     merchant_id: &id_type::MerchantId,
     retry_count: i32,
+    process_tracker_id: Option<&str>,
 ) -> Option<time::PrimitiveDateTime> {
+    if let Some(policy) = scheduler_utils::get_runner_retry_policy(
+        db,
+        storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+    )
+    .await
+    {
+        let time_delta = scheduler_utils::get_runner_retry_schedule_time(
+            policy,
+            merchant_id,
+            retry_count,
+            process_tracker_id,
+        );
+        return scheduler_utils::get_time_from_delta(time_delta);
+    }
+
     let key = "pt_mapping_outgoing_webhooks";
 
     let result = db
@@ -329,8 +348,13 @@ pub(crate) async fn retry_webhook_delivery_task(
     merchant_id: &id_type::MerchantId,
     process: storage::ProcessTracker,
 ) -> errors::CustomResult<(), errors::StorageError> {
-    let schedule_time =
-        get_webhook_delivery_retry_schedule_time(db, merchant_id, process.retry_count + 1).await;
+    let schedule_time = get_webhook_delivery_retry_schedule_time(
+        db,
+        merchant_id,
+        process.retry_count + 1,
+        Some(&process.id),
+    )
+    .await;
 
     match schedule_time {
         Some(schedule_time) => {
@@ -591,5 +615,12 @@ async fn get_outgoing_webhook_content_and_event_type(
                 Some(EventType::InvoicePaid),
             ))
         }
+        // Routing events are always stored along with their request, and the rollback details
+        // cannot be fetched again once the schedule has completed
+        diesel_models::enums::EventClass::Routing => {
+            Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: tracking_data.primary_object_id.clone(),
+            })
+        }
     }
 }
@@ -266,7 +266,23 @@ pub async fn get_sync_process_schedule_time(
     connector: &str,
     merchant_id: &common_utils::id_type::MerchantId,
     retry_count: i32,
+    process_tracker_id: Option<&str>,
 ) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
+    if let Some(policy) = scheduler_utils::get_runner_retry_policy(
+        db,
+        storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+    )
+    .await
+    {
+        let time_delta = scheduler_utils::get_runner_retry_schedule_time(
+            policy,
+            merchant_id,
+            retry_count,
+            process_tracker_id,
+        );
+        return Ok(scheduler_utils::get_time_from_delta(time_delta));
+    }
+
     let mapping: common_utils::errors::CustomResult<
         process_data::ConnectorPTMapping,
         errors::StorageError,
@@ -300,8 +316,14 @@ pub async fn retry_sync_task(
     merchant_id: common_utils::id_type::MerchantId,
     pt: storage::ProcessTracker,
 ) -> Result<bool, sch_errors::ProcessTrackerError> {
-    let schedule_time =
-        get_sync_process_schedule_time(db, &connector, &merchant_id, pt.retry_count + 1).await?;
+    let schedule_time = get_sync_process_schedule_time(
+        db,
+        &connector,
+        &merchant_id,
+        pt.retry_count + 1,
+        Some(&pt.id),
+    )
+    .await?;
 
     match schedule_time {
         Some(s_time) => {
@@ -329,8 +351,14 @@ pub async fn recovery_retry_sync_task(
     pt: storage::ProcessTracker,
 ) -> Result<bool, sch_errors::ProcessTrackerError> {
     let db = &*state.store;
-    let schedule_time =
-        get_sync_process_schedule_time(db, &connector, &merchant_id, pt.retry_count + 1).await?;
+    let schedule_time = get_sync_process_schedule_time(
+        db,
+        &connector,
+        &merchant_id,
+        pt.retry_count + 1,
+        Some(&pt.id),
+    )
+    .await?;
 
     match schedule_time {
         Some(s_time) => {
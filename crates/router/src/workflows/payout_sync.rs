@@ -230,12 +230,7 @@ impl PayoutSyncWorkFlow {
 
     /// get the schedule time for next process
     pub fn get_schedule_time(mapping: process_data::RetryMapping, retry_count: i32) -> Option<i32> {
-        // For first try, get the `start_after` time
-        if retry_count == 0 {
-            Some(mapping.start_after)
-        } else {
-            scheduler_utils::get_delay(retry_count, &mapping.frequencies)
-        }
+        scheduler_utils::get_retry_delay(&mapping, retry_count)
     }
 
     /// process the task if the status is terminal
@@ -0,0 +1,101 @@
+use common_utils::{errors::CustomResult, ext_traits::ValueExt};
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::routing::scheduled_activation::{self, RoutingActivationOutcome},
+    logger,
+    routes::SessionState,
+    types::storage,
+};
+
+/// Number of times a failed run is retried before the schedule is marked as failed
+const MAX_ROUTING_ACTIVATION_RETRIES: i32 = 3;
+
+pub struct RoutingActivationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for RoutingActivationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: scheduled_activation::RoutingActivationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("RoutingActivationTrackingData")?;
+
+        let outcome =
+            scheduled_activation::perform_routing_activation(state, &process, tracking_data)
+                .await?;
+
+        let db = state.store.as_scheduler();
+        match outcome {
+            RoutingActivationOutcome::Monitoring(tracking_data) => {
+                let process_tracker_update = storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: Some(scheduled_activation::get_next_guardrail_check_time()),
+                    tracking_data: Some(scheduled_activation::encode_tracking_data(
+                        &tracking_data,
+                    )?),
+                    business_status: Some(String::from(
+                        business_status::ROUTING_GUARDRAIL_MONITORING,
+                    )),
+                    status: Some(storage::enums::ProcessTrackerStatus::Pending),
+                    updated_at: Some(common_utils::date_time::now()),
+                };
+                db.update_process(process, process_tracker_update).await?;
+            }
+            RoutingActivationOutcome::Completed => {
+                db.finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?
+            }
+            RoutingActivationOutcome::RolledBack => {
+                db.finish_process_with_business_status(
+                    process,
+                    business_status::ROUTING_ROLLED_BACK,
+                )
+                .await?
+            }
+            RoutingActivationOutcome::Superseded => {
+                db.finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await?
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(?error, "Failed to perform scheduled routing activation");
+
+        // A transient failure must not leave an activated algorithm without its guard-rail
+        if process.retry_count < MAX_ROUTING_ACTIVATION_RETRIES {
+            return state
+                .store
+                .as_scheduler()
+                .retry_process(
+                    process,
+                    scheduled_activation::get_next_guardrail_check_time(),
+                )
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed);
+        }
+
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,49 @@
+use common_utils::{errors::CustomResult, ext_traits::ValueExt};
+use diesel_models::process_tracker::business_status;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::webhooks::{types, webhook_secret_rotation},
+    logger,
+    routes::SessionState,
+    types::storage,
+};
+
+pub struct WebhookSecretRotationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for WebhookSecretRotationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: types::WebhookSecretRotationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("WebhookSecretRotationTrackingData")?;
+
+        webhook_secret_rotation::complete_webhook_secret_rotation(state, &tracking_data).await?;
+
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(?error, "Failed to complete webhook signing secret rotation");
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
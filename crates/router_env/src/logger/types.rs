@@ -92,6 +92,10 @@ pub enum Flow {
     MerchantConnectorsList,
     /// Merchant Transfer Keys
     MerchantTransferKey,
+    /// Merchant KV migration start flow.
+    MerchantKvMigrationStart,
+    /// Merchant KV migration retrieve flow.
+    MerchantKvMigrationRetrieve,
     /// Merchant Connector Webhook register flow.
     MerchantConnectorWebhookRegister,
     /// Merchant Connector Webhook list flow.
@@ -325,6 +329,10 @@ pub enum Flow {
     ListBlocklist,
     /// Toggle blocklist for merchant
     ToggleBlocklistGuard,
+    /// Import blocklist entries from a CSV file
+    ImportBlocklist,
+    /// Export blocklist entries to a CSV file
+    ExportBlocklist,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
     /// Recovery incoming webhook receive
@@ -427,6 +435,18 @@ pub enum Flow {
     DecisionManagerRetrieveConfig,
     /// Manual payment fulfillment acknowledgement
     FrmFulfillment,
+    /// List payments held for a manual fraud review
+    FrmReviewList,
+    /// Retrieve the manual fraud review of a payment
+    FrmReviewRetrieve,
+    /// Assign the manual fraud review of a payment to a reviewer
+    FrmReviewAssign,
+    /// Add a note to the manual fraud review of a payment
+    FrmReviewAddNote,
+    /// Approve a payment held for a manual fraud review
+    FrmReviewApprove,
+    /// Reject a payment held for a manual fraud review
+    FrmReviewReject,
     /// Get connectors feature matrix
     FeatureMatrix,
     /// Change password flow
@@ -609,6 +629,22 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Create a webhook endpoint for a profile
+    WebhookEndpointCreate,
+    /// List the webhook endpoints of a profile
+    WebhookEndpointList,
+    /// Retrieve a webhook endpoint
+    WebhookEndpointRetrieve,
+    /// Update a webhook endpoint
+    WebhookEndpointUpdate,
+    /// Delete a webhook endpoint
+    WebhookEndpointDelete,
+    /// Rotate the webhook signing secret of a profile
+    WebhookSecretRotate,
+    /// List the active card testing guard blocks of a profile
+    CardTestingGuardBlockList,
+    /// Lift a card testing guard block before it expires
+    CardTestingGuardBlockLift,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
@@ -617,6 +653,8 @@ pub enum Flow {
     ToggleConnectorAgnosticMit,
     /// Get the extended card info associated to a payment_id
     GetExtendedCardInfo,
+    /// Get the routing decision traces of the attempts of a payment
+    PaymentsRoutingDecisionTrace,
     /// Manually update the refund details like status, error code, error message etc.
     RefundsManualUpdate,
     /// Manually update the payment details like status, error code, error message etc.
@@ -637,6 +675,18 @@ pub enum Flow {
     VolumeSplitOnRoutingType,
     /// Routing evaluate rule flow
     RoutingEvaluateRule,
+    /// Routing simulation flow
+    RoutingSimulate,
+    /// Routing create config from text flow
+    RoutingCreateTextConfig,
+    /// Routing retrieve config as text flow
+    RoutingRetrieveTextConfig,
+    /// Routing schedule activation flow
+    RoutingScheduleActivation,
+    /// Routing retrieve activation schedule flow
+    RoutingRetrieveActivationSchedule,
+    /// Routing cancel activation schedule flow
+    RoutingCancelActivationSchedule,
     /// Relay flow
     Relay,
     /// Relay retrieve flow
@@ -679,6 +729,16 @@ pub enum Flow {
     RevenueRecoveryRetrieve,
     /// Process Tracker Revenue Recovery Workflow Resume
     RevenueRecoveryResume,
+    /// Process Tracker list flow
+    ProcessTrackerList,
+    /// Process Tracker retrieve flow
+    ProcessTrackerRetrieve,
+    /// Process Tracker run now flow
+    ProcessTrackerRun,
+    /// Process Tracker reschedule flow
+    ProcessTrackerReschedule,
+    /// Process Tracker finish flow
+    ProcessTrackerFinish,
     /// Tokenization flow
     TokenizationCreate,
     /// Tokenization retrieve flow
@@ -3,10 +3,25 @@ use std::collections::HashMap;
 use diesel_models::enums;
 use serde::{Deserialize, Serialize};
 
+/// Retry policy of a process tracker task, the delays of which are in seconds.
+///
+/// Retries are first scheduled as per the step table in `frequencies`, and then with an
+/// exponential backoff if one is configured. The delays can be randomized with `jitter` so that
+/// tasks which failed together are not retried together.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct RetryMapping {
     pub start_after: i32,
+    #[serde(default)]
     pub frequencies: Vec<(i32, i32)>, // (frequency, count)
+    /// Backoff for the retries after the ones covered by `frequencies`
+    #[serde(default)]
+    pub backoff: Option<ExponentialBackoff>,
+    #[serde(default)]
+    pub jitter: RetryJitter,
+    /// Retries which would happen later than this many seconds after the first attempt are not
+    /// scheduled
+    #[serde(default)]
+    pub deadline_in_seconds: Option<i32>,
 }
 
 impl Default for RetryMapping {
@@ -14,10 +29,55 @@ impl Default for RetryMapping {
         Self {
             start_after: 60,
             frequencies: vec![(300, 5)],
+            backoff: None,
+            jitter: RetryJitter::default(),
+            deadline_in_seconds: None,
         }
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExponentialBackoff {
+    /// Delay of the first retry with this backoff
+    pub initial_delay: i32,
+    /// Factor by which the delay grows after every retry
+    #[serde(default = "ExponentialBackoff::default_multiplier")]
+    pub multiplier: i32,
+    /// Delay which the backoff does not grow beyond
+    pub max_delay: i32,
+    /// Number of retries with this backoff, retries are not limited by the backoff if unset
+    pub max_retries: Option<i32>,
+}
+
+impl ExponentialBackoff {
+    fn default_multiplier() -> i32 {
+        2
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RetryJitter {
+    /// The delay is used as is
+    #[default]
+    None,
+    /// The delay is picked at random between zero and the delay
+    Full,
+    /// The delay is picked at random between the first delay of the policy and thrice the delay
+    /// picked for the previous retry of the task, without exceeding the delay. Full jitter is
+    /// applied when the task is not known.
+    Decorrelated,
+}
+
+/// Retry policy configured for a `ProcessTrackerRunner`, which takes precedence over the policy
+/// configured for the flow the task belongs to.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ProcessTrackerRetryPolicy {
+    pub default_mapping: RetryMapping,
+    #[serde(default)]
+    pub custom_merchant_mapping: HashMap<common_utils::id_type::MerchantId, RetryMapping>,
+}
+
 impl TryFrom<serde_json::Value> for RetryMapping {
     type Error = String;
 
@@ -58,6 +118,7 @@ impl Default for SubscriptionInvoiceSyncPTMapping {
             default_mapping: RetryMapping {
                 start_after: 60,
                 frequencies: vec![(300, 5)],
+                ..Default::default()
             },
             max_retries_count: 5,
         }
@@ -78,6 +139,7 @@ impl Default for PaymentMethodsPTMapping {
             default_mapping: RetryMapping {
                 start_after: 900,
                 frequencies: vec![(300, 5)],
+                ..Default::default()
             },
             max_retries_count: 5,
         }
@@ -112,6 +174,7 @@ impl Default for OutgoingWebhookRetryProcessTrackerMapping {
                     // 14th, 15th and 16th attempts happen at intervals of 6 hours each
                     (60 * 60 * 6, 3),
                 ],
+                ..Default::default()
             },
             custom_merchant_mapping: HashMap::new(),
         }
@@ -148,6 +211,7 @@ impl Default for RevenueRecoveryPaymentProcessTrackerMapping {
                     // 13th, 14th and 15th attempts happen at intervals of 18 hours each
                     (60 * 60 * 18, 3),
                 ],
+                ..Default::default()
             },
             custom_merchant_mapping: HashMap::new(),
         }
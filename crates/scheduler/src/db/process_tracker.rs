@@ -55,6 +55,16 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         business_status: &'static str,
     ) -> CustomResult<(), errors::StorageError>;
 
+    /// Finishes the process only if it is still in one of the given statuses, so that a process
+    /// picked up by the scheduler in the meantime is left untouched. Returns whether the process
+    /// was finished.
+    async fn finish_process_if_status_in(
+        &self,
+        id: &str,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        business_status: &'static str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
     async fn find_processes_by_time_status(
         &self,
         time_lower_limit: PrimitiveDateTime,
@@ -62,6 +72,18 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         status: storage_enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_processes_by_constraints(
+        &self,
+        runner: Option<storage::ProcessTrackerRunner>,
+        status: Option<storage_enums::ProcessTrackerStatus>,
+        merchant_id: Option<&common_utils::id_type::MerchantId>,
+        schedule_time_lower_limit: Option<PrimitiveDateTime>,
+        schedule_time_upper_limit: Option<PrimitiveDateTime>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -107,6 +129,31 @@ impl ProcessTrackerInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    async fn list_processes_by_constraints(
+        &self,
+        runner: Option<storage::ProcessTrackerRunner>,
+        status: Option<storage_enums::ProcessTrackerStatus>,
+        merchant_id: Option<&common_utils::id_type::MerchantId>,
+        schedule_time_lower_limit: Option<PrimitiveDateTime>,
+        schedule_time_upper_limit: Option<PrimitiveDateTime>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::list_processes_by_constraints(
+            &conn,
+            runner.map(|runner| runner.to_string()),
+            status,
+            merchant_id,
+            schedule_time_lower_limit,
+            schedule_time_upper_limit,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -192,6 +239,32 @@ impl ProcessTrackerInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    async fn finish_process_if_status_in(
+        &self,
+        id: &str,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        business_status: &'static str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let updated_count = storage::ProcessTracker::update_process_status_by_id_if_status_in(
+            &conn,
+            id.to_owned(),
+            statuses,
+            storage::ProcessTrackerUpdate::StatusUpdate {
+                status: storage_enums::ProcessTrackerStatus::Finish,
+                business_status: Some(String::from(business_status)),
+            },
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+        let is_finished = updated_count > 0;
+        if is_finished {
+            metrics::TASK_FINISHED.add(1, &[]);
+        }
+        Ok(is_finished)
+    }
 }
 
 #[async_trait::async_trait]
@@ -230,6 +303,58 @@ impl ProcessTrackerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn list_processes_by_constraints(
+        &self,
+        runner: Option<storage::ProcessTrackerRunner>,
+        status: Option<storage_enums::ProcessTrackerStatus>,
+        merchant_id: Option<&common_utils::id_type::MerchantId>,
+        schedule_time_lower_limit: Option<PrimitiveDateTime>,
+        schedule_time_upper_limit: Option<PrimitiveDateTime>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let runner = runner.map(|runner| runner.to_string());
+        let mut processes = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                runner
+                    .as_ref()
+                    .is_none_or(|runner| process.runner.as_ref() == Some(runner))
+                    && status.is_none_or(|status| process.status == status)
+                    && merchant_id.is_none_or(|merchant_id| {
+                        process
+                            .tracking_data
+                            .get("merchant_id")
+                            .and_then(serde_json::Value::as_str)
+                            == Some(merchant_id.get_string_repr())
+                    })
+                    && schedule_time_lower_limit.is_none_or(|lower_limit| {
+                        process
+                            .schedule_time
+                            .is_some_and(|schedule_time| schedule_time >= lower_limit)
+                    })
+                    && schedule_time_upper_limit.is_none_or(|upper_limit| {
+                        process
+                            .schedule_time
+                            .is_some_and(|schedule_time| schedule_time <= upper_limit)
+                    })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        processes.sort_by(|a, b| (a.schedule_time, &a.id).cmp(&(b.schedule_time, &b.id)));
+
+        Ok(processes
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or_default())
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .collect())
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -300,4 +425,14 @@ impl ProcessTrackerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn finish_process_if_status_in(
+        &self,
+        _id: &str,
+        _statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        _business_status: &'static str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
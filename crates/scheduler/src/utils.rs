@@ -1,6 +1,6 @@
 use std::sync;
 
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, ext_traits::StringExt};
 use diesel_models::enums::{self, ProcessTrackerStatus};
 pub use diesel_models::process_tracker as storage;
 use error_stack::{report, ResultExt};
@@ -332,17 +332,13 @@ pub fn get_schedule_time(
     merchant_id: &common_utils::id_type::MerchantId,
     retry_count: i32,
 ) -> Option<i32> {
-    let mapping = match mapping.custom_merchant_mapping.get(merchant_id) {
-        Some(map) => map.clone(),
-        None => mapping.default_mapping,
-    };
+    let mapping = get_merchant_retry_mapping(
+        mapping.default_mapping,
+        mapping.custom_merchant_mapping,
+        merchant_id,
+    );
 
-    // For first try, get the `start_after` time
-    if retry_count == 0 {
-        Some(mapping.start_after)
-    } else {
-        get_delay(retry_count, &mapping.frequencies)
-    }
+    get_retry_delay(&mapping, retry_count)
 }
 
 pub fn get_pm_schedule_time(
@@ -355,11 +351,7 @@ pub fn get_pm_schedule_time(
         None => mapping.default_mapping,
     };
 
-    if retry_count == 0 {
-        Some(mapping.start_after)
-    } else {
-        get_delay(retry_count, &mapping.frequencies)
-    }
+    get_retry_delay(&mapping, retry_count)
 }
 
 pub fn get_outgoing_webhook_retry_schedule_time(
@@ -367,17 +359,13 @@ pub fn get_outgoing_webhook_retry_schedule_time(
     merchant_id: &common_utils::id_type::MerchantId,
     retry_count: i32,
 ) -> Option<i32> {
-    let retry_mapping = match mapping.custom_merchant_mapping.get(merchant_id) {
-        Some(map) => map.clone(),
-        None => mapping.default_mapping,
-    };
+    let retry_mapping = get_merchant_retry_mapping(
+        mapping.default_mapping,
+        mapping.custom_merchant_mapping,
+        merchant_id,
+    );
 
-    // For first try, get the `start_after` time
-    if retry_count == 0 {
-        Some(retry_mapping.start_after)
-    } else {
-        get_delay(retry_count, &retry_mapping.frequencies)
-    }
+    get_retry_delay(&retry_mapping, retry_count)
 }
 
 pub fn get_pcr_payments_retry_schedule_time(
@@ -385,18 +373,14 @@ pub fn get_pcr_payments_retry_schedule_time(
     merchant_id: &common_utils::id_type::MerchantId,
     retry_count: i32,
 ) -> Option<i32> {
-    let mapping = match mapping.custom_merchant_mapping.get(merchant_id) {
-        Some(map) => map.clone(),
-        None => mapping.default_mapping,
-    };
+    let mapping = get_merchant_retry_mapping(
+        mapping.default_mapping,
+        mapping.custom_merchant_mapping,
+        merchant_id,
+    );
     // TODO: check if the current scheduled time is not more than the configured timerange
 
-    // For first try, get the `start_after` time
-    if retry_count == 0 {
-        Some(mapping.start_after)
-    } else {
-        get_delay(retry_count, &mapping.frequencies)
-    }
+    get_retry_delay(&mapping, retry_count)
 }
 
 pub fn get_subscription_invoice_sync_retry_schedule_time(
@@ -404,18 +388,214 @@ pub fn get_subscription_invoice_sync_retry_schedule_time(
     merchant_id: &common_utils::id_type::MerchantId,
     retry_count: i32,
 ) -> Option<i32> {
-    let mapping = match mapping.custom_merchant_mapping.get(merchant_id) {
-        Some(map) => map.clone(),
-        None => mapping.default_mapping,
-    };
+    let mapping = get_merchant_retry_mapping(
+        mapping.default_mapping,
+        mapping.custom_merchant_mapping,
+        merchant_id,
+    );
+
+    get_retry_delay(&mapping, retry_count)
+}
+
+/// Retry policy configured for all tasks of the runner, under the key
+/// `pt_retry_policy_{runner}` in configs, eg: `pt_retry_policy_PAYMENTS_SYNC_WORKFLOW`
+///
+/// ```json
+/// {
+///     "default_mapping": {
+///         "start_after": 60,
+///         "frequencies": [[300, 2]],
+///         "backoff": { "initial_delay": 600, "multiplier": 2, "max_delay": 21600, "max_retries": 6 },
+///         "jitter": "full",
+///         "deadline_in_seconds": 86400
+///     },
+///     "custom_merchant_mapping": {}
+/// }
+/// ```
+///
+/// Takes precedence over the retry mapping of the flow the task belongs to when present.
+#[instrument(skip_all)]
+pub async fn get_runner_retry_policy<T>(
+    db: &T,
+    runner: storage::ProcessTrackerRunner,
+) -> Option<process_data::ProcessTrackerRetryPolicy>
+where
+    T: hyperswitch_domain_models::configs::ConfigInterface<
+            Error = storage_impl::errors::StorageError,
+        > + ?Sized,
+{
+    let key = format!("pt_retry_policy_{runner}");
+
+    db.find_config_by_key(&key)
+        .await
+        .and_then(|config| {
+            config
+                .config
+                .parse_struct("ProcessTrackerRetryPolicy")
+                .change_context(storage_impl::errors::StorageError::DeserializationFailed)
+        })
+        .map_err(|error| {
+            if !error.current_context().is_db_not_found() {
+                logger::error!(
+                    ?error,
+                    "Failed to read process tracker retry policy `{key}`"
+                );
+            }
+        })
+        .ok()
+}
+
+/// The ID of the task is required for the decorrelated jitter of the policy to be applied, and can
+/// be omitted when the first attempt of the task is scheduled.
+pub fn get_runner_retry_schedule_time(
+    policy: process_data::ProcessTrackerRetryPolicy,
+    merchant_id: &common_utils::id_type::MerchantId,
+    retry_count: i32,
+    process_tracker_id: Option<&str>,
+) -> Option<i32> {
+    let mapping = get_merchant_retry_mapping(
+        policy.default_mapping,
+        policy.custom_merchant_mapping,
+        merchant_id,
+    );
+
+    get_task_retry_delay(&mapping, retry_count, process_tracker_id)
+}
 
+fn get_merchant_retry_mapping(
+    default_mapping: process_data::RetryMapping,
+    mut custom_merchant_mapping: std::collections::HashMap<
+        common_utils::id_type::MerchantId,
+        process_data::RetryMapping,
+    >,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> process_data::RetryMapping {
+    custom_merchant_mapping
+        .remove(merchant_id)
+        .unwrap_or(default_mapping)
+}
+
+/// Get the delay before the attempt with the given retry count as per the retry policy, the first
+/// attempt being the one with a retry count of zero. Returns `None` once the retries are exhausted
+/// or the deadline of the policy is reached.
+pub fn get_retry_delay(mapping: &process_data::RetryMapping, retry_count: i32) -> Option<i32> {
+    get_task_retry_delay(mapping, retry_count, None)
+}
+
+/// Get the delay before the attempt of the task with the given retry count as per the retry
+/// policy. The previous delays of the task are replayed from its ID for decorrelated jitter, which
+/// falls back to full jitter when the ID is not known.
+pub fn get_task_retry_delay(
+    mapping: &process_data::RetryMapping,
+    retry_count: i32,
+    process_tracker_id: Option<&str>,
+) -> Option<i32> {
+    // For first try, get the `start_after` time
     if retry_count == 0 {
-        Some(mapping.start_after)
-    } else {
-        get_delay(retry_count, &mapping.frequencies)
+        return Some(mapping.start_after);
+    }
+
+    let delay = get_nominal_retry_delay(mapping, retry_count)?;
+
+    if let Some(deadline) = mapping.deadline_in_seconds {
+        let elapsed = (0..=retry_count).try_fold(0i64, |elapsed, count| {
+            get_nominal_retry_delay(mapping, count)
+                .map(|delay| elapsed.saturating_add(i64::from(delay)))
+        })?;
+        if elapsed > i64::from(deadline) {
+            return None;
+        }
+    }
+
+    Some(apply_retry_jitter(
+        mapping,
+        retry_count,
+        delay,
+        process_tracker_id,
+    ))
+}
+
+/// Delay as per the step table and the backoff of the policy, without any jitter
+fn get_nominal_retry_delay(mapping: &process_data::RetryMapping, retry_count: i32) -> Option<i32> {
+    if retry_count == 0 {
+        return Some(mapping.start_after);
+    }
+
+    get_delay(retry_count, &mapping.frequencies).or_else(|| {
+        let backoff = mapping.backoff.as_ref()?;
+        let step_retries = mapping
+            .frequencies
+            .iter()
+            .fold(0i32, |total, &(_, count)| total.saturating_add(count));
+        let backoff_retry = retry_count.checked_sub(step_retries)?;
+
+        if backoff_retry <= 0 || backoff.max_retries.is_some_and(|max| backoff_retry > max) {
+            return None;
+        }
+
+        let exponent = u32::try_from(backoff_retry - 1).ok()?;
+        let delay = backoff
+            .multiplier
+            .checked_pow(exponent)
+            .and_then(|factor| backoff.initial_delay.checked_mul(factor))
+            .unwrap_or(backoff.max_delay);
+
+        Some(delay.min(backoff.max_delay))
+    })
+}
+
+fn apply_retry_jitter(
+    mapping: &process_data::RetryMapping,
+    retry_count: i32,
+    delay: i32,
+    process_tracker_id: Option<&str>,
+) -> i32 {
+    use rand::Rng;
+
+    match (mapping.jitter, process_tracker_id) {
+        (process_data::RetryJitter::None, _) => delay,
+        (process_data::RetryJitter::Decorrelated, Some(process_tracker_id)) => {
+            get_decorrelated_retry_delay(mapping, retry_count, process_tracker_id).unwrap_or(delay)
+        }
+        (process_data::RetryJitter::Full, _) | (process_data::RetryJitter::Decorrelated, None) => {
+            rand::thread_rng().gen_range(0..=delay.max(0))
+        }
     }
 }
 
+/// Each retry picks its delay between the first delay of the policy and thrice the delay actually
+/// picked by the previous retry, without exceeding its nominal delay. The picks are derived from
+/// the ID of the task rather than drawn at random, so that the delays of the previous retries of
+/// the task can be replayed without being stored.
+fn get_decorrelated_retry_delay(
+    mapping: &process_data::RetryMapping,
+    retry_count: i32,
+    process_tracker_id: &str,
+) -> Option<i32> {
+    let base = get_nominal_retry_delay(mapping, 1)?.max(0);
+
+    (2..=retry_count).try_fold(base, |previous_delay, count| {
+        let delay = get_nominal_retry_delay(mapping, count)?.max(0);
+        let upper = delay.min(previous_delay.saturating_mul(3));
+        let lower = base.min(upper);
+        let offset =
+            get_seeded_value(process_tracker_id, count) % (u64::try_from(upper - lower).ok()? + 1);
+
+        lower.checked_add(i32::try_from(offset).ok()?)
+    })
+}
+
+/// Stable hash of the task ID and the retry count (FNV-1a), which does not change across releases
+/// unlike the hashers of the standard library
+fn get_seeded_value(process_tracker_id: &str, retry_count: i32) -> u64 {
+    process_tracker_id
+        .bytes()
+        .chain(retry_count.to_le_bytes())
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
 /// Get the delay based on the retry count
 pub fn get_delay<'a>(
     retry_count: i32,
@@ -502,4 +682,105 @@ mod tests {
             );
         }
     }
+
+    fn get_backoff_mapping(jitter: process_data::RetryJitter) -> process_data::RetryMapping {
+        process_data::RetryMapping {
+            start_after: 60,
+            frequencies: vec![(300, 2)],
+            backoff: Some(process_data::ExponentialBackoff {
+                initial_delay: 600,
+                multiplier: 2,
+                max_delay: 3000,
+                max_retries: Some(5),
+            }),
+            jitter,
+            deadline_in_seconds: None,
+        }
+    }
+
+    #[test]
+    fn test_get_retry_delay_with_backoff() {
+        let mapping = get_backoff_mapping(process_data::RetryJitter::None);
+
+        let delays = (0..=8)
+            .map(|retry_count| get_retry_delay(&mapping, retry_count))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            delays,
+            vec![
+                Some(60),
+                Some(300),
+                Some(300),
+                Some(600),
+                Some(1200),
+                Some(2400),
+                Some(3000),
+                Some(3000),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_retry_delay_with_deadline() {
+        let mapping = process_data::RetryMapping {
+            deadline_in_seconds: Some(2000),
+            ..get_backoff_mapping(process_data::RetryJitter::None)
+        };
+
+        // 60 + 300 + 300 + 600 = 1260 and 1260 + 1200 = 2460
+        assert_eq!(get_retry_delay(&mapping, 3), Some(600));
+        assert_eq!(get_retry_delay(&mapping, 4), None);
+    }
+
+    #[test]
+    fn test_get_retry_delay_with_jitter() {
+        let full = get_backoff_mapping(process_data::RetryJitter::Full);
+        let decorrelated = get_backoff_mapping(process_data::RetryJitter::Decorrelated);
+
+        for _ in 0..100 {
+            let delay = get_retry_delay(&full, 5);
+            assert!(delay.is_some_and(|delay| (0..=2400).contains(&delay)));
+
+            let delay = get_task_retry_delay(&decorrelated, 5, Some("pt_test"));
+            assert!(delay.is_some_and(|delay| (300..=2400).contains(&delay)));
+        }
+
+        assert_eq!(get_retry_delay(&full, 0), Some(60));
+        assert_eq!(get_retry_delay(&full, 8), None);
+    }
+
+    #[test]
+    fn test_decorrelated_jitter_is_bound_by_the_actual_previous_delay() {
+        let mapping = get_backoff_mapping(process_data::RetryJitter::Decorrelated);
+
+        for task_index in 0..100 {
+            let process_tracker_id = format!("pt_test_{task_index}");
+            let delays = (1..=7)
+                .map(|retry_count| {
+                    get_task_retry_delay(&mapping, retry_count, Some(&process_tracker_id))
+                        .expect("Retries of the policy are not exhausted")
+                })
+                .collect::<Vec<_>>();
+
+            // The delays of a task are the same every time they are computed
+            assert_eq!(
+                get_task_retry_delay(&mapping, 7, Some(&process_tracker_id)),
+                delays.last().copied()
+            );
+
+            for (retry_count, (previous_delay, delay)) in
+                (2..).zip(delays.iter().zip(delays.iter().skip(1)))
+            {
+                let nominal_delay = get_nominal_retry_delay(&mapping, retry_count)
+                    .expect("Retries of the policy are not exhausted");
+                assert!(*delay >= 300, "{process_tracker_id}: {delays:?}");
+                assert!(
+                    *delay <= nominal_delay.min(previous_delay * 3),
+                    "{process_tracker_id}: {delays:?}"
+                );
+            }
+        }
+    }
 }
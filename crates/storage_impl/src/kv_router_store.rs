@@ -192,8 +192,18 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         R: KvStorePartition,
     {
         let global_id = format!("{partition_key}");
+        let merchant_id = partition_key
+            .get_merchant_id()
+            .map(|merchant_id| merchant_id.get_string_repr().to_owned());
         let request_id = self.request_id.clone().unwrap_or_default();
 
+        let mut fields = redis_entry
+            .to_field_value_pairs(request_id, global_id)
+            .change_context(RedisError::JsonSerializationFailed)?;
+        // The merchant ID cannot be told apart from the rest of the `global_id`, as it may
+        // contain underscores itself
+        fields.extend(merchant_id.map(|merchant_id| ("merchant_id", merchant_id)));
+
         let shard_key = R::shard_key(partition_key, self.drainer_num_partitions);
         let stream_name = self.get_drainer_stream_name(&shard_key);
         self.router_store
@@ -202,9 +212,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
             .stream_append_entry(
                 &stream_name.into(),
                 &redis_interface::RedisEntryId::AutoGeneratedID,
-                redis_entry
-                    .to_field_value_pairs(request_id, global_id)
-                    .change_context(RedisError::JsonSerializationFailed)?,
+                fields,
             )
             .await
             .map(|_| metrics::KV_PUSHED_TO_DRAINER.add(1, &[]))
@@ -16,3 +16,6 @@ gauge_metric!(IN_MEMORY_CACHE_ENTRY_COUNT, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_HIT, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_MISS, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_EVICTION_COUNT, GLOBAL_METER);
+counter_metric!(IN_MEMORY_CACHE_COALESCED, GLOBAL_METER); // No. of misses served by the load of a concurrent caller
+counter_metric!(IN_MEMORY_CACHE_STALE_HIT, GLOBAL_METER); // No. of expired entries served while being refreshed
+counter_metric!(IN_MEMORY_CACHE_NEGATIVE_HIT, GLOBAL_METER); // No. of lookups answered by a cached absence
@@ -92,11 +92,7 @@ impl MockDb {
             customers: Default::default(),
             refunds: Default::default(),
             processes: Default::default(),
-            redis: Arc::new(
-                RedisStore::new(redis)
-                    .await
-                    .change_context(StorageError::InitializationError)?,
-            ),
+            redis: Arc::new(RedisStore::new_in_memory(redis)),
             api_keys: Default::default(),
             ephemeral_keys: Default::default(),
             cards_info: Default::default(),
@@ -75,6 +75,18 @@ impl PaymentAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn get_attempt_status_with_count(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _routing_approaches: Vec<common_enums::RoutingApproach>,
+        _time_range: &common_utils::types::TimeRange,
+    ) -> CustomResult<Vec<(common_enums::AttemptStatus, i64)>, StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
+
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_attempt_id_processor_merchant_id(
         &self,
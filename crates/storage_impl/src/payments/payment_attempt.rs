@@ -725,6 +725,36 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             er.change_context(new_err)
         })
     }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[instrument(skip_all)]
+    async fn get_attempt_status_with_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        routing_approaches: Vec<common_enums::RoutingApproach>,
+        time_range: &common_utils::types::TimeRange,
+    ) -> CustomResult<Vec<(common_enums::AttemptStatus, i64)>, errors::StorageError> {
+        let conn = self
+            .db_store
+            .get_replica_pool()
+            .get()
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        DieselPaymentAttempt::get_attempt_status_with_count(
+            &conn,
+            merchant_id,
+            profile_id,
+            routing_approaches,
+            time_range,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(*er.current_context());
+            er.change_context(new_err)
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -2042,6 +2072,20 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             )
             .await
     }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[instrument(skip_all)]
+    async fn get_attempt_status_with_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        routing_approaches: Vec<common_enums::RoutingApproach>,
+        time_range: &common_utils::types::TimeRange,
+    ) -> CustomResult<Vec<(common_enums::AttemptStatus, i64)>, errors::StorageError> {
+        self.router_store
+            .get_attempt_status_with_count(merchant_id, profile_id, routing_approaches, time_range)
+            .await
+    }
 }
 
 impl DataModelExt for MandateAmountData {
@@ -31,6 +31,13 @@ impl RedisStore {
         })
     }
 
+    /// Backed by an in-process stand-in for a Redis server, for tests
+    pub fn new_in_memory(conf: &redis_interface::RedisSettings) -> Self {
+        Self {
+            redis_conn: Arc::new(redis_interface::RedisConnectionPool::new_in_memory(conf)),
+        }
+    }
+
     pub fn set_error_callback(&self, callback: tokio::sync::oneshot::Sender<()>) {
         let redis_clone = self.redis_conn.clone();
         let _task_handle = tokio::spawn(
@@ -1,8 +1,10 @@
 use std::{
     any::Any,
     borrow::Cow,
+    collections::HashMap,
     fmt::Debug,
-    sync::{Arc, LazyLock},
+    sync::{Arc, LazyLock, Mutex, PoisonError},
+    time::{Duration, Instant},
 };
 
 use common_utils::{
@@ -10,7 +12,7 @@ use common_utils::{
     ext_traits::ByteSliceExt,
 };
 use dyn_clone::DynClone;
-use error_stack::{Report, ResultExt};
+use error_stack::{report, Report, ResultExt};
 use moka::future::Cache as MokaCache;
 use redis_interface::{errors::RedisError, RedisConnectionPool, RedisValue};
 use router_env::{
@@ -36,9 +38,18 @@ const CACHE_TTI: u64 = 10 * 60;
 /// Max Capacity of Cache in MB
 const MAX_CAPACITY: u64 = 30;
 
+/// Time past their time to live for which entries are served by [`get_or_populate_in_memory`]
+/// while a single caller refreshes them, 5 mins
+const STALE_WHILE_REVALIDATE: u64 = 5 * 60;
+
+/// Time for which the absence of a value is cached, 1 min
+const NEGATIVE_CACHE_TTL: u64 = 60;
+
 /// Config Cache with time_to_live as 30 mins and time_to_idle as 10 mins.
-pub static CONFIG_CACHE: LazyLock<Cache> =
-    LazyLock::new(|| Cache::new("CONFIG_CACHE", CACHE_TTL, CACHE_TTI, None));
+/// The absence of a config is cached for 1 min, inserting a config invalidates it.
+pub static CONFIG_CACHE: LazyLock<Cache> = LazyLock::new(|| {
+    Cache::new("CONFIG_CACHE", CACHE_TTL, CACHE_TTI, None).with_negative_caching(NEGATIVE_CACHE_TTL)
+});
 
 /// Accounts cache with time_to_live as 30 mins and size limit
 pub static ACCOUNTS_CACHE: LazyLock<Cache> =
@@ -190,7 +201,52 @@ dyn_clone::clone_trait_object!(Cacheable);
 
 pub struct Cache {
     name: &'static str,
-    inner: MokaCache<String, Arc<dyn Cacheable>>,
+    inner: MokaCache<String, CacheEntry>,
+    time_to_live: Duration,
+    negative_time_to_live: Option<Duration>,
+    /// Locks of the keys being loaded by [`get_or_populate_in_memory`]
+    loading: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+#[derive(Clone)]
+struct CacheEntry {
+    /// `None` if the value was not found
+    value: Option<Arc<dyn Cacheable>>,
+    inserted_at: Instant,
+}
+
+enum Lookup<T> {
+    Fresh(T),
+    /// Past its time to live, but still within the stale-while-revalidate window
+    Stale(T),
+    NotFound,
+    Miss,
+}
+
+/// Held while loading a key, released when dropped
+struct KeyLock<'a> {
+    cache: &'a Cache,
+    key: String,
+    guard: Option<tokio::sync::OwnedMutexGuard<()>>,
+}
+
+impl Drop for KeyLock<'_> {
+    fn drop(&mut self) {
+        let mut loading = self
+            .cache
+            .loading
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        drop(self.guard.take());
+
+        // Callers waiting on the key hold a reference to its lock as well
+        if loading
+            .get(&self.key)
+            .is_some_and(|lock| Arc::strong_count(lock) == 1)
+        {
+            loading.remove(&self.key);
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -217,6 +273,9 @@ impl Cache {
     /// `time_to_live`: Time in seconds before an object is stored in a caching system before it’s deleted
     /// `time_to_idle`: Time in seconds before a `get` or `insert` operation an object is stored in a caching system before it's deleted
     /// `max_capacity`: Max size in MB's that the cache can hold
+    ///
+    /// Entries are kept for a further stale-while-revalidate window past their time to live, in
+    /// which only [`get_or_populate_in_memory`] serves them.
     pub fn new(
         name: &'static str,
         time_to_live: u64,
@@ -234,8 +293,8 @@ impl Cache {
             );
         };
         let mut cache_builder = MokaCache::builder()
-            .time_to_live(std::time::Duration::from_secs(time_to_live))
-            .time_to_idle(std::time::Duration::from_secs(time_to_idle))
+            .time_to_live(Duration::from_secs(time_to_live + STALE_WHILE_REVALIDATE))
+            .time_to_idle(Duration::from_secs(time_to_idle))
             .eviction_listener(eviction_listener);
 
         if let Some(capacity) = max_capacity {
@@ -245,33 +304,115 @@ impl Cache {
         Self {
             name,
             inner: cache_builder.build(),
+            time_to_live: Duration::from_secs(time_to_live),
+            negative_time_to_live: None,
+            loading: Mutex::default(),
         }
     }
 
+    /// Cache the absence of a value for `time_to_live` seconds when [`get_or_populate_in_memory`]
+    /// fails to find it. Only suitable for caches whose keys are invalidated on insertion.
+    pub fn with_negative_caching(mut self, time_to_live: u64) -> Self {
+        self.negative_time_to_live = Some(Duration::from_secs(time_to_live));
+        self
+    }
+
     pub async fn push<T: Cacheable>(&self, key: CacheKey, val: T) {
-        self.inner.insert(key.into(), Arc::new(val)).await;
+        self.insert(key.into(), Some(Arc::new(val))).await;
+    }
+
+    async fn insert(&self, key: String, value: Option<Arc<dyn Cacheable>>) {
+        let entry = CacheEntry {
+            value,
+            inserted_at: Instant::now(),
+        };
+        self.inner.insert(key, entry).await;
     }
 
     pub async fn get_val<T: Clone + Cacheable>(&self, key: CacheKey) -> Option<T> {
-        let val = self.inner.get::<String>(&key.into()).await;
+        let val = match self.lookup::<T>(&String::from(key)).await {
+            Lookup::Fresh(val) => Some(val),
+            Lookup::Stale(_) | Lookup::NotFound | Lookup::Miss => None,
+        };
 
         // Add cache hit and cache miss metrics
         if val.is_some() {
-            metrics::IN_MEMORY_CACHE_HIT
-                .add(1, router_env::metric_attributes!(("cache_type", self.name)));
+            self.record_metric(&metrics::IN_MEMORY_CACHE_HIT);
         } else {
-            metrics::IN_MEMORY_CACHE_MISS
-                .add(1, router_env::metric_attributes!(("cache_type", self.name)));
+            self.record_metric(&metrics::IN_MEMORY_CACHE_MISS);
         }
 
-        let val = (*val?).as_any().downcast_ref::<T>().cloned();
-
         val
     }
 
-    /// Check if a key exists in cache
+    async fn lookup<T: Clone + Cacheable>(&self, key: &str) -> Lookup<T> {
+        let Some(entry) = self.inner.get(key).await else {
+            return Lookup::Miss;
+        };
+        let age = entry.inserted_at.elapsed();
+
+        match entry.value {
+            Some(value) => match value.as_any().downcast_ref::<T>().cloned() {
+                Some(value) if age < self.time_to_live => Lookup::Fresh(value),
+                Some(value) => Lookup::Stale(value),
+                None => Lookup::Miss,
+            },
+            None if self
+                .negative_time_to_live
+                .is_some_and(|time_to_live| age < time_to_live) =>
+            {
+                Lookup::NotFound
+            }
+            None => Lookup::Miss,
+        }
+    }
+
+    /// Waits for the caller loading the key, if any, before taking over its lock
+    async fn lock_key(&self, key: &str) -> KeyLock<'_> {
+        let lock = Arc::clone(
+            self.loading
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .entry(key.to_owned())
+                .or_default(),
+        );
+
+        KeyLock {
+            cache: self,
+            key: key.to_owned(),
+            guard: Some(lock.lock_owned().await),
+        }
+    }
+
+    /// Takes the lock of the key only if no caller is loading it
+    fn try_lock_key(&self, key: &str) -> Option<KeyLock<'_>> {
+        let lock = Arc::clone(
+            self.loading
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .entry(key.to_owned())
+                .or_default(),
+        );
+
+        Some(KeyLock {
+            cache: self,
+            key: key.to_owned(),
+            guard: Some(lock.try_lock_owned().ok()?),
+        })
+    }
+
+    fn record_metric(&self, metric: &router_env::opentelemetry::metrics::Counter<u64>) {
+        metric.add(1, router_env::metric_attributes!(("cache_type", self.name)));
+    }
+
+    /// Check if a fresh value exists in cache for the key
     pub async fn exists(&self, key: CacheKey) -> bool {
-        self.inner.contains_key::<String>(&key.into())
+        self.inner
+            .get::<String>(&key.into())
+            .await
+            .is_some_and(|entry| {
+                entry.value.is_some() && entry.inserted_at.elapsed() < self.time_to_live
+            })
     }
 
     pub async fn remove(&self, key: CacheKey) {
@@ -357,29 +498,93 @@ where
             RedisError::RedisConnectionError.into(),
         ))
         .attach_printable("Failed to get redis connection")?;
-    let cache_val = cache
-        .get_val::<T>(CacheKey {
-            key: key.to_string(),
-            prefix: redis.key_prefix.clone(),
-        })
-        .await;
-    if let Some(val) = cache_val {
-        Ok(val)
-    } else {
-        let val = get_or_populate_redis(redis, key, fun).await?;
-        cache
-            .push(
-                CacheKey {
-                    key: key.to_string(),
-                    prefix: redis.key_prefix.clone(),
-                },
-                val.clone(),
-            )
-            .await;
-        Ok(val)
+    let cache_key = String::from(CacheKey {
+        key: key.to_string(),
+        prefix: redis.key_prefix.clone(),
+    });
+
+    match cache.lookup::<T>(&cache_key).await {
+        Lookup::Fresh(val) => {
+            cache.record_metric(&metrics::IN_MEMORY_CACHE_HIT);
+            return Ok(val);
+        }
+        Lookup::NotFound => {
+            cache.record_metric(&metrics::IN_MEMORY_CACHE_NEGATIVE_HIT);
+            return Err(cached_not_found(key));
+        }
+        Lookup::Stale(val) => {
+            // A single caller refreshes the entry, the others are served the stale value meanwhile
+            let Some(_key_lock) = cache.try_lock_key(&cache_key) else {
+                cache.record_metric(&metrics::IN_MEMORY_CACHE_STALE_HIT);
+                return Ok(val);
+            };
+
+            return match populate_in_memory(redis, key, fun, cache, cache_key.clone()).await {
+                Err(error) if !error.current_context().is_db_not_found() => {
+                    logger::error!(?error, "Failed to refresh the stale entry of {cache_key}");
+                    cache.record_metric(&metrics::IN_MEMORY_CACHE_STALE_HIT);
+                    Ok(val)
+                }
+                result => result,
+            };
+        }
+        Lookup::Miss => {}
+    }
+
+    // Concurrent misses of the key wait for the first one to load it, instead of loading it again
+    let _key_lock = cache.lock_key(&cache_key).await;
+    match cache.lookup::<T>(&cache_key).await {
+        Lookup::Fresh(val) | Lookup::Stale(val) => {
+            cache.record_metric(&metrics::IN_MEMORY_CACHE_COALESCED);
+            Ok(val)
+        }
+        Lookup::NotFound => {
+            cache.record_metric(&metrics::IN_MEMORY_CACHE_COALESCED);
+            Err(cached_not_found(key))
+        }
+        Lookup::Miss => {
+            cache.record_metric(&metrics::IN_MEMORY_CACHE_MISS);
+            populate_in_memory(redis, key, fun, cache, cache_key).await
+        }
     }
 }
 
+async fn populate_in_memory<T, F, Fut>(
+    redis: &Arc<RedisConnectionPool>,
+    key: &str,
+    fun: F,
+    cache: &Cache,
+    cache_key: String,
+) -> CustomResult<T, StorageError>
+where
+    T: Cacheable + serde::Serialize + serde::de::DeserializeOwned + Debug + Clone,
+    F: FnOnce() -> Fut + Send,
+    Fut: futures::Future<Output = CustomResult<T, StorageError>> + Send,
+{
+    match get_or_populate_redis(redis, key, fun).await {
+        Ok(val) => {
+            cache.insert(cache_key, Some(Arc::new(val.clone()))).await;
+            Ok(val)
+        }
+        Err(error) => {
+            if error.current_context().is_db_not_found() {
+                // The stale entry of a deleted value must not be served to concurrent callers
+                match cache.negative_time_to_live {
+                    Some(_) => cache.insert(cache_key, None).await,
+                    None => cache.inner.invalidate(&cache_key).await,
+                }
+            }
+            Err(error)
+        }
+    }
+}
+
+fn cached_not_found(key: &str) -> Report<StorageError> {
+    report!(StorageError::ValueNotFound(format!(
+        "{key} was cached as not found"
+    )))
+}
+
 #[instrument(skip_all)]
 pub async fn redact_from_redis_and_publish<
     'a,
@@ -460,8 +665,24 @@ where
 
 #[cfg(test)]
 mod cache_tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
     use super::*;
 
+    struct InMemoryRedis(Arc<RedisConnectionPool>);
+
+    impl RedisConnInterface for InMemoryRedis {
+        fn get_redis_conn(&self) -> error_stack::Result<Arc<RedisConnectionPool>, RedisError> {
+            Ok(Arc::clone(&self.0))
+        }
+    }
+
+    fn store() -> InMemoryRedis {
+        InMemoryRedis(Arc::new(RedisConnectionPool::new_in_memory(
+            &redis_interface::RedisSettings::default(),
+        )))
+    }
+
     #[tokio::test]
     async fn construct_and_get_cache() {
         let cache = Cache::new("test", 1800, 1800, None);
@@ -562,4 +783,110 @@ mod cache_tests {
             None
         );
     }
+
+    #[tokio::test]
+    async fn concurrent_misses_load_once() {
+        let cache = Cache::new("test", 1800, 1800, None);
+        let store = store();
+        let loads = &AtomicUsize::new(0);
+
+        let results = futures::future::join_all((0..10).map(|_| {
+            get_or_populate_in_memory(
+                &store,
+                "key",
+                || async move {
+                    loads.fetch_add(1, Ordering::SeqCst);
+                    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                    Ok("val".to_string())
+                },
+                &cache,
+            )
+        }))
+        .await;
+
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+        assert!(results
+            .into_iter()
+            .all(|result| result.is_ok_and(|val| val == "val")));
+    }
+
+    #[tokio::test]
+    async fn absence_is_cached() {
+        let cache = Cache::new("test", 1800, 1800, None).with_negative_caching(60);
+        let store = store();
+        let loads = &AtomicUsize::new(0);
+
+        for _ in 0..2 {
+            let result = get_or_populate_in_memory(
+                &store,
+                "key",
+                || async move {
+                    loads.fetch_add(1, Ordering::SeqCst);
+                    Err::<String, _>(report!(StorageError::ValueNotFound("key".to_string())))
+                },
+                &cache,
+            )
+            .await;
+            assert!(result.is_err_and(|error| error.current_context().is_db_not_found()));
+        }
+
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn stale_value_is_served_while_refreshing() {
+        let cache = Cache::new("test", 1, 1800, None);
+        let store = store();
+        let cache_key = CacheKey {
+            key: "key".to_string(),
+            prefix: String::new(),
+        };
+        cache.push(cache_key.clone(), "stale".to_string()).await;
+        tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+        assert_eq!(cache.get_val::<String>(cache_key.clone()).await, None);
+
+        // Another caller is refreshing the entry
+        let key_lock = cache.try_lock_key(&String::from(cache_key.clone()));
+        assert!(key_lock.is_some());
+        let val =
+            get_or_populate_in_memory(&store, "key", || async { Ok("fresh".to_string()) }, &cache)
+                .await;
+        assert_eq!(val.ok(), Some("stale".to_string()));
+
+        drop(key_lock);
+        let val =
+            get_or_populate_in_memory(&store, "key", || async { Ok("fresh".to_string()) }, &cache)
+                .await;
+        assert_eq!(val.ok(), Some("fresh".to_string()));
+        assert_eq!(
+            cache.get_val::<String>(cache_key).await,
+            Some("fresh".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn stale_value_is_removed_when_not_found() {
+        let cache = Cache::new("test", 1, 1800, None);
+        let store = store();
+        let cache_key = CacheKey {
+            key: "key".to_string(),
+            prefix: String::new(),
+        };
+        cache.push(cache_key.clone(), "stale".to_string()).await;
+        tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+
+        let result = get_or_populate_in_memory(
+            &store,
+            "key",
+            || async { Err::<String, _>(report!(StorageError::ValueNotFound("key".to_string()))) },
+            &cache,
+        )
+        .await;
+        assert!(result.is_err_and(|error| error.current_context().is_db_not_found()));
+        assert!(cache
+            .inner
+            .get::<String>(&String::from(cache_key))
+            .await
+            .is_none());
+    }
 }
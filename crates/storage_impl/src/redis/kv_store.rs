@@ -60,6 +60,25 @@ pub enum PartitionKey<'a> {
         id: &'a common_utils::id_type::GlobalPaymentId,
     },
 }
+
+impl<'a> PartitionKey<'a> {
+    /// Merchant whose entity is stored in the partition, the global IDs are not scoped to one
+    pub fn get_merchant_id(&self) -> Option<&'a common_utils::id_type::MerchantId> {
+        match *self {
+            Self::MerchantIdPaymentId { merchant_id, .. }
+            | Self::MerchantIdCustomerId { merchant_id, .. }
+            | Self::MerchantIdPayoutId { merchant_id, .. }
+            | Self::MerchantIdPayoutAttemptId { merchant_id, .. }
+            | Self::MerchantIdMandateId { merchant_id, .. } => Some(merchant_id),
+            #[cfg(feature = "v2")]
+            Self::MerchantIdMerchantReferenceId { merchant_id, .. } => Some(merchant_id),
+            Self::CombinationKey { .. } => None,
+            #[cfg(feature = "v2")]
+            Self::GlobalId { .. } | Self::GlobalPaymentId { .. } => None,
+        }
+    }
+}
+
 // PartitionKey::MerchantIdPaymentId {merchant_id, payment_id}
 impl std::fmt::Display for PartitionKey<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
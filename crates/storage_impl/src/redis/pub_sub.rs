@@ -1,7 +1,7 @@
 use std::sync::atomic;
 
 use error_stack::ResultExt;
-use redis_interface::{errors as redis_errors, EventInterface, PubsubInterface, RedisValue};
+use redis_interface::{errors as redis_errors, RedisValue};
 use router_env::{logger, tracing::Instrument};
 
 use crate::redis::cache::{
@@ -32,7 +32,7 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
         self.subscriber.manage_subscriptions();
 
         self.subscriber
-            .subscribe::<_>(channel)
+            .subscribe(channel)
             .await
             .change_context(redis_errors::RedisError::SubscribeError)?;
 
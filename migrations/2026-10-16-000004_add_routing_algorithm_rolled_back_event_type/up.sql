@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TYPE "EventType"
+ADD VALUE IF NOT EXISTS 'routing_algorithm_rolled_back';
+
+ALTER TYPE "EventObjectType"
+ADD VALUE IF NOT EXISTS 'routing_rollback_details';
+
+ALTER TYPE "EventClass"
+ADD VALUE IF NOT EXISTS 'routing';
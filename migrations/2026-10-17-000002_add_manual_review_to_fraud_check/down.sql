@@ -0,0 +1,11 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS fraud_check_merchant_id_review_status_index;
+
+ALTER TABLE fraud_check
+DROP COLUMN IF EXISTS review_status,
+DROP COLUMN IF EXISTS review_assigned_to,
+DROP COLUMN IF EXISTS review_assigned_at,
+DROP COLUMN IF EXISTS review_deadline,
+DROP COLUMN IF EXISTS review_notes,
+DROP COLUMN IF EXISTS reviewed_by,
+DROP COLUMN IF EXISTS reviewed_at;
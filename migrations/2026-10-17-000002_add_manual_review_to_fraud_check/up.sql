@@ -0,0 +1,11 @@
+-- Your SQL goes here
+ALTER TABLE fraud_check
+ADD COLUMN IF NOT EXISTS review_status VARCHAR(32),
+ADD COLUMN IF NOT EXISTS review_assigned_to VARCHAR(64),
+ADD COLUMN IF NOT EXISTS review_assigned_at TIMESTAMP,
+ADD COLUMN IF NOT EXISTS review_deadline TIMESTAMP,
+ADD COLUMN IF NOT EXISTS review_notes JSONB,
+ADD COLUMN IF NOT EXISTS reviewed_by VARCHAR(64),
+ADD COLUMN IF NOT EXISTS reviewed_at TIMESTAMP;
+
+CREATE INDEX IF NOT EXISTS fraud_check_merchant_id_review_status_index ON fraud_check (merchant_id, review_status);
@@ -0,0 +1,12 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS blocklist_merchant_id_data_kind_range_start_index;
+
+ALTER TABLE blocklist DROP COLUMN IF EXISTS range_end;
+
+ALTER TABLE blocklist DROP COLUMN IF EXISTS range_start;
+
+ALTER TABLE blocklist DROP COLUMN IF EXISTS expires_at;
+
+-- The added values of "BlocklistDataKind" are left in place, as entries of the new kinds may
+-- already be stored
+SELECT(1);
@@ -0,0 +1,18 @@
+-- Your SQL goes here
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'email';
+
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'ip_address';
+
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'device_fingerprint';
+
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'card_bin_range';
+
+ALTER TABLE blocklist ADD COLUMN IF NOT EXISTS expires_at TIMESTAMP;
+
+-- The bounds of the IP address and card BIN range entries, as fixed width strings so that
+-- they are compared in order
+ALTER TABLE blocklist ADD COLUMN IF NOT EXISTS range_start VARCHAR(32);
+
+ALTER TABLE blocklist ADD COLUMN IF NOT EXISTS range_end VARCHAR(32);
+
+CREATE INDEX IF NOT EXISTS blocklist_merchant_id_data_kind_range_start_index ON blocklist (merchant_id, data_kind, range_start);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+-- Backs the merchant filter of the process tracker admin API, which reads the merchant from the
+-- tracking data of the tasks
+CREATE INDEX CONCURRENTLY IF NOT EXISTS process_tracker_tracking_data_merchant_id_schedule_time_index ON process_tracker ((tracking_data ->> 'merchant_id'), schedule_time);